@@ -8,15 +8,8 @@
 //! cargo run --example generate_and_persist -- --count 100000 --output ./data
 //! ```
 
-use poker_db::{
-    ActionType as DbActionType, GameFormat, HandAction, HandMetadata, ParquetWriteConfig,
-    ParquetWriter, Street as DbStreet,
-};
-use poker_parsers::{
-    synthetic_generator::{generate_synthetic_hands, SyntheticConfig},
-    ActionType, ParsedHand, Street,
-};
-use std::collections::HashMap;
+use poker_db::{ConversionConfig, HandConverter, ParquetWriteConfig, ParquetWriter};
+use poker_parsers::synthetic_generator::{generate_synthetic_hands, SyntheticConfig};
 use std::env;
 use std::time::Instant;
 
@@ -96,9 +89,16 @@ fn main() {
     println!("[2/3] Convirtiendo a formato DB...");
     let conv_start = Instant::now();
 
-    let (metadata_list, actions_list, timestamps) = convert_hands_to_db_format(&result.hands);
+    let mut converter = HandConverter::new(ConversionConfig::default());
+    let batch = converter.convert_batch(&result.hands);
+    let (metadata_list, actions_list, timestamps) =
+        (batch.metadata, batch.actions, batch.timestamps);
     let conv_elapsed = conv_start.elapsed();
 
+    if !batch.errors.is_empty() {
+        println!("      {} manos descartadas", batch.errors.len());
+    }
+
     println!(
         "      Convertidas {} manos y {} acciones en {:.2}s",
         format_number(metadata_list.len()),
@@ -159,115 +159,6 @@ fn main() {
     }
 }
 
-/// Convierte manos parseadas al formato de la base de datos
-fn convert_hands_to_db_format(
-    hands: &[ParsedHand],
-) -> (
-    Vec<HandMetadata>,
-    Vec<HandAction>,
-    HashMap<String, chrono::NaiveDateTime>,
-) {
-    let mut metadata_list = Vec::with_capacity(hands.len());
-    let mut actions_list = Vec::with_capacity(hands.len() * 10);
-    let mut timestamps = HashMap::new();
-
-    for hand in hands {
-        // Parsear timestamp
-        let naive_dt = parse_timestamp(&hand.timestamp);
-        timestamps.insert(hand.hand_id.clone(), naive_dt);
-
-        // Determinar stake string
-        let stake = format!(
-            "NL{}",
-            (hand.big_blind_cents * 100 / 100).max(2) // Simplificado
-        );
-
-        // Crear metadata
-        let metadata = HandMetadata {
-            hand_id: hand.hand_id.clone(),
-            session_id: Some(format!(
-                "SESSION-{}",
-                &hand.hand_id[..8.min(hand.hand_id.len())]
-            )),
-            tournament_id: None,
-            timestamp: naive_dt.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
-            stake,
-            format: GameFormat::Cash,
-            table_name: hand.table_name.clone(),
-            blind_level: hand.small_blind_cents,
-            button_seat: hand.button_seat,
-            created_at: chrono::Utc::now().to_rfc3339(),
-        };
-        metadata_list.push(metadata);
-
-        // Crear acciones
-        for (seq, action) in hand.actions.iter().enumerate() {
-            // Encontrar player_id
-            let player_id = hand
-                .players
-                .iter()
-                .find(|p| p.name == action.player_name)
-                .map(|p| format!("PLAYER-{}", &p.name))
-                .unwrap_or_else(|| format!("PLAYER-{}", &action.player_name));
-
-            let is_hero = hand
-                .players
-                .iter()
-                .any(|p| p.name == action.player_name && p.is_hero);
-
-            // Convertir street
-            let db_street = match action.street {
-                Street::Preflop => DbStreet::Preflop,
-                Street::Flop => DbStreet::Flop,
-                Street::Turn => DbStreet::Turn,
-                Street::River => DbStreet::River,
-            };
-
-            // Convertir action type (filtrar los que no son validos para hands_actions)
-            let db_action_type = match action.action_type {
-                ActionType::Fold => Some(DbActionType::Fold),
-                ActionType::Call => Some(DbActionType::Call),
-                ActionType::Raise => Some(DbActionType::Raise),
-                ActionType::Bet => Some(DbActionType::Bet),
-                ActionType::Check => Some(DbActionType::Check),
-                ActionType::AllIn => Some(DbActionType::AllIn),
-                // Ignorar acciones de ciegas y showdown para la tabla de acciones
-                ActionType::PostSmallBlind
-                | ActionType::PostBigBlind
-                | ActionType::PostAnte
-                | ActionType::Show
-                | ActionType::Collect => None,
-            };
-
-            if let Some(action_type) = db_action_type {
-                let hand_action = HandAction {
-                    action_id: uuid::Uuid::new_v4().to_string(),
-                    hand_id: hand.hand_id.clone(),
-                    player_id,
-                    street: db_street,
-                    action_type,
-                    amount_cents: action.amount_cents.unwrap_or(0),
-                    is_hero_action: is_hero,
-                    ev_cents: None,
-                    action_sequence: seq as i32,
-                    created_at: chrono::Utc::now().to_rfc3339(),
-                };
-                actions_list.push(hand_action);
-            }
-        }
-    }
-
-    (metadata_list, actions_list, timestamps)
-}
-
-/// Parsea timestamp del formato del generador
-fn parse_timestamp(ts: &str) -> chrono::NaiveDateTime {
-    // Formato: "2024/01/15 10:30:00 UTC"
-    chrono::NaiveDateTime::parse_from_str(ts, "%Y/%m/%d %H:%M:%S UTC")
-        .or_else(|_| chrono::NaiveDateTime::parse_from_str(ts, "%Y-%m-%d %H:%M:%S"))
-        .unwrap_or_else(|_| chrono::Utc::now().naive_utc())
-}
-
 fn print_help() {
     println!("Generate and Persist Synthetic Hands");
     println!();
@@ -0,0 +1,29 @@
+-- ============================================================================
+-- MIGRACIÓN 002: hands_players (FACT TABLE)
+-- ============================================================================
+-- Una fila por jugador y mano con su resultado, para consultas como "neto por
+-- posición" o "manos con showdown con AK" sin reconstruirlas desde
+-- hands_actions. Se carga desde data/hands_players/ como las demás tablas de
+-- manos.
+
+CREATE TABLE IF NOT EXISTS hands_players (
+    hand_id VARCHAR NOT NULL,
+    player_id VARCHAR NOT NULL,
+    seat UTINYINT NOT NULL,
+    position VARCHAR,
+    starting_stack_cents BIGINT NOT NULL,
+    hole_cards VARCHAR,
+    is_hero BOOLEAN NOT NULL DEFAULT FALSE,
+    invested_cents BIGINT NOT NULL DEFAULT 0,
+    collected_cents BIGINT NOT NULL DEFAULT 0,
+    net_cents BIGINT NOT NULL DEFAULT 0,
+    rake_cents BIGINT NOT NULL DEFAULT 0,
+    went_to_showdown BOOLEAN NOT NULL DEFAULT FALSE,
+    won_at_showdown BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (hand_id, player_id)
+);
+
+-- Índice para agregaciones por jugador (neto, showdowns...)
+CREATE INDEX IF NOT EXISTS idx_hands_players_player_id
+ON hands_players(player_id);
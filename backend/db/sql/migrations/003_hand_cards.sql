@@ -0,0 +1,107 @@
+-- ============================================================================
+-- MIGRACIÓN 003: CARTAS DE LA MANO (BOARD Y HOLE CARDS)
+-- ============================================================================
+-- Añade a hands_metadata el board (flop, turn, river) y las cartas del héroe
+-- en codificación compacta: rango + palo en minúscula, sin separadores
+-- ("Ah7d2c", "9s", "AhKd"). NULL si la calle no se vio o las cartas no se
+-- conocen. Las cartas de villanos vistas en showdown ya viven en
+-- hands_players.hole_cards con la misma codificación.
+--
+-- DuckDB no permite ALTER TABLE sobre tablas con índices, así que la tabla se
+-- recrea (MigrationKind::Rebuild) y se recarga desde data/hands_metadata/.
+
+CREATE TABLE IF NOT EXISTS hands_metadata (
+    hand_id VARCHAR PRIMARY KEY,
+    session_id VARCHAR,
+    tournament_id VARCHAR,
+    timestamp TIMESTAMP NOT NULL,
+    stake VARCHAR NOT NULL,
+    format VARCHAR NOT NULL CHECK (format IN ('CASH', 'MTT', 'SNG', 'EXPRESSO')),
+    table_name VARCHAR NOT NULL,
+    blind_level BIGINT NOT NULL,
+    button_seat UTINYINT NOT NULL,
+    max_players UTINYINT NOT NULL DEFAULT 6,
+    winner_ids VARCHAR[],
+    flop VARCHAR,
+    turn VARCHAR,
+    river VARCHAR,
+    hero_cards VARCHAR,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_hands_metadata_timestamp
+ON hands_metadata(timestamp);
+
+CREATE INDEX IF NOT EXISTS idx_hands_metadata_format
+ON hands_metadata(format);
+
+CREATE INDEX IF NOT EXISTS idx_hands_metadata_stake
+ON hands_metadata(stake);
+
+CREATE INDEX IF NOT EXISTS idx_hands_metadata_session_id
+ON hands_metadata(session_id);
+
+-- ============================================================================
+-- MACROS DE CARTAS
+-- ============================================================================
+-- Operan sobre cadenas compactas. Los palos van en minúscula y los rangos en
+-- mayúscula o dígito, así que contar un palo es contar su letra.
+
+-- Board completo visto (flop + turn + river, ignorando calles NULL)
+CREATE OR REPLACE MACRO full_board(flop, turn, river) AS
+    concat(coalesce(flop, ''), coalesce(turn, ''), coalesce(river, ''));
+
+-- Número de cartas de una cadena compacta
+CREATE OR REPLACE MACRO card_count(cards) AS
+    CAST(length(cards) / 2 AS INTEGER);
+
+-- Cartas de un palo concreto ('c', 'd', 'h', 's')
+CREATE OR REPLACE MACRO suit_count(cards, suit) AS
+    length(cards) - length(replace(cards, suit, ''));
+
+-- Máximo de cartas de un mismo palo
+CREATE OR REPLACE MACRO max_suit_count(cards) AS
+    greatest(
+        suit_count(cards, 'c'),
+        suit_count(cards, 'd'),
+        suit_count(cards, 'h'),
+        suit_count(cards, 's')
+    );
+
+-- Flop o turn con dos cartas del mismo palo (proyecto de color posible)
+CREATE OR REPLACE MACRO has_flush_draw(board) AS
+    card_count(board) BETWEEN 3 AND 4 AND max_suit_count(board) = 2;
+
+-- Board con tres o más cartas del mismo palo (color posible)
+CREATE OR REPLACE MACRO has_flush_possible(board) AS
+    max_suit_count(board) >= 3;
+
+-- Todas las cartas del board del mismo palo
+CREATE OR REPLACE MACRO is_monotone(board) AS
+    card_count(board) >= 3 AND max_suit_count(board) = card_count(board);
+
+-- Ningún palo repetido en el board
+CREATE OR REPLACE MACRO is_rainbow(board) AS
+    card_count(board) >= 3 AND max_suit_count(board) = 1;
+
+-- Board con al menos un rango repetido
+CREATE OR REPLACE MACRO is_paired(board) AS
+    len(list_distinct(regexp_extract_all(board, '[2-9TJQKA]'))) < card_count(board);
+
+-- Clase de mano de dos cartas ("AKs", "T9o", "QQ"); NULL si no se conocen
+CREATE OR REPLACE MACRO hand_class(hole) AS
+    CASE
+        WHEN hole IS NULL OR length(hole) <> 4 THEN NULL
+        ELSE
+            CASE
+                WHEN instr('23456789TJQKA', substr(hole, 1, 1))
+                    >= instr('23456789TJQKA', substr(hole, 3, 1))
+                THEN substr(hole, 1, 1) || substr(hole, 3, 1)
+                ELSE substr(hole, 3, 1) || substr(hole, 1, 1)
+            END
+            || CASE
+                WHEN substr(hole, 1, 1) = substr(hole, 3, 1) THEN ''
+                WHEN substr(hole, 2, 1) = substr(hole, 4, 1) THEN 's'
+                ELSE 'o'
+            END
+    END;
@@ -6,6 +6,10 @@
 -- - 64GB RAM (In-Memory Strategy)
 -- - Operaciones vectorizadas SIMD
 -- - Consultas analíticas de winrate/ROI
+--
+-- Migración 1 (línea base) del esquema versionado: los cambios posteriores
+-- van en sql/migrations/NNN_nombre.sql (ver src/migrations.rs). Los PRAGMA
+-- finales no se ejecutan al migrar; los aplica DbConfig.
 -- ============================================================================
 
 -- ============================================================================
@@ -23,10 +27,9 @@ CREATE TABLE IF NOT EXISTS players (
     updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
 );
 
--- Índice para filtros rápidos de Hero
+-- Índice para filtros rápidos de Hero (DuckDB no admite índices parciales)
 CREATE INDEX IF NOT EXISTS idx_players_is_hero 
-ON players(is_hero) 
-WHERE is_hero = TRUE;
+ON players(is_hero);
 
 -- Tabla: player_aliases
 -- Gestión de múltiples nicknames y cuentas (multi-sala)
@@ -62,6 +65,8 @@ CREATE TABLE IF NOT EXISTS hands_metadata (
     table_name VARCHAR NOT NULL,
     blind_level BIGINT NOT NULL,
     button_seat UTINYINT NOT NULL,
+    max_players UTINYINT NOT NULL DEFAULT 6,
+    winner_ids VARCHAR[],
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
 );
 
@@ -106,8 +111,7 @@ ON hands_actions(player_id, street);
 
 -- Índice para filtros de Hero
 CREATE INDEX IF NOT EXISTS idx_hands_actions_is_hero 
-ON hands_actions(is_hero_action) 
-WHERE is_hero_action = TRUE;
+ON hands_actions(is_hero_action);
 
 -- Índice para análisis por tipo de acción
 CREATE INDEX IF NOT EXISTS idx_hands_actions_action_type 
@@ -193,7 +197,40 @@ CREATE INDEX IF NOT EXISTS idx_tournament_results_player_id
 ON tournament_results(player_id);
 
 -- ============================================================================
--- 4. CONFIGURACIÓN DE DUCKDB PARA OPTIMIZACIÓN
+-- 4. ESTADÍSTICAS (WIDE TABLE)
+-- ============================================================================
+
+-- Tabla: player_stats_flat
+-- Contadores HUD por jugador, stake y posición. Las stats se agregan sumando
+-- contadores, así que el refresco incremental solo procesa manos nuevas.
+CREATE TABLE IF NOT EXISTS player_stats_flat (
+    player_id VARCHAR NOT NULL,
+    stake VARCHAR NOT NULL,
+    position VARCHAR NOT NULL,
+    hands BIGINT NOT NULL DEFAULT 0,
+    vpip_count BIGINT NOT NULL DEFAULT 0,
+    pfr_count BIGINT NOT NULL DEFAULT 0,
+    three_bet_count BIGINT NOT NULL DEFAULT 0,
+    three_bet_opportunities BIGINT NOT NULL DEFAULT 0,
+    postflop_aggressive_count BIGINT NOT NULL DEFAULT 0,
+    postflop_call_count BIGINT NOT NULL DEFAULT 0,
+    saw_flop_count BIGINT NOT NULL DEFAULT 0,
+    went_to_showdown_count BIGINT NOT NULL DEFAULT 0,
+    won_at_showdown_count BIGINT NOT NULL DEFAULT 0,
+    cbet_count BIGINT NOT NULL DEFAULT 0,
+    cbet_opportunities BIGINT NOT NULL DEFAULT 0,
+    updated_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (player_id, stake, position)
+);
+
+-- Tabla: player_stats_hands
+-- Manos ya contabilizadas en player_stats_flat (refresco incremental)
+CREATE TABLE IF NOT EXISTS player_stats_hands (
+    hand_id VARCHAR PRIMARY KEY
+);
+
+-- ============================================================================
+-- 5. CONFIGURACIÓN DE DUCKDB PARA OPTIMIZACIÓN
 -- ============================================================================
 
 -- Configurar DuckDB para aprovechar los 16 threads del Ryzen 3800X
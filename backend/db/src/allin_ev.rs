@@ -0,0 +1,366 @@
+//! # All-in EV Module
+//!
+//! Cálculo del resultado esperado ("all-in adjusted") de las manos en las que
+//! los jugadores quedan all-in antes del river con cartas conocidas.
+//!
+//! ## Algoritmo
+//! 1. `PotResolution` reconstruye lo invertido por cada jugador, el bote
+//!    principal, los side pots y la apuesta no igualada
+//! 2. Se localiza la calle en la que el dinero queda bloqueado: la de la
+//!    última acción que pone fichas. Debe ser anterior al river y como mucho
+//!    un jugador vivo puede conservar fichas detrás
+//! 3. La apuesta no igualada y los botes con un solo jugador elegible van a
+//!    su dueño sin depender de las cartas
+//! 4. Cada bote se reparte según la equity de sus jugadores elegibles con el
+//!    board visible en el momento del all-in (`calculate_equity_multiway`,
+//!    o `calculate_omaha_equity_multiway` en manos de PLO).
+//!    El rake se descuenta de los botes disputados de forma proporcional
+//!
+//! El EV neto de un jugador es `cobro esperado - invertido`, comparable con
+//! `net_won_cents` para las gráficas de "ganado vs esperado".
+
+use poker_math::{calculate_equity_multiway, calculate_omaha_equity_multiway};
+use poker_parsers::pot_resolver::PotResolution;
+use poker_parsers::types::{ActionType as ParsedActionType, ParsedHand, Street as ParsedStreet};
+use std::collections::HashMap;
+
+/// Simulaciones por bote para el cálculo de equity
+pub const DEFAULT_EV_SIMULATIONS: u32 = 20_000;
+
+/// Resultado esperado de un jugador en una mano all-in
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayerEv {
+    pub player_name: String,
+    /// Fichas invertidas en la mano (centavos)
+    pub invested_cents: i64,
+    /// Cobro esperado según equity (centavos, rake descontado)
+    pub expected_collected_cents: i64,
+    /// Resultado neto esperado: cobro esperado - invertido
+    pub ev_cents: i64,
+}
+
+/// EV de una mano con all-in antes del river
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllInEv {
+    /// Calle en la que quedó bloqueado el dinero
+    pub street: ParsedStreet,
+    /// Jugadores que llegaron al showdown
+    pub players: Vec<PlayerEv>,
+}
+
+impl AllInEv {
+    /// EV neto de un jugador, si participó en el all-in
+    pub fn ev_cents(&self, player_name: &str) -> Option<i64> {
+        self.players
+            .iter()
+            .find(|p| p.player_name == player_name)
+            .map(|p| p.ev_cents)
+    }
+}
+
+/// Calcula el EV all-in de una mano a partir de sus botes ya resueltos.
+///
+/// Devuelve `None` si la mano no tiene un all-in antes del river, si queda
+/// acción pendiente tras el all-in o si falta alguna de las cartas de los
+/// jugadores vivos.
+pub fn compute_allin_ev(
+    hand: &ParsedHand,
+    pots: &PotResolution,
+    num_simulations: u32,
+) -> Option<AllInEv> {
+    let street = lock_street(hand)?;
+    let board_len = match street {
+        ParsedStreet::Preflop => 0,
+        ParsedStreet::Flop => 3,
+        ParsedStreet::Turn => 4,
+        ParsedStreet::River => return None,
+    };
+    if hand.board.len() < board_len {
+        return None;
+    }
+
+    let live: Vec<_> = pots
+        .players
+        .iter()
+        .filter(|p| !p.folded && p.invested_cents > 0)
+        .collect();
+    if live.len() < 2 {
+        return None;
+    }
+
+    // Tras el all-in solo un jugador vivo puede conservar fichas
+    if !live.iter().any(|p| p.all_in) || live.iter().filter(|p| !p.all_in).count() > 1 {
+        return None;
+    }
+
+    // Cartas de los jugadores vivos
+    let cards: HashMap<&str, Vec<String>> = live
+        .iter()
+        .map(|result| {
+            let player = hand
+                .players
+                .iter()
+                .find(|p| *p.name == *result.player_name)?;
+            let hole = player.hole_cards.as_ref().or(if player.is_hero {
+                hand.hero_cards.as_ref()
+            } else {
+                None
+            })?;
+            let hole = hole
+                .iter()
+                .map(|c| format!("{}{}", c.rank, c.suit))
+                .collect();
+            Some((result.player_name.as_str(), hole))
+        })
+        .collect::<Option<_>>()?;
+    let board: Vec<String> = hand.board[..board_len]
+        .iter()
+        .map(|c| format!("{}{}", c.rank, c.suit))
+        .collect();
+    let board_refs: Vec<&str> = board.iter().map(String::as_str).collect();
+
+    let contested = pots.contested_cents();
+    let rake_factor = if contested > 0 {
+        (contested - pots.rake_cents.clamp(0, contested)) as f64 / contested as f64
+    } else {
+        1.0
+    };
+
+    let mut expected: HashMap<&str, f64> = HashMap::new();
+    if let Some((name, amount)) = &pots.uncalled {
+        *expected.entry(name.as_str()).or_default() += *amount as f64;
+    }
+    for pot in &pots.pots {
+        if let [only] = &pot.eligible[..] {
+            *expected.entry(only.as_str()).or_default() += pot.amount_cents as f64;
+            continue;
+        }
+
+        let hands: Vec<Vec<&str>> = pot
+            .eligible
+            .iter()
+            .map(|name| {
+                cards
+                    .get(name.as_str())
+                    .map(|hole| hole.iter().map(String::as_str).collect())
+            })
+            .collect::<Option<_>>()?;
+        let hand_refs: Vec<&[&str]> = hands.iter().map(Vec::as_slice).collect();
+        let equities = if hand.variant.is_omaha() {
+            calculate_omaha_equity_multiway(&hand_refs, &board_refs, num_simulations)
+        } else {
+            calculate_equity_multiway(&hand_refs, &board_refs, num_simulations)
+        };
+
+        let total: f64 = equities.iter().sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let amount = pot.amount_cents as f64 * rake_factor;
+        for (name, equity) in pot.eligible.iter().zip(&equities) {
+            *expected.entry(name.as_str()).or_default() += amount * equity / total;
+        }
+    }
+
+    let players = live
+        .iter()
+        .map(|p| {
+            let expected_collected_cents = expected
+                .get(p.player_name.as_str())
+                .copied()
+                .unwrap_or(0.0)
+                .round() as i64;
+            PlayerEv {
+                player_name: p.player_name.clone(),
+                invested_cents: p.invested_cents,
+                expected_collected_cents,
+                ev_cents: expected_collected_cents - p.invested_cents,
+            }
+        })
+        .collect();
+
+    Some(AllInEv { street, players })
+}
+
+/// Calle de la última acción que pone fichas en el bote
+fn lock_street(hand: &ParsedHand) -> Option<ParsedStreet> {
+    hand.actions
+        .iter()
+        .rev()
+        .find(|a| {
+            matches!(
+                a.action_type,
+                ParsedActionType::Call
+                    | ParsedActionType::Bet
+                    | ParsedActionType::Raise
+                    | ParsedActionType::AllIn
+            )
+        })
+        .map(|a| a.street)
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poker_parsers::types::{Action, Card, Player, PotInfo};
+
+    fn cards(cards: &str) -> Vec<Card> {
+        cards.split(' ').filter_map(Card::parse).collect()
+    }
+
+    fn preflop_allin_hand() -> ParsedHand {
+        use ParsedActionType::*;
+        let pf = ParsedStreet::Preflop;
+        ParsedHand {
+            hand_id: "h1".to_string(),
+            small_blind_cents: 5,
+            big_blind_cents: 10,
+            players: vec![
+                Player::new("hero", 1, 1000)
+                    .hero()
+                    .with_cards(cards("Ah As")),
+                Player::new("villain", 2, 1000).with_cards(cards("Kd Kc")),
+            ],
+            board: ["2c", "7d", "9h", "Js", "3c"]
+                .iter()
+                .filter_map(|c| Card::parse(c))
+                .collect(),
+            actions: vec![
+                Action::new("hero", PostSmallBlind, Some(5), pf),
+                Action::new("villain", PostBigBlind, Some(10), pf),
+                Action::new("hero", Raise, Some(1000), pf).all_in(),
+                Action::new("villain", Call, Some(990), pf).all_in(),
+            ],
+            pot: PotInfo {
+                total_cents: 2000,
+                rake_cents: 0,
+                winners: vec![("hero".into(), 2000)],
+            },
+            ..Default::default()
+        }
+    }
+
+    fn ev_of(hand: &ParsedHand) -> Option<AllInEv> {
+        compute_allin_ev(
+            hand,
+            &PotResolution::from_hand(hand),
+            DEFAULT_EV_SIMULATIONS,
+        )
+    }
+
+    #[test]
+    fn test_preflop_allin_ev() {
+        let ev = ev_of(&preflop_allin_hand()).unwrap();
+
+        assert_eq!(ev.street, ParsedStreet::Preflop);
+        assert_eq!(ev.players.len(), 2);
+
+        // AA vs KK: ~82% de equity sobre un bote de 2000
+        let hero = ev.ev_cents("hero").unwrap();
+        assert!((560..=700).contains(&hero), "hero EV {}", hero);
+        let villain = ev.ev_cents("villain").unwrap();
+        assert_eq!(hero + villain, 0);
+    }
+
+    #[test]
+    fn test_no_ev_without_allin_or_cards() {
+        let mut hand = preflop_allin_hand();
+        hand.players[1].hole_cards = None;
+        assert!(ev_of(&hand).is_none());
+
+        // Apuesta en el river: no hay all-in antes del river
+        let mut hand = preflop_allin_hand();
+        for action in hand.actions.iter_mut().skip(2) {
+            action.street = ParsedStreet::River;
+        }
+        assert!(ev_of(&hand).is_none());
+    }
+
+    #[test]
+    fn test_side_pot_ev() {
+        use ParsedActionType::*;
+        let pf = ParsedStreet::Preflop;
+
+        // El corto (AA) solo disputa el bote principal
+        let hand = ParsedHand {
+            players: vec![
+                Player::new("short", 2, 100).with_cards(cards("Ah As")),
+                Player::new("big1", 3, 300).with_cards(cards("Kd Kc")),
+                Player::new("big2", 4, 300).with_cards(cards("Qd Qc")),
+            ],
+            board: ["2c", "7d", "9h", "Js", "3c"]
+                .iter()
+                .filter_map(|c| Card::parse(c))
+                .collect(),
+            actions: vec![
+                Action::new("short", Raise, Some(100), pf).all_in(),
+                Action::new("big1", Raise, Some(300), pf).all_in(),
+                Action::new("big2", Call, Some(300), pf).all_in(),
+            ],
+            pot: PotInfo {
+                total_cents: 700,
+                rake_cents: 0,
+                winners: vec![("short".into(), 300), ("big1".into(), 400)],
+            },
+            ..Default::default()
+        };
+
+        let ev = ev_of(&hand).unwrap();
+        let short = ev
+            .players
+            .iter()
+            .find(|p| p.player_name == "short")
+            .unwrap();
+        assert!(short.expected_collected_cents <= 300);
+        assert!(short.ev_cents > 0);
+
+        let total: i64 = ev.players.iter().map(|p| p.ev_cents).sum();
+        assert!(total.abs() <= 1, "suma de EV {}", total);
+    }
+
+    #[test]
+    fn test_plo_flop_allin_ev() {
+        use poker_parsers::types::GameVariant;
+        use ParsedActionType::*;
+        let flop = ParsedStreet::Flop;
+
+        // Trío de ases contra proyecto de escalera en el flop
+        let mut hand = ParsedHand {
+            variant: GameVariant::PLO4,
+            players: vec![
+                Player::new("hero", 1, 1000)
+                    .hero()
+                    .with_cards(cards("Ah As Kd Qc")),
+                Player::new("villain", 2, 1000).with_cards(cards("Jh Tc 9c 8d")),
+            ],
+            board: ["Ad", "7h", "2c", "3s", "4d"]
+                .iter()
+                .filter_map(|c| Card::parse(c))
+                .collect(),
+            actions: vec![
+                Action::new("hero", Bet, Some(1000), flop).all_in(),
+                Action::new("villain", Call, Some(1000), flop).all_in(),
+            ],
+            pot: PotInfo {
+                total_cents: 2000,
+                rake_cents: 0,
+                winners: vec![("hero".into(), 2000)],
+            },
+            ..Default::default()
+        };
+
+        let ev = ev_of(&hand).unwrap();
+        assert_eq!(ev.street, ParsedStreet::Flop);
+        let hero = ev.ev_cents("hero").unwrap();
+        assert!(hero > 0, "hero EV {}", hero);
+        assert_eq!(hero + ev.ev_cents("villain").unwrap(), 0);
+
+        // Con reglas de Hold'em las manos de 4 cartas no son evaluables
+        hand.variant = GameVariant::Holdem;
+        assert!(ev_of(&hand).is_none());
+    }
+}
@@ -5,12 +5,14 @@
 //! ## Características
 //! - In-Memory Database con 48GB de límite
 //! - 16 threads para paralelización
-//! - Carga automática del esquema SQL
+//! - Carga automática del esquema SQL (migraciones versionadas)
 //! - Soporte para Parquet I/O
 
 use duckdb::{Connection, Result as DuckDbResult};
 use std::path::Path;
 
+use crate::migrations::{MigrationError, MigrationReport, MigrationRunner};
+
 /// Configuración de la base de datos
 #[derive(Debug, Clone)]
 pub struct DbConfig {
@@ -129,49 +131,15 @@ impl DbConnection {
         Ok(())
     }
 
-    /// Inicializa el esquema desde el SQL embebido
-    pub fn init_schema_embedded(&mut self) -> DuckDbResult<()> {
-        const SCHEMA_SQL: &str = include_str!("../sql/schema.sql");
-
-        // Limpiar el SQL: remover comentarios y líneas vacías
-        let cleaned_sql: String = SCHEMA_SQL
-            .lines()
-            .filter(|line| {
-                let trimmed = line.trim();
-                !trimmed.is_empty() && !trimmed.starts_with("--")
-            })
-            .collect::<Vec<_>>()
-            .join("\n");
-
-        // Dividir por punto y coma y ejecutar cada statement
-        let statements: Vec<&str> = cleaned_sql
-            .split(';')
-            .map(|s| s.trim())
-            .filter(|s| !s.is_empty())
-            .collect();
-
-        eprintln!("Executing {} SQL statements...", statements.len());
-
-        for (i, statement) in statements.iter().enumerate() {
-            match self.conn.execute(statement, []) {
-                Ok(_) => {
-                    if statement.starts_with("CREATE TABLE") {
-                        eprintln!("[{}/{}] ✓ Created table", i + 1, statements.len());
-                    } else if statement.starts_with("CREATE INDEX") {
-                        eprintln!("[{}/{}] ✓ Created index", i + 1, statements.len());
-                    } else if statement.starts_with("PRAGMA") {
-                        eprintln!("[{}/{}] ✓ Set pragma", i + 1, statements.len());
-                    }
-                }
-                Err(e) => {
-                    eprintln!("[{}/{}] ✗ Failed: {}", i + 1, statements.len(), e);
-                    eprintln!("   Statement: {}", &statement[..statement.len().min(80)]);
-                    // No retornar error, continuar con el siguiente statement
-                }
-            }
-        }
+    /// Inicializa el esquema desde el SQL embebido aplicando las
+    /// migraciones pendientes (ver `migrations`)
+    pub fn init_schema_embedded(&mut self) -> Result<MigrationReport, MigrationError> {
+        MigrationRunner::new().run(self)
+    }
 
-        Ok(())
+    /// Configuración de la conexión
+    pub fn config(&self) -> &DbConfig {
+        &self.config
     }
 
     /// Obtiene una referencia a la conexión DuckDB subyacente
@@ -191,9 +159,12 @@ impl DbConnection {
             "player_aliases",
             "hands_metadata",
             "hands_actions",
+            "hands_players",
             "cash_sessions",
             "tournaments",
             "tournament_results",
+            "player_stats_flat",
+            "player_stats_hands",
         ];
 
         for table in tables {
@@ -0,0 +1,1455 @@
+//! # Hand Converter Module
+//!
+//! Conversión de manos parseadas (`poker_parsers::ParsedHand`) a filas del
+//! Star Schema (`HandMetadata`, `HandAction`, `HandPlayer`, `CashSession`,
+//! `Player`, `Tournament`, `TournamentResult`).
+//!
+//! ## Responsabilidades
+//! - Asignar `player_id` estables mediante `PlayerAlias` (sala + nickname);
+//!   todas las cuentas del héroe son alias de un mismo `Player`
+//! - Mapear `parsers::ActionType` al conjunto del CHECK de `hands_actions`
+//!   (ciegas, antes, shows y collects no generan filas)
+//! - Numerar `action_sequence` de forma contigua (1-based) dentro de cada mano
+//! - Resolver botes, side pots y rake con `PotResolution` para el resultado
+//!   neto y el rake de las sesiones y de cada jugador (`hands_players`)
+//! - Rellenar `ev_cents` y `ev_won_cents` en manos con all-in antes del river
+//!   (ver `allin_ev`)
+//! - Crear o actualizar `CashSession` del héroe agrupando manos por stake
+//!   y separando sesiones cuando hay un hueco mayor que `session_gap_minutes`
+//! - Registrar los torneos (MTT, SNG, Expresso) de las manos de torneo y los
+//!   resultados de los resúmenes de torneo (`TournamentSummary`)
+//!
+//! En las manos de torneo los importes son fichas: se guardan tal cual en
+//! `hands_actions` pero no se acumulan en sesiones de dinero.
+//!
+//! ## Uso
+//!
+//! ```rust,ignore
+//! use poker_db::{ConversionConfig, HandConverter};
+//!
+//! let mut converter = HandConverter::new(ConversionConfig::default());
+//! let batch = converter.convert_batch(&parsed_hands);
+//!
+//! writer.write_hands_metadata(batch.metadata)?;
+//! writer.write_hands_actions(batch.actions, &batch.timestamps)?;
+//! writer.write_hands_players(batch.players, &batch.timestamps)?;
+//! let sessions = converter.sessions();
+//! ```
+
+use crate::allin_ev::{compute_allin_ev, AllInEv, DEFAULT_EV_SIMULATIONS};
+use crate::schema::{
+    ActionType, CashSession, GameFormat, HandAction, HandMetadata, HandPlayer, Player, PlayerAlias,
+    SiteName, Street, Tournament, TournamentResult,
+};
+use chrono::NaiveDateTime;
+use poker_parsers::hero::HeroConfig;
+use poker_parsers::pot_resolver::{PlayerPotResult, PotResolution};
+use poker_parsers::tournament_summary::TournamentSummary;
+use poker_parsers::types::{
+    Action as ParsedAction, ActionType as ParsedActionType, Card, GameType, ParsedHand, Site,
+    Street as ParsedStreet, TournamentFormat,
+};
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Formato ISO 8601 usado en las columnas TIMESTAMP
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+/// Formatos de timestamp aceptados en `ParsedHand::timestamp`
+const INPUT_TIMESTAMP_FORMATS: [&str; 3] = [
+    "%Y/%m/%d %H:%M:%S UTC", // Parsers de todas las salas y generador sintético
+    "%Y-%m-%d %H:%M:%S",
+    TIMESTAMP_FORMAT,
+];
+
+// ============================================================================
+// ERRORES Y CONFIGURACIÓN
+// ============================================================================
+
+/// Errores de conversión de una mano
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ConversionError {
+    #[error("Mano sin identificador")]
+    MissingHandId,
+    #[error("Mano {hand_id}: timestamp inválido '{value}'")]
+    InvalidTimestamp { hand_id: String, value: String },
+    #[error("Mano {hand_id}: acción de un jugador no sentado '{player}'")]
+    UnknownPlayer { hand_id: String, player: String },
+    #[error("Resumen del torneo {tournament_id} sin jugador o posición final")]
+    IncompleteSummary { tournament_id: String },
+}
+
+/// Configuración de la conversión
+#[derive(Debug, Clone)]
+pub struct ConversionConfig {
+    /// Sala de origen de los resúmenes de torneo (para los aliases). Las
+    /// manos usan la sala detectada por el parser (`ParsedHand::site`)
+    pub site: SiteName,
+    /// Minutos sin manos tras los que se abre una nueva sesión
+    pub session_gap_minutes: i64,
+    /// Simulaciones por bote para el EV all-in (0 = no calcular EV)
+    pub ev_simulations: u32,
+}
+
+impl Default for ConversionConfig {
+    fn default() -> Self {
+        Self {
+            site: SiteName::Winamax,
+            session_gap_minutes: 30,
+            ev_simulations: DEFAULT_EV_SIMULATIONS,
+        }
+    }
+}
+
+impl ConversionConfig {
+    /// Configuración para una sala concreta
+    pub fn for_site(site: SiteName) -> Self {
+        Self {
+            site,
+            ..Self::default()
+        }
+    }
+
+    /// Establece el hueco máximo entre manos de una misma sesión
+    pub fn with_session_gap_minutes(mut self, minutes: i64) -> Self {
+        self.session_gap_minutes = minutes;
+        self
+    }
+
+    /// Establece las simulaciones del EV all-in (0 lo desactiva)
+    pub fn with_ev_simulations(mut self, simulations: u32) -> Self {
+        self.ev_simulations = simulations;
+        self
+    }
+}
+
+// ============================================================================
+// REGISTRO DE JUGADORES
+// ============================================================================
+
+/// Registro de identidades: resuelve (sala, nickname) a un `player_id` estable.
+///
+/// Se puede inicializar con los jugadores y aliases ya persistidos para que
+/// los IDs se mantengan entre importaciones.
+#[derive(Debug, Clone, Default)]
+pub struct PlayerRegistry {
+    players: Vec<Player>,
+    aliases: Vec<PlayerAlias>,
+    /// (sala, nickname) -> índice en `players`
+    by_alias: HashMap<(SiteName, String), usize>,
+    /// Jugadores creados o modificados desde el último `take_pending`
+    dirty_players: Vec<usize>,
+    /// Aliases creados desde el último `take_pending`
+    new_aliases: Vec<usize>,
+}
+
+impl PlayerRegistry {
+    /// Crea un registro vacío
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Crea un registro a partir de filas ya persistidas
+    pub fn with_existing(players: Vec<Player>, aliases: Vec<PlayerAlias>) -> Self {
+        let index: HashMap<&str, usize> = players
+            .iter()
+            .enumerate()
+            .map(|(i, p)| (p.player_id.as_str(), i))
+            .collect();
+
+        let by_alias = aliases
+            .iter()
+            .filter_map(|alias| {
+                index
+                    .get(alias.player_id.as_str())
+                    .map(|&i| ((alias.site_name, alias.site_nickname.clone()), i))
+            })
+            .collect();
+
+        Self {
+            players,
+            aliases,
+            by_alias,
+            dirty_players: Vec::new(),
+            new_aliases: Vec::new(),
+        }
+    }
+
+    /// Devuelve el `player_id` de un nickname, creando jugador y alias si no existe.
+    ///
+    /// Si el nickname es el héroe y el jugador existente no lo estaba marcado,
+    /// se actualiza `is_hero`. Un nickname nuevo del héroe se añade como alias
+    /// del jugador héroe ya registrado, si lo hay.
+    pub fn resolve(&mut self, site: SiteName, nickname: &str, is_hero: bool) -> String {
+        if let Some(&idx) = self.by_alias.get(&(site, nickname.to_string())) {
+            let player = &mut self.players[idx];
+            if is_hero && !player.is_hero {
+                player.is_hero = true;
+                player.updated_at = chrono::Utc::now().to_rfc3339();
+                if !self.dirty_players.contains(&idx) {
+                    self.dirty_players.push(idx);
+                }
+            }
+            return player.player_id.clone();
+        }
+
+        let hero = is_hero
+            .then(|| self.players.iter().position(|p| p.is_hero))
+            .flatten();
+        let idx = hero.unwrap_or_else(|| {
+            self.players
+                .push(Player::new(nickname.to_string(), is_hero));
+            self.dirty_players.push(self.players.len() - 1);
+            self.players.len() - 1
+        });
+        let player_id = self.players[idx].player_id.clone();
+
+        self.new_aliases.push(self.aliases.len());
+        self.aliases.push(PlayerAlias::new(
+            player_id.clone(),
+            site,
+            nickname.to_string(),
+        ));
+        self.by_alias.insert((site, nickname.to_string()), idx);
+
+        player_id
+    }
+
+    /// Registra los alias del héroe limitados a una sala.
+    ///
+    /// Los alias válidos en cualquier sala se añaden al jugador héroe la
+    /// primera vez que aparecen en una mano.
+    pub fn register_heroes(&mut self, heroes: &HeroConfig) {
+        for alias in heroes.aliases() {
+            if let Some(site) = alias.site {
+                self.resolve(map_site(site), &alias.nickname, true);
+            }
+        }
+    }
+
+    /// Busca el `player_id` de un nickname sin crearlo
+    pub fn player_id(&self, site: SiteName, nickname: &str) -> Option<&str> {
+        self.by_alias
+            .get(&(site, nickname.to_string()))
+            .map(|&idx| self.players[idx].player_id.as_str())
+    }
+
+    /// Todos los jugadores conocidos
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    /// Todos los aliases conocidos
+    pub fn aliases(&self) -> &[PlayerAlias] {
+        &self.aliases
+    }
+
+    /// Devuelve los jugadores nuevos o modificados y los aliases nuevos
+    /// desde la última llamada, para persistirlos
+    pub fn take_pending(&mut self) -> (Vec<Player>, Vec<PlayerAlias>) {
+        let players = self
+            .dirty_players
+            .drain(..)
+            .map(|idx| self.players[idx].clone())
+            .collect();
+        let aliases = self
+            .new_aliases
+            .drain(..)
+            .map(|idx| self.aliases[idx].clone())
+            .collect();
+        (players, aliases)
+    }
+}
+
+// ============================================================================
+// CONVERSIÓN
+// ============================================================================
+
+/// Filas generadas para una mano
+#[derive(Debug, Clone)]
+pub struct ConvertedHand {
+    pub metadata: HandMetadata,
+    pub actions: Vec<HandAction>,
+    pub players: Vec<HandPlayer>,
+    /// Timestamp de la mano (para el particionado de `ParquetWriter`)
+    pub timestamp: NaiveDateTime,
+}
+
+/// Resultado de convertir un lote de manos
+#[derive(Debug, Clone, Default)]
+pub struct ConversionBatch {
+    pub metadata: Vec<HandMetadata>,
+    pub actions: Vec<HandAction>,
+    pub players: Vec<HandPlayer>,
+    /// hand_id -> timestamp, formato esperado por `ParquetWriter::write_hands_actions`
+    pub timestamps: HashMap<String, NaiveDateTime>,
+    /// Manos descartadas y el motivo
+    pub errors: Vec<ConversionError>,
+}
+
+/// Sesión de cash abierta con el instante de su última mano
+#[derive(Debug, Clone)]
+struct SessionState {
+    session: CashSession,
+    last_hand: NaiveDateTime,
+    blind_level: i64,
+}
+
+/// Conversor de `ParsedHand` a filas del esquema
+#[derive(Debug, Clone)]
+pub struct HandConverter {
+    config: ConversionConfig,
+    registry: PlayerRegistry,
+    sessions: Vec<SessionState>,
+    tournaments: Vec<Tournament>,
+    results: Vec<TournamentResult>,
+}
+
+impl HandConverter {
+    /// Crea un conversor con un registro de jugadores vacío
+    pub fn new(config: ConversionConfig) -> Self {
+        Self::with_registry(config, PlayerRegistry::new())
+    }
+
+    /// Crea un conversor con un registro de jugadores existente
+    pub fn with_registry(config: ConversionConfig, registry: PlayerRegistry) -> Self {
+        Self {
+            config,
+            registry,
+            sessions: Vec::new(),
+            tournaments: Vec::new(),
+            results: Vec::new(),
+        }
+    }
+
+    /// Retoma sesiones persistidas para que las nuevas manos las actualicen.
+    ///
+    /// `blind_level` es la SB en centavos del stake de cada sesión (para bb/100).
+    pub fn with_sessions(mut self, sessions: Vec<(CashSession, i64)>) -> Self {
+        for (session, blind_level) in sessions {
+            let last = session.end_time.as_deref().unwrap_or(&session.start_time);
+            if let Some(last_hand) = parse_timestamp(last) {
+                self.sessions.push(SessionState {
+                    session,
+                    last_hand,
+                    blind_level,
+                });
+            }
+        }
+        self
+    }
+
+    /// Retoma torneos persistidos para no perder los datos de sus resúmenes
+    /// cuando llegan nuevas manos del mismo torneo.
+    pub fn with_tournaments(mut self, tournaments: Vec<Tournament>) -> Self {
+        self.tournaments.extend(tournaments);
+        self
+    }
+
+    /// Registro de jugadores
+    pub fn registry(&self) -> &PlayerRegistry {
+        &self.registry
+    }
+
+    /// Registro de jugadores (mutable, para `take_pending`)
+    pub fn registry_mut(&mut self) -> &mut PlayerRegistry {
+        &mut self.registry
+    }
+
+    /// Sesiones de cash creadas o actualizadas
+    pub fn sessions(&self) -> Vec<CashSession> {
+        self.sessions.iter().map(|s| s.session.clone()).collect()
+    }
+
+    /// Torneos creados o actualizados
+    pub fn tournaments(&self) -> &[Tournament] {
+        &self.tournaments
+    }
+
+    /// Resultados de torneo generados a partir de resúmenes
+    pub fn tournament_results(&self) -> &[TournamentResult] {
+        &self.results
+    }
+
+    /// Convierte un lote de manos en orden cronológico.
+    ///
+    /// Las manos con errores se descartan y se reportan en `errors`.
+    pub fn convert_batch(&mut self, hands: &[ParsedHand]) -> ConversionBatch {
+        let mut ordered: Vec<&ParsedHand> = hands.iter().collect();
+        ordered.sort_by_key(|hand| parse_timestamp(&hand.timestamp));
+
+        let mut batch = ConversionBatch::default();
+        for hand in ordered {
+            match self.convert(hand) {
+                Ok(converted) => {
+                    batch
+                        .timestamps
+                        .insert(converted.metadata.hand_id.clone(), converted.timestamp);
+                    batch.metadata.push(converted.metadata);
+                    batch.actions.extend(converted.actions);
+                    batch.players.extend(converted.players);
+                }
+                Err(e) => batch.errors.push(e),
+            }
+        }
+        batch
+    }
+
+    /// Convierte una mano y actualiza la sesión del héroe si es de cash.
+    ///
+    /// Las manos deben llegar en orden cronológico para que las sesiones
+    /// se agrupen correctamente (`convert_batch` las ordena).
+    pub fn convert(&mut self, hand: &ParsedHand) -> Result<ConvertedHand, ConversionError> {
+        if hand.hand_id.is_empty() {
+            return Err(ConversionError::MissingHandId);
+        }
+
+        let timestamp =
+            parse_timestamp(&hand.timestamp).ok_or_else(|| ConversionError::InvalidTimestamp {
+                hand_id: hand.hand_id.clone(),
+                value: hand.timestamp.clone(),
+            })?;
+
+        // Validar acciones antes de registrar jugadores
+        if let Some(action) = hand.actions.iter().find(|a| {
+            is_player_action(a.action_type) && !hand.players.iter().any(|p| p.name == a.player_name)
+        }) {
+            return Err(ConversionError::UnknownPlayer {
+                hand_id: hand.hand_id.clone(),
+                player: action.player_name.to_string(),
+            });
+        }
+
+        let site = map_site(hand.site);
+        let player_ids: HashMap<&str, String> = hand
+            .players
+            .iter()
+            .map(|p| (&*p.name, self.registry.resolve(site, &p.name, p.is_hero)))
+            .collect();
+
+        let pots = PotResolution::from_hand(hand);
+        let allin_ev = if self.config.ev_simulations > 0 {
+            compute_allin_ev(hand, &pots, self.config.ev_simulations)
+        } else {
+            None
+        };
+        let actions = convert_actions(hand, &player_ids, allin_ev.as_ref());
+        let players = convert_players(hand, &player_ids, &pots);
+
+        let stake = stake_name(hand);
+        let format = match (hand.game_type, &hand.tournament) {
+            (GameType::CashGame, _) => GameFormat::Cash,
+            (GameType::Tournament, Some(info)) => map_tournament_format(info.format),
+            (GameType::Tournament, None) => GameFormat::MTT,
+        };
+        let tournament_id = self.track_tournament(hand, format, timestamp);
+
+        let hero = hand.players.iter().find(|p| p.is_hero);
+        let session_id = match (hand.game_type, hero) {
+            (GameType::CashGame, Some(hero)) => Some(self.track_session(
+                &player_ids[&*hero.name],
+                &stake,
+                hand,
+                &hero.name,
+                timestamp,
+                &pots,
+                allin_ev.as_ref(),
+            )),
+            _ => None,
+        };
+
+        // Recuperar la apuesta no igualada no es ganar un bote (W$SD)
+        let winner_ids: Vec<String> = pots
+            .players
+            .iter()
+            .filter(|p| pots.won_cents(&p.player_name) > 0)
+            .filter_map(|p| player_ids.get(p.player_name.as_str()).cloned())
+            .collect();
+
+        let (flop, turn, river) = encode_board(&hand.board);
+        let hero_cards = players
+            .iter()
+            .find(|p| p.is_hero)
+            .and_then(|p| p.hole_cards.clone());
+
+        let metadata = HandMetadata {
+            hand_id: hand.hand_id.clone(),
+            session_id,
+            tournament_id,
+            timestamp: timestamp.format(TIMESTAMP_FORMAT).to_string(),
+            stake,
+            format,
+            table_name: hand.table_name.clone(),
+            blind_level: hand.small_blind_cents,
+            button_seat: hand.button_seat,
+            max_players: hand.max_players,
+            winner_ids,
+            flop,
+            turn,
+            river,
+            hero_cards,
+            created_at: chrono::Utc::now().to_rfc3339(),
+        };
+
+        Ok(ConvertedHand {
+            metadata,
+            actions,
+            players,
+            timestamp,
+        })
+    }
+
+    /// Convierte un resumen de torneo en su `TournamentResult`.
+    ///
+    /// Completa el `Tournament` correspondiente con los inscritos, el prize
+    /// pool y la hora de inicio del resumen. Si el resumen se vuelve a
+    /// procesar, el resultado del jugador se reemplaza.
+    pub fn convert_summary(
+        &mut self,
+        summary: &TournamentSummary,
+    ) -> Result<TournamentResult, ConversionError> {
+        let finish_position = match summary.finish_position {
+            Some(position) if !summary.player_name.is_empty() => position,
+            _ => {
+                return Err(ConversionError::IncompleteSummary {
+                    tournament_id: summary.tournament_id.clone(),
+                })
+            }
+        };
+
+        // Los resúmenes solo se generan para el titular de la cuenta
+        let player_id = self
+            .registry
+            .resolve(self.config.site, &summary.player_name, true);
+
+        let start_time =
+            parse_timestamp(&summary.start_time).map(|ts| ts.format(TIMESTAMP_FORMAT).to_string());
+        let tournament = self.tournament_entry(
+            &summary.tournament_id,
+            &summary.name,
+            map_tournament_format(summary.format),
+            start_time.as_deref().unwrap_or(""),
+        );
+        tournament.buyin_cents = summary.buy_in_cents;
+        tournament.rake_cents = summary.fee_cents;
+        tournament.total_entries = summary.registered_players.or(tournament.total_entries);
+        tournament.prize_pool_cents = summary.prize_pool_cents.or(tournament.prize_pool_cents);
+        if let Some(start_time) = start_time {
+            tournament.start_time = start_time;
+        }
+
+        let mut result = TournamentResult::new(
+            summary.tournament_id.clone(),
+            player_id,
+            finish_position,
+            summary.prize_won_cents,
+            summary.bounty_won_cents,
+        );
+        result.calculate_roi(summary.buy_in_cents, summary.fee_cents);
+
+        self.results
+            .retain(|r| r.tournament_id != result.tournament_id || r.player_id != result.player_id);
+        self.results.push(result.clone());
+        Ok(result)
+    }
+
+    /// Registra el torneo de una mano y devuelve su ID.
+    ///
+    /// La hora de inicio se ajusta a la primera mano vista si el torneo aún
+    /// no tiene la del resumen.
+    fn track_tournament(
+        &mut self,
+        hand: &ParsedHand,
+        format: GameFormat,
+        timestamp: NaiveDateTime,
+    ) -> Option<String> {
+        let info = hand.tournament.as_ref()?;
+        if info.tournament_id.is_empty() {
+            return None;
+        }
+
+        let hand_time = timestamp.format(TIMESTAMP_FORMAT).to_string();
+        let tournament = self.tournament_entry(&info.tournament_id, &info.name, format, &hand_time);
+        if tournament.buyin_cents == 0 && tournament.rake_cents == 0 {
+            tournament.buyin_cents = info.buy_in_cents;
+            tournament.rake_cents = info.fee_cents;
+        }
+        let earlier = match parse_timestamp(&tournament.start_time) {
+            Some(start) => timestamp < start,
+            None => true,
+        };
+        if earlier {
+            tournament.start_time = hand_time;
+        }
+
+        Some(info.tournament_id.clone())
+    }
+
+    /// Torneo con ese ID, creándolo si no existe
+    fn tournament_entry(
+        &mut self,
+        tournament_id: &str,
+        name: &str,
+        format: GameFormat,
+        start_time: &str,
+    ) -> &mut Tournament {
+        let idx = match self
+            .tournaments
+            .iter()
+            .position(|t| t.tournament_id == tournament_id)
+        {
+            Some(idx) => idx,
+            None => {
+                self.tournaments.push(Tournament::new(
+                    tournament_id.to_string(),
+                    name.to_string(),
+                    format,
+                    0,
+                    0,
+                    start_time.to_string(),
+                ));
+                self.tournaments.len() - 1
+            }
+        };
+        &mut self.tournaments[idx]
+    }
+
+    /// Añade la mano a la sesión abierta del héroe en ese stake o abre una nueva
+    #[allow(clippy::too_many_arguments)]
+    fn track_session(
+        &mut self,
+        player_id: &str,
+        stake: &str,
+        hand: &ParsedHand,
+        hero_name: &str,
+        timestamp: NaiveDateTime,
+        pots: &PotResolution,
+        allin_ev: Option<&AllInEv>,
+    ) -> String {
+        let gap = chrono::Duration::minutes(self.config.session_gap_minutes);
+
+        let existing = self.sessions.iter().rposition(|s| {
+            s.session.player_id == player_id
+                && s.session.stake == stake
+                && timestamp >= s.last_hand
+                && timestamp - s.last_hand <= gap
+        });
+
+        let idx = match existing {
+            Some(idx) => idx,
+            None => {
+                self.sessions.push(SessionState {
+                    session: CashSession::new(
+                        player_id.to_string(),
+                        timestamp.format(TIMESTAMP_FORMAT).to_string(),
+                        stake.to_string(),
+                    ),
+                    last_hand: timestamp,
+                    blind_level: hand.small_blind_cents,
+                });
+                self.sessions.len() - 1
+            }
+        };
+
+        let state = &mut self.sessions[idx];
+        let net_won = pots.net_cents(hero_name);
+
+        state.last_hand = timestamp;
+        state.session.end_time = Some(timestamp.format(TIMESTAMP_FORMAT).to_string());
+        state.session.hands_played += 1;
+        state.session.net_won_cents += net_won;
+        // Sin all-in el resultado esperado es el real
+        state.session.ev_won_cents += allin_ev
+            .and_then(|ev| ev.ev_cents(hero_name))
+            .unwrap_or(net_won);
+        // Rake proporcional a lo que ganó el héroe (sin la apuesta no igualada)
+        state.session.rake_cents += pots.rake_share_cents(hero_name);
+        state.session.updated_at = chrono::Utc::now().to_rfc3339();
+        state.session.calculate_bb_100(state.blind_level);
+
+        state.session.session_id.clone()
+    }
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+/// Mapea un tipo de acción del parser al conjunto de `hands_actions`.
+///
+/// Los bets y raises all-in se guardan como `ALL_IN`; un call all-in sigue
+/// siendo `CALL`. Ciegas, antes, shows y collects devuelven `None`.
+pub fn map_action_type(action: &ParsedAction) -> Option<ActionType> {
+    match action.action_type {
+        ParsedActionType::Fold => Some(ActionType::Fold),
+        ParsedActionType::Check => Some(ActionType::Check),
+        ParsedActionType::Call => Some(ActionType::Call),
+        ParsedActionType::Bet | ParsedActionType::Raise if action.is_all_in => {
+            Some(ActionType::AllIn)
+        }
+        ParsedActionType::Bet => Some(ActionType::Bet),
+        ParsedActionType::Raise => Some(ActionType::Raise),
+        ParsedActionType::AllIn => Some(ActionType::AllIn),
+        ParsedActionType::PostSmallBlind
+        | ParsedActionType::PostBigBlind
+        | ParsedActionType::PostAnte
+        | ParsedActionType::Show
+        | ParsedActionType::Collect => None,
+    }
+}
+
+/// Acciones que realiza un jugador sentado (excluye shows y collects)
+fn is_player_action(action_type: ParsedActionType) -> bool {
+    !matches!(
+        action_type,
+        ParsedActionType::Show | ParsedActionType::Collect
+    )
+}
+
+fn map_street(street: ParsedStreet) -> Street {
+    match street {
+        ParsedStreet::Preflop => Street::Preflop,
+        ParsedStreet::Flop => Street::Flop,
+        ParsedStreet::Turn => Street::Turn,
+        ParsedStreet::River => Street::River,
+    }
+}
+
+/// Genera las filas de `hands_actions` con `action_sequence` contiguo desde 1.
+///
+/// Con EV all-in, `ev_cents` se guarda en la última acción de cada jugador
+/// que llegó al showdown.
+fn convert_actions(
+    hand: &ParsedHand,
+    player_ids: &HashMap<&str, String>,
+    allin_ev: Option<&AllInEv>,
+) -> Vec<HandAction> {
+    let hero_name = hand.players.iter().find(|p| p.is_hero).map(|p| &p.name);
+    let created_at = chrono::Utc::now().to_rfc3339();
+
+    let mut actions: Vec<HandAction> = hand
+        .actions
+        .iter()
+        .filter_map(|action| {
+            let action_type = map_action_type(action)?;
+            let player_id = player_ids.get(&*action.player_name)?;
+            Some((action, action_type, player_id))
+        })
+        .enumerate()
+        .map(|(seq, (action, action_type, player_id))| HandAction {
+            action_id: uuid::Uuid::new_v4().to_string(),
+            hand_id: hand.hand_id.clone(),
+            player_id: player_id.clone(),
+            street: map_street(action.street),
+            action_type,
+            amount_cents: action.amount_cents.unwrap_or(0),
+            is_hero_action: hero_name == Some(&action.player_name),
+            ev_cents: None,
+            action_sequence: seq as i32 + 1,
+            created_at: created_at.clone(),
+        })
+        .collect();
+
+    if let Some(ev) = allin_ev {
+        for player in &ev.players {
+            let Some(player_id) = player_ids.get(player.player_name.as_str()) else {
+                continue;
+            };
+            if let Some(last) = actions.iter_mut().rev().find(|a| &a.player_id == player_id) {
+                last.ev_cents = Some(player.ev_cents);
+            }
+        }
+    }
+
+    actions
+}
+
+/// Genera la fila de `hands_players` de cada jugador sentado.
+///
+/// Hay showdown si al final de la mano quedan al menos dos jugadores sin
+/// retirarse (el mismo criterio que `player_stats`); lo gana quien cobra de
+/// algún bote (`PotResolution::won_cents`): recuperar la apuesta no igualada
+/// no cuenta.
+/// Solo cuentan los jugadores que actuaron o pusieron fichas: los sentados
+/// que no juegan la mano (sit out) nunca se retiran pero tampoco llegan al
+/// showdown.
+fn convert_players(
+    hand: &ParsedHand,
+    player_ids: &HashMap<&str, String>,
+    pots: &PotResolution,
+) -> Vec<HandPlayer> {
+    let created_at = chrono::Utc::now().to_rfc3339();
+    let live = |result: &PlayerPotResult| {
+        !result.folded
+            && (result.invested_cents > 0
+                || hand
+                    .actions
+                    .iter()
+                    .any(|a| *a.player_name == *result.player_name))
+    };
+    let showdown = pots.players.iter().filter(|p| live(p)).count() >= 2;
+
+    hand.players
+        .iter()
+        .map(|player| {
+            let result = pots.player(&player.name);
+            let invested_cents = result.map_or(0, |r| r.invested_cents);
+            let collected_cents = result.map_or(0, |r| r.collected_cents);
+            let went_to_showdown = showdown && result.is_some_and(live);
+            let hole_cards = match (&player.hole_cards, player.is_hero) {
+                (Some(cards), _) => Some(cards),
+                (None, true) => hand.hero_cards.as_ref(),
+                (None, false) => None,
+            };
+
+            HandPlayer {
+                hand_id: hand.hand_id.clone(),
+                player_id: player_ids[&*player.name].clone(),
+                seat: player.seat,
+                position: player.position.map(|p| p.label().to_string()),
+                starting_stack_cents: player.stack_cents,
+                hole_cards: hole_cards.map(|cards| encode_cards(cards)),
+                is_hero: player.is_hero,
+                invested_cents,
+                collected_cents,
+                net_cents: collected_cents - invested_cents,
+                rake_cents: pots.rake_share_cents(&player.name),
+                went_to_showdown,
+                won_at_showdown: went_to_showdown && pots.won_cents(&player.name) > 0,
+                created_at: created_at.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Codificación compacta de cartas: rango y palo seguidos (`AhKd`)
+pub fn encode_cards(cards: &[Card]) -> String {
+    cards
+        .iter()
+        .flat_map(|card| [card.rank, card.suit])
+        .collect()
+}
+
+/// Separa el board en flop, turn y river codificados con `encode_cards`.
+///
+/// Las calles que no se llegaron a ver quedan en `None`.
+fn encode_board(board: &[Card]) -> (Option<String>, Option<String>, Option<String>) {
+    let street = |range: std::ops::Range<usize>| board.get(range).map(encode_cards);
+    (street(0..3), street(3..4), street(4..5))
+}
+
+/// Sala del parser al conjunto de `SiteName`
+fn map_site(site: Site) -> SiteName {
+    match site {
+        Site::Winamax => SiteName::Winamax,
+        Site::PokerStars => SiteName::PokerStars,
+        Site::GGPoker => SiteName::GGPoker,
+    }
+}
+
+/// Formato de torneo del parser al conjunto de `GameFormat`
+fn map_tournament_format(format: TournamentFormat) -> GameFormat {
+    match format {
+        TournamentFormat::MTT => GameFormat::MTT,
+        TournamentFormat::SNG => GameFormat::SNG,
+        TournamentFormat::Expresso => GameFormat::Expresso,
+    }
+}
+
+/// Nombre del stake: `NL{bb}` en centavos para cash, `SB/BB` en torneos
+fn stake_name(hand: &ParsedHand) -> String {
+    match hand.game_type {
+        GameType::CashGame => format!("NL{}", hand.big_blind_cents),
+        GameType::Tournament => format!("{}/{}", hand.small_blind_cents, hand.big_blind_cents),
+    }
+}
+
+/// Resultado neto de un jugador en la mano (cobrado - invertido).
+///
+/// Atajo sobre `PotResolution`; para varios jugadores de la misma mano es
+/// preferible resolver los botes una sola vez.
+pub fn net_won_cents(hand: &ParsedHand, player_name: &str) -> i64 {
+    PotResolution::from_hand(hand).net_cents(player_name)
+}
+
+/// Parsea los formatos de timestamp soportados
+fn parse_timestamp(value: &str) -> Option<NaiveDateTime> {
+    INPUT_TIMESTAMP_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value.trim(), format).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poker_parsers::types::{Card, Player as ParsedPlayer, Position, PotInfo, TournamentInfo};
+
+    /// Mano NL10: el héroe sube a 0.25, villain paga, el héroe apuesta 0.30
+    /// en el flop, villain foldea y el héroe cobra 0.77 (bote de 0.50 menos
+    /// 0.03 de rake, más su apuesta no igualada).
+    fn sample_hand(hand_id: &str, timestamp: &str) -> ParsedHand {
+        use ParsedActionType::*;
+        use ParsedStreet::*;
+
+        let hand = ParsedHand {
+            hand_id: hand_id.to_string(),
+            table_name: "Nice 01".to_string(),
+            small_blind_cents: 5,
+            big_blind_cents: 10,
+            timestamp: timestamp.to_string(),
+            button_seat: 1,
+            players: vec![
+                ParsedPlayer::new("thesmoy", 1, 1000).hero(),
+                ParsedPlayer::new("villain", 2, 1000),
+            ],
+            actions: vec![
+                ParsedAction::new("thesmoy", PostSmallBlind, Some(5), Preflop),
+                ParsedAction::new("villain", PostBigBlind, Some(10), Preflop),
+                ParsedAction::new("thesmoy", Raise, Some(25), Preflop),
+                ParsedAction::new("villain", Call, Some(15), Preflop),
+                ParsedAction::new("villain", Check, None, Flop),
+                ParsedAction::new("thesmoy", Bet, Some(30), Flop),
+                ParsedAction::new("villain", Fold, None, Flop),
+                ParsedAction::new("thesmoy", Collect, Some(77), Flop),
+            ],
+            pot: PotInfo {
+                total_cents: 77,
+                rake_cents: 3,
+                winners: vec![("thesmoy".into(), 77)],
+            },
+            ..Default::default()
+        };
+        assert!(PotResolution::from_hand(&hand).is_consistent());
+        hand
+    }
+
+    #[test]
+    fn test_convert_hand_rows() {
+        let mut converter = HandConverter::new(ConversionConfig::default());
+        let converted = converter
+            .convert(&sample_hand("H1", "2025/12/15 14:02:20 UTC"))
+            .unwrap();
+
+        assert_eq!(converted.metadata.timestamp, "2025-12-15T14:02:20Z");
+        assert_eq!(converted.metadata.stake, "NL10");
+        assert_eq!(converted.metadata.format, GameFormat::Cash);
+        assert!(converted.metadata.session_id.is_some());
+        assert_eq!(
+            converted.metadata.winner_ids,
+            vec![converted.actions[0].player_id.clone()]
+        );
+
+        // Las ciegas y el collect no generan filas
+        let types: Vec<ActionType> = converted.actions.iter().map(|a| a.action_type).collect();
+        assert_eq!(
+            types,
+            vec![
+                ActionType::Raise,
+                ActionType::Call,
+                ActionType::Check,
+                ActionType::Bet,
+                ActionType::Fold
+            ]
+        );
+        let sequence: Vec<i32> = converted
+            .actions
+            .iter()
+            .map(|a| a.action_sequence)
+            .collect();
+        assert_eq!(sequence, vec![1, 2, 3, 4, 5]);
+        assert!(converted.actions[0].is_hero_action);
+        assert!(!converted.actions[1].is_hero_action);
+        assert_eq!(converted.actions[3].street, Street::Flop);
+    }
+
+    #[test]
+    fn test_hand_players_rows() {
+        let mut converter = HandConverter::new(ConversionConfig::default());
+        let converted = converter
+            .convert(&sample_hand("H1", "2025/12/15 14:02:20 UTC"))
+            .unwrap();
+
+        let hero = &converted.players[0];
+        assert!(hero.is_hero);
+        assert_eq!(hero.seat, 1);
+        assert_eq!(hero.player_id, converted.actions[0].player_id);
+        assert_eq!(
+            (hero.invested_cents, hero.collected_cents, hero.net_cents),
+            (55, 77, 22)
+        );
+        assert_eq!(hero.rake_cents, 3);
+        assert_eq!(hero.hole_cards, None);
+        let villain = &converted.players[1];
+        assert_eq!(villain.net_cents, -25);
+        assert_eq!(villain.rake_cents, 0);
+        // Villain foldea en el flop: no hay showdown
+        assert!(converted
+            .players
+            .iter()
+            .all(|p| !p.went_to_showdown && !p.won_at_showdown));
+
+        // Villain paga la apuesta del flop y gana el showdown
+        let mut hand = sample_hand("H2", "2025/12/15 14:03:00 UTC");
+        hand.players[0].position = Some(Position::Button);
+        hand.players[1].hole_cards =
+            Some(vec![Card::parse("Kd").unwrap(), Card::parse("Kc").unwrap()]);
+        hand.hero_cards = Some(vec![Card::parse("Ah").unwrap(), Card::parse("As").unwrap()]);
+        hand.actions[6] = ParsedAction::new(
+            "villain",
+            ParsedActionType::Call,
+            Some(30),
+            ParsedStreet::Flop,
+        );
+        hand.actions[7] = ParsedAction::new(
+            "villain",
+            ParsedActionType::Collect,
+            Some(107),
+            ParsedStreet::Flop,
+        );
+        hand.pot = PotInfo {
+            total_cents: 110,
+            rake_cents: 3,
+            winners: vec![("villain".into(), 107)],
+        };
+
+        let converted = converter.convert(&hand).unwrap();
+        let (hero, villain) = (&converted.players[0], &converted.players[1]);
+        assert_eq!(hero.position.as_deref(), Some("BTN"));
+        assert_eq!(hero.hole_cards.as_deref(), Some("AhAs"));
+        assert_eq!(villain.hole_cards.as_deref(), Some("KdKc"));
+        assert_eq!((hero.net_cents, villain.net_cents), (-55, 52));
+        assert_eq!(villain.rake_cents, 3);
+        assert!(hero.went_to_showdown && !hero.won_at_showdown);
+        assert!(villain.went_to_showdown && villain.won_at_showdown);
+    }
+
+    #[test]
+    fn test_sitting_out_seat_is_not_at_showdown() {
+        use ParsedActionType::*;
+        use ParsedStreet::Preflop;
+
+        // Walk con un asiento sin jugar: "idle" no actúa ni pone fichas
+        let hand = ParsedHand {
+            players: vec![
+                ParsedPlayer::new("thesmoy", 1, 1000).hero(),
+                ParsedPlayer::new("idle", 2, 1000),
+                ParsedPlayer::new("bb", 3, 1000),
+                ParsedPlayer::new("utg", 4, 1000),
+            ],
+            actions: vec![
+                ParsedAction::new("bb", PostBigBlind, Some(10), Preflop),
+                ParsedAction::new("utg", Fold, None, Preflop),
+                ParsedAction::new("thesmoy", Fold, None, Preflop),
+                ParsedAction::new("bb", Collect, Some(10), Preflop),
+            ],
+            pot: PotInfo {
+                total_cents: 10,
+                rake_cents: 0,
+                winners: vec![("bb".into(), 10)],
+            },
+            ..sample_hand("H1", "2025/12/15 14:02:20 UTC")
+        };
+
+        let mut converter = HandConverter::new(ConversionConfig::default());
+        let converted = converter.convert(&hand).unwrap();
+        assert_eq!(converted.players.len(), 4);
+        assert!(converted
+            .players
+            .iter()
+            .all(|p| !p.went_to_showdown && !p.won_at_showdown));
+        assert_eq!(converted.players[2].net_cents, 0);
+    }
+
+    #[test]
+    fn test_uncalled_bet_is_not_a_showdown_win() {
+        // Bastionkid32 pierde con pareja contra escalera y solo recupera su
+        // subida no igualada ("collected 1.50€ from side pot 1")
+        let content = include_str!("../../parsers/tests/fixtures/winamax/uncalled_side_pot.txt");
+        let hand = poker_parsers::WinamaxParser::new()
+            .parse(content)
+            .hands
+            .remove(0);
+
+        let mut converter = HandConverter::new(ConversionConfig::default());
+        let converted = converter.convert(&hand).unwrap();
+        let (bastion, verlan) = (&converted.players[0], &converted.players[1]);
+        assert_eq!(bastion.collected_cents, 150);
+        assert_eq!(bastion.net_cents, -131);
+        assert_eq!(bastion.rake_cents, 0);
+        assert!(bastion.went_to_showdown && !bastion.won_at_showdown);
+        assert!(verlan.went_to_showdown && verlan.won_at_showdown);
+        assert_eq!(
+            converted.metadata.winner_ids,
+            vec![verlan.player_id.clone()]
+        );
+    }
+
+    #[test]
+    fn test_board_and_hero_cards_in_metadata() {
+        let mut converter = HandConverter::new(ConversionConfig::default());
+        let mut hand = sample_hand("H1", "2025/12/15 14:02:20 UTC");
+        hand.board = ["Ah", "7d", "2c", "9s"]
+            .iter()
+            .filter_map(|c| Card::parse(c))
+            .collect();
+        hand.hero_cards = Some(vec![Card::parse("Kh").unwrap(), Card::parse("Qh").unwrap()]);
+
+        let metadata = converter.convert(&hand).unwrap().metadata;
+        assert_eq!(metadata.flop.as_deref(), Some("Ah7d2c"));
+        assert_eq!(metadata.turn.as_deref(), Some("9s"));
+        assert_eq!(metadata.river, None);
+        assert_eq!(metadata.hero_cards.as_deref(), Some("KhQh"));
+
+        // Mano sin flop ni cartas conocidas
+        let metadata = converter
+            .convert(&sample_hand("H2", "2025/12/15 14:03:00 UTC"))
+            .unwrap()
+            .metadata;
+        assert_eq!(
+            (
+                metadata.flop,
+                metadata.turn,
+                metadata.river,
+                metadata.hero_cards
+            ),
+            (None, None, None, None)
+        );
+    }
+
+    #[test]
+    fn test_player_ids_are_stable() {
+        let mut converter = HandConverter::new(ConversionConfig::default());
+        let first = converter
+            .convert(&sample_hand("H1", "2025/12/15 14:02:20 UTC"))
+            .unwrap();
+        let second = converter
+            .convert(&sample_hand("H2", "2025/12/15 14:03:20 UTC"))
+            .unwrap();
+
+        assert_eq!(first.actions[0].player_id, second.actions[0].player_id);
+        assert_ne!(first.actions[0].player_id, first.actions[1].player_id);
+
+        let (players, aliases) = converter.registry_mut().take_pending();
+        assert_eq!(players.len(), 2);
+        assert_eq!(aliases.len(), 2);
+        assert!(players
+            .iter()
+            .any(|p| p.is_hero && p.display_name == "thesmoy"));
+
+        // Un registro reconstruido desde las filas persistidas mantiene los IDs
+        let registry = PlayerRegistry::with_existing(players, aliases);
+        let mut resumed = HandConverter::with_registry(ConversionConfig::default(), registry);
+        let third = resumed
+            .convert(&sample_hand("H3", "2025/12/15 14:04:20 UTC"))
+            .unwrap();
+        assert_eq!(third.actions[0].player_id, first.actions[0].player_id);
+        assert_eq!(resumed.registry_mut().take_pending().0.len(), 0);
+    }
+
+    #[test]
+    fn test_aliases_use_hand_site() {
+        let mut converter = HandConverter::new(ConversionConfig::default());
+        let winamax = converter
+            .convert(&sample_hand("H1", "2025/12/15 14:02:20 UTC"))
+            .unwrap();
+        let stars_hand = ParsedHand {
+            site: Site::PokerStars,
+            ..sample_hand("254458712345", "2025/12/15 14:03:20 UTC")
+        };
+        let stars = converter.convert(&stars_hand).unwrap();
+
+        // El mismo nickname en otra sala es otro jugador
+        assert_ne!(winamax.actions[1].player_id, stars.actions[1].player_id);
+        assert_eq!(
+            converter
+                .registry()
+                .player_id(SiteName::PokerStars, "villain"),
+            Some(stars.actions[1].player_id.as_str())
+        );
+
+        // salvo el héroe, que es un único jugador con un alias por sala
+        assert_eq!(winamax.actions[0].player_id, stars.actions[0].player_id);
+    }
+
+    #[test]
+    fn test_hero_aliases_share_player() {
+        let heroes = HeroConfig::parse(["PokerStars:smoy_ps", "GGPoker:smoy_gg"]).unwrap();
+        let mut registry = PlayerRegistry::new();
+        registry.register_heroes(&heroes);
+        let winamax_id = registry.resolve(SiteName::Winamax, "thesmoy", true);
+
+        let (players, aliases) = registry.take_pending();
+        assert_eq!(players.len(), 1);
+        assert!(players[0].is_hero);
+        assert_eq!(players[0].display_name, "smoy_ps");
+        assert_eq!(aliases.len(), 3);
+        assert!(aliases.iter().all(|a| a.player_id == winamax_id));
+
+        // Tras recargar, cualquier cuenta (también una nueva) es el mismo jugador
+        let mut registry = PlayerRegistry::with_existing(players, aliases);
+        assert_eq!(
+            registry.resolve(SiteName::PokerStars, "smoy_ps", true),
+            winamax_id
+        );
+        assert_eq!(
+            registry.resolve(SiteName::PartyPoker, "smoy_party", true),
+            winamax_id
+        );
+        assert_ne!(
+            registry.resolve(SiteName::PokerStars, "villain", false),
+            winamax_id
+        );
+        assert_eq!(registry.players().len(), 2);
+    }
+
+    #[test]
+    fn test_all_in_mapping() {
+        let raise = ParsedAction::new(
+            "thesmoy",
+            ParsedActionType::Raise,
+            Some(1000),
+            ParsedStreet::Preflop,
+        )
+        .all_in();
+        assert_eq!(map_action_type(&raise), Some(ActionType::AllIn));
+
+        let call = ParsedAction::new(
+            "thesmoy",
+            ParsedActionType::Call,
+            Some(1000),
+            ParsedStreet::Preflop,
+        )
+        .all_in();
+        assert_eq!(map_action_type(&call), Some(ActionType::Call));
+
+        let ante = ParsedAction::new(
+            "thesmoy",
+            ParsedActionType::PostAnte,
+            Some(1),
+            ParsedStreet::Preflop,
+        );
+        assert_eq!(map_action_type(&ante), None);
+    }
+
+    #[test]
+    fn test_net_won() {
+        let hand = sample_hand("H1", "2025/12/15 14:02:20 UTC");
+        // Héroe: 25 preflop + 30 flop = 55 invertidos, cobra 77
+        assert_eq!(net_won_cents(&hand, "thesmoy"), 22);
+        // Villain: 10 + 15 = 25 invertidos
+        assert_eq!(net_won_cents(&hand, "villain"), -25);
+    }
+
+    #[test]
+    fn test_sessions_split_by_gap() {
+        let mut converter = HandConverter::new(ConversionConfig::default());
+        let batch = converter.convert_batch(&[
+            sample_hand("H3", "2025/12/15 16:00:00 UTC"),
+            sample_hand("H1", "2025/12/15 14:00:00 UTC"),
+            sample_hand("H2", "2025/12/15 14:20:00 UTC"),
+        ]);
+
+        assert!(batch.errors.is_empty());
+        assert_eq!(batch.metadata.len(), 3);
+        assert_eq!(batch.timestamps.len(), 3);
+        // Orden cronológico
+        assert_eq!(batch.metadata[0].hand_id, "H1");
+        assert_eq!(batch.metadata[0].session_id, batch.metadata[1].session_id);
+        assert_ne!(batch.metadata[1].session_id, batch.metadata[2].session_id);
+
+        let sessions = converter.sessions();
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].hands_played, 2);
+        assert_eq!(sessions[0].net_won_cents, 44);
+        // Sin all-in el EV coincide con el resultado real
+        assert_eq!(sessions[0].ev_won_cents, 44);
+        assert_eq!(sessions[0].rake_cents, 6);
+        assert_eq!(
+            sessions[0].end_time.as_deref(),
+            Some("2025-12-15T14:20:00Z")
+        );
+        assert!(sessions[0].bb_100.is_some());
+    }
+
+    #[test]
+    fn test_allin_ev_columns() {
+        use ParsedActionType::*;
+        use ParsedStreet::Preflop;
+
+        let hero = ParsedPlayer::new("thesmoy", 1, 1000)
+            .hero()
+            .with_cards(vec![Card::parse("Ah").unwrap(), Card::parse("As").unwrap()]);
+        let villain = ParsedPlayer::new("villain", 2, 1000)
+            .with_cards(vec![Card::parse("Kd").unwrap(), Card::parse("Kc").unwrap()]);
+
+        let shove = ParsedAction::new("thesmoy", Raise, Some(1000), Preflop).all_in();
+        let call = ParsedAction::new("villain", Call, Some(990), Preflop).all_in();
+
+        // AA pierde contra KK: el resultado real es -1000, el esperado positivo
+        let hand = ParsedHand {
+            players: vec![hero, villain],
+            board: ["Kh", "7d", "2c", "9s", "3h"]
+                .iter()
+                .filter_map(|c| Card::parse(c))
+                .collect(),
+            actions: vec![
+                ParsedAction::new("thesmoy", PostSmallBlind, Some(5), Preflop),
+                ParsedAction::new("villain", PostBigBlind, Some(10), Preflop),
+                shove,
+                call,
+                ParsedAction::new("villain", Collect, Some(2000), Preflop),
+            ],
+            pot: PotInfo {
+                total_cents: 2000,
+                rake_cents: 0,
+                winners: vec![("villain".into(), 2000)],
+            },
+            ..sample_hand("H1", "2025/12/15 14:00:00 UTC")
+        };
+
+        let mut converter = HandConverter::new(ConversionConfig::default());
+        let converted = converter.convert(&hand).unwrap();
+
+        let hero_ev = converted.actions[0].ev_cents.unwrap();
+        assert!(hero_ev > 500, "EV del héroe {}", hero_ev);
+        assert_eq!(converted.actions[1].ev_cents, Some(-hero_ev));
+
+        let session = &converter.sessions()[0];
+        assert_eq!(session.net_won_cents, -1000);
+        assert_eq!(session.ev_won_cents, hero_ev);
+        assert!(session.ev_bb_100.unwrap() > 0.0);
+
+        // Con el EV desactivado no se rellena
+        let mut converter = HandConverter::new(ConversionConfig::default().with_ev_simulations(0));
+        let converted = converter.convert(&hand).unwrap();
+        assert!(converted.actions.iter().all(|a| a.ev_cents.is_none()));
+    }
+
+    #[test]
+    fn test_resume_existing_session() {
+        let mut converter = HandConverter::new(ConversionConfig::default());
+        converter
+            .convert(&sample_hand("H1", "2025/12/15 14:00:00 UTC"))
+            .unwrap();
+        let (players, aliases) = converter.registry_mut().take_pending();
+        let session = converter.sessions().remove(0);
+
+        let mut resumed = HandConverter::with_registry(
+            ConversionConfig::default(),
+            PlayerRegistry::with_existing(players, aliases),
+        )
+        .with_sessions(vec![(session.clone(), 5)]);
+
+        let converted = resumed
+            .convert(&sample_hand("H2", "2025/12/15 14:10:00 UTC"))
+            .unwrap();
+
+        assert_eq!(converted.metadata.session_id, Some(session.session_id));
+        assert_eq!(resumed.sessions()[0].hands_played, 2);
+    }
+
+    #[test]
+    fn test_tournament_hand_and_summary() {
+        let mut converter = HandConverter::new(ConversionConfig::default());
+
+        let mut hand = sample_hand("T1", "2025/12/15 14:03:20 UTC");
+        hand.game_type = GameType::Tournament;
+        hand.tournament = Some(TournamentInfo {
+            tournament_id: "654321987".to_string(),
+            name: "Expresso".to_string(),
+            format: TournamentFormat::Expresso,
+            buy_in_cents: 93,
+            fee_cents: 7,
+            level: 1,
+            bb_ante: false,
+        });
+        let converted = converter.convert(&hand).unwrap();
+
+        assert_eq!(converted.metadata.format, GameFormat::Expresso);
+        assert_eq!(
+            converted.metadata.tournament_id.as_deref(),
+            Some("654321987")
+        );
+        assert!(converted.metadata.session_id.is_none());
+        assert!(converter.sessions().is_empty());
+
+        let tournament = &converter.tournaments()[0];
+        assert_eq!(tournament.buyin_cents, 93);
+        assert_eq!(tournament.rake_cents, 7);
+        assert_eq!(tournament.start_time, "2025-12-15T14:03:20Z");
+
+        let summary = TournamentSummary {
+            tournament_id: "654321987".to_string(),
+            name: "Expresso".to_string(),
+            format: TournamentFormat::Expresso,
+            player_name: "thesmoy".to_string(),
+            buy_in_cents: 93,
+            fee_cents: 7,
+            registered_players: Some(3),
+            prize_pool_cents: Some(500),
+            start_time: "2025/12/15 14:02:19 UTC".to_string(),
+            finish_position: Some(1),
+            prize_won_cents: 500,
+            bounty_won_cents: 0,
+        };
+        let result = converter.convert_summary(&summary).unwrap();
+        converter.convert_summary(&summary).unwrap();
+
+        assert_eq!(result.finish_position, 1);
+        assert_eq!(result.total_won_cents, 500);
+        assert!((result.roi_real.unwrap() - 400.0).abs() < 1e-9);
+        assert_eq!(
+            Some(result.player_id.as_str()),
+            converter.registry().player_id(SiteName::Winamax, "thesmoy")
+        );
+        assert_eq!(converter.tournament_results().len(), 1);
+
+        let tournaments = converter.tournaments();
+        assert_eq!(tournaments.len(), 1);
+        assert_eq!(tournaments[0].total_entries, Some(3));
+        assert_eq!(tournaments[0].prize_pool_cents, Some(500));
+        assert_eq!(tournaments[0].start_time, "2025-12-15T14:02:19Z");
+
+        let incomplete = TournamentSummary {
+            finish_position: None,
+            ..summary
+        };
+        assert!(matches!(
+            converter.convert_summary(&incomplete),
+            Err(ConversionError::IncompleteSummary { .. })
+        ));
+    }
+
+    #[test]
+    fn test_conversion_errors() {
+        let mut converter = HandConverter::new(ConversionConfig::default());
+
+        let bad_timestamp = sample_hand("H1", "ayer por la tarde");
+        assert!(matches!(
+            converter.convert(&bad_timestamp),
+            Err(ConversionError::InvalidTimestamp { .. })
+        ));
+
+        let mut unknown = sample_hand("H2", "2025/12/15 14:00:00 UTC");
+        unknown.actions.push(ParsedAction::new(
+            "ghost",
+            ParsedActionType::Fold,
+            None,
+            ParsedStreet::Preflop,
+        ));
+        assert_eq!(
+            converter.convert(&unknown).unwrap_err(),
+            ConversionError::UnknownPlayer {
+                hand_id: "H2".to_string(),
+                player: "ghost".to_string()
+            }
+        );
+
+        assert_eq!(
+            converter
+                .convert(&sample_hand("", "2025/12/15 14:00:00 UTC"))
+                .unwrap_err(),
+            ConversionError::MissingHandId
+        );
+    }
+}
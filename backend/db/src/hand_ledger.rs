@@ -0,0 +1,134 @@
+//! # Hand Ledger Module
+//!
+//! Registro de `hand_id` ya ingeridos. Es la clave de idempotencia del
+//! pipeline de ingesta: Winamax añade manos al mismo archivo durante toda
+//! la sesión, así que cada evento del watcher vuelve a parsear manos que ya
+//! están en Parquet/DuckDB y hay que descartarlas antes de escribir.
+//!
+//! El ledger se reconstruye al arrancar desde `hands_metadata`, que es la
+//! fuente de verdad; no necesita persistencia propia.
+
+use poker_parsers::types::ParsedHand;
+use std::collections::HashSet;
+
+/// Conjunto de manos ya ingeridas
+#[derive(Debug, Clone, Default)]
+pub struct HandLedger {
+    ids: HashSet<String>,
+}
+
+impl HandLedger {
+    /// Crea un ledger vacío
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Crea un ledger a partir de los `hand_id` ya persistidos
+    pub fn from_ids(ids: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            ids: ids.into_iter().collect(),
+        }
+    }
+
+    /// Indica si la mano ya fue ingerida
+    pub fn contains(&self, hand_id: &str) -> bool {
+        self.ids.contains(hand_id)
+    }
+
+    /// Número de manos registradas
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Indica si el ledger está vacío
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Registra manos como ingeridas
+    pub fn mark_ingested<I, S>(&mut self, hand_ids: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.ids.extend(hand_ids.into_iter().map(Into::into));
+    }
+
+    /// Separa las manos nuevas de las ya ingeridas.
+    ///
+    /// También descarta repeticiones dentro del propio lote (el mismo archivo
+    /// encolado dos veces). Las manos sin `hand_id` se dejan pasar para que el
+    /// conversor las reporte como error. Devuelve las manos nuevas y el número
+    /// de duplicadas descartadas.
+    pub fn split_new(&self, hands: Vec<ParsedHand>) -> (Vec<ParsedHand>, usize) {
+        let mut seen = HashSet::new();
+        let mut duplicates = 0;
+
+        let fresh = hands
+            .into_iter()
+            .filter(|hand| {
+                if hand.hand_id.is_empty() {
+                    return true;
+                }
+                if self.contains(&hand.hand_id) || !seen.insert(hand.hand_id.clone()) {
+                    duplicates += 1;
+                    return false;
+                }
+                true
+            })
+            .collect();
+
+        (fresh, duplicates)
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand(hand_id: &str) -> ParsedHand {
+        ParsedHand {
+            hand_id: hand_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_split_new_skips_ingested_hands() {
+        let ledger = HandLedger::from_ids(vec!["h1".to_string(), "h2".to_string()]);
+
+        let (fresh, duplicates) = ledger.split_new(vec![hand("h1"), hand("h3"), hand("h2")]);
+
+        assert_eq!(duplicates, 2);
+        assert_eq!(fresh.len(), 1);
+        assert_eq!(fresh[0].hand_id, "h3");
+    }
+
+    #[test]
+    fn test_split_new_dedups_within_batch() {
+        let ledger = HandLedger::new();
+
+        let (fresh, duplicates) = ledger.split_new(vec![hand("h1"), hand("h1"), hand("")]);
+
+        assert_eq!(duplicates, 1);
+        assert_eq!(fresh.len(), 2);
+        assert!(fresh.iter().any(|h| h.hand_id.is_empty()));
+    }
+
+    #[test]
+    fn test_mark_ingested() {
+        let mut ledger = HandLedger::new();
+        assert!(ledger.is_empty());
+
+        ledger.mark_ingested(["h1", "h2"]);
+        ledger.mark_ingested(vec!["h2".to_string()]);
+
+        assert_eq!(ledger.len(), 2);
+        assert!(ledger.contains("h1"));
+        assert!(!ledger.contains("h3"));
+    }
+}
@@ -0,0 +1,173 @@
+//! # Hand Quarantine Module
+//!
+//! Manos que no superan la validación de integridad (`HandValidator`).
+//! El pipeline de ingesta las aparta antes de convertirlas, así que no llegan
+//! a Parquet ni a `player_stats_flat`, y las guarda con el motivo en un
+//! archivo JSON Lines (una `QuarantinedHand` por línea) para revisarlas.
+//!
+//! ```text
+//! {"violations":[{"rule":"ChipConservation","message":"Pot mismatch: ..."}],"hand":{...}}
+//! ```
+
+use anyhow::{Context, Result};
+use poker_parsers::QuarantinedHand;
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Manos apartadas por no superar la validación
+#[derive(Debug, Clone)]
+pub struct HandQuarantine {
+    path: PathBuf,
+    ids: HashSet<String>,
+}
+
+impl HandQuarantine {
+    /// Abre la cuarentena, cargando los `hand_id` ya apartados.
+    ///
+    /// Si el archivo no existe la cuarentena empieza vacía.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let ids = read_entries(&path)?
+            .into_iter()
+            .map(|entry| entry.hand.hand_id)
+            .collect();
+        Ok(Self { path, ids })
+    }
+
+    /// Archivo de la cuarentena
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Indica si la mano ya está en cuarentena
+    pub fn contains(&self, hand_id: &str) -> bool {
+        self.ids.contains(hand_id)
+    }
+
+    /// Número de manos en cuarentena
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Indica si la cuarentena está vacía
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Añade manos a la cuarentena.
+    ///
+    /// Las que ya estaban (el mismo archivo re-importado) no se duplican.
+    /// Devuelve el número de manos añadidas.
+    pub fn add(&mut self, hands: &[QuarantinedHand]) -> Result<usize> {
+        let mut lines = String::new();
+        let mut added = 0;
+        for hand in hands {
+            if !self.ids.insert(hand.hand_id().to_string()) {
+                continue;
+            }
+            lines.push_str(&serde_json::to_string(hand)?);
+            lines.push('\n');
+            added += 1;
+        }
+        if added == 0 {
+            return Ok(0);
+        }
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(lines.as_bytes()))
+            .with_context(|| format!("Failed to write hand quarantine: {:?}", self.path))?;
+        Ok(added)
+    }
+
+    /// Lee las manos en cuarentena con sus motivos
+    pub fn entries(&self) -> Result<Vec<QuarantinedHand>> {
+        read_entries(&self.path)
+    }
+}
+
+/// Lee el archivo JSON Lines (vacío si no existe)
+fn read_entries(path: &Path) -> Result<Vec<QuarantinedHand>> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => {
+            return Err(e).with_context(|| format!("Failed to read hand quarantine: {:?}", path))
+        }
+    };
+
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| {
+            serde_json::from_str(line)
+                .with_context(|| format!("Invalid quarantine entry {} in {:?}", i + 1, path))
+        })
+        .collect()
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poker_parsers::{IntegrityRule, IntegrityViolation, ParsedHand};
+    use tempfile::TempDir;
+
+    fn quarantined(hand_id: &str) -> QuarantinedHand {
+        QuarantinedHand {
+            violations: vec![IntegrityViolation {
+                rule: IntegrityRule::ChipConservation,
+                message: "Total pot 10 != collected 12".to_string(),
+            }],
+            hand: ParsedHand {
+                hand_id: hand_id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_add_and_reopen() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("hands_quarantine.jsonl");
+
+        let mut quarantine = HandQuarantine::open(&path).unwrap();
+        assert!(quarantine.is_empty());
+
+        assert_eq!(
+            quarantine
+                .add(&[quarantined("h1"), quarantined("h2")])
+                .unwrap(),
+            2
+        );
+        // Re-importar las mismas manos no las duplica
+        assert_eq!(
+            quarantine
+                .add(&[quarantined("h2"), quarantined("h3")])
+                .unwrap(),
+            1
+        );
+
+        let reopened = HandQuarantine::open(&path).unwrap();
+        assert_eq!(reopened.len(), 3);
+        assert!(reopened.contains("h2"));
+
+        let entries = reopened.entries().unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(
+            entries[0].reason(),
+            "[chip conservation] Total pot 10 != collected 12"
+        );
+    }
+}
@@ -0,0 +1,1180 @@
+//! # Ingest Module
+//!
+//! Pipeline de ingesta end-to-end: watcher → parser → Parquet → DuckDB.
+//!
+//! ## Flujo
+//! 1. `FileWatcher` detecta archivos de historial nuevos o modificados
+//! 2. `ParallelProcessor` los parsea a `ParsedHand` (en vivo, `FileTailer`
+//!    parsea solo las manos añadidas a cada archivo); los archivos que el
+//!    parser no consigue leer repetidamente van a `history/quarantine/`
+//!    (`FileQuarantine`) y se reimportan con `reimport_quarantined`
+//! 3. `HandLedger` descarta manos ya ingeridas (idempotencia por `hand_id`)
+//! 4. `HandValidator` aparta las manos inconsistentes a `HandQuarantine`
+//! 5. `HandConverter` genera las filas del Star Schema
+//! 6. `ParquetWriter` persiste `hands_metadata`, `hands_actions` y `hands_players`
+//! 7. `ParquetReader::load_incremental` carga los archivos nuevos en DuckDB
+//! 8. `PlayerStatsEngine::refresh` contabiliza las manos nuevas en `player_stats_flat`
+//!
+//! Jugadores, aliases, sesiones de cash, torneos y resultados de torneo son
+//! tablas pequeñas que se actualizan directamente en DuckDB. Los resultados
+//! salen de los archivos de resumen de torneo que el parser encuentra junto
+//! a los historiales.
+//!
+//! ## Layout en disco
+//! Cada tabla tiene su propio directorio (y su propia caché de archivos
+//! cargados) para que el reader no mezcle archivos de ambas tablas:
+//!
+//! ```text
+//! data/
+//!   hands_metadata/year=YYYY/month=MM/day=DD/*.parquet
+//!   hands_actions/year=YYYY/month=MM/day=DD/*.parquet
+//!   hands_players/year=YYYY/month=MM/day=DD/*.parquet
+//!   hands_quarantine.jsonl
+//!   watcher_ledger.json
+//! ```
+//!
+//! ## Uso
+//!
+//! ```rust,ignore
+//! use poker_db::{DbConnection, IngestConfig, IngestService};
+//! use poker_parsers::WatcherConfig;
+//!
+//! let mut conn = DbConnection::persistent("poker.duckdb".to_string())?;
+//! conn.init_schema_embedded()?;
+//!
+//! let service = IngestService::new(conn, IngestConfig::new("data"))?;
+//! service.run(WatcherConfig::default())?; // bloquea el hilo
+//! ```
+
+use anyhow::{Context, Result};
+use duckdb::params;
+use poker_parsers::{
+    BatchProcessingResult, DiagnosticSummary, FileLedger, FileQuarantine, FileTailer, FileWatcher,
+    HandValidator, HeroConfig, ParallelProcessor, ParsedHand, ProcessingConfig, TailState,
+    TailUpdate, TournamentSummary, WatcherConfig,
+};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::connection::DbConnection;
+use crate::hand_converter::{ConversionConfig, HandConverter, PlayerRegistry};
+use crate::hand_ledger::HandLedger;
+use crate::hand_quarantine::HandQuarantine;
+use crate::parquet_reader::{ParquetReadConfig, ParquetReader, ReadResult};
+use crate::parquet_writer::{ParquetWriteConfig, ParquetWriter};
+use crate::player_stats::PlayerStatsEngine;
+use crate::schema::{CashSession, GameFormat, Player, PlayerAlias, SiteName, Tournament};
+
+/// Tabla de metadata de manos
+pub const HANDS_METADATA_TABLE: &str = "hands_metadata";
+
+/// Tabla de acciones
+pub const HANDS_ACTIONS_TABLE: &str = "hands_actions";
+
+/// Tabla de resultados por jugador y mano
+pub const HANDS_PLAYERS_TABLE: &str = "hands_players";
+
+/// Ledger del watcher (archivos leídos y su offset), relativo a `data_dir`
+pub const WATCHER_LEDGER_FILE: &str = "watcher_ledger.json";
+
+/// Manos en cuarentena (JSON Lines), relativo a `data_dir`
+pub const HANDS_QUARANTINE_FILE: &str = "hands_quarantine.jsonl";
+
+/// Formato de timestamps que usa el conversor (`HandMetadata`, `CashSession`)
+const ISO_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+// ============================================================================
+// CONFIGURATION
+// ============================================================================
+
+/// Configuración del pipeline de ingesta
+#[derive(Debug, Clone)]
+pub struct IngestConfig {
+    /// Directorio raíz de los archivos Parquet
+    pub data_dir: PathBuf,
+
+    /// Sala y agrupación de sesiones
+    pub conversion: ConversionConfig,
+
+    /// Configuración del parser paralelo
+    pub processing: ProcessingConfig,
+
+    /// Ventana para agrupar eventos del watcher en un solo lote (ms)
+    pub batch_window_ms: u64,
+
+    /// Reglas de integridad que deben cumplir las manos para ingerirse
+    pub validator: HandValidator,
+}
+
+impl Default for IngestConfig {
+    fn default() -> Self {
+        Self {
+            data_dir: PathBuf::from("data"),
+            conversion: ConversionConfig::default(),
+            processing: ProcessingConfig::default(),
+            batch_window_ms: 500,
+            validator: HandValidator::default(),
+        }
+    }
+}
+
+impl IngestConfig {
+    /// Crea una configuración con un directorio de datos personalizado
+    pub fn new(data_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            data_dir: data_dir.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Establece la configuración de conversión
+    pub fn with_conversion(mut self, conversion: ConversionConfig) -> Self {
+        self.conversion = conversion;
+        self
+    }
+
+    /// Establece la configuración del parser paralelo
+    pub fn with_processing(mut self, processing: ProcessingConfig) -> Self {
+        self.processing = processing;
+        self
+    }
+
+    /// Establece los nicknames del héroe (se usan al parsear y al registrar
+    /// jugadores: todas las cuentas son el mismo `Player`)
+    pub fn with_heroes(mut self, heroes: HeroConfig) -> Self {
+        self.processing = self.processing.with_heroes(heroes);
+        self
+    }
+
+    /// Establece la ventana de agrupación de eventos
+    pub fn with_batch_window_ms(mut self, window_ms: u64) -> Self {
+        self.batch_window_ms = window_ms;
+        self
+    }
+
+    /// Establece las reglas de validación de manos
+    pub fn with_validator(mut self, validator: HandValidator) -> Self {
+        self.validator = validator;
+        self
+    }
+
+    /// Directorio de Parquet de `hands_metadata`
+    pub fn metadata_dir(&self) -> PathBuf {
+        self.data_dir.join(HANDS_METADATA_TABLE)
+    }
+
+    /// Directorio de Parquet de `hands_actions`
+    pub fn actions_dir(&self) -> PathBuf {
+        self.data_dir.join(HANDS_ACTIONS_TABLE)
+    }
+
+    /// Directorio de Parquet de `hands_players`
+    pub fn players_dir(&self) -> PathBuf {
+        self.data_dir.join(HANDS_PLAYERS_TABLE)
+    }
+
+    /// Archivo del ledger del watcher
+    pub fn watcher_ledger_path(&self) -> PathBuf {
+        self.data_dir.join(WATCHER_LEDGER_FILE)
+    }
+
+    /// Archivo de manos en cuarentena
+    pub fn hand_quarantine_path(&self) -> PathBuf {
+        self.data_dir.join(HANDS_QUARANTINE_FILE)
+    }
+}
+
+// ============================================================================
+// INGEST REPORT
+// ============================================================================
+
+/// Resultado de una ingesta
+#[derive(Debug, Clone, Default)]
+pub struct IngestReport {
+    /// Archivos parseados correctamente
+    pub files_processed: usize,
+
+    /// Manos parseadas (incluye duplicadas)
+    pub hands_parsed: usize,
+
+    /// Manos nuevas escritas a Parquet
+    pub hands_ingested: usize,
+
+    /// Manos descartadas por estar ya ingeridas
+    pub hands_skipped: usize,
+
+    /// Manos apartadas a cuarentena por no superar la validación
+    pub hands_quarantined: usize,
+
+    /// Archivos apartados a cuarentena por fallos repetidos del parser
+    pub files_quarantined: usize,
+
+    /// Acciones escritas a Parquet
+    pub actions_written: usize,
+
+    /// Filas de `hands_players` escritas a Parquet
+    pub player_rows_written: usize,
+
+    /// Filas cargadas en DuckDB (metadata + acciones + jugadores)
+    pub rows_loaded: usize,
+
+    /// Resultados de torneo registrados desde resúmenes
+    pub tournament_results: usize,
+
+    /// Errores de parsing, conversión o carga
+    pub errors: Vec<String>,
+
+    /// Diagnósticos del parsing (errores, avisos e informativos) por categoría
+    pub diagnostics: DiagnosticSummary,
+
+    /// Tiempo total en milisegundos
+    pub elapsed_ms: u128,
+}
+
+impl IngestReport {
+    /// Verifica si hubo errores
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+
+    /// Genera un resumen legible
+    pub fn summary(&self) -> String {
+        format!(
+            "Ingested {} hands ({} skipped, {} quarantined, {} actions) from {} files, {} rows loaded in {}ms{}",
+            self.hands_ingested,
+            self.hands_skipped,
+            self.hands_quarantined,
+            self.actions_written,
+            self.files_processed,
+            self.rows_loaded,
+            self.elapsed_ms,
+            if self.has_errors() {
+                format!(" with {} errors", self.errors.len())
+            } else {
+                String::new()
+            }
+        )
+    }
+
+    /// Acumula el resultado de una carga incremental
+    fn add_load(&mut self, load: ReadResult) {
+        self.rows_loaded += load.rows_loaded;
+        self.errors.extend(load.errors);
+    }
+}
+
+// ============================================================================
+// INGEST SERVICE
+// ============================================================================
+
+/// Servicio de ingesta: une watcher, parser, conversor, Parquet y DuckDB
+pub struct IngestService {
+    config: IngestConfig,
+    conn: DbConnection,
+    processor: ParallelProcessor,
+    converter: HandConverter,
+    ledger: HandLedger,
+    quarantine: HandQuarantine,
+    metadata_writer: ParquetWriter,
+    actions_writer: ParquetWriter,
+    players_writer: ParquetWriter,
+    metadata_reader: ParquetReader,
+    actions_reader: ParquetReader,
+    players_reader: ParquetReader,
+}
+
+impl IngestService {
+    /// Crea el servicio sobre una conexión con el esquema ya inicializado.
+    ///
+    /// Carga en DuckDB los Parquet pendientes y reconstruye el ledger de
+    /// manos, el registro de jugadores y las sesiones abiertas.
+    pub fn new(conn: DbConnection, config: IngestConfig) -> Result<Self> {
+        let metadata_dir = config.metadata_dir();
+        let actions_dir = config.actions_dir();
+        let players_dir = config.players_dir();
+        for dir in [&metadata_dir, &actions_dir, &players_dir] {
+            fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create data directory: {:?}", dir))?;
+        }
+
+        let mut service = Self {
+            processor: ParallelProcessor::new(config.processing.clone()),
+            converter: HandConverter::new(config.conversion.clone()),
+            ledger: HandLedger::new(),
+            quarantine: HandQuarantine::open(config.hand_quarantine_path())?,
+            metadata_writer: ParquetWriter::new(ParquetWriteConfig::new(&metadata_dir)),
+            actions_writer: ParquetWriter::new(ParquetWriteConfig::new(&actions_dir)),
+            players_writer: ParquetWriter::new(ParquetWriteConfig::new(&players_dir)),
+            metadata_reader: ParquetReader::new(read_config(&metadata_dir))?,
+            actions_reader: ParquetReader::new(read_config(&actions_dir))?,
+            players_reader: ParquetReader::new(read_config(&players_dir))?,
+            config,
+            conn,
+        };
+
+        service.bootstrap()?;
+        Ok(service)
+    }
+
+    /// Conexión DuckDB con los datos ingeridos
+    pub fn connection(&self) -> &DbConnection {
+        &self.conn
+    }
+
+    /// Devuelve la conexión consumiendo el servicio
+    pub fn into_connection(self) -> DbConnection {
+        self.conn
+    }
+
+    /// Configuración del servicio
+    pub fn config(&self) -> &IngestConfig {
+        &self.config
+    }
+
+    /// Ledger de manos ya ingeridas
+    pub fn ledger(&self) -> &HandLedger {
+        &self.ledger
+    }
+
+    /// Manos apartadas por no superar la validación
+    pub fn quarantine(&self) -> &HandQuarantine {
+        &self.quarantine
+    }
+
+    /// Parsea e ingiere un lote de archivos de historial
+    pub fn ingest_files(&mut self, files: Vec<PathBuf>) -> Result<IngestReport> {
+        let start_time = Instant::now();
+        let batch = self.processor.process_files(files, None::<fn(_)>);
+        self.ingest_batch(batch, start_time)
+    }
+
+    /// Vuelve a parsear los archivos en cuarentena (p.ej. tras corregir el
+    /// parser) e ingiere los que ya se leen bien, que vuelven a su ruta
+    /// original. Los que siguen fallando cuentan en `files_quarantined`.
+    pub fn reimport_quarantined(&mut self, quarantine_dir: &Path) -> Result<IngestReport> {
+        let start_time = Instant::now();
+        let mut quarantine = FileQuarantine::open(quarantine_dir)
+            .with_context(|| format!("Failed to open quarantine {:?}", quarantine_dir))?;
+        let batch = self
+            .processor
+            .reimport_quarantined(&mut quarantine)
+            .with_context(|| format!("Failed to reimport from {:?}", quarantine_dir))?;
+        self.ingest_batch(batch, start_time)
+    }
+
+    /// Ingiere las manos nuevas leídas por el watcher en modo tail.
+    pub fn ingest_updates(&mut self, updates: Vec<TailUpdate>) -> Result<IngestReport> {
+        let start_time = Instant::now();
+        let mut files: Vec<PathBuf> = Vec::new();
+        let mut hands = Vec::new();
+        let mut summaries = Vec::new();
+        let mut parse_errors = Vec::new();
+        let mut diagnostics = DiagnosticSummary::default();
+        for update in updates {
+            parse_errors.extend(update.result.errors().map(ToString::to_string));
+            diagnostics.add_file(&update.result.diagnostics);
+            hands.extend(update.result.hands);
+            summaries.extend(update.result.summaries);
+            if !files.contains(&update.path) {
+                files.push(update.path);
+            }
+        }
+
+        let mut report = self.ingest_parsed(hands, &summaries)?;
+        report.files_processed = files.len();
+        report.diagnostics = diagnostics;
+        report.errors.extend(parse_errors);
+        report.elapsed_ms = start_time.elapsed().as_millis();
+        Ok(report)
+    }
+
+    /// Ingiere todos los historiales `.txt` de un directorio
+    pub fn ingest_directory(&mut self, dir: &Path) -> Result<IngestReport> {
+        let mut files: Vec<PathBuf> = fs::read_dir(dir)
+            .with_context(|| format!("Failed to read directory: {:?}", dir))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| is_history_file(path))
+            .collect();
+        files.sort();
+
+        self.ingest_files(files)
+    }
+
+    /// Ingiere manos ya parseadas.
+    ///
+    /// Las manos cuyo `hand_id` ya está en el ledger se descartan, por lo que
+    /// re-ingerir el mismo archivo no duplica filas. Las que no superan el
+    /// `HandValidator` de la configuración van a la cuarentena con el motivo
+    /// y no se convierten.
+    pub fn ingest_hands(&mut self, hands: Vec<ParsedHand>) -> Result<IngestReport> {
+        let start_time = Instant::now();
+        let mut report = IngestReport {
+            hands_parsed: hands.len(),
+            ..Default::default()
+        };
+
+        let (fresh, skipped) = self.ledger.split_new(hands);
+        report.hands_skipped = skipped;
+
+        let (fresh, quarantined) = self.config.validator.partition(fresh);
+        if !quarantined.is_empty() {
+            report.hands_quarantined = quarantined.len();
+            self.quarantine.add(&quarantined)?;
+        }
+
+        if !fresh.is_empty() {
+            let batch = self.converter.convert_batch(&fresh);
+            report
+                .errors
+                .extend(batch.errors.iter().map(|e| e.to_string()));
+
+            if !batch.metadata.is_empty() {
+                let hand_ids: Vec<String> =
+                    batch.metadata.iter().map(|m| m.hand_id.clone()).collect();
+                let action_count = batch.actions.len();
+                let player_count = batch.players.len();
+
+                self.metadata_writer
+                    .write_hands_metadata(batch.metadata)
+                    .context("Failed to write hands_metadata")?;
+                if action_count > 0 {
+                    self.actions_writer
+                        .write_hands_actions(batch.actions, &batch.timestamps)
+                        .context("Failed to write hands_actions")?;
+                }
+                if player_count > 0 {
+                    self.players_writer
+                        .write_hands_players(batch.players, &batch.timestamps)
+                        .context("Failed to write hands_players")?;
+                }
+
+                report.hands_ingested = hand_ids.len();
+                report.actions_written = action_count;
+                report.player_rows_written = player_count;
+                self.ledger.mark_ingested(hand_ids);
+            }
+
+            self.persist_players()?;
+            self.persist_sessions()?;
+            self.persist_tournaments()?;
+        }
+
+        let load = self.refresh()?;
+        report.rows_loaded = load.rows_loaded;
+        report.errors.extend(load.errors);
+
+        report.elapsed_ms = start_time.elapsed().as_millis();
+        Ok(report)
+    }
+
+    /// Registra los resultados de los resúmenes de torneo.
+    ///
+    /// Re-procesar un resumen reemplaza el resultado anterior del jugador.
+    pub fn ingest_summaries(&mut self, summaries: &[TournamentSummary]) -> Result<IngestReport> {
+        let start_time = Instant::now();
+        let mut report = IngestReport::default();
+
+        for summary in summaries {
+            match self.converter.convert_summary(summary) {
+                Ok(_) => report.tournament_results += 1,
+                Err(e) => report.errors.push(e.to_string()),
+            }
+        }
+
+        if report.tournament_results > 0 {
+            self.persist_players()?;
+            self.persist_tournaments()?;
+            self.persist_tournament_results()?;
+        }
+
+        report.elapsed_ms = start_time.elapsed().as_millis();
+        Ok(report)
+    }
+
+    /// Carga en DuckDB los Parquet que aún no estén cargados
+    pub fn refresh(&mut self) -> Result<IngestReport> {
+        let start_time = Instant::now();
+        let mut report = IngestReport::default();
+
+        let metadata_load = self
+            .metadata_reader
+            .load_incremental(&self.conn, HANDS_METADATA_TABLE)?;
+        let actions_load = self
+            .actions_reader
+            .load_incremental(&self.conn, HANDS_ACTIONS_TABLE)?;
+        let players_load = self
+            .players_reader
+            .load_incremental(&self.conn, HANDS_PLAYERS_TABLE)?;
+        report.add_load(metadata_load);
+        report.add_load(actions_load);
+        report.add_load(players_load);
+
+        PlayerStatsEngine::refresh(self.conn.conn()).context("Failed to refresh player stats")?;
+
+        report.elapsed_ms = start_time.elapsed().as_millis();
+        Ok(report)
+    }
+
+    /// Ingiere lo nuevo del directorio vigilado y después las manos que se
+    /// vayan añadiendo a sus archivos.
+    ///
+    /// Al arrancar se carga el ledger del watcher (`watcher_ledger.json`) y
+    /// solo se leen los archivos nuevos o modificados desde la última
+    /// ejecución; después el watcher lee solo lo añadido a cada archivo. El
+    /// ledger se guarda tras cada lote ingerido, así que un cierre inesperado
+    /// vuelve a leer como mucho el último lote (el ledger de manos descarta lo
+    /// ya ingerido). Las lecturas que llegan dentro de `batch_window_ms` se
+    /// agrupan en un único lote.
+    ///
+    /// Los nicknames del héroe son los de `IngestConfig` y sustituyen a los
+    /// de `watcher_config`. Si `watcher_config` no indica otra, la cuarentena
+    /// de archivos es `quarantine/` dentro del directorio vigilado.
+    ///
+    /// Esta función bloquea el hilo actual y solo retorna si el watcher
+    /// termina (p.ej. el directorio no existe).
+    pub fn run(mut self, mut watcher_config: WatcherConfig) -> Result<()> {
+        let ledger_path = self.config.watcher_ledger_path();
+        let ledger = FileLedger::load(&ledger_path).unwrap_or_else(|e| {
+            eprintln!(
+                "✗ Ignoring unreadable watcher ledger {:?}: {}",
+                ledger_path, e
+            );
+            FileLedger::new()
+        });
+
+        watcher_config.heroes = self.config.processing.heroes.clone();
+        let quarantine_dir = watcher_config
+            .quarantine_dir
+            .get_or_insert_with(|| FileQuarantine::dir_for(&watcher_config.watch_path))
+            .clone();
+        let mut tailer = FileTailer::with_ledger(ledger).with_heroes(watcher_config.heroes.clone());
+        match FileQuarantine::open(&quarantine_dir) {
+            Ok(quarantine) => tailer = tailer.with_quarantine(quarantine),
+            Err(e) => eprintln!(
+                "✗ File quarantine disabled, unreadable {:?}: {}",
+                quarantine_dir, e
+            ),
+        }
+        if watcher_config.watch_path.is_dir() {
+            let scan = tailer
+                .reconcile(&watcher_config.watch_path)
+                .with_context(|| format!("Failed to scan {:?}", watcher_config.watch_path))?;
+            let mut report = self.ingest_updates(scan.updates)?;
+            report.errors.extend(scan.errors);
+            eprintln!(
+                "✓ {} ({} unchanged files, {} removed)",
+                report.summary(),
+                scan.unchanged,
+                scan.removed.len()
+            );
+            save_ledger(tailer.ledger(), &ledger_path);
+        }
+        let mut ledger = tailer.into_ledger();
+
+        let (tx, rx) = mpsc::channel::<TailUpdate>();
+        let watcher = FileWatcher::new(watcher_config).with_ledger(ledger.clone());
+        let watcher_thread = thread::spawn(move || {
+            watcher.start_tailing(move |update| {
+                // El receptor solo desaparece si el servicio terminó
+                let _ = tx.send(update);
+            })
+        });
+
+        let window = Duration::from_millis(self.config.batch_window_ms);
+        while let Ok(first) = rx.recv() {
+            let mut updates = vec![first];
+            let deadline = Instant::now() + window;
+
+            while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+                match rx.recv_timeout(remaining) {
+                    Ok(update) => updates.push(update),
+                    Err(RecvTimeoutError::Timeout) | Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+
+            let states: Vec<(PathBuf, TailState)> = updates
+                .iter()
+                .map(|update| (update.path.clone(), update.state.clone()))
+                .collect();
+            match self.ingest_updates(updates) {
+                Ok(report) => {
+                    for (path, state) in states {
+                        ledger.insert(path, state);
+                    }
+                    save_ledger(&ledger, &ledger_path);
+                    eprintln!("✓ {}", report.summary());
+                    for error in &report.errors {
+                        eprintln!("  ✗ {}", error);
+                    }
+                }
+                Err(e) => eprintln!("✗ Ingest failed: {:#}", e),
+            }
+        }
+
+        match watcher_thread.join() {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => Err(anyhow::anyhow!("File watcher stopped: {}", e)),
+            Err(_) => Err(anyhow::anyhow!("File watcher thread panicked")),
+        }
+    }
+
+    // ========================================================================
+    // PRIVATE METHODS - Ingest
+    // ========================================================================
+
+    /// Ingiere el resultado de un batch del parser
+    fn ingest_batch(
+        &mut self,
+        batch: BatchProcessingResult,
+        start_time: Instant,
+    ) -> Result<IngestReport> {
+        let mut hands = Vec::with_capacity(batch.total_hands);
+        let mut summaries = Vec::new();
+        let mut parse_errors = Vec::new();
+        for file in batch.results {
+            match file.result {
+                Ok(parsed) => {
+                    parse_errors.extend(parsed.errors().map(ToString::to_string));
+                    hands.extend(parsed.hands);
+                    summaries.extend(parsed.summaries);
+                }
+                Err(e) => parse_errors.push(format!("{}: {}", file.path.display(), e)),
+            }
+        }
+
+        let mut report = self.ingest_parsed(hands, &summaries)?;
+        report.files_processed = batch.successful_files;
+        report.files_quarantined = batch.quarantined.len();
+        report.diagnostics = batch.diagnostics;
+        report.errors.extend(parse_errors);
+        report.errors.extend(batch.quarantined.iter().map(|entry| {
+            format!(
+                "{}: in quarantine ({})",
+                entry.original_path.display(),
+                entry.reason
+            )
+        }));
+        report.elapsed_ms = start_time.elapsed().as_millis();
+        Ok(report)
+    }
+
+    /// Ingiere manos y resúmenes ya parseados
+    fn ingest_parsed(
+        &mut self,
+        hands: Vec<ParsedHand>,
+        summaries: &[TournamentSummary],
+    ) -> Result<IngestReport> {
+        // Las manos primero: los resúmenes completan torneos ya registrados
+        let mut report = self.ingest_hands(hands)?;
+        let summary_report = self.ingest_summaries(summaries)?;
+        report.tournament_results = summary_report.tournament_results;
+        report.errors.extend(summary_report.errors);
+        Ok(report)
+    }
+
+    // ========================================================================
+    // PRIVATE METHODS - Bootstrap
+    // ========================================================================
+
+    /// Sincroniza DuckDB con los Parquet existentes y reconstruye el estado
+    fn bootstrap(&mut self) -> Result<()> {
+        // Una tabla vacía (base de datos nueva o reconstruida por una
+        // migración) necesita todos sus Parquet aunque la caché diga que ya
+        // se cargaron en otra sesión. Se decide tabla a tabla para no
+        // recargar filas que siguen presentes en las demás.
+        if self.count_rows(HANDS_METADATA_TABLE)? == 0 {
+            self.metadata_reader.reset_cache()?;
+        }
+        if self.count_rows(HANDS_ACTIONS_TABLE)? == 0 {
+            self.actions_reader.reset_cache()?;
+        }
+        if self.count_rows(HANDS_PLAYERS_TABLE)? == 0 {
+            self.players_reader.reset_cache()?;
+        }
+        let load = self.refresh()?;
+        if load.has_errors() {
+            return Err(anyhow::anyhow!(
+                "Failed to load existing Parquet files: {}",
+                load.errors.join("; ")
+            ));
+        }
+
+        self.ledger = HandLedger::from_ids(self.load_hand_ids()?);
+
+        let mut registry =
+            PlayerRegistry::with_existing(self.load_players()?, self.load_aliases()?);
+        registry.register_heroes(&self.config.processing.heroes);
+        self.converter = HandConverter::with_registry(self.config.conversion.clone(), registry)
+            .with_sessions(self.load_open_sessions()?)
+            .with_tournaments(self.load_tournaments()?);
+
+        Ok(())
+    }
+
+    /// Cuenta las filas de una tabla
+    fn count_rows(&self, table_name: &str) -> Result<i64> {
+        let query = format!("SELECT COUNT(*) FROM {}", table_name);
+        self.conn
+            .conn()
+            .query_row(&query, [], |row| row.get(0))
+            .with_context(|| format!("Failed to count rows in {}", table_name))
+    }
+
+    /// `hand_id` ya presentes en DuckDB
+    fn load_hand_ids(&self) -> Result<Vec<String>> {
+        let mut stmt = self
+            .conn
+            .conn()
+            .prepare("SELECT hand_id FROM hands_metadata")?;
+        let ids = stmt
+            .query_map([], |row| row.get::<_, String>(0))?
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .context("Failed to load hand ids")?;
+        Ok(ids)
+    }
+
+    /// Jugadores persistidos
+    fn load_players(&self) -> Result<Vec<Player>> {
+        let query = format!(
+            "SELECT player_id, display_name, is_hero, notes,
+                    COALESCE(strftime(created_at, '{fmt}'), ''),
+                    COALESCE(strftime(updated_at, '{fmt}'), '')
+             FROM players",
+            fmt = ISO_FORMAT
+        );
+        let mut stmt = self.conn.conn().prepare(&query)?;
+        let players = stmt
+            .query_map([], |row| {
+                Ok(Player {
+                    player_id: row.get(0)?,
+                    display_name: row.get(1)?,
+                    is_hero: row.get(2)?,
+                    notes: row.get(3)?,
+                    created_at: row.get(4)?,
+                    updated_at: row.get(5)?,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .context("Failed to load players")?;
+        Ok(players)
+    }
+
+    /// Aliases persistidos (se ignoran salas desconocidas)
+    fn load_aliases(&self) -> Result<Vec<PlayerAlias>> {
+        let query = format!(
+            "SELECT alias_id, player_id, site_name, site_nickname,
+                    COALESCE(strftime(created_at, '{}'), '')
+             FROM player_aliases",
+            ISO_FORMAT
+        );
+        let mut stmt = self.conn.conn().prepare(&query)?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                    row.get::<_, String>(4)?,
+                ))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .context("Failed to load player aliases")?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(alias_id, player_id, site, site_nickname, created_at)| {
+                parse_site_name(&site).map(|site_name| PlayerAlias {
+                    alias_id,
+                    player_id,
+                    site_name,
+                    site_nickname,
+                    created_at,
+                })
+            })
+            .collect())
+    }
+
+    /// Última sesión de cada jugador y stake, con su blind level
+    fn load_open_sessions(&self) -> Result<Vec<(CashSession, i64)>> {
+        let query = format!(
+            "SELECT s.session_id, s.player_id,
+                    strftime(s.start_time, '{fmt}'), strftime(s.end_time, '{fmt}'),
+                    s.stake, s.net_won_cents, s.ev_won_cents, s.rake_cents,
+                    s.rakeback_cents, s.bb_100, s.ev_bb_100, s.hands_played,
+                    COALESCE(strftime(s.created_at, '{fmt}'), ''),
+                    COALESCE(strftime(s.updated_at, '{fmt}'), ''),
+                    COALESCE(
+                        (SELECT MAX(h.blind_level) FROM hands_metadata h
+                         WHERE h.session_id = s.session_id),
+                        0
+                    )
+             FROM cash_sessions s
+             QUALIFY ROW_NUMBER() OVER (
+                 PARTITION BY s.player_id, s.stake ORDER BY s.start_time DESC
+             ) = 1",
+            fmt = ISO_FORMAT
+        );
+        let mut stmt = self.conn.conn().prepare(&query)?;
+        let sessions = stmt
+            .query_map([], |row| {
+                Ok((
+                    CashSession {
+                        session_id: row.get(0)?,
+                        player_id: row.get(1)?,
+                        start_time: row.get(2)?,
+                        end_time: row.get(3)?,
+                        stake: row.get(4)?,
+                        net_won_cents: row.get(5)?,
+                        ev_won_cents: row.get(6)?,
+                        rake_cents: row.get(7)?,
+                        rakeback_cents: row.get(8)?,
+                        bb_100: row.get(9)?,
+                        ev_bb_100: row.get(10)?,
+                        hands_played: row.get(11)?,
+                        created_at: row.get(12)?,
+                        updated_at: row.get(13)?,
+                    },
+                    row.get::<_, i64>(14)?,
+                ))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .context("Failed to load cash sessions")?;
+        Ok(sessions)
+    }
+
+    /// Torneos persistidos
+    fn load_tournaments(&self) -> Result<Vec<Tournament>> {
+        let query = format!(
+            "SELECT tournament_id, tournament_name, format, buyin_cents, rake_cents,
+                    total_entries, prize_pool_cents,
+                    strftime(start_time, '{fmt}'),
+                    COALESCE(strftime(created_at, '{fmt}'), '')
+             FROM tournaments",
+            fmt = ISO_FORMAT
+        );
+        let mut stmt = self.conn.conn().prepare(&query)?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(2)?,
+                    Tournament {
+                        tournament_id: row.get(0)?,
+                        tournament_name: row.get(1)?,
+                        format: GameFormat::MTT,
+                        buyin_cents: row.get(3)?,
+                        rake_cents: row.get(4)?,
+                        total_entries: row.get(5)?,
+                        prize_pool_cents: row.get(6)?,
+                        start_time: row.get(7)?,
+                        created_at: row.get(8)?,
+                    },
+                ))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .context("Failed to load tournaments")?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(format, tournament)| {
+                parse_tournament_format(&format).map(|format| Tournament {
+                    format,
+                    ..tournament
+                })
+            })
+            .collect())
+    }
+
+    // ========================================================================
+    // PRIVATE METHODS - Dimension tables
+    // ========================================================================
+
+    /// Persiste jugadores nuevos o modificados y aliases nuevos.
+    ///
+    /// DuckDB no permite `INSERT OR REPLACE` sobre columnas indexadas, así que
+    /// las actualizaciones se hacen como DELETE + INSERT.
+    fn persist_players(&mut self) -> Result<()> {
+        let (players, aliases) = self.converter.registry_mut().take_pending();
+        let conn = self.conn.conn();
+
+        for player in &players {
+            conn.execute(
+                "DELETE FROM players WHERE player_id = ?",
+                params![player.player_id],
+            )?;
+            conn.execute(
+                "INSERT INTO players (player_id, display_name, is_hero, notes) VALUES (?, ?, ?, ?)",
+                params![
+                    player.player_id,
+                    player.display_name,
+                    player.is_hero,
+                    player.notes
+                ],
+            )
+            .with_context(|| format!("Failed to persist player {}", player.display_name))?;
+        }
+
+        for alias in &aliases {
+            conn.execute(
+                "INSERT INTO player_aliases (alias_id, player_id, site_name, site_nickname)
+                 VALUES (?, ?, ?, ?)",
+                params![
+                    alias.alias_id,
+                    alias.player_id,
+                    alias.site_name.to_string(),
+                    alias.site_nickname
+                ],
+            )
+            .with_context(|| format!("Failed to persist alias {}", alias.site_nickname))?;
+        }
+
+        Ok(())
+    }
+
+    /// Reescribe las sesiones de cash que mantiene el conversor
+    fn persist_sessions(&mut self) -> Result<()> {
+        let conn = self.conn.conn();
+
+        for session in self.converter.sessions() {
+            conn.execute(
+                "DELETE FROM cash_sessions WHERE session_id = ?",
+                params![session.session_id],
+            )?;
+            conn.execute(
+                "INSERT INTO cash_sessions (
+                    session_id, player_id, start_time, end_time, stake,
+                    net_won_cents, ev_won_cents, rake_cents, rakeback_cents,
+                    bb_100, ev_bb_100, hands_played
+                 ) VALUES (
+                    ?, ?, CAST(? AS TIMESTAMP), CAST(? AS TIMESTAMP), ?,
+                    ?, ?, ?, ?, ?, ?, ?
+                 )",
+                params![
+                    session.session_id,
+                    session.player_id,
+                    sql_timestamp(&session.start_time),
+                    session.end_time.as_deref().and_then(sql_timestamp),
+                    session.stake,
+                    session.net_won_cents,
+                    session.ev_won_cents,
+                    session.rake_cents,
+                    session.rakeback_cents,
+                    session.bb_100,
+                    session.ev_bb_100,
+                    session.hands_played
+                ],
+            )
+            .with_context(|| format!("Failed to persist session {}", session.session_id))?;
+        }
+
+        Ok(())
+    }
+
+    /// Reescribe los torneos que mantiene el conversor.
+    ///
+    /// Un torneo creado solo desde un resumen sin hora de inicio se omite
+    /// hasta que lleguen sus manos (`start_time` es NOT NULL).
+    fn persist_tournaments(&mut self) -> Result<()> {
+        let conn = self.conn.conn();
+
+        for tournament in self.converter.tournaments() {
+            let Some(start_time) = sql_timestamp(&tournament.start_time) else {
+                continue;
+            };
+            conn.execute(
+                "DELETE FROM tournaments WHERE tournament_id = ?",
+                params![tournament.tournament_id],
+            )?;
+            conn.execute(
+                "INSERT INTO tournaments (
+                    tournament_id, tournament_name, format, buyin_cents, rake_cents,
+                    total_entries, prize_pool_cents, start_time
+                 ) VALUES (?, ?, ?, ?, ?, ?, ?, CAST(? AS TIMESTAMP))",
+                params![
+                    tournament.tournament_id,
+                    tournament.tournament_name,
+                    tournament.format.to_string(),
+                    tournament.buyin_cents,
+                    tournament.rake_cents,
+                    tournament.total_entries,
+                    tournament.prize_pool_cents,
+                    start_time
+                ],
+            )
+            .with_context(|| {
+                format!("Failed to persist tournament {}", tournament.tournament_id)
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Reescribe los resultados de torneo (uno por torneo y jugador)
+    fn persist_tournament_results(&mut self) -> Result<()> {
+        let conn = self.conn.conn();
+
+        for result in self.converter.tournament_results() {
+            conn.execute(
+                "DELETE FROM tournament_results WHERE tournament_id = ? AND player_id = ?",
+                params![result.tournament_id, result.player_id],
+            )?;
+            conn.execute(
+                "INSERT INTO tournament_results (
+                    result_id, tournament_id, player_id, finish_position,
+                    prize_won_cents, bounty_won_cents, total_won_cents, roi_real
+                 ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                params![
+                    result.result_id,
+                    result.tournament_id,
+                    result.player_id,
+                    result.finish_position,
+                    result.prize_won_cents,
+                    result.bounty_won_cents,
+                    result.total_won_cents,
+                    result.roi_real
+                ],
+            )
+            .with_context(|| {
+                format!(
+                    "Failed to persist result of tournament {}",
+                    result.tournament_id
+                )
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+/// Configuración del reader para un directorio de tabla
+pub(crate) fn read_config(table_dir: &Path) -> ParquetReadConfig {
+    let mut config = ParquetReadConfig::new(table_dir);
+    config.loaded_files_cache = table_dir.join(".loaded_files.json");
+    config
+}
+
+/// Guarda el ledger del watcher; un fallo solo provoca relecturas al arrancar
+fn save_ledger(ledger: &FileLedger, path: &Path) {
+    if let Err(e) = ledger.save(path) {
+        eprintln!("✗ Failed to save watcher ledger {:?}: {}", path, e);
+    }
+}
+
+/// Indica si la ruta es un historial de texto
+fn is_history_file(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.eq_ignore_ascii_case("txt"))
+            .unwrap_or(false)
+}
+
+/// Convierte un timestamp ISO 8601 al formato literal de DuckDB
+fn sql_timestamp(value: &str) -> Option<String> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|dt| dt.naive_utc().format("%Y-%m-%d %H:%M:%S").to_string())
+}
+
+/// Inverso de `GameFormat::to_string` para la tabla `tournaments`
+fn parse_tournament_format(value: &str) -> Option<GameFormat> {
+    match value {
+        "MTT" => Some(GameFormat::MTT),
+        "SNG" => Some(GameFormat::SNG),
+        "EXPRESSO" => Some(GameFormat::Expresso),
+        _ => None,
+    }
+}
+
+/// Inverso de `SiteName::to_string`
+fn parse_site_name(value: &str) -> Option<SiteName> {
+    match value {
+        "Winamax" => Some(SiteName::Winamax),
+        "PokerStars" => Some(SiteName::PokerStars),
+        "GGPoker" => Some(SiteName::GGPoker),
+        "PartyPoker" => Some(SiteName::PartyPoker),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ingest_config_dirs() {
+        let config = IngestConfig::new("/tmp/poker").with_batch_window_ms(100);
+
+        assert_eq!(
+            config.metadata_dir(),
+            PathBuf::from("/tmp/poker/hands_metadata")
+        );
+        assert_eq!(
+            config.actions_dir(),
+            PathBuf::from("/tmp/poker/hands_actions")
+        );
+        assert_eq!(
+            config.players_dir(),
+            PathBuf::from("/tmp/poker/hands_players")
+        );
+        assert_eq!(
+            config.watcher_ledger_path(),
+            PathBuf::from("/tmp/poker/watcher_ledger.json")
+        );
+        assert_eq!(
+            config.hand_quarantine_path(),
+            PathBuf::from("/tmp/poker/hands_quarantine.jsonl")
+        );
+        assert_eq!(config.batch_window_ms, 100);
+    }
+
+    #[test]
+    fn test_sql_timestamp() {
+        assert_eq!(
+            sql_timestamp("2025-12-15T14:02:20Z").as_deref(),
+            Some("2025-12-15 14:02:20")
+        );
+        assert_eq!(sql_timestamp("15/12/2025"), None);
+    }
+
+    #[test]
+    fn test_parse_site_name_roundtrip() {
+        for site in [
+            SiteName::Winamax,
+            SiteName::PokerStars,
+            SiteName::GGPoker,
+            SiteName::PartyPoker,
+        ] {
+            assert_eq!(parse_site_name(&site.to_string()), Some(site));
+        }
+        assert_eq!(parse_site_name("Unibet"), None);
+    }
+
+    #[test]
+    fn test_report_summary() {
+        let report = IngestReport {
+            files_processed: 1,
+            hands_ingested: 10,
+            hands_skipped: 5,
+            rows_loaded: 70,
+            ..Default::default()
+        };
+
+        let summary = report.summary();
+        assert!(summary.contains("10 hands"));
+        assert!(summary.contains("5 skipped"));
+        assert!(!summary.contains("errors"));
+    }
+}
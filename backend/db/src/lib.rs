@@ -9,7 +9,7 @@
 //! - Esquema Star Schema optimizado para lectura
 //!
 //! ## Data Model
-//! - Tabla Fact: `hands_actions`
+//! - Tablas Fact: `hands_actions`, `hands_players`
 //! - Tabla Wide: `player_stats_flat`
 //! - Índices optimizados para Ryzen 3800X
 //!
@@ -18,25 +18,46 @@
 //! "caliente" en memoria para latencia cero.
 
 // Módulos implementados
+pub mod allin_ev;
 pub mod connection;
+pub mod hand_converter;
+pub mod hand_ledger;
+pub mod hand_quarantine;
+pub mod ingest;
 pub mod inmemory;
 pub mod memory_monitor;
+pub mod migrations;
 pub mod parquet_loader;
 pub mod parquet_reader;
 pub mod parquet_writer;
+pub mod player_stats;
 pub mod schema;
 
 // Re-exports principales
 pub use schema::{
-    ActionType, CashSession, GameFormat, HandAction, HandMetadata, HandMetadataBuilder, Player,
-    PlayerAlias, SiteName, Street, Tournament, TournamentResult,
+    ActionType, CashSession, GameFormat, HandAction, HandMetadata, HandMetadataBuilder, HandPlayer,
+    Player, PlayerAlias, SiteName, Street, Tournament, TournamentResult,
 };
 
+pub use allin_ev::{compute_allin_ev, AllInEv, PlayerEv, DEFAULT_EV_SIMULATIONS};
 pub use connection::{DbConfig, DbConnection, DbStats};
+pub use hand_converter::{
+    encode_cards, map_action_type, net_won_cents, ConversionBatch, ConversionConfig,
+    ConversionError, ConvertedHand, HandConverter, PlayerRegistry,
+};
+pub use hand_ledger::HandLedger;
+pub use hand_quarantine::HandQuarantine;
+pub use ingest::{IngestConfig, IngestReport, IngestService};
 pub use inmemory::{CacheStats, InMemoryOptimization, MemoryMaintenance, QueryOptimizer};
 pub use memory_monitor::{MemoryMetrics, MemoryMonitor, MemoryReport, MemoryTrend};
+pub use migrations::{
+    Migration, MigrationError, MigrationKind, MigrationReport, MigrationRunner, MIGRATIONS,
+};
 pub use parquet_loader::{LoadResult, ParquetLoadConfig, ParquetLoader};
 pub use parquet_reader::{
     CacheStats as ReaderCacheStats, ParquetReadConfig, ParquetReader, ReadResult,
 };
 pub use parquet_writer::{DatePartition, ParquetWriteConfig, ParquetWriter};
+pub use player_stats::{
+    PlayerStats, PlayerStatsEngine, StatCounts, StatValue, StatsQuery, StatsRefresh,
+};
@@ -0,0 +1,653 @@
+//! # Migrations Module
+//!
+//! Versionado del esquema DuckDB con migraciones numeradas y embebidas.
+//!
+//! La versión aplicada se guarda en la tabla `schema_version` (una fila por
+//! migración). Al abrir una base de datos, `MigrationRunner` aplica en orden
+//! las migraciones con versión superior a la registrada:
+//!
+//! 1. Si la base de datos es persistente y ya tiene tablas, copia el archivo
+//!    `.duckdb` a `<archivo>.v<N>.bak` (N = versión previa) tras un
+//!    `CHECKPOINT`
+//! 2. Cada migración se ejecuta en su propia transacción junto con su fila de
+//!    `schema_version`: o se aplica entera o no se aplica
+//! 3. Las migraciones incompatibles (`MigrationKind::Rebuild`) borran las
+//!    tablas afectadas, las recrean con su SQL y las recargan desde los
+//!    Parquet, que son la fuente de verdad
+//!
+//! Volver a ejecutar el runner no aplica nada: las migraciones registradas
+//! en `schema_version` se saltan.
+//!
+//! ## Añadir una migración
+//! Se crea `sql/migrations/NNN_nombre.sql` y se añade a `MIGRATIONS` con la
+//! siguiente versión. La migración 1 es `sql/schema.sql` (línea base). Las
+//! sentencias `PRAGMA` se ignoran: la configuración la aplica `DbConfig`.
+//!
+//! ```rust,ignore
+//! use poker_db::{DbConnection, MigrationRunner};
+//!
+//! let conn = DbConnection::persistent("poker.duckdb".to_string())?;
+//! let report = MigrationRunner::new().with_parquet_dir("data").run(&conn)?;
+//! println!("Esquema v{} -> v{}", report.from_version, report.to_version);
+//! ```
+
+use duckdb::{params, Connection};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+use crate::connection::DbConnection;
+use crate::ingest::read_config;
+use crate::parquet_reader::ParquetReader;
+
+/// Tabla con las migraciones aplicadas
+pub const SCHEMA_VERSION_TABLE: &str = "schema_version";
+
+/// Migraciones embebidas, en orden de versión
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "baseline",
+        kind: MigrationKind::InPlace,
+        sql: include_str!("../sql/schema.sql"),
+    },
+    Migration {
+        version: 2,
+        name: "hands_players",
+        kind: MigrationKind::InPlace,
+        sql: include_str!("../sql/migrations/002_hands_players.sql"),
+    },
+    Migration {
+        version: 3,
+        name: "hand_cards",
+        kind: MigrationKind::Rebuild {
+            tables: &["hands_metadata"],
+        },
+        sql: include_str!("../sql/migrations/003_hand_cards.sql"),
+    },
+];
+
+// ============================================================================
+// MIGRACIONES
+// ============================================================================
+
+/// Forma de aplicar una migración
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationKind {
+    /// El SQL transforma el esquema en sitio (`CREATE`, `ALTER TABLE`...)
+    InPlace,
+    /// Cambio incompatible (p.ej. DuckDB no admite `ALTER TABLE` sobre
+    /// tablas con índices): las tablas se borran, el SQL las recrea y se
+    /// recargan desde `<parquet_dir>/<tabla>/`. Las tablas sin directorio
+    /// Parquet quedan vacías (p.ej. estadísticas derivadas que se recalculan)
+    Rebuild { tables: &'static [&'static str] },
+}
+
+/// Migración numerada del esquema
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Migration {
+    /// Versión del esquema tras aplicarla (estrictamente creciente)
+    pub version: u32,
+    /// Nombre corto (se guarda en `schema_version`)
+    pub name: &'static str,
+    /// Forma de aplicarla
+    pub kind: MigrationKind,
+    /// Sentencias SQL separadas por `;`
+    pub sql: &'static str,
+}
+
+impl Migration {
+    /// Sentencias a ejecutar, sin comentarios ni `PRAGMA`
+    pub fn statements(&self) -> Vec<String> {
+        let cleaned: String = self
+            .sql
+            .lines()
+            .filter(|line| !line.trim_start().starts_with("--"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        cleaned
+            .split(';')
+            .map(str::trim)
+            .filter(|s| !s.is_empty() && !s.to_ascii_uppercase().starts_with("PRAGMA"))
+            .map(String::from)
+            .collect()
+    }
+}
+
+/// Errores al migrar el esquema
+#[derive(Debug, Error)]
+pub enum MigrationError {
+    #[error("Error de DuckDB: {0}")]
+    Db(#[from] duckdb::Error),
+    #[error("Migraciones fuera de orden en la versión {0}")]
+    InvalidOrder(u32),
+    #[error("El esquema está en la versión {found}, posterior a la soportada ({supported})")]
+    NewerSchema { found: u32, supported: u32 },
+    #[error("No se pudo copiar {path:?} antes de migrar: {source}")]
+    Backup { path: PathBuf, source: io::Error },
+    #[error("La migración {version} ({name}) falló: {source}")]
+    Failed {
+        version: u32,
+        name: &'static str,
+        source: duckdb::Error,
+    },
+    #[error("No se pudo recargar {table} desde Parquet: {message}")]
+    Reload { table: String, message: String },
+}
+
+/// Resultado de una ejecución del runner
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// Versión antes de migrar (0 = sin versionar)
+    pub from_version: u32,
+    /// Versión tras migrar
+    pub to_version: u32,
+    /// Versiones aplicadas
+    pub applied: Vec<u32>,
+    /// Copia del archivo `.duckdb` hecha antes de migrar
+    pub backup_path: Option<PathBuf>,
+    /// Tablas reconstruidas por migraciones incompatibles
+    pub rebuilt_tables: Vec<String>,
+    /// Filas recargadas desde Parquet en las reconstrucciones
+    pub rows_reloaded: usize,
+}
+
+impl MigrationReport {
+    /// Indica si no había migraciones pendientes
+    pub fn is_noop(&self) -> bool {
+        self.applied.is_empty()
+    }
+}
+
+// ============================================================================
+// RUNNER
+// ============================================================================
+
+/// Aplica las migraciones pendientes sobre una conexión
+#[derive(Debug, Clone)]
+pub struct MigrationRunner {
+    migrations: Vec<Migration>,
+    parquet_dir: Option<PathBuf>,
+}
+
+impl Default for MigrationRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MigrationRunner {
+    /// Runner con las migraciones embebidas y sin recarga desde Parquet
+    pub fn new() -> Self {
+        Self {
+            migrations: MIGRATIONS.to_vec(),
+            parquet_dir: None,
+        }
+    }
+
+    /// Sustituye la lista de migraciones
+    pub fn with_migrations(mut self, migrations: Vec<Migration>) -> Self {
+        self.migrations = migrations;
+        self
+    }
+
+    /// Directorio de datos con un subdirectorio Parquet por tabla (el
+    /// `data_dir` de `IngestConfig`) para las reconstrucciones
+    pub fn with_parquet_dir(mut self, parquet_dir: impl Into<PathBuf>) -> Self {
+        self.parquet_dir = Some(parquet_dir.into());
+        self
+    }
+
+    /// Última versión conocida por el runner
+    pub fn latest_version(&self) -> u32 {
+        self.migrations.last().map_or(0, |m| m.version)
+    }
+
+    /// Versión registrada en la base de datos (0 si no está versionada)
+    pub fn current_version(conn: &DbConnection) -> Result<u32, MigrationError> {
+        if !table_exists(conn.conn(), SCHEMA_VERSION_TABLE)? {
+            return Ok(0);
+        }
+        let query = format!(
+            "SELECT COALESCE(MAX(version), 0) FROM {}",
+            SCHEMA_VERSION_TABLE
+        );
+        let version: i64 = conn.conn().query_row(&query, [], |row| row.get(0))?;
+        Ok(version as u32)
+    }
+
+    /// Migraciones con versión superior a la registrada
+    pub fn pending(&self, conn: &DbConnection) -> Result<Vec<Migration>, MigrationError> {
+        let current = Self::current_version(conn)?;
+        Ok(self
+            .migrations
+            .iter()
+            .filter(|m| m.version > current)
+            .copied()
+            .collect())
+    }
+
+    /// Aplica las migraciones pendientes
+    pub fn run(&self, conn: &DbConnection) -> Result<MigrationReport, MigrationError> {
+        self.check_order()?;
+
+        let from_version = Self::current_version(conn)?;
+        let mut report = MigrationReport {
+            from_version,
+            to_version: from_version,
+            ..MigrationReport::default()
+        };
+        if from_version > self.latest_version() {
+            return Err(MigrationError::NewerSchema {
+                found: from_version,
+                supported: self.latest_version(),
+            });
+        }
+
+        let pending = self.pending(conn)?;
+        if pending.is_empty() {
+            return Ok(report);
+        }
+
+        report.backup_path = backup(conn, from_version)?;
+        conn.conn().execute_batch(&format!(
+            "CREATE TABLE IF NOT EXISTS {} (
+                version INTEGER PRIMARY KEY,
+                name VARCHAR NOT NULL,
+                applied_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+            )",
+            SCHEMA_VERSION_TABLE
+        ))?;
+
+        for migration in pending {
+            apply(conn.conn(), &migration)?;
+            if let MigrationKind::Rebuild { tables } = migration.kind {
+                for table in tables {
+                    report.rows_reloaded += self.reload(conn, table)?;
+                    report.rebuilt_tables.push(table.to_string());
+                }
+            }
+            report.applied.push(migration.version);
+            report.to_version = migration.version;
+        }
+
+        Ok(report)
+    }
+
+    // ========================================================================
+    // PRIVATE METHODS
+    // ========================================================================
+
+    /// Comprueba que las versiones sean estrictamente crecientes
+    fn check_order(&self) -> Result<(), MigrationError> {
+        let mut previous = 0;
+        for migration in &self.migrations {
+            if migration.version <= previous {
+                return Err(MigrationError::InvalidOrder(migration.version));
+            }
+            previous = migration.version;
+        }
+        Ok(())
+    }
+
+    /// Recarga una tabla reconstruida desde `<parquet_dir>/<tabla>/`.
+    ///
+    /// La caché de archivos cargados del directorio se reinicia, así que
+    /// queda coherente con la tabla para la carga incremental de la ingesta.
+    fn reload(&self, conn: &DbConnection, table: &str) -> Result<usize, MigrationError> {
+        let Some(table_dir) = self.parquet_dir.as_ref().map(|dir| dir.join(table)) else {
+            return Ok(0);
+        };
+        if !table_dir.is_dir() {
+            return Ok(0);
+        }
+
+        let reload_error = |message: String| MigrationError::Reload {
+            table: table.to_string(),
+            message,
+        };
+        let mut reader =
+            ParquetReader::new(read_config(&table_dir)).map_err(|e| reload_error(e.to_string()))?;
+        reader
+            .reset_cache()
+            .map_err(|e| reload_error(e.to_string()))?;
+        let load = reader
+            .load_incremental(conn, table)
+            .map_err(|e| reload_error(e.to_string()))?;
+        if load.has_errors() {
+            return Err(reload_error(load.errors.join("; ")));
+        }
+        Ok(load.rows_loaded)
+    }
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+/// Aplica una migración y registra su versión en una única transacción
+fn apply(conn: &Connection, migration: &Migration) -> Result<(), MigrationError> {
+    let failed = |source| MigrationError::Failed {
+        version: migration.version,
+        name: migration.name,
+        source,
+    };
+
+    conn.execute_batch("BEGIN TRANSACTION").map_err(failed)?;
+    match apply_statements(conn, migration) {
+        Ok(()) => conn.execute_batch("COMMIT").map_err(failed),
+        Err(e) => {
+            let _ = conn.execute_batch("ROLLBACK");
+            Err(failed(e))
+        }
+    }
+}
+
+/// Sentencias de una migración (dentro de la transacción)
+fn apply_statements(conn: &Connection, migration: &Migration) -> duckdb::Result<()> {
+    if let MigrationKind::Rebuild { tables } = migration.kind {
+        for table in tables {
+            conn.execute_batch(&format!("DROP TABLE IF EXISTS {}", table))?;
+        }
+    }
+    for statement in migration.statements() {
+        conn.execute_batch(&statement)?;
+    }
+    conn.execute(
+        &format!(
+            "INSERT INTO {} (version, name) VALUES (?, ?)",
+            SCHEMA_VERSION_TABLE
+        ),
+        params![migration.version as i64, migration.name],
+    )?;
+    Ok(())
+}
+
+/// Copia el archivo `.duckdb` antes de migrar.
+///
+/// No hay copia para bases de datos en memoria ni para archivos recién
+/// creados (sin tablas): no hay nada que perder.
+fn backup(conn: &DbConnection, from_version: u32) -> Result<Option<PathBuf>, MigrationError> {
+    let Some(db_path) = conn.config().db_path.as_deref().map(Path::new) else {
+        return Ok(None);
+    };
+    let tables: i64 = conn.conn().query_row(
+        "SELECT COUNT(*) FROM information_schema.tables WHERE table_schema = 'main'",
+        [],
+        |row| row.get(0),
+    )?;
+    if tables == 0 || !db_path.exists() {
+        return Ok(None);
+    }
+
+    // Vuelca el WAL al archivo principal para que la copia sea completa
+    conn.conn().execute_batch("CHECKPOINT")?;
+
+    let path = backup_path(db_path, from_version);
+    fs::copy(db_path, &path).map_err(|source| MigrationError::Backup {
+        path: db_path.to_path_buf(),
+        source,
+    })?;
+    Ok(Some(path))
+}
+
+/// Ruta de la copia: `<archivo>.v<versión>.bak` junto al original
+pub fn backup_path(db_path: &Path, version: u32) -> PathBuf {
+    let mut name = db_path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".v{}.bak", version));
+    db_path.with_file_name(name)
+}
+
+/// Indica si existe una tabla en el esquema `main`
+fn table_exists(conn: &Connection, table: &str) -> duckdb::Result<bool> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM information_schema.tables
+         WHERE table_schema = 'main' AND table_name = ?",
+        params![table],
+        |row| row.get(0),
+    )?;
+    Ok(count > 0)
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::DbConfig;
+    use tempfile::TempDir;
+
+    const ADD_NOTES: Migration = Migration {
+        version: 2,
+        name: "player_notes",
+        kind: MigrationKind::InPlace,
+        sql:
+            "CREATE TABLE IF NOT EXISTS player_notes (player_id VARCHAR PRIMARY KEY, note VARCHAR);",
+    };
+
+    /// Runner con solo la línea base (las pruebas añaden sus migraciones)
+    fn baseline() -> MigrationRunner {
+        MigrationRunner::new().with_migrations(vec![MIGRATIONS[0]])
+    }
+
+    fn count(conn: &DbConnection, query: &str) -> i64 {
+        conn.conn().query_row(query, [], |row| row.get(0)).unwrap()
+    }
+
+    fn insert_hand(conn: &DbConnection, hand_id: &str) {
+        conn.conn()
+            .execute(
+                "INSERT INTO hands_metadata
+                 (hand_id, timestamp, stake, format, table_name, blind_level, button_seat)
+                 VALUES (?, TIMESTAMP '2024-01-01 12:00:00', 'NL10', 'CASH', 'T', 10, 1)",
+                params![hand_id],
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_embedded_migrations_are_ordered() {
+        assert!(MigrationRunner::new().check_order().is_ok());
+        assert_eq!(MIGRATIONS[0].version, 1);
+
+        let statements = MIGRATIONS[0].statements();
+        assert!(statements.iter().any(|s| s.contains("hands_metadata")));
+        assert!(statements.iter().all(|s| !s.starts_with("PRAGMA")));
+
+        let unordered = MigrationRunner::new().with_migrations(vec![ADD_NOTES, MIGRATIONS[0]]);
+        assert!(matches!(
+            unordered.check_order(),
+            Err(MigrationError::InvalidOrder(1))
+        ));
+    }
+
+    #[test]
+    fn test_migrations_are_idempotent() {
+        let conn = DbConnection::in_memory().unwrap();
+        let runner = MigrationRunner::new();
+
+        let first = runner.run(&conn).unwrap();
+        assert_eq!(first.from_version, 0);
+        assert_eq!(first.to_version, runner.latest_version());
+        assert_eq!(first.backup_path, None);
+        assert!(conn.verify_schema().unwrap());
+
+        let second = runner.run(&conn).unwrap();
+        assert!(second.is_noop());
+        assert_eq!(second.from_version, runner.latest_version());
+        assert_eq!(
+            count(&conn, "SELECT COUNT(*) FROM schema_version"),
+            MIGRATIONS.len() as i64
+        );
+    }
+
+    #[test]
+    fn test_backup_before_migrating_persistent_db() {
+        let dir = TempDir::new().unwrap();
+        let db_path = dir.path().join("poker.duckdb");
+        let config = DbConfig::persistent(db_path.to_string_lossy().into_owned());
+
+        let conn = DbConnection::new(config.clone()).unwrap();
+        let created = baseline().run(&conn).unwrap();
+        assert_eq!(created.backup_path, None);
+        conn.conn()
+            .execute(
+                "INSERT INTO players (player_id, display_name) VALUES ('p1', 'thesmoy')",
+                [],
+            )
+            .unwrap();
+        drop(conn);
+
+        let conn = DbConnection::new(config).unwrap();
+        let runner = MigrationRunner::new().with_migrations(vec![MIGRATIONS[0], ADD_NOTES]);
+        let report = runner.run(&conn).unwrap();
+        assert_eq!(report.applied, vec![2]);
+        assert_eq!(report.backup_path, Some(backup_path(&db_path, 1)));
+        assert!(backup_path(&db_path, 1).exists());
+        assert!(table_exists(conn.conn(), "player_notes").unwrap());
+        assert_eq!(count(&conn, "SELECT COUNT(*) FROM players"), 1);
+
+        // Sin migraciones pendientes no hay copia nueva
+        let again = runner.run(&conn).unwrap();
+        assert!(again.is_noop());
+        assert_eq!(again.backup_path, None);
+    }
+
+    #[test]
+    fn test_failed_migration_rolls_back() {
+        let conn = DbConnection::in_memory().unwrap();
+        let broken = Migration {
+            version: 2,
+            name: "broken",
+            kind: MigrationKind::InPlace,
+            sql: "CREATE TABLE half_done (id INTEGER); ALTER TABLE missing ADD COLUMN x INTEGER;",
+        };
+        let runner = MigrationRunner::new().with_migrations(vec![MIGRATIONS[0], broken]);
+
+        let err = runner.run(&conn).unwrap_err();
+        assert!(matches!(err, MigrationError::Failed { version: 2, .. }));
+        assert_eq!(MigrationRunner::current_version(&conn).unwrap(), 1);
+        assert!(!table_exists(conn.conn(), "half_done").unwrap());
+
+        // Un runner más antiguo que el esquema no lo toca
+        let newer = MigrationRunner::new().with_migrations(vec![MIGRATIONS[0], ADD_NOTES]);
+        newer.run(&conn).unwrap();
+        assert!(matches!(
+            baseline().run(&conn),
+            Err(MigrationError::NewerSchema {
+                found: 2,
+                supported: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_incompatible_migration_rebuilds_from_parquet() {
+        let dir = TempDir::new().unwrap();
+        let table_dir = dir.path().join("hands_metadata");
+        fs::create_dir_all(&table_dir).unwrap();
+
+        let conn = DbConnection::in_memory().unwrap();
+        baseline().run(&conn).unwrap();
+        insert_hand(&conn, "h1");
+        insert_hand(&conn, "h2");
+        conn.export_to_parquet("hands_metadata", &table_dir.join("hands.parquet"))
+            .unwrap();
+
+        // La nueva definición quita columnas con índices y añade `board`
+        let rebuild = Migration {
+            version: 2,
+            name: "hands_metadata_v2",
+            kind: MigrationKind::Rebuild {
+                tables: &["hands_metadata"],
+            },
+            sql: "CREATE TABLE hands_metadata (
+                      hand_id VARCHAR PRIMARY KEY,
+                      timestamp TIMESTAMP NOT NULL,
+                      stake VARCHAR NOT NULL,
+                      format VARCHAR NOT NULL,
+                      table_name VARCHAR NOT NULL,
+                      blind_level BIGINT NOT NULL,
+                      button_seat UTINYINT NOT NULL,
+                      board VARCHAR
+                  );
+                  CREATE INDEX IF NOT EXISTS idx_hands_metadata_timestamp
+                  ON hands_metadata(timestamp);",
+        };
+        let runner = MigrationRunner::new()
+            .with_migrations(vec![MIGRATIONS[0], rebuild])
+            .with_parquet_dir(dir.path());
+
+        let report = runner.run(&conn).unwrap();
+        assert_eq!(report.applied, vec![2]);
+        assert_eq!(report.rebuilt_tables, vec!["hands_metadata".to_string()]);
+        assert_eq!(report.rows_reloaded, 2);
+        assert_eq!(
+            count(
+                &conn,
+                "SELECT COUNT(*) FROM hands_metadata WHERE board IS NULL"
+            ),
+            2
+        );
+
+        // Idempotente: la segunda ejecución no vuelve a reconstruir
+        assert!(runner.run(&conn).unwrap().is_noop());
+        assert_eq!(count(&conn, "SELECT COUNT(*) FROM hands_metadata"), 2);
+    }
+
+    #[test]
+    fn test_hand_cards_migration_and_macros() {
+        let dir = TempDir::new().unwrap();
+        let table_dir = dir.path().join("hands_metadata");
+        fs::create_dir_all(&table_dir).unwrap();
+
+        // Parquet escrito antes de la migración 003, sin columnas de cartas
+        let conn = DbConnection::in_memory().unwrap();
+        MigrationRunner::new()
+            .with_migrations(MIGRATIONS[..2].to_vec())
+            .run(&conn)
+            .unwrap();
+        insert_hand(&conn, "h1");
+        conn.export_to_parquet("hands_metadata", &table_dir.join("old.parquet"))
+            .unwrap();
+
+        let runner = MigrationRunner::new().with_parquet_dir(dir.path());
+        let report = runner.run(&conn).unwrap();
+        assert_eq!(report.applied, vec![3]);
+        assert_eq!(report.rows_reloaded, 1);
+        assert_eq!(
+            count(
+                &conn,
+                "SELECT COUNT(*) FROM hands_metadata WHERE flop IS NULL AND hero_cards IS NULL"
+            ),
+            1
+        );
+
+        let text = |query: &str| -> Option<String> {
+            conn.conn().query_row(query, [], |row| row.get(0)).unwrap()
+        };
+        let flag =
+            |query: &str| -> bool { conn.conn().query_row(query, [], |row| row.get(0)).unwrap() };
+
+        assert_eq!(
+            text("SELECT full_board('Ah7d2h', '9s', NULL)").as_deref(),
+            Some("Ah7d2h9s")
+        );
+        assert!(flag("SELECT has_flush_draw('Ah7d2h')"));
+        assert!(!flag("SELECT has_flush_draw('Ah7h2h')"));
+        assert!(!flag("SELECT has_flush_draw('Ah7d2h9s3c')"));
+        assert!(flag("SELECT has_flush_possible('Ah7h2h')"));
+        assert!(flag("SELECT is_monotone('Ah7h2h')"));
+        assert!(flag("SELECT is_rainbow('Ah7d2c')"));
+        assert!(flag("SELECT is_paired('Ah7dAc')"));
+        assert!(!flag("SELECT is_paired('Ah7d2c')"));
+        assert_eq!(text("SELECT hand_class('KdAd')").as_deref(), Some("AKs"));
+        assert_eq!(text("SELECT hand_class('9hTc')").as_deref(), Some("T9o"));
+        assert_eq!(text("SELECT hand_class('QsQh')").as_deref(), Some("QQ"));
+        assert_eq!(text("SELECT hand_class(NULL)"), None);
+    }
+}
@@ -9,6 +9,8 @@
 //! - Soporte para caché de consultas frecuentes
 //! - Estrategia de precarga para manos históricas
 
+use crate::parquet_reader::insert_by_name_query;
+use crate::player_stats::PlayerStatsEngine;
 use duckdb::Result as DuckDbResult;
 use std::path::{Path, PathBuf};
 use std::time::Instant;
@@ -24,6 +26,8 @@ pub struct ParquetLoadConfig {
     pub max_files: Option<usize>,
     /// Tablas a cargar (None = todas)
     pub tables: Option<Vec<String>>,
+    /// Refrescar `player_stats_flat` tras cargar manos
+    pub refresh_stats: bool,
 }
 
 impl ParquetLoadConfig {
@@ -34,6 +38,7 @@ impl ParquetLoadConfig {
             preload_all: true,
             max_files: None,
             tables: None,
+            refresh_stats: true,
         }
     }
 
@@ -54,6 +59,12 @@ impl ParquetLoadConfig {
         self.tables = Some(tables);
         self
     }
+
+    /// Establece si se refrescan las estadísticas tras cada carga de manos
+    pub fn with_stats_refresh(mut self, refresh: bool) -> Self {
+        self.refresh_stats = refresh;
+        self
+    }
 }
 
 /// Resultado de carga de Parquet
@@ -140,14 +151,12 @@ impl ParquetLoader {
 
         // Construir query de carga con patrón wildcard
         let pattern = table_dir.join("*.parquet");
-        let query = format!(
-            "INSERT INTO {} SELECT * FROM read_parquet('{}')",
-            table_name,
-            pattern.display()
-        );
+        let pattern = pattern.to_string_lossy().replace('\\', "/");
+        let query = insert_by_name_query(conn, table_name, &pattern)?;
 
         // Ejecutar query de inserción
         conn.execute(&query, [])?;
+        self.refresh_stats(conn, table_name)?;
 
         // Obtener número de filas insertadas
         let row_count = self.count_table_rows(conn, table_name)?;
@@ -193,8 +202,8 @@ impl ParquetLoader {
             });
         }
 
-        // Contar archivos
-        let (file_count, total_size) = self.count_parquet_files(&path);
+        // Contar archivos (incluyendo subparticiones year=/month=/day=)
+        let (file_count, total_size) = self.count_partitioned_files(&path);
 
         if file_count == 0 {
             return Ok(LoadResult {
@@ -208,14 +217,12 @@ impl ParquetLoader {
 
         // Construir query con patrón wildcard recursivo
         let pattern = path.join("**/*.parquet");
-        let query = format!(
-            "INSERT INTO {} SELECT * FROM read_parquet('{}')",
-            table_name,
-            pattern.display()
-        );
+        let pattern = pattern.to_string_lossy().replace('\\', "/");
+        let query = insert_by_name_query(conn, table_name, &pattern)?;
 
         // Ejecutar inserción
         conn.execute(&query, [])?;
+        self.refresh_stats(conn, table_name)?;
 
         // Contar filas
         let row_count = self.count_table_rows(conn, table_name)?;
@@ -249,6 +256,14 @@ impl ParquetLoader {
         Ok(results)
     }
 
+    /// Contabiliza en `player_stats_flat` las manos recién cargadas
+    fn refresh_stats(&self, conn: &duckdb::Connection, table_name: &str) -> DuckDbResult<()> {
+        if self.config.refresh_stats && matches!(table_name, "hands_metadata" | "hands_actions") {
+            PlayerStatsEngine::refresh(conn)?;
+        }
+        Ok(())
+    }
+
     /// Obtiene la lista de tablas a cargar
     fn get_tables_to_load(&self) -> Vec<String> {
         if let Some(ref tables) = self.config.tables {
@@ -260,6 +275,7 @@ impl ParquetLoader {
                 "player_aliases".to_string(),
                 "hands_metadata".to_string(),
                 "hands_actions".to_string(),
+                "hands_players".to_string(),
                 "cash_sessions".to_string(),
                 "tournaments".to_string(),
                 "tournament_results".to_string(),
@@ -288,6 +304,24 @@ impl ParquetLoader {
         (count, total_size)
     }
 
+    /// Cuenta archivos Parquet en un directorio y sus subparticiones
+    fn count_partitioned_files(&self, dir: &Path) -> (usize, u64) {
+        let (mut count, mut total_size) = self.count_parquet_files(dir);
+
+        if let Ok(entries) = std::fs::read_dir(dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    let (sub_count, sub_size) = self.count_partitioned_files(&path);
+                    count += sub_count;
+                    total_size += sub_size;
+                }
+            }
+        }
+
+        (count, total_size)
+    }
+
     /// Cuenta filas en una tabla
     fn count_table_rows(&self, conn: &duckdb::Connection, table_name: &str) -> DuckDbResult<i64> {
         let query = format!("SELECT COUNT(*) FROM {}", table_name);
@@ -308,6 +342,7 @@ mod tests {
         assert_eq!(config.data_dir, PathBuf::from("/data/parquet"));
         assert!(config.preload_all);
         assert_eq!(config.max_files, None);
+        assert!(config.refresh_stats);
     }
 
     #[test]
@@ -377,4 +412,18 @@ mod tests {
         let (count, _size) = loader.count_parquet_files(temp_dir.path());
         assert_eq!(count, 2); // Solo .parquet files
     }
+
+    #[test]
+    fn test_count_partitioned_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let loader = ParquetLoader::new(ParquetLoadConfig::new(temp_dir.path()));
+
+        let day_dir = temp_dir.path().join("year=2024/month=03/day=15");
+        fs::create_dir_all(&day_dir).unwrap();
+        fs::write(day_dir.join("hands.parquet"), "mock data").unwrap();
+        fs::write(temp_dir.path().join("root.parquet"), "mock data").unwrap();
+
+        let (count, _size) = loader.count_partitioned_files(temp_dir.path());
+        assert_eq!(count, 2);
+    }
 }
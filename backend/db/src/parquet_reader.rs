@@ -4,7 +4,7 @@
 //! - Carga incremental (solo archivos nuevos)
 //! - Filtrado por predicados de fecha
 //! - Validación de integridad de datos
-//! - Integración directa con DuckDB
+//! - Integración directa con DuckDB (inserción por nombre de columna)
 //!
 //! ## Estrategia de Carga
 //! - Mantiene registro de archivos ya cargados
@@ -19,6 +19,8 @@ use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use duckdb::{Connection, Result as DuckDbResult};
+
 use crate::connection::DbConnection;
 
 // ============================================================================
@@ -386,11 +388,8 @@ impl ParquetReader {
         // Normalizar path para Windows (reemplazar backslashes)
         let file_str = file_str.replace('\\', "/");
 
-        // Crear query INSERT FROM Parquet
-        let query = format!(
-            "INSERT INTO {} SELECT * FROM read_parquet('{}')",
-            table_name, file_str
-        );
+        let query = insert_by_name_query(conn.conn(), table_name, &file_str)
+            .with_context(|| format!("Failed to map columns of {}", file_str))?;
 
         // Ejecutar query usando la conexión directa
         conn.conn()
@@ -429,6 +428,57 @@ impl ParquetReader {
     }
 }
 
+// ============================================================================
+// INSERT BY NAME
+// ============================================================================
+
+/// Construye un `INSERT ... SELECT` desde Parquet emparejando columnas por nombre.
+///
+/// El Parquet puede traer columnas auxiliares (p.ej. `timestamp` en acciones)
+/// y la tabla columnas con DEFAULT (`created_at`) que no existen en el archivo,
+/// así que un `SELECT *` posicional no sirve. `source` es la ruta o el patrón
+/// glob que recibe `read_parquet`.
+///
+/// Los archivos se unen por nombre (`union_by_name`): un glob puede mezclar
+/// Parquet escritos antes y después de añadir columnas (p.ej. `flop` en
+/// `hands_metadata`) y las que faltan en los antiguos se cargan como NULL.
+pub(crate) fn insert_by_name_query(
+    conn: &Connection,
+    table_name: &str,
+    source: &str,
+) -> DuckDbResult<String> {
+    let mut stmt =
+        conn.prepare("SELECT column_name FROM information_schema.columns WHERE table_name = ?")?;
+    let table_columns = stmt
+        .query_map(duckdb::params![table_name], |row| row.get::<_, String>(0))?
+        .collect::<DuckDbResult<Vec<_>>>()?;
+
+    let source = format!("read_parquet('{}', union_by_name = true)", source);
+    let describe = format!("DESCRIBE SELECT * FROM {}", source);
+    let mut stmt = conn.prepare(&describe)?;
+    let columns: Vec<String> = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<DuckDbResult<Vec<_>>>()?
+        .into_iter()
+        .filter(|c| table_columns.contains(c))
+        .map(|c| format!("\"{}\"", c))
+        .collect();
+
+    // Sin columnas en común se deja que DuckDB reporte el error posicional
+    if columns.is_empty() {
+        return Ok(format!(
+            "INSERT INTO {} SELECT * FROM {}",
+            table_name, source
+        ));
+    }
+
+    let column_list = columns.join(", ");
+    Ok(format!(
+        "INSERT INTO {} ({}) SELECT {} FROM {}",
+        table_name, column_list, column_list, source
+    ))
+}
+
 // ============================================================================
 // CACHE STATS
 // ============================================================================
@@ -19,8 +19,8 @@
 
 use anyhow::{Context, Result};
 use arrow::array::{
-    ArrayRef, BooleanArray, Int64Array, RecordBatch, StringArray, TimestampMicrosecondArray,
-    UInt8Array,
+    ArrayRef, BooleanArray, Int32Array, Int64Array, ListBuilder, RecordBatch, StringArray,
+    StringBuilder, TimestampMicrosecondArray, UInt8Array,
 };
 use arrow::datatypes::{DataType, Field, Schema, TimeUnit};
 use chrono::{Datelike, NaiveDateTime, Utc};
@@ -32,7 +32,7 @@ use std::fs::{self, File};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use crate::schema::{HandAction, HandMetadata};
+use crate::schema::{HandAction, HandMetadata, HandPlayer};
 
 // ============================================================================
 // CONFIGURATION
@@ -124,15 +124,20 @@ impl DatePartition {
     }
 
     /// Genera el nombre del archivo Parquet para esta partición
-    /// Formato: hands_YYYY_MM_DD_HHMMSS.parquet
+    /// Formato: hands_YYYY_MM_DD_HHMMSSmmm_XXXXXXXX.parquet
+    ///
+    /// El sufijo aleatorio evita sobrescribir un archivo ya cargado cuando
+    /// la ingesta escribe varios lotes en el mismo segundo.
     pub fn to_filename(&self) -> String {
         let now = Utc::now();
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
         format!(
-            "hands_{}_{:02}_{:02}_{}.parquet",
+            "hands_{}_{:02}_{:02}_{}_{}.parquet",
             self.year,
             self.month,
             self.day,
-            now.format("%H%M%S")
+            now.format("%H%M%S%3f"),
+            &suffix[..8]
         )
     }
 }
@@ -157,6 +162,16 @@ pub fn hands_metadata_schema() -> Arc<Schema> {
         Field::new("table_name", DataType::Utf8, false),
         Field::new("blind_level", DataType::Int64, false),
         Field::new("button_seat", DataType::UInt8, false),
+        Field::new("max_players", DataType::UInt8, false),
+        Field::new(
+            "winner_ids",
+            DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
+            false,
+        ),
+        Field::new("flop", DataType::Utf8, true),
+        Field::new("turn", DataType::Utf8, true),
+        Field::new("river", DataType::Utf8, true),
+        Field::new("hero_cards", DataType::Utf8, true),
     ]))
 }
 
@@ -171,6 +186,31 @@ pub fn hands_actions_schema() -> Arc<Schema> {
         Field::new("amount_cents", DataType::Int64, false),
         Field::new("is_hero_action", DataType::Boolean, false),
         Field::new("ev_cents", DataType::Int64, true),
+        Field::new("action_sequence", DataType::Int32, false),
+        Field::new(
+            "timestamp",
+            DataType::Timestamp(TimeUnit::Microsecond, None),
+            false,
+        ),
+    ]))
+}
+
+/// Schema Arrow para la tabla hands_players
+pub fn hands_players_schema() -> Arc<Schema> {
+    Arc::new(Schema::new(vec![
+        Field::new("hand_id", DataType::Utf8, false),
+        Field::new("player_id", DataType::Utf8, false),
+        Field::new("seat", DataType::UInt8, false),
+        Field::new("position", DataType::Utf8, true),
+        Field::new("starting_stack_cents", DataType::Int64, false),
+        Field::new("hole_cards", DataType::Utf8, true),
+        Field::new("is_hero", DataType::Boolean, false),
+        Field::new("invested_cents", DataType::Int64, false),
+        Field::new("collected_cents", DataType::Int64, false),
+        Field::new("net_cents", DataType::Int64, false),
+        Field::new("rake_cents", DataType::Int64, false),
+        Field::new("went_to_showdown", DataType::Boolean, false),
+        Field::new("won_at_showdown", DataType::Boolean, false),
         Field::new(
             "timestamp",
             DataType::Timestamp(TimeUnit::Microsecond, None),
@@ -251,7 +291,36 @@ impl ParquetWriter {
         // Procesar cada grupo
         let mut written_files = Vec::new();
         for (partition, batch) in grouped.drain(..) {
-            let path = self.write_actions_partition(&partition, &batch)?;
+            let path = self.write_actions_partition(&partition, &batch, timestamps)?;
+            written_files.push(path);
+        }
+
+        Ok(written_files.into_iter().next().unwrap())
+    }
+
+    /// Escribe un lote de resultados por jugador (`hands_players`) a Parquet
+    ///
+    /// # Arguments
+    /// * `players` - Vector de filas jugador-mano a escribir
+    /// * `timestamps` - Mapa de hand_id -> timestamp para particionamiento
+    ///
+    /// # Returns
+    /// Path del archivo Parquet creado
+    pub fn write_hands_players(
+        &self,
+        players: Vec<HandPlayer>,
+        timestamps: &std::collections::HashMap<String, NaiveDateTime>,
+    ) -> Result<PathBuf> {
+        if players.is_empty() {
+            return Err(anyhow::anyhow!("No hand players to write"));
+        }
+
+        // Agrupar por fecha, ordenar por player_id y hand_id
+        let mut grouped = self.group_and_sort_players(players, timestamps);
+
+        let mut written_files = Vec::new();
+        for (partition, batch) in grouped.drain(..) {
+            let path = self.write_players_partition(&partition, &batch, timestamps)?;
             written_files.push(path);
         }
 
@@ -313,6 +382,31 @@ impl ParquetWriter {
         groups.into_iter().collect()
     }
 
+    /// Agrupa filas jugador-mano por fecha y ordena por player_id + hand_id
+    fn group_and_sort_players(
+        &self,
+        mut players: Vec<HandPlayer>,
+        timestamps: &std::collections::HashMap<String, NaiveDateTime>,
+    ) -> Vec<(DatePartition, Vec<HandPlayer>)> {
+        players.sort_by(|a, b| {
+            a.player_id
+                .cmp(&b.player_id)
+                .then_with(|| a.hand_id.cmp(&b.hand_id))
+        });
+
+        let mut groups: std::collections::HashMap<DatePartition, Vec<HandPlayer>> =
+            std::collections::HashMap::new();
+
+        for player in players {
+            if let Some(ts) = timestamps.get(&player.hand_id) {
+                let partition = DatePartition::from_timestamp(ts);
+                groups.entry(partition).or_default().push(player);
+            }
+        }
+
+        groups.into_iter().collect()
+    }
+
     // ========================================================================
     // PRIVATE METHODS - Writing
     // ========================================================================
@@ -345,6 +439,7 @@ impl ParquetWriter {
         &self,
         partition: &DatePartition,
         actions: &[HandAction],
+        timestamps: &std::collections::HashMap<String, NaiveDateTime>,
     ) -> Result<PathBuf> {
         // Construir path completo
         let partition_dir = self.config.base_path.join(partition.to_path());
@@ -355,7 +450,7 @@ impl ParquetWriter {
         let file_path = partition_dir.join(partition.to_filename());
 
         // Convertir a RecordBatch Arrow
-        let batch = self.actions_to_record_batch(actions)?;
+        let batch = self.actions_to_record_batch(actions, timestamps)?;
 
         // Escribir archivo Parquet
         self.write_record_batch(&file_path, batch, hands_actions_schema())?;
@@ -363,6 +458,25 @@ impl ParquetWriter {
         Ok(file_path)
     }
 
+    /// Escribe una partición de filas jugador-mano
+    fn write_players_partition(
+        &self,
+        partition: &DatePartition,
+        players: &[HandPlayer],
+        timestamps: &std::collections::HashMap<String, NaiveDateTime>,
+    ) -> Result<PathBuf> {
+        let partition_dir = self.config.base_path.join(partition.to_path());
+        fs::create_dir_all(&partition_dir).with_context(|| {
+            format!("Failed to create partition directory: {:?}", partition_dir)
+        })?;
+
+        let file_path = partition_dir.join(partition.to_filename());
+        let batch = self.players_to_record_batch(players, timestamps)?;
+        self.write_record_batch(&file_path, batch, hands_players_schema())?;
+
+        Ok(file_path)
+    }
+
     /// Escribe un RecordBatch a archivo Parquet
     fn write_record_batch(
         &self,
@@ -431,6 +545,20 @@ impl ParquetWriter {
             .collect();
         let blind_levels: Vec<i64> = metadata.iter().map(|m| m.blind_level).collect();
         let button_seats: Vec<u8> = metadata.iter().map(|m| m.button_seat).collect();
+        let max_players: Vec<u8> = metadata.iter().map(|m| m.max_players).collect();
+        let flops: Vec<Option<String>> = metadata.iter().map(|m| m.flop.clone()).collect();
+        let turns: Vec<Option<String>> = metadata.iter().map(|m| m.turn.clone()).collect();
+        let rivers: Vec<Option<String>> = metadata.iter().map(|m| m.river.clone()).collect();
+        let hero_cards: Vec<Option<String>> =
+            metadata.iter().map(|m| m.hero_cards.clone()).collect();
+
+        let mut winners_builder = ListBuilder::new(StringBuilder::new());
+        for meta in metadata {
+            for winner in &meta.winner_ids {
+                winners_builder.values().append_value(winner);
+            }
+            winners_builder.append(true);
+        }
 
         // Crear arrays Arrow
         let hand_id_array: ArrayRef = Arc::new(StringArray::from(hand_ids));
@@ -442,6 +570,12 @@ impl ParquetWriter {
         let table_name_array: ArrayRef = Arc::new(StringArray::from(table_names));
         let blind_level_array: ArrayRef = Arc::new(Int64Array::from(blind_levels));
         let button_seat_array: ArrayRef = Arc::new(UInt8Array::from(button_seats));
+        let max_players_array: ArrayRef = Arc::new(UInt8Array::from(max_players));
+        let winner_ids_array: ArrayRef = Arc::new(winners_builder.finish());
+        let flop_array: ArrayRef = Arc::new(StringArray::from(flops));
+        let turn_array: ArrayRef = Arc::new(StringArray::from(turns));
+        let river_array: ArrayRef = Arc::new(StringArray::from(rivers));
+        let hero_cards_array: ArrayRef = Arc::new(StringArray::from(hero_cards));
 
         // Crear RecordBatch
         let batch = RecordBatch::try_new(
@@ -456,6 +590,12 @@ impl ParquetWriter {
                 table_name_array,
                 blind_level_array,
                 button_seat_array,
+                max_players_array,
+                winner_ids_array,
+                flop_array,
+                turn_array,
+                river_array,
+                hero_cards_array,
             ],
         )
         .context("Failed to create RecordBatch for metadata")?;
@@ -464,9 +604,11 @@ impl ParquetWriter {
     }
 
     /// Convierte acciones a RecordBatch Arrow
-    fn actions_to_record_batch(&self, actions: &[HandAction]) -> Result<RecordBatch> {
-        let n = actions.len();
-
+    fn actions_to_record_batch(
+        &self,
+        actions: &[HandAction],
+        timestamps: &std::collections::HashMap<String, NaiveDateTime>,
+    ) -> Result<RecordBatch> {
         // Construir arrays
         let action_ids: Vec<Option<String>> =
             actions.iter().map(|a| Some(a.action_id.clone())).collect();
@@ -483,10 +625,18 @@ impl ParquetWriter {
         let amount_cents: Vec<i64> = actions.iter().map(|a| a.amount_cents).collect();
         let is_hero_actions: Vec<bool> = actions.iter().map(|a| a.is_hero_action).collect();
         let ev_cents: Vec<Option<i64>> = actions.iter().map(|a| a.ev_cents).collect();
+        let sequences: Vec<i32> = actions.iter().map(|a| a.action_sequence).collect();
 
-        // Para timestamp, usamos el actual como placeholder (en producción vendría del metadata)
-        let now = chrono::Utc::now().timestamp_micros();
-        let timestamps: Vec<i64> = vec![now; n];
+        // Timestamp de la mano a la que pertenece cada acción
+        let timestamps: Vec<i64> = actions
+            .iter()
+            .map(|a| {
+                timestamps
+                    .get(&a.hand_id)
+                    .map(|ts| ts.timestamp_micros())
+                    .unwrap_or(0)
+            })
+            .collect();
 
         // Crear arrays Arrow
         let action_id_array: ArrayRef = Arc::new(StringArray::from(action_ids));
@@ -497,6 +647,7 @@ impl ParquetWriter {
         let amount_cents_array: ArrayRef = Arc::new(Int64Array::from(amount_cents));
         let is_hero_action_array: ArrayRef = Arc::new(BooleanArray::from(is_hero_actions));
         let ev_cents_array: ArrayRef = Arc::new(Int64Array::from(ev_cents));
+        let sequence_array: ArrayRef = Arc::new(Int32Array::from(sequences));
         let timestamp_array: ArrayRef = Arc::new(TimestampMicrosecondArray::from(timestamps));
 
         // Crear RecordBatch
@@ -511,6 +662,7 @@ impl ParquetWriter {
                 amount_cents_array,
                 is_hero_action_array,
                 ev_cents_array,
+                sequence_array,
                 timestamp_array,
             ],
         )
@@ -518,6 +670,61 @@ impl ParquetWriter {
 
         Ok(batch)
     }
+
+    /// Convierte filas jugador-mano a RecordBatch Arrow
+    fn players_to_record_batch(
+        &self,
+        players: &[HandPlayer],
+        timestamps: &std::collections::HashMap<String, NaiveDateTime>,
+    ) -> Result<RecordBatch> {
+        let hand_ids: Vec<Option<String>> =
+            players.iter().map(|p| Some(p.hand_id.clone())).collect();
+        let player_ids: Vec<Option<String>> =
+            players.iter().map(|p| Some(p.player_id.clone())).collect();
+        let seats: Vec<u8> = players.iter().map(|p| p.seat).collect();
+        let positions: Vec<Option<String>> = players.iter().map(|p| p.position.clone()).collect();
+        let stacks: Vec<i64> = players.iter().map(|p| p.starting_stack_cents).collect();
+        let hole_cards: Vec<Option<String>> =
+            players.iter().map(|p| p.hole_cards.clone()).collect();
+        let is_hero: Vec<bool> = players.iter().map(|p| p.is_hero).collect();
+        let invested: Vec<i64> = players.iter().map(|p| p.invested_cents).collect();
+        let collected: Vec<i64> = players.iter().map(|p| p.collected_cents).collect();
+        let net: Vec<i64> = players.iter().map(|p| p.net_cents).collect();
+        let rake: Vec<i64> = players.iter().map(|p| p.rake_cents).collect();
+        let went_to_showdown: Vec<bool> = players.iter().map(|p| p.went_to_showdown).collect();
+        let won_at_showdown: Vec<bool> = players.iter().map(|p| p.won_at_showdown).collect();
+
+        // Timestamp de la mano de cada fila
+        let timestamps: Vec<i64> = players
+            .iter()
+            .map(|p| {
+                timestamps
+                    .get(&p.hand_id)
+                    .map(|ts| ts.timestamp_micros())
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(hand_ids)),
+            Arc::new(StringArray::from(player_ids)),
+            Arc::new(UInt8Array::from(seats)),
+            Arc::new(StringArray::from(positions)),
+            Arc::new(Int64Array::from(stacks)),
+            Arc::new(StringArray::from(hole_cards)),
+            Arc::new(BooleanArray::from(is_hero)),
+            Arc::new(Int64Array::from(invested)),
+            Arc::new(Int64Array::from(collected)),
+            Arc::new(Int64Array::from(net)),
+            Arc::new(Int64Array::from(rake)),
+            Arc::new(BooleanArray::from(went_to_showdown)),
+            Arc::new(BooleanArray::from(won_at_showdown)),
+            Arc::new(TimestampMicrosecondArray::from(timestamps)),
+        ];
+
+        RecordBatch::try_new(hands_players_schema(), columns)
+            .context("Failed to create RecordBatch for hand players")
+    }
 }
 
 // ============================================================================
@@ -606,4 +813,35 @@ mod tests {
         let result = writer.write_hands_actions(vec![], &timestamps);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_write_hands_players_partitioned() {
+        let temp_dir = TempDir::new().unwrap();
+        let writer = ParquetWriter::new(ParquetWriteConfig::new(temp_dir.path()));
+
+        let player = HandPlayer {
+            hand_id: "H1".to_string(),
+            player_id: "P1".to_string(),
+            seat: 3,
+            position: Some("BTN".to_string()),
+            starting_stack_cents: 1000,
+            hole_cards: Some("AhKd".to_string()),
+            is_hero: true,
+            invested_cents: 55,
+            collected_cents: 77,
+            net_cents: 22,
+            rake_cents: 3,
+            went_to_showdown: false,
+            won_at_showdown: false,
+            created_at: String::new(),
+        };
+        let ts = NaiveDateTime::parse_from_str("2024-03-15 14:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let timestamps = std::collections::HashMap::from([("H1".to_string(), ts)]);
+
+        let path = writer
+            .write_hands_players(vec![player], &timestamps)
+            .unwrap();
+        assert!(path.starts_with(temp_dir.path().join("year=2024/month=03/day=15")));
+        assert!(path.exists());
+    }
 }
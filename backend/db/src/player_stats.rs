@@ -0,0 +1,664 @@
+//! # Player Stats Module
+//!
+//! Motor de estadísticas HUD sobre DuckDB: VPIP, PFR, 3Bet, AF, WTSD, W$SD
+//! y C-bet por jugador, posición y stake (definiciones en `poker-logic.md`).
+//!
+//! ## Estrategia
+//! - `player_stats_flat` guarda **contadores** (acciones y oportunidades) con
+//!   grano (player_id, stake, position). Cualquier agregación (lifetime, por
+//!   stake, por posición) es una suma de contadores.
+//! - `player_stats_hands` registra las manos ya contabilizadas, de modo que
+//!   `refresh` solo procesa las manos nuevas que cargue `ParquetLoader` o el
+//!   pipeline de ingesta.
+//! - Una mano solo se contabiliza cuando tiene metadata **y** acciones
+//!   cargadas, así que el orden de carga de las tablas no importa.
+//!
+//! ## Posiciones
+//! La posición es la que asignó el parser y guarda `hands_players.position`.
+//! Las manos sin filas en `hands_players` (cargadas desde Parquet anteriores
+//! a la migración 002) la deducen del orden de la primera acción preflop de
+//! cada jugador (el último en hablar es la BB; en un walk la BB no actúa y se
+//! cuenta un asiento extra), lo que falla si una ciega queda all-in sin
+//! actuar. La nomenclatura coincide con `Position::table_order` del parser:
+//! `UTG`/`MP`/`CO` en mesas de hasta 6 jugadores y
+//! `UTG`/`UTG+1`/`UTG+2`/`LJ`/`HJ`/`CO` en full ring (`max_players` >= 7 o
+//! más de 6 jugadores sentados).
+//!
+//! ## Uso
+//!
+//! ```rust,ignore
+//! use poker_db::{PlayerStatsEngine, StatsQuery};
+//!
+//! PlayerStatsEngine::refresh(conn.conn())?;
+//! let rows = PlayerStatsEngine::query(conn.conn(), &StatsQuery::for_player(&hero_id).by_position())?;
+//! for row in rows {
+//!     println!("{:?}: VPIP {:?}", row.position, row.counts.vpip().percentage());
+//! }
+//! ```
+
+use duckdb::{params_from_iter, Connection, Result as DuckDbResult};
+use serde::{Deserialize, Serialize};
+use std::ops::AddAssign;
+use std::time::Instant;
+
+/// Tabla wide de contadores
+pub const PLAYER_STATS_TABLE: &str = "player_stats_flat";
+
+/// Tabla de manos ya contabilizadas
+pub const PLAYER_STATS_HANDS_TABLE: &str = "player_stats_hands";
+
+/// Columnas de contadores de `player_stats_flat`, en el orden de `StatCounts`
+const COUNTER_COLUMNS: [&str; 12] = [
+    "hands",
+    "vpip_count",
+    "pfr_count",
+    "three_bet_count",
+    "three_bet_opportunities",
+    "postflop_aggressive_count",
+    "postflop_call_count",
+    "saw_flop_count",
+    "went_to_showdown_count",
+    "won_at_showdown_count",
+    "cbet_count",
+    "cbet_opportunities",
+];
+
+/// Manos con metadata y acciones cargadas que aún no se contabilizaron
+const PENDING_HANDS_SQL: &str = "
+CREATE OR REPLACE TEMP TABLE stats_pending AS
+SELECT
+    m.hand_id, m.stake, COALESCE(m.winner_ids, []::VARCHAR[]) AS winner_ids,
+    COALESCE(m.max_players, 6) AS max_players
+FROM hands_metadata m
+WHERE NOT EXISTS (SELECT 1 FROM player_stats_hands p WHERE p.hand_id = m.hand_id)
+  AND EXISTS (SELECT 1 FROM hands_actions a WHERE a.hand_id = m.hand_id)";
+
+/// Contadores por (jugador, stake, posición) de las manos pendientes
+const COUNTERS_SQL: &str = "
+WITH acts AS (
+    SELECT a.hand_id, a.player_id, a.street, a.action_type, a.action_sequence
+    FROM hands_actions a
+    JOIN stats_pending n ON n.hand_id = a.hand_id
+),
+preflop AS (
+    SELECT
+        hand_id, player_id, action_type, action_sequence,
+        COALESCE(SUM(CASE WHEN action_type IN ('RAISE', 'ALL_IN') THEN 1 ELSE 0 END) OVER (
+            PARTITION BY hand_id ORDER BY action_sequence
+            ROWS BETWEEN UNBOUNDED PRECEDING AND 1 PRECEDING
+        ), 0) AS raises_before,
+        ROW_NUMBER() OVER (PARTITION BY hand_id, player_id ORDER BY action_sequence) AS player_turn
+    FROM acts
+    WHERE street = 'PREFLOP'
+),
+first_actions AS (
+    SELECT
+        hand_id, player_id,
+        action_type AS first_action,
+        raises_before AS raises_faced,
+        ROW_NUMBER() OVER (PARTITION BY hand_id ORDER BY action_sequence) AS seat_order,
+        COUNT(*) OVER (PARTITION BY hand_id) AS actors
+    FROM preflop
+    WHERE player_turn = 1
+),
+walks AS (
+    SELECT hand_id, BOOL_AND(action_type = 'FOLD') AS is_walk
+    FROM preflop
+    GROUP BY hand_id
+),
+positions AS (
+    SELECT
+        f.hand_id, f.player_id, f.first_action, f.raises_faced,
+        COALESCE(hp.position, CASE
+            WHEN s.seats = 2 AND f.seat_order = 1 THEN 'BTN'
+            WHEN s.seats - f.seat_order = 0 THEN 'BB'
+            WHEN s.seats - f.seat_order = 1 THEN 'SB'
+            WHEN s.seats - f.seat_order = 2 THEN 'BTN'
+            WHEN s.seats - f.seat_order = 3 THEN 'CO'
+            WHEN s.full_ring AND s.seats - f.seat_order = 4 THEN 'HJ'
+            WHEN s.full_ring AND s.seats - f.seat_order = 5 THEN 'LJ'
+            WHEN s.full_ring THEN ['UTG', 'UTG+1', 'UTG+2'][LEAST(f.seat_order, 3)]
+            WHEN s.seats - f.seat_order = 4 THEN 'MP'
+            ELSE 'UTG'
+        END) AS position
+    FROM first_actions f
+    LEFT JOIN hands_players hp ON hp.hand_id = f.hand_id AND hp.player_id = f.player_id
+    JOIN (
+        SELECT hand_id, seats, max_players >= 7 OR seats > 6 AS full_ring
+        FROM (
+            SELECT
+                f2.hand_id,
+                MAX(f2.actors) + CASE WHEN BOOL_OR(w.is_walk) THEN 1 ELSE 0 END AS seats,
+                MAX(n.max_players) AS max_players
+            FROM first_actions f2
+            JOIN walks w ON w.hand_id = f2.hand_id
+            JOIN stats_pending n ON n.hand_id = f2.hand_id
+            GROUP BY f2.hand_id
+        )
+    ) s ON s.hand_id = f.hand_id
+),
+preflop_aggressor AS (
+    SELECT hand_id, arg_max(player_id, action_sequence) AS player_id
+    FROM preflop
+    WHERE action_type IN ('RAISE', 'ALL_IN')
+    GROUP BY hand_id
+),
+flop AS (
+    SELECT
+        hand_id, player_id, action_type,
+        COALESCE(SUM(CASE WHEN action_type IN ('BET', 'RAISE', 'ALL_IN') THEN 1 ELSE 0 END) OVER (
+            PARTITION BY hand_id ORDER BY action_sequence
+            ROWS BETWEEN UNBOUNDED PRECEDING AND 1 PRECEDING
+        ), 0) AS bets_before,
+        ROW_NUMBER() OVER (PARTITION BY hand_id, player_id ORDER BY action_sequence) AS player_turn
+    FROM acts
+    WHERE street = 'FLOP'
+),
+cbets AS (
+    SELECT
+        f.hand_id, f.player_id,
+        f.bets_before = 0 AS cbet_opportunity,
+        f.bets_before = 0 AND f.action_type IN ('BET', 'ALL_IN') AS cbet
+    FROM flop f
+    JOIN preflop_aggressor pa ON pa.hand_id = f.hand_id AND pa.player_id = f.player_id
+    WHERE f.player_turn = 1
+),
+player_hands AS (
+    SELECT
+        p.hand_id, p.player_id, n.stake, p.position,
+        p.raises_faced = 1 AS three_bet_opportunity,
+        p.raises_faced = 1 AND p.first_action IN ('RAISE', 'ALL_IN') AS three_bet,
+        BOOL_OR(a.street = 'PREFLOP' AND a.action_type IN ('CALL', 'BET', 'RAISE', 'ALL_IN')) AS vpip,
+        BOOL_OR(a.street = 'PREFLOP' AND a.action_type IN ('RAISE', 'ALL_IN')) AS pfr,
+        SUM(CASE WHEN a.street <> 'PREFLOP' AND a.action_type IN ('BET', 'RAISE', 'ALL_IN') THEN 1 ELSE 0 END) AS aggressive_actions,
+        SUM(CASE WHEN a.street <> 'PREFLOP' AND a.action_type = 'CALL' THEN 1 ELSE 0 END) AS call_actions,
+        BOOL_OR(a.street = 'PREFLOP' AND a.action_type = 'FOLD') AS folded_preflop,
+        BOOL_OR(a.action_type = 'FOLD') AS folded,
+        list_contains(n.winner_ids, p.player_id) AS won
+    FROM positions p
+    JOIN stats_pending n ON n.hand_id = p.hand_id
+    JOIN acts a ON a.hand_id = p.hand_id AND a.player_id = p.player_id
+    GROUP BY p.hand_id, p.player_id, n.stake, p.position, p.raises_faced, p.first_action, n.winner_ids
+),
+hand_flags AS (
+    SELECT
+        hand_id,
+        SUM(CASE WHEN NOT folded_preflop THEN 1 ELSE 0 END) >= 2 AS has_flop,
+        SUM(CASE WHEN NOT folded THEN 1 ELSE 0 END) >= 2 AS has_showdown
+    FROM player_hands
+    GROUP BY hand_id
+)
+SELECT
+    ph.player_id,
+    ph.stake,
+    ph.position,
+    COUNT(*) AS hands,
+    SUM(CASE WHEN ph.vpip THEN 1 ELSE 0 END) AS vpip_count,
+    SUM(CASE WHEN ph.pfr THEN 1 ELSE 0 END) AS pfr_count,
+    SUM(CASE WHEN ph.three_bet THEN 1 ELSE 0 END) AS three_bet_count,
+    SUM(CASE WHEN ph.three_bet_opportunity THEN 1 ELSE 0 END) AS three_bet_opportunities,
+    SUM(ph.aggressive_actions) AS postflop_aggressive_count,
+    SUM(ph.call_actions) AS postflop_call_count,
+    SUM(CASE WHEN hf.has_flop AND NOT ph.folded_preflop THEN 1 ELSE 0 END) AS saw_flop_count,
+    SUM(CASE WHEN hf.has_showdown AND NOT ph.folded THEN 1 ELSE 0 END) AS went_to_showdown_count,
+    SUM(CASE WHEN hf.has_showdown AND NOT ph.folded AND ph.won THEN 1 ELSE 0 END) AS won_at_showdown_count,
+    SUM(CASE WHEN c.cbet THEN 1 ELSE 0 END) AS cbet_count,
+    SUM(CASE WHEN c.cbet_opportunity THEN 1 ELSE 0 END) AS cbet_opportunities
+FROM player_hands ph
+JOIN hand_flags hf ON hf.hand_id = ph.hand_id
+LEFT JOIN cbets c ON c.hand_id = ph.hand_id AND c.player_id = ph.player_id
+GROUP BY ph.player_id, ph.stake, ph.position";
+
+// ============================================================================
+// STAT VALUES
+// ============================================================================
+
+/// Una estadística como número de veces y oportunidades
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatValue {
+    pub count: i64,
+    pub opportunities: i64,
+}
+
+impl StatValue {
+    /// Porcentaje (0-100), `None` si no hubo oportunidades
+    pub fn percentage(&self) -> Option<f64> {
+        if self.opportunities == 0 {
+            None
+        } else {
+            Some(self.count as f64 * 100.0 / self.opportunities as f64)
+        }
+    }
+}
+
+/// Contadores de `player_stats_flat` para una fila o una agregación
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatCounts {
+    pub hands: i64,
+    pub vpip: i64,
+    pub pfr: i64,
+    pub three_bet: i64,
+    pub three_bet_opportunities: i64,
+    pub postflop_aggressive: i64,
+    pub postflop_calls: i64,
+    pub saw_flop: i64,
+    pub went_to_showdown: i64,
+    pub won_at_showdown: i64,
+    pub cbet: i64,
+    pub cbet_opportunities: i64,
+}
+
+impl StatCounts {
+    /// VPIP: manos con dinero voluntario preflop / manos
+    pub fn vpip(&self) -> StatValue {
+        StatValue {
+            count: self.vpip,
+            opportunities: self.hands,
+        }
+    }
+
+    /// PFR: manos con subida preflop / manos
+    pub fn pfr(&self) -> StatValue {
+        StatValue {
+            count: self.pfr,
+            opportunities: self.hands,
+        }
+    }
+
+    /// 3Bet: re-raises ante un open raise / veces que se enfrentó un open raise
+    pub fn three_bet(&self) -> StatValue {
+        StatValue {
+            count: self.three_bet,
+            opportunities: self.three_bet_opportunities,
+        }
+    }
+
+    /// WTSD: showdowns / flops vistos
+    pub fn wtsd(&self) -> StatValue {
+        StatValue {
+            count: self.went_to_showdown,
+            opportunities: self.saw_flop,
+        }
+    }
+
+    /// W$SD: showdowns ganados / showdowns
+    pub fn wsd(&self) -> StatValue {
+        StatValue {
+            count: self.won_at_showdown,
+            opportunities: self.went_to_showdown,
+        }
+    }
+
+    /// C-bet en el flop / veces que el agresor preflop pudo apostar primero
+    pub fn cbet(&self) -> StatValue {
+        StatValue {
+            count: self.cbet,
+            opportunities: self.cbet_opportunities,
+        }
+    }
+
+    /// AF: (bets + raises) / calls postflop, `None` sin calls
+    pub fn aggression_factor(&self) -> Option<f64> {
+        if self.postflop_calls == 0 {
+            None
+        } else {
+            Some(self.postflop_aggressive as f64 / self.postflop_calls as f64)
+        }
+    }
+
+    /// Construye los contadores desde las columnas de `COUNTER_COLUMNS`
+    fn from_row(row: &duckdb::Row<'_>, offset: usize) -> DuckDbResult<Self> {
+        Ok(Self {
+            hands: row.get(offset)?,
+            vpip: row.get(offset + 1)?,
+            pfr: row.get(offset + 2)?,
+            three_bet: row.get(offset + 3)?,
+            three_bet_opportunities: row.get(offset + 4)?,
+            postflop_aggressive: row.get(offset + 5)?,
+            postflop_calls: row.get(offset + 6)?,
+            saw_flop: row.get(offset + 7)?,
+            went_to_showdown: row.get(offset + 8)?,
+            won_at_showdown: row.get(offset + 9)?,
+            cbet: row.get(offset + 10)?,
+            cbet_opportunities: row.get(offset + 11)?,
+        })
+    }
+}
+
+impl AddAssign for StatCounts {
+    fn add_assign(&mut self, other: Self) {
+        self.hands += other.hands;
+        self.vpip += other.vpip;
+        self.pfr += other.pfr;
+        self.three_bet += other.three_bet;
+        self.three_bet_opportunities += other.three_bet_opportunities;
+        self.postflop_aggressive += other.postflop_aggressive;
+        self.postflop_calls += other.postflop_calls;
+        self.saw_flop += other.saw_flop;
+        self.went_to_showdown += other.went_to_showdown;
+        self.won_at_showdown += other.won_at_showdown;
+        self.cbet += other.cbet;
+        self.cbet_opportunities += other.cbet_opportunities;
+    }
+}
+
+/// Estadísticas de un jugador para una combinación de stake/posición.
+///
+/// `stake` o `position` son `None` cuando la consulta agrega esa dimensión.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerStats {
+    pub player_id: String,
+    pub stake: Option<String>,
+    pub position: Option<String>,
+    pub counts: StatCounts,
+}
+
+// ============================================================================
+// QUERY
+// ============================================================================
+
+/// Filtros y agrupación para consultar `player_stats_flat`
+#[derive(Debug, Clone, Default)]
+pub struct StatsQuery {
+    /// Filtrar por jugador
+    pub player_id: Option<String>,
+    /// Filtrar por stake (ej: "NL10")
+    pub stake: Option<String>,
+    /// Filtrar por posición (ej: "BTN")
+    pub position: Option<String>,
+    /// Una fila por stake
+    pub group_by_stake: bool,
+    /// Una fila por posición
+    pub group_by_position: bool,
+    /// Muestra mínima (manos) para devolver una fila
+    pub min_hands: i64,
+}
+
+impl StatsQuery {
+    /// Consulta de todos los jugadores, agregando stakes y posiciones
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consulta de un jugador
+    pub fn for_player(player_id: &str) -> Self {
+        Self {
+            player_id: Some(player_id.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Filtra por stake
+    pub fn with_stake(mut self, stake: &str) -> Self {
+        self.stake = Some(stake.to_string());
+        self
+    }
+
+    /// Filtra por posición
+    pub fn with_position(mut self, position: &str) -> Self {
+        self.position = Some(position.to_string());
+        self
+    }
+
+    /// Desglosa por stake
+    pub fn by_stake(mut self) -> Self {
+        self.group_by_stake = true;
+        self
+    }
+
+    /// Desglosa por posición
+    pub fn by_position(mut self) -> Self {
+        self.group_by_position = true;
+        self
+    }
+
+    /// Establece la muestra mínima
+    pub fn with_min_hands(mut self, min_hands: i64) -> Self {
+        self.min_hands = min_hands;
+        self
+    }
+
+    /// Genera el SQL y sus parámetros
+    fn to_sql(&self) -> (String, Vec<String>) {
+        let stake = if self.group_by_stake {
+            "stake"
+        } else {
+            "CAST(NULL AS VARCHAR)"
+        };
+        let position = if self.group_by_position {
+            "position"
+        } else {
+            "CAST(NULL AS VARCHAR)"
+        };
+        let sums: Vec<String> = COUNTER_COLUMNS
+            .iter()
+            .map(|c| format!("CAST(SUM({c}) AS BIGINT)"))
+            .collect();
+
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+        for (column, value) in [
+            ("player_id", &self.player_id),
+            ("stake", &self.stake),
+            ("position", &self.position),
+        ] {
+            if let Some(value) = value {
+                conditions.push(format!("{} = ?", column));
+                params.push(value.clone());
+            }
+        }
+        let where_clause = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {}", conditions.join(" AND "))
+        };
+
+        let sql = format!(
+            "SELECT player_id, {stake} AS stake, {position} AS position, {sums} \
+             FROM {table}{where_clause} \
+             GROUP BY 1, 2, 3 \
+             HAVING SUM(hands) >= {min_hands} \
+             ORDER BY 1, 2, 3",
+            sums = sums.join(", "),
+            table = PLAYER_STATS_TABLE,
+            min_hands = self.min_hands.max(1),
+        );
+
+        (sql, params)
+    }
+}
+
+// ============================================================================
+// ENGINE
+// ============================================================================
+
+/// Resultado de un refresco de estadísticas
+#[derive(Debug, Clone, Default)]
+pub struct StatsRefresh {
+    /// Manos nuevas contabilizadas
+    pub hands_processed: usize,
+    /// Tiempo en milisegundos
+    pub elapsed_ms: u128,
+}
+
+/// Motor de estadísticas sobre `hands_actions`/`hands_metadata`
+pub struct PlayerStatsEngine;
+
+impl PlayerStatsEngine {
+    /// Contabiliza las manos cargadas desde el último refresco.
+    ///
+    /// Es idempotente: las manos ya procesadas se registran en
+    /// `player_stats_hands` dentro de la misma transacción.
+    pub fn refresh(conn: &Connection) -> DuckDbResult<StatsRefresh> {
+        let start = Instant::now();
+
+        conn.execute_batch("BEGIN TRANSACTION")?;
+        match Self::refresh_pending(conn) {
+            Ok(hands_processed) => {
+                conn.execute_batch("COMMIT")?;
+                Ok(StatsRefresh {
+                    hands_processed,
+                    elapsed_ms: start.elapsed().as_millis(),
+                })
+            }
+            Err(e) => {
+                let _ = conn.execute_batch("ROLLBACK");
+                Err(e)
+            }
+        }
+    }
+
+    /// Borra los contadores y recalcula todo el historial
+    pub fn rebuild(conn: &Connection) -> DuckDbResult<StatsRefresh> {
+        conn.execute(&format!("DELETE FROM {}", PLAYER_STATS_TABLE), [])?;
+        conn.execute(&format!("DELETE FROM {}", PLAYER_STATS_HANDS_TABLE), [])?;
+        Self::refresh(conn)
+    }
+
+    /// Consulta estadísticas agregadas según `query`
+    pub fn query(conn: &Connection, query: &StatsQuery) -> DuckDbResult<Vec<PlayerStats>> {
+        let (sql, params) = query.to_sql();
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt
+            .query_map(params_from_iter(params.iter()), |row| {
+                Ok(PlayerStats {
+                    player_id: row.get(0)?,
+                    stake: row.get(1)?,
+                    position: row.get(2)?,
+                    counts: StatCounts::from_row(row, 3)?,
+                })
+            })?
+            .collect::<DuckDbResult<Vec<_>>>()?;
+        Ok(rows)
+    }
+
+    /// Estadísticas lifetime de un jugador (todas las posiciones y stakes)
+    pub fn player_summary(conn: &Connection, player_id: &str) -> DuckDbResult<Option<PlayerStats>> {
+        Ok(Self::query(conn, &StatsQuery::for_player(player_id))?
+            .into_iter()
+            .next())
+    }
+
+    /// Manos contabilizadas por el motor
+    fn refresh_pending(conn: &Connection) -> DuckDbResult<usize> {
+        conn.execute_batch(PENDING_HANDS_SQL)?;
+        let pending: i64 =
+            conn.query_row("SELECT COUNT(*) FROM stats_pending", [], |row| row.get(0))?;
+
+        if pending > 0 {
+            let columns = COUNTER_COLUMNS.join(", ");
+            let updates: Vec<String> = COUNTER_COLUMNS
+                .iter()
+                .map(|c| format!("{c} = {c} + EXCLUDED.{c}"))
+                .collect();
+            let upsert = format!(
+                "INSERT INTO {table} (player_id, stake, position, {columns}) {select} \
+                 ON CONFLICT (player_id, stake, position) DO UPDATE SET {updates}, \
+                 updated_at = CURRENT_TIMESTAMP",
+                table = PLAYER_STATS_TABLE,
+                select = COUNTERS_SQL,
+                updates = updates.join(", "),
+            );
+            conn.execute(&upsert, [])?;
+            conn.execute(
+                &format!(
+                    "INSERT INTO {} SELECT hand_id FROM stats_pending",
+                    PLAYER_STATS_HANDS_TABLE
+                ),
+                [],
+            )?;
+        }
+
+        conn.execute_batch("DROP TABLE IF EXISTS stats_pending")?;
+        Ok(pending as usize)
+    }
+}
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stat_value_percentage() {
+        let value = StatValue {
+            count: 3,
+            opportunities: 12,
+        };
+        assert_eq!(value.percentage(), Some(25.0));
+
+        let empty = StatValue {
+            count: 0,
+            opportunities: 0,
+        };
+        assert_eq!(empty.percentage(), None);
+    }
+
+    #[test]
+    fn test_stat_counts_ratios() {
+        let counts = StatCounts {
+            hands: 10,
+            vpip: 3,
+            pfr: 2,
+            three_bet: 1,
+            three_bet_opportunities: 4,
+            postflop_aggressive: 6,
+            postflop_calls: 4,
+            saw_flop: 4,
+            went_to_showdown: 2,
+            won_at_showdown: 1,
+            cbet: 1,
+            cbet_opportunities: 2,
+        };
+
+        assert_eq!(counts.vpip().percentage(), Some(30.0));
+        assert_eq!(counts.pfr().opportunities, 10);
+        assert_eq!(counts.three_bet().percentage(), Some(25.0));
+        assert_eq!(counts.wtsd().percentage(), Some(50.0));
+        assert_eq!(counts.wsd().percentage(), Some(50.0));
+        assert_eq!(counts.cbet().percentage(), Some(50.0));
+        assert_eq!(counts.aggression_factor(), Some(1.5));
+        assert_eq!(StatCounts::default().aggression_factor(), None);
+    }
+
+    #[test]
+    fn test_stat_counts_add_assign() {
+        let mut total = StatCounts {
+            hands: 5,
+            vpip: 2,
+            ..Default::default()
+        };
+        total += StatCounts {
+            hands: 3,
+            vpip: 1,
+            cbet_opportunities: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(total.hands, 8);
+        assert_eq!(total.vpip, 3);
+        assert_eq!(total.cbet_opportunities, 1);
+    }
+
+    #[test]
+    fn test_stats_query_sql() {
+        let (sql, params) = StatsQuery::for_player("p1")
+            .with_stake("NL10")
+            .by_position()
+            .with_min_hands(50)
+            .to_sql();
+
+        assert!(sql.contains("WHERE player_id = ? AND stake = ?"));
+        assert!(sql.contains("position AS position"));
+        assert!(sql.contains("CAST(NULL AS VARCHAR) AS stake"));
+        assert!(sql.contains("HAVING SUM(hands) >= 50"));
+        assert_eq!(params, vec!["p1".to_string(), "NL10".to_string()]);
+
+        let (sql, params) = StatsQuery::new().to_sql();
+        assert!(!sql.contains("WHERE"));
+        assert!(params.is_empty());
+    }
+}
@@ -4,7 +4,7 @@
 //!
 //! ## Arquitectura
 //! - **Dimension Tables**: `Player`, `HandMetadata`
-//! - **Fact Tables**: `HandAction`
+//! - **Fact Tables**: `HandAction`, `HandPlayer`
 //! - **Result Tables**: `CashSession`, `Tournament`, `TournamentResult`
 //!
 //! ## Tipos de Datos
@@ -45,7 +45,7 @@ impl Player {
         }
     }
 
-    /// Crea el jugador Hero (thesmoy)
+    /// Crea un jugador marcado como héroe
     pub fn create_hero(display_name: String) -> Self {
         Self::new(display_name, true)
     }
@@ -76,7 +76,7 @@ impl PlayerAlias {
 }
 
 /// Enum: Sitios de poker soportados
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SiteName {
     Winamax,
     PokerStars,
@@ -112,6 +112,25 @@ pub struct HandMetadata {
     pub table_name: String,
     pub blind_level: i64, // SB en centavos
     pub button_seat: u8,  // 0-5 para 6-max
+    /// Tamaño de la mesa (2 a 9), base de la nomenclatura de posiciones
+    #[serde(default = "default_max_players")]
+    pub max_players: u8,
+    /// `player_id` de los jugadores que cobraron algún bote (sin contar la
+    /// apuesta no igualada devuelta)
+    #[serde(default)]
+    pub winner_ids: Vec<String>,
+    /// Cartas del flop compactas (`Ah7d2c`), si se vio
+    #[serde(default)]
+    pub flop: Option<String>,
+    /// Carta del turn (`9s`), si se vio
+    #[serde(default)]
+    pub turn: Option<String>,
+    /// Carta del river (`3h`), si se vio
+    #[serde(default)]
+    pub river: Option<String>,
+    /// Cartas del héroe compactas (`AhKd`), si se conocen
+    #[serde(default)]
+    pub hero_cards: Option<String>,
     pub created_at: String,
 }
 
@@ -136,6 +155,12 @@ impl HandMetadata {
             table_name,
             blind_level,
             button_seat,
+            max_players: DEFAULT_MAX_PLAYERS,
+            winner_ids: Vec::new(),
+            flop: None,
+            turn: None,
+            river: None,
+            hero_cards: None,
             created_at: chrono::Utc::now().to_rfc3339(),
         }
     }
@@ -162,11 +187,24 @@ impl HandMetadata {
             table_name,
             blind_level,
             button_seat,
+            max_players: DEFAULT_MAX_PLAYERS,
+            winner_ids: Vec::new(),
+            flop: None,
+            turn: None,
+            river: None,
+            hero_cards: None,
             created_at: chrono::Utc::now().to_rfc3339(),
         }
     }
 }
 
+/// Tamaño de mesa por defecto (filas anteriores a `max_players`)
+pub const DEFAULT_MAX_PLAYERS: u8 = 6;
+
+fn default_max_players() -> u8 {
+    DEFAULT_MAX_PLAYERS
+}
+
 /// Enum: Formato de juego
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GameFormat {
@@ -230,6 +268,33 @@ impl HandAction {
     }
 }
 
+/// Tabla: hands_players (FACT TABLE)
+/// Resultado de cada jugador en cada mano (una fila por jugador y mano)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HandPlayer {
+    pub hand_id: String,
+    pub player_id: String,
+    pub seat: u8,
+    /// Etiqueta de la posición (`BTN`, `SB`, `UTG+1`...), si se conoce
+    pub position: Option<String>,
+    pub starting_stack_cents: i64,
+    /// Cartas propias compactas (`AhKd`), si se conocen
+    pub hole_cards: Option<String>,
+    pub is_hero: bool,
+    /// Ciegas, antes y apuestas puestas en el bote
+    pub invested_cents: i64,
+    /// Cobrado de los botes (incluye la apuesta no igualada)
+    pub collected_cents: i64,
+    /// `collected_cents - invested_cents`
+    pub net_cents: i64,
+    /// Rake atribuible, proporcional a lo cobrado sin la apuesta no igualada
+    pub rake_cents: i64,
+    pub went_to_showdown: bool,
+    /// Cobró de algún bote en el showdown (sin contar la apuesta no igualada)
+    pub won_at_showdown: bool,
+    pub created_at: String,
+}
+
 /// Enum: Street (calle del juego)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Street {
@@ -435,6 +500,12 @@ pub struct HandMetadataBuilder {
     table_name: String,
     blind_level: i64,
     button_seat: u8,
+    max_players: u8,
+    winner_ids: Vec<String>,
+    flop: Option<String>,
+    turn: Option<String>,
+    river: Option<String>,
+    hero_cards: Option<String>,
 }
 
 impl HandMetadataBuilder {
@@ -449,6 +520,12 @@ impl HandMetadataBuilder {
             table_name: String::new(),
             blind_level: 0,
             button_seat: 0,
+            max_players: DEFAULT_MAX_PLAYERS,
+            winner_ids: Vec::new(),
+            flop: None,
+            turn: None,
+            river: None,
+            hero_cards: None,
         }
     }
 
@@ -487,6 +564,36 @@ impl HandMetadataBuilder {
         self
     }
 
+    pub fn max_players(mut self, max_players: u8) -> Self {
+        self.max_players = max_players;
+        self
+    }
+
+    pub fn winner_ids(mut self, winner_ids: Vec<String>) -> Self {
+        self.winner_ids = winner_ids;
+        self
+    }
+
+    pub fn flop(mut self, flop: String) -> Self {
+        self.flop = Some(flop);
+        self
+    }
+
+    pub fn turn(mut self, turn: String) -> Self {
+        self.turn = Some(turn);
+        self
+    }
+
+    pub fn river(mut self, river: String) -> Self {
+        self.river = Some(river);
+        self
+    }
+
+    pub fn hero_cards(mut self, hero_cards: String) -> Self {
+        self.hero_cards = Some(hero_cards);
+        self
+    }
+
     pub fn build(self) -> HandMetadata {
         HandMetadata {
             hand_id: self.hand_id,
@@ -498,6 +605,12 @@ impl HandMetadataBuilder {
             table_name: self.table_name,
             blind_level: self.blind_level,
             button_seat: self.button_seat,
+            max_players: self.max_players,
+            winner_ids: self.winner_ids,
+            flop: self.flop,
+            turn: self.turn,
+            river: self.river,
+            hero_cards: self.hero_cards,
             created_at: chrono::Utc::now().to_rfc3339(),
         }
     }
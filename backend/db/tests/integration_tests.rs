@@ -5,10 +5,12 @@
 #[cfg(test)]
 mod integration_tests {
     use poker_db::{
-        ActionType, DbConnection, HandAction, HandMetadata, InMemoryOptimization,
-        MemoryMaintenance, MemoryMonitor, ParquetLoadConfig, ParquetLoader, ParquetReadConfig,
-        ParquetReader, ParquetWriteConfig, ParquetWriter, Street,
+        ActionType, DbConnection, HandAction, HandMetadata, InMemoryOptimization, IngestConfig,
+        IngestService, MemoryMaintenance, MemoryMonitor, ParquetLoadConfig, ParquetLoader,
+        ParquetReadConfig, ParquetReader, ParquetWriteConfig, ParquetWriter, PlayerStatsEngine,
+        StatsQuery, Street,
     };
+    use poker_parsers::{SyntheticConfig, SyntheticGenerator};
     use std::collections::HashMap;
     use tempfile::TempDir;
 
@@ -291,4 +293,468 @@ mod integration_tests {
         );
         println!("Compressed 1000 hands to {} bytes", file_size);
     }
+
+    #[test]
+    fn test_ingest_pipeline_is_idempotent() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let hands =
+            SyntheticGenerator::new(SyntheticConfig::new(50).with_seed(7)).generate_sequential();
+
+        let mut conn = DbConnection::in_memory().expect("Conexión fallida");
+        conn.init_schema_embedded().expect("Schema init failed");
+        let mut service =
+            IngestService::new(conn, IngestConfig::new(temp_dir.path())).expect("Service failed");
+
+        // Primera ingesta: todas las manos llegan a DuckDB
+        let report = service.ingest_hands(hands.clone()).expect("Ingest failed");
+        assert!(!report.has_errors(), "Errors: {:?}", report.errors);
+        assert_eq!(report.hands_ingested, 50);
+        assert_eq!(
+            report.rows_loaded,
+            50 + report.actions_written + report.player_rows_written
+        );
+
+        let stats = service.connection().get_stats().unwrap();
+        assert_eq!(stats.hand_count, 50);
+        assert_eq!(stats.action_count as usize, report.actions_written);
+        assert!(stats.player_count > 0);
+
+        // Una fila por jugador sentado en cada mano
+        let seated: usize = hands.iter().map(|h| h.players.len()).sum();
+        let player_rows: i64 = service
+            .connection()
+            .conn()
+            .query_row("SELECT COUNT(*) FROM hands_players", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(report.player_rows_written, seated);
+        assert_eq!(player_rows as usize, seated);
+
+        // Re-ingerir las mismas manos no duplica filas
+        let report = service.ingest_hands(hands).expect("Re-ingest failed");
+        assert_eq!(report.hands_ingested, 0);
+        assert_eq!(report.hands_skipped, 50);
+        assert_eq!(service.connection().get_stats().unwrap().hand_count, 50);
+
+        // Una base de datos nueva se reconstruye desde los Parquet
+        let mut conn = DbConnection::in_memory().expect("Conexión fallida");
+        conn.init_schema_embedded().expect("Schema init failed");
+        let service =
+            IngestService::new(conn, IngestConfig::new(temp_dir.path())).expect("Service failed");
+        assert_eq!(service.ledger().len(), 50);
+        assert_eq!(service.connection().get_stats().unwrap().hand_count, 50);
+    }
+
+    #[test]
+    fn test_ingest_quarantines_inconsistent_hands() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let mut hands =
+            SyntheticGenerator::new(SyntheticConfig::new(10).with_seed(3)).generate_sequential();
+        // Cobro inflado: invertido != cobrado + rake
+        hands[4].pot.winners[0].1 += 100;
+        let bad_id = hands[4].hand_id.clone();
+
+        let mut conn = DbConnection::in_memory().expect("Conexión fallida");
+        conn.init_schema_embedded().expect("Schema init failed");
+        let config = IngestConfig::new(temp_dir.path());
+        let quarantine_path = config.hand_quarantine_path();
+        let mut service = IngestService::new(conn, config).expect("Service failed");
+
+        let report = service.ingest_hands(hands.clone()).expect("Ingest failed");
+        assert!(!report.has_errors(), "Errors: {:?}", report.errors);
+        assert_eq!(report.hands_ingested, 9);
+        assert_eq!(report.hands_quarantined, 1);
+        assert_eq!(service.connection().get_stats().unwrap().hand_count, 9);
+        assert!(!service.ledger().contains(&bad_id));
+
+        // La mano apartada queda con el motivo y no se duplica al re-ingerir
+        let report = service.ingest_hands(hands).expect("Re-ingest failed");
+        assert_eq!(report.hands_quarantined, 1);
+        let entries = service.quarantine().entries().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].hand_id(), bad_id);
+        assert!(entries[0].reason().contains("[chip conservation]"));
+        assert!(quarantine_path.exists());
+    }
+
+    #[test]
+    fn test_player_stats_refresh_after_parquet_load() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+        let mut metadata = HandMetadata::new_cash(
+            "hand_001".to_string(),
+            "session_001".to_string(),
+            "2024-03-15T14:30:00Z".to_string(),
+            "NL10".to_string(),
+            "Table 1".to_string(),
+            5,
+            0,
+        );
+        metadata.winner_ids = vec!["p2".to_string()];
+
+        // p1 (BTN) abre, p2 (SB) hace 3bet, p3 (BB) foldea y p1 paga.
+        // p2 c-betea el flop, turn check-check, p1 apuesta el river y p2 gana.
+        let script = [
+            ("p1", Street::Preflop, ActionType::Raise, 30),
+            ("p2", Street::Preflop, ActionType::Raise, 100),
+            ("p3", Street::Preflop, ActionType::Fold, 0),
+            ("p1", Street::Preflop, ActionType::Call, 70),
+            ("p2", Street::Flop, ActionType::Bet, 120),
+            ("p1", Street::Flop, ActionType::Call, 120),
+            ("p2", Street::Turn, ActionType::Check, 0),
+            ("p1", Street::Turn, ActionType::Check, 0),
+            ("p2", Street::River, ActionType::Check, 0),
+            ("p1", Street::River, ActionType::Bet, 300),
+            ("p2", Street::River, ActionType::Call, 300),
+        ];
+        let actions: Vec<HandAction> = script
+            .iter()
+            .enumerate()
+            .map(|(i, (player, street, action, amount))| {
+                HandAction::new(
+                    "hand_001".to_string(),
+                    player.to_string(),
+                    *street,
+                    *action,
+                    *amount,
+                    false,
+                    i as i32 + 1,
+                )
+            })
+            .collect();
+
+        let mut timestamps = HashMap::new();
+        timestamps.insert(
+            "hand_001".to_string(),
+            chrono::NaiveDateTime::parse_from_str("2024-03-15 14:30:00", "%Y-%m-%d %H:%M:%S")
+                .unwrap(),
+        );
+
+        ParquetWriter::new(ParquetWriteConfig::new(
+            temp_dir.path().join("hands_metadata"),
+        ))
+        .write_hands_metadata(vec![metadata])
+        .expect("Failed to write metadata");
+        ParquetWriter::new(ParquetWriteConfig::new(
+            temp_dir.path().join("hands_actions"),
+        ))
+        .write_hands_actions(actions, &timestamps)
+        .expect("Failed to write actions");
+
+        let mut conn = DbConnection::in_memory().expect("Conexión fallida");
+        conn.init_schema_embedded().expect("Schema init failed");
+        let loader = ParquetLoader::new(ParquetLoadConfig::new(temp_dir.path()));
+        for table in ["hands_metadata", "hands_actions"] {
+            loader
+                .load_partitioned(conn.conn(), table, None, None)
+                .expect("Load failed");
+        }
+
+        let p1 = PlayerStatsEngine::player_summary(conn.conn(), "p1")
+            .unwrap()
+            .expect("p1 sin estadísticas");
+        assert_eq!(p1.counts.hands, 1);
+        assert_eq!(p1.counts.vpip().percentage(), Some(100.0));
+        assert_eq!(p1.counts.pfr, 1);
+        assert_eq!(p1.counts.three_bet_opportunities, 0);
+        assert_eq!(p1.counts.aggression_factor(), Some(1.0));
+        assert_eq!(p1.counts.wtsd().percentage(), Some(100.0));
+        assert_eq!(p1.counts.wsd().percentage(), Some(0.0));
+        assert_eq!(p1.counts.cbet().opportunities, 0);
+
+        let p2 = PlayerStatsEngine::player_summary(conn.conn(), "p2")
+            .unwrap()
+            .unwrap();
+        assert_eq!(p2.counts.three_bet().percentage(), Some(100.0));
+        assert_eq!(p2.counts.cbet().percentage(), Some(100.0));
+        assert_eq!(p2.counts.wsd().percentage(), Some(100.0));
+
+        let p3 = PlayerStatsEngine::player_summary(conn.conn(), "p3")
+            .unwrap()
+            .unwrap();
+        assert_eq!(p3.counts.vpip, 0);
+        assert_eq!(p3.counts.saw_flop, 0);
+        assert_eq!(p3.counts.three_bet_opportunities, 0);
+
+        let positions: Vec<_> =
+            PlayerStatsEngine::query(conn.conn(), &StatsQuery::new().by_position())
+                .unwrap()
+                .into_iter()
+                .map(|row| (row.player_id, row.position.unwrap()))
+                .collect();
+        assert_eq!(
+            positions,
+            vec![
+                ("p1".to_string(), "BTN".to_string()),
+                ("p2".to_string(), "SB".to_string()),
+                ("p3".to_string(), "BB".to_string()),
+            ]
+        );
+
+        // Las manos ya contabilizadas no se vuelven a sumar
+        let refresh = PlayerStatsEngine::refresh(conn.conn()).unwrap();
+        assert_eq!(refresh.hands_processed, 0);
+        let rebuilt = PlayerStatsEngine::rebuild(conn.conn()).unwrap();
+        assert_eq!(rebuilt.hands_processed, 1);
+        assert_eq!(
+            PlayerStatsEngine::player_summary(conn.conn(), "p1")
+                .unwrap()
+                .unwrap()
+                .counts,
+            p1.counts
+        );
+    }
+
+    #[test]
+    fn test_ingest_tournament_hands_and_summary() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let history_dir = temp_dir.path().join("history");
+        std::fs::create_dir_all(&history_dir).unwrap();
+
+        std::fs::write(
+            history_dir.join("20251215_Expresso(654321987)_real_holdem_no-limit.txt"),
+            r#"Winamax Poker - Tournament "Expresso" buyIn: 0.93€ + 0.07€ level: 1 - HandId: #654321987-1-1765807400 - Holdem no limit (10/20) - 2025/12/15 14:03:20 UTC
+Table: 'Expresso(654321987)#0' 3-max (real money) Seat #1 is the button
+Seat 1: thesmoy (500)
+Seat 2: Villain1 (500)
+Seat 3: Villain2 (500)
+*** ANTE/BLINDS ***
+Villain1 posts small blind 10
+Villain2 posts big blind 20
+Dealt to thesmoy [Ah Kd]
+*** PRE-FLOP ***
+thesmoy raises 20 to 40
+Villain1 folds
+Villain2 folds
+thesmoy collected 70 from pot
+*** SUMMARY ***
+Total pot 70 | No rake
+Seat 1: thesmoy (button) won 70
+
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            history_dir.join("20251215_Expresso(654321987)_real_holdem_no-limit_summary.txt"),
+            "Winamax Poker - Tournament summary : Expresso(654321987)
+Player : thesmoy
+Buy-In : 0.93€ + 0.07€
+Registered players : 3
+Prizepool : 5€
+Tournament started 2025/12/15 14:02:19 UTC
+You finished in 1st place
+You won 5€
+",
+        )
+        .unwrap();
+
+        let mut conn = DbConnection::in_memory().expect("Conexión fallida");
+        conn.init_schema_embedded().expect("Schema init failed");
+        let mut service = IngestService::new(conn, IngestConfig::new(temp_dir.path().join("data")))
+            .expect("Service failed");
+
+        let report = service
+            .ingest_directory(&history_dir)
+            .expect("Ingest failed");
+        assert!(!report.has_errors(), "Errors: {:?}", report.errors);
+        assert_eq!(report.hands_ingested, 1);
+        assert_eq!(report.tournament_results, 1);
+
+        let conn = service.connection().conn();
+        let (format, tournament_id): (String, String) = conn
+            .query_row(
+                "SELECT format, tournament_id FROM hands_metadata",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(format, "EXPRESSO");
+        assert_eq!(tournament_id, "654321987");
+
+        let (buyin, rake, entries): (i64, i64, i32) = conn
+            .query_row(
+                "SELECT buyin_cents, rake_cents, total_entries FROM tournaments
+                 WHERE tournament_id = '654321987'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!((buyin, rake, entries), (93, 7, 3));
+
+        let (position, won): (i32, i64) = conn
+            .query_row(
+                "SELECT finish_position, total_won_cents FROM tournament_results",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!((position, won), (1, 500));
+
+        // Re-procesar el resumen reemplaza el resultado
+        service
+            .ingest_directory(&history_dir)
+            .expect("Re-ingest failed");
+        let results: i64 = service
+            .connection()
+            .conn()
+            .query_row("SELECT COUNT(*) FROM tournament_results", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(results, 1);
+    }
+
+    #[test]
+    fn test_full_ring_positions_in_stats() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+        // 9-max con 7 jugadores: UTG, LJ, HJ, CO, BTN, SB y BB
+        let mut content = String::from(
+            "Winamax Poker - CashGame - HandId: #900-1-1765807340 - Holdem no limit (0.01€/0.02€) - 2025/12/15 14:02:20 UTC
+Table: 'Paris 01' 9-max (real money) Seat #5 is the button
+",
+        );
+        let seats = [1, 2, 3, 5, 6, 8, 9];
+        for seat in seats {
+            content.push_str(&format!("Seat {}: s{} (2€)\n", seat, seat));
+        }
+        content.push_str(
+            "*** ANTE/BLINDS ***
+s6 posts small blind 0.01€
+s8 posts big blind 0.02€
+*** PRE-FLOP ***
+s9 folds
+s1 folds
+s2 calls 0.02€
+s3 folds
+s5 folds
+s6 folds
+s8 checks
+*** FLOP *** [2c 7d Ks]
+s8 checks
+s2 checks
+*** TURN *** [2c 7d Ks][4h]
+s8 checks
+s2 checks
+*** RIVER *** [2c 7d Ks 4h][9s]
+s8 checks
+s2 checks
+*** SHOW DOWN ***
+s8 shows [Kd 2d] (Two pairs)
+s2 shows [Qc Jc] (High card)
+s8 collected 0.05€ from pot
+*** SUMMARY ***
+Total pot 0.05€ | No rake
+
+",
+        );
+        let hands = poker_parsers::WinamaxParser::new().parse(&content).hands;
+        let parsed: Vec<_> = hands[0]
+            .players
+            .iter()
+            .map(|p| p.position.unwrap().label())
+            .collect();
+        assert_eq!(parsed, vec!["LJ", "HJ", "CO", "BTN", "SB", "BB", "UTG"]);
+
+        let mut conn = DbConnection::in_memory().expect("Conexión fallida");
+        conn.init_schema_embedded().expect("Schema init failed");
+        let mut service =
+            IngestService::new(conn, IngestConfig::new(temp_dir.path())).expect("Service failed");
+        let report = service.ingest_hands(hands).expect("Ingest failed");
+        assert!(!report.has_errors(), "Errors: {:?}", report.errors);
+
+        // Las estadísticas usan la posición asignada por el parser
+        let mut stmt = service
+            .connection()
+            .conn()
+            .prepare(
+                "SELECT p.display_name, s.position
+                 FROM player_stats_flat s JOIN players p USING (player_id)
+                 ORDER BY p.display_name",
+            )
+            .unwrap();
+        let positions: Vec<(String, String)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let expected = [
+            ("s1", "LJ"),
+            ("s2", "HJ"),
+            ("s3", "CO"),
+            ("s5", "BTN"),
+            ("s6", "SB"),
+            ("s8", "BB"),
+            ("s9", "UTG"),
+        ];
+        assert_eq!(
+            positions,
+            expected
+                .iter()
+                .map(|(n, p)| (n.to_string(), p.to_string()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_allin_blind_does_not_shift_stats_positions() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+        // La BB queda all-in al poner la ciega y nunca actúa preflop: el orden
+        // de acción (d, a, b) ya no identifica las posiciones
+        let content = "Winamax Poker - CashGame - HandId: #901-1-1765807340 - Holdem no limit (0.01€/0.02€) - 2025/12/15 14:02:20 UTC
+Table: 'Paris 02' 6-max (real money) Seat #1 is the button
+Seat 1: a (2€)
+Seat 2: b (2€)
+Seat 3: c (0.02€)
+Seat 4: d (2€)
+*** ANTE/BLINDS ***
+b posts small blind 0.01€
+c posts big blind 0.02€ and is all-in
+*** PRE-FLOP ***
+d calls 0.02€
+a folds
+b folds
+*** FLOP *** [2c 7d Ks]
+*** TURN *** [2c 7d Ks][4h]
+*** RIVER *** [2c 7d Ks 4h][9s]
+*** SHOW DOWN ***
+c shows [Qc Jc] (High card)
+d shows [Kd 2d] (Two pairs)
+d collected 0.05€ from pot
+*** SUMMARY ***
+Total pot 0.05€ | No rake
+
+";
+        let hands = poker_parsers::WinamaxParser::new().parse(content).hands;
+        assert_eq!(hands.len(), 1);
+
+        let mut conn = DbConnection::in_memory().expect("Conexión fallida");
+        conn.init_schema_embedded().expect("Schema init failed");
+        let mut service =
+            IngestService::new(conn, IngestConfig::new(temp_dir.path())).expect("Service failed");
+        let report = service.ingest_hands(hands).expect("Ingest failed");
+        assert!(!report.has_errors(), "Errors: {:?}", report.errors);
+
+        let mut stmt = service
+            .connection()
+            .conn()
+            .prepare(
+                "SELECT p.display_name, s.position
+                 FROM player_stats_flat s JOIN players p USING (player_id)
+                 ORDER BY p.display_name",
+            )
+            .unwrap();
+        let positions: Vec<(String, String)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            positions,
+            [("a", "BTN"), ("b", "SB"), ("d", "CO")]
+                .iter()
+                .map(|(n, p)| (n.to_string(), p.to_string()))
+                .collect::<Vec<_>>()
+        );
+    }
 }
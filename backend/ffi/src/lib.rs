@@ -4,6 +4,7 @@
 //!
 //! Este módulo proporciona bindings para:
 //! - Parsing de historiales Winamax
+//! - Cuarentena de archivos que el parser no consigue leer
 //! - Cálculo de equity Monte Carlo
 //! - Consultas a DuckDB
 //!
@@ -13,6 +14,7 @@
 //! |--------|------|-------------|
 //! | 101 | IO_ERROR | No se puede acceder al archivo |
 //! | 102 | PARSER_ERROR | Formato irreconocible o corrupto |
+//! | 103 | INVALID_HERO | Alias de héroe inválido |
 //! | 201 | INVALID_RANGE | Error de sintaxis en rango |
 //! | 202 | SIM_TIMEOUT | Simulación excedió 500ms |
 //!
@@ -24,8 +26,14 @@
 //! # Parsear archivos
 //! count = poker_ffi.parse_winamax_files(["path/to/file.txt"])
 //!
+//! # Varias cuentas del héroe (`nick` o `Sala:nick`)
+//! count = poker_ffi.parse_winamax_files(files, heroes=["thesmoy", "Winamax:smoy2"])
+//!
 //! # Calcular equity
-//! equity = poker_ffi.calculate_equity("AhKd", "QQ+,AKs", "Qh7s2c", 100000)
+//! equity = poker_ffi.calculate_equity("AhKd", "QsQc", "Qh7s2c", 100000)
+//!
+//! # Equity contra un rango (notación HandRangeDSL)
+//! equity = poker_ffi.calculate_equity_vs_range("AhKd", "QQ:1,AKs:0.5", "Qh7s2c")
 //! ```
 
 use pyo3::exceptions::{PyIOError, PyRuntimeError, PyValueError};
@@ -34,10 +42,15 @@ use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 // Re-exports de crates internos
-use poker_math::{calculate_equity as rust_calculate_equity, EquityResult};
+use poker_math::{
+    calculate_equity as rust_calculate_equity, calculate_equity_vs_range as rust_equity_vs_range,
+    calculate_range_vs_range as rust_range_vs_range, Card, EquityResult, MonteCarloConfig,
+};
 use poker_parsers::{
-    process_files_parallel, BatchProcessingResult, FileWatcher, ParsedHand, WatcherConfig,
+    BatchProcessingResult, FileQuarantine, FileWatcher, HeroConfig, ParallelProcessor, ParsedHand,
+    ProcessingConfig, ProcessingProgress, QuarantineEntry, WatcherConfig,
 };
+use poker_ranges::Range;
 
 // ============================================================================
 // CÓDIGOS DE ERROR FFI (según docs/specs/ffi-contract.md)
@@ -47,6 +60,8 @@ use poker_parsers::{
 const ERR_IO_ERROR: i32 = 101;
 /// Error de parsing - formato irreconocible
 const ERR_PARSER_ERROR: i32 = 102;
+/// Alias de héroe inválido
+const ERR_INVALID_HERO: i32 = 103;
 /// Rango inválido - error de sintaxis
 const ERR_INVALID_RANGE: i32 = 201;
 /// Timeout de simulación - excedió 500ms
@@ -109,17 +124,21 @@ pub struct PyEquityResult {
     /// Error estándar estimado
     #[pyo3(get)]
     pub standard_error: f64,
+    /// Si el resultado es exacto (todos los runouts enumerados)
+    #[pyo3(get)]
+    pub is_exact: bool,
 }
 
 #[pymethods]
 impl PyEquityResult {
     fn __repr__(&self) -> String {
         format!(
-            "EquityResult(hero={:.2}%, villain={:.2}%, tie={:.2}%, sims={})",
+            "EquityResult(hero={:.2}%, villain={:.2}%, tie={:.2}%, sims={}, exact={})",
             self.hero_equity * 100.0,
             self.villain_equity * 100.0,
             self.tie_equity * 100.0,
-            self.simulations_run
+            self.simulations_run,
+            self.is_exact
         )
     }
 
@@ -134,6 +153,20 @@ impl PyEquityResult {
     }
 }
 
+impl From<EquityResult> for PyEquityResult {
+    fn from(result: EquityResult) -> Self {
+        PyEquityResult {
+            hero_equity: result.hero_equity,
+            villain_equity: result.villain_equity,
+            tie_equity: result.tie_equity,
+            simulations_run: result.simulations_run,
+            converged_early: result.converged_early,
+            standard_error: result.standard_error,
+            is_exact: result.is_exact,
+        }
+    }
+}
+
 /// Resumen de una mano parseada para Python
 #[pyclass]
 #[derive(Clone)]
@@ -150,7 +183,7 @@ pub struct PyHandSummary {
     /// Número de jugadores
     #[pyo3(get)]
     pub player_count: usize,
-    /// Si el héroe (thesmoy) participó
+    /// Si alguno de los alias del héroe participó
     #[pyo3(get)]
     pub hero_played: bool,
     /// Pot total en centavos
@@ -214,6 +247,8 @@ impl PyDbStats {
 ///
 /// # Argumentos
 /// * `files` - Lista de rutas a archivos de historial
+/// * `heroes` - Nicknames del héroe (`nick` o `Sala:nick`); sin ellos el
+///   héroe es el jugador de `Dealt to`
 ///
 /// # Retorna
 /// * `PyParseResult` con estadísticas del procesamiento
@@ -221,6 +256,7 @@ impl PyDbStats {
 /// # Errores
 /// * `IOError` (101) - Si no se puede acceder a algún archivo
 /// * `RuntimeError` (102) - Si hay errores de parsing
+/// * `ValueError` (103) - Si algún alias de héroe es inválido
 ///
 /// # Ejemplo
 /// ```python
@@ -228,8 +264,9 @@ impl PyDbStats {
 /// print(f"Parseadas {result.total_hands} manos en {result.elapsed_ms}ms")
 /// ```
 #[pyfunction]
-#[pyo3(signature = (files))]
-fn parse_winamax_files(files: Vec<String>) -> PyResult<PyParseResult> {
+#[pyo3(signature = (files, heroes = None))]
+fn parse_winamax_files(files: Vec<String>, heroes: Option<Vec<String>>) -> PyResult<PyParseResult> {
+    let heroes = parse_heroes(heroes.unwrap_or_default())?;
     if files.is_empty() {
         return Ok(PyParseResult {
             total_hands: 0,
@@ -254,7 +291,7 @@ fn parse_winamax_files(files: Vec<String>) -> PyResult<PyParseResult> {
     }
 
     // Procesar en paralelo con Rayon
-    let result: BatchProcessingResult = process_files_parallel(paths);
+    let result: BatchProcessingResult = process_with_heroes(paths, heroes);
 
     // Verificar si hubo errores de parsing
     if result.failed_files > 0 && result.successful_files == 0 {
@@ -279,12 +316,17 @@ fn parse_winamax_files(files: Vec<String>) -> PyResult<PyParseResult> {
 ///
 /// # Argumentos
 /// * `files` - Lista de rutas a archivos de historial
+/// * `heroes` - Nicknames del héroe (`nick` o `Sala:nick`)
 ///
 /// # Retorna
 /// * Lista de `PyHandSummary` con información de cada mano
 #[pyfunction]
-#[pyo3(signature = (files))]
-fn parse_winamax_with_details(files: Vec<String>) -> PyResult<Vec<PyHandSummary>> {
+#[pyo3(signature = (files, heroes = None))]
+fn parse_winamax_with_details(
+    files: Vec<String>,
+    heroes: Option<Vec<String>>,
+) -> PyResult<Vec<PyHandSummary>> {
+    let heroes = parse_heroes(heroes.unwrap_or_default())?;
     if files.is_empty() {
         return Ok(vec![]);
     }
@@ -302,7 +344,7 @@ fn parse_winamax_with_details(files: Vec<String>) -> PyResult<Vec<PyHandSummary>
         }
     }
 
-    let result = process_files_parallel(paths);
+    let result = process_with_heroes(paths, heroes);
 
     // Convertir manos a PyHandSummary
     let summaries: Vec<PyHandSummary> = result
@@ -316,6 +358,18 @@ fn parse_winamax_with_details(files: Vec<String>) -> PyResult<Vec<PyHandSummary>
     Ok(summaries)
 }
 
+/// Lee los alias del héroe recibidos desde Python.
+fn parse_heroes(heroes: Vec<String>) -> PyResult<HeroConfig> {
+    HeroConfig::parse(heroes)
+        .map_err(|e| PyValueError::new_err(format!("[{}] {}", ERR_INVALID_HERO, e)))
+}
+
+/// Procesa archivos en paralelo (16 threads) con los alias del héroe dados.
+fn process_with_heroes(paths: Vec<PathBuf>, heroes: HeroConfig) -> BatchProcessingResult {
+    ParallelProcessor::new(ProcessingConfig::default().with_heroes(heroes))
+        .process_files(paths, None::<fn(ProcessingProgress)>)
+}
+
 /// Calcula la equity de una mano contra otra usando Monte Carlo.
 ///
 /// Ejecuta simulaciones Monte Carlo paralelizadas con Rayon y SIMD AVX2
@@ -375,7 +429,6 @@ fn calculate_equity(
 
     // Ejecutar con timeout
     let start = Instant::now();
-    let timeout = Duration::from_millis(SIM_TIMEOUT_MS);
 
     // Convertir a slices de &str para la función Rust
     let hero_refs: Vec<&str> = hero.iter().map(|s| s.as_str()).collect();
@@ -387,21 +440,116 @@ fn calculate_equity(
         rust_calculate_equity(&hero_refs, &villain_refs, &board_refs, iterations);
 
     // Verificar timeout
-    if start.elapsed() > timeout {
-        return Err(PyRuntimeError::new_err(format!(
-            "[{}] Simulación excedió el tiempo límite de {}ms",
-            ERR_SIM_TIMEOUT, SIM_TIMEOUT_MS
+    check_simulation_timeout(start)?;
+
+    Ok(result.into())
+}
+
+/// Calcula la equity de una mano contra un rango del villano.
+///
+/// El rango usa la notación compacta HandRangeDSL (`AA:1,AKs:0.8,...`).
+/// Los combos se ponderan por su frecuencia y se eliminan los que chocan
+/// con las cartas del héroe o el board (card removal).
+///
+/// # Argumentos
+/// * `hero_cards` - Cartas del héroe (ej: "AhKd")
+/// * `villain_range` - Rango del villano (ej: "QQ:1,AKs:0.5,KQs:1")
+/// * `board` - Cartas comunitarias (ej: "Qh7s2c" o "" para preflop)
+/// * `iterations` - Número de simulaciones (default: 100000)
+///
+/// # Errores
+/// * `ValueError` (201) - Cartas o rango inválidos, o rango vacío tras card removal
+/// * `RuntimeError` (202) - Si la simulación excede 500ms
+#[pyfunction]
+#[pyo3(signature = (hero_cards, villain_range, board = "", iterations = 100000))]
+fn calculate_equity_vs_range(
+    hero_cards: &str,
+    villain_range: &str,
+    board: &str,
+    iterations: u32,
+) -> PyResult<PyEquityResult> {
+    let hero = to_math_cards(&parse_cards(hero_cards)?);
+    if hero.len() != 2 {
+        return Err(PyValueError::new_err(format!(
+            "[{}] Hero debe tener exactamente 2 cartas, recibido: {}",
+            ERR_INVALID_RANGE,
+            hero.len()
         )));
     }
+    let hero = [hero[0], hero[1]];
 
-    Ok(PyEquityResult {
-        hero_equity: result.hero_equity,
-        villain_equity: result.villain_equity,
-        tie_equity: result.tie_equity,
-        simulations_run: result.simulations_run,
-        converged_early: result.converged_early,
-        standard_error: result.standard_error,
-    })
+    let board_cards = to_math_cards(&parse_board(board)?);
+    let range = parse_range(villain_range)?;
+
+    let mut dead_cards = board_cards.clone();
+    dead_cards.extend_from_slice(&hero);
+    let villain_combos = range.expand(&dead_cards);
+    if villain_combos.is_empty() {
+        return Err(PyValueError::new_err(format!(
+            "[{}] El rango del villano no tiene combos vivos con las cartas conocidas",
+            ERR_INVALID_RANGE
+        )));
+    }
+
+    let config = MonteCarloConfig {
+        num_simulations: iterations,
+        ..Default::default()
+    };
+
+    let start = Instant::now();
+    let result = rust_equity_vs_range(&hero, &villain_combos, &board_cards, &config);
+    check_simulation_timeout(start)?;
+
+    if result.simulations_run == 0 {
+        return Err(PyValueError::new_err(format!(
+            "[{}] Cartas repetidas entre héroe y board",
+            ERR_INVALID_RANGE
+        )));
+    }
+
+    Ok(result.into())
+}
+
+/// Calcula la equity de un rango contra otro rango.
+///
+/// # Argumentos
+/// * `hero_range` - Rango del héroe en notación HandRangeDSL
+/// * `villain_range` - Rango del villano en notación HandRangeDSL
+/// * `board` - Cartas comunitarias (ej: "Qh7s2c" o "" para preflop)
+/// * `iterations` - Número de simulaciones (default: 100000)
+///
+/// # Errores
+/// * `ValueError` (201) - Rango inválido o sin combos compatibles
+/// * `RuntimeError` (202) - Si la simulación excede 500ms
+#[pyfunction]
+#[pyo3(signature = (hero_range, villain_range, board = "", iterations = 100000))]
+fn calculate_range_vs_range(
+    hero_range: &str,
+    villain_range: &str,
+    board: &str,
+    iterations: u32,
+) -> PyResult<PyEquityResult> {
+    let board_cards = to_math_cards(&parse_board(board)?);
+    let hero_combos = parse_range(hero_range)?.expand(&board_cards);
+    let villain_combos = parse_range(villain_range)?.expand(&board_cards);
+
+    let config = MonteCarloConfig {
+        num_simulations: iterations,
+        ..Default::default()
+    };
+
+    let start = Instant::now();
+    let result = rust_range_vs_range(&hero_combos, &villain_combos, &board_cards, &config);
+    check_simulation_timeout(start)?;
+
+    if result.simulations_run == 0 {
+        return Err(PyValueError::new_err(format!(
+            "[{}] Los rangos no tienen combos compatibles con el board",
+            ERR_INVALID_RANGE
+        )));
+    }
+
+    Ok(result.into())
 }
 
 /// Calcula equity multiway (3+ jugadores).
@@ -544,6 +692,32 @@ fn parse_board(board_str: &str) -> PyResult<Vec<String>> {
     Ok(cards)
 }
 
+/// Parsea un rango en notación HandRangeDSL (ej: "AA:1,AKs:0.8")
+fn parse_range(range_str: &str) -> PyResult<Range> {
+    Range::parse(range_str.trim()).map_err(|e| {
+        PyValueError::new_err(format!(
+            "[{}] Rango inválido '{}': {}",
+            ERR_INVALID_RANGE, range_str, e
+        ))
+    })
+}
+
+/// Convierte cartas ya validadas por `parse_cards` a `poker_math::Card`
+fn to_math_cards(cards: &[String]) -> Vec<Card> {
+    cards.iter().filter_map(|c| c.parse().ok()).collect()
+}
+
+/// Devuelve `SIM_TIMEOUT` si la simulación excedió el tiempo límite
+fn check_simulation_timeout(start: Instant) -> PyResult<()> {
+    if start.elapsed() > Duration::from_millis(SIM_TIMEOUT_MS) {
+        return Err(PyRuntimeError::new_err(format!(
+            "[{}] Simulación excedió el tiempo límite de {}ms",
+            ERR_SIM_TIMEOUT, SIM_TIMEOUT_MS
+        )));
+    }
+    Ok(())
+}
+
 /// Valida si una carta tiene formato correcto (ej: "Ah", "Kd", "2c")
 fn is_valid_card(card: &str) -> bool {
     if card.len() != 2 {
@@ -564,7 +738,7 @@ fn is_valid_card(card: &str) -> bool {
 
 /// Convierte una mano parseada a PyHandSummary
 fn convert_hand_to_summary(hand: &ParsedHand) -> PyHandSummary {
-    let hero_played = hand.players.iter().any(|p| p.name == "thesmoy");
+    let hero_played = hand.players.iter().any(|p| p.is_hero);
 
     PyHandSummary {
         hand_id: hand.hand_id.clone(),
@@ -593,24 +767,33 @@ pub struct PyWatcherConfig {
     /// Delay inicial para retry en milisegundos
     #[pyo3(get, set)]
     pub retry_delay_ms: u64,
+    /// Nicknames del héroe (`nick` o `Sala:nick`)
+    #[pyo3(get, set)]
+    pub heroes: Vec<String>,
 }
 
 #[pymethods]
 impl PyWatcherConfig {
     #[new]
-    #[pyo3(signature = (watch_path, max_retries = 3, retry_delay_ms = 100))]
-    fn new(watch_path: String, max_retries: u32, retry_delay_ms: u64) -> Self {
+    #[pyo3(signature = (watch_path, max_retries = 3, retry_delay_ms = 100, heroes = None))]
+    fn new(
+        watch_path: String,
+        max_retries: u32,
+        retry_delay_ms: u64,
+        heroes: Option<Vec<String>>,
+    ) -> Self {
         Self {
             watch_path,
             max_retries,
             retry_delay_ms,
+            heroes: heroes.unwrap_or_default(),
         }
     }
 
     fn __repr__(&self) -> String {
         format!(
-            "WatcherConfig(path='{}', retries={}, delay={}ms)",
-            self.watch_path, self.max_retries, self.retry_delay_ms
+            "WatcherConfig(path='{}', retries={}, delay={}ms, heroes={:?})",
+            self.watch_path, self.max_retries, self.retry_delay_ms, self.heroes
         )
     }
 }
@@ -689,6 +872,8 @@ fn start_file_watcher(config: PyWatcherConfig, callback: PyObject) -> PyResult<(
         max_retries: config.max_retries,
         retry_delay_ms: config.retry_delay_ms,
         use_exponential_backoff: true,
+        heroes: parse_heroes(config.heroes.clone())?,
+        quarantine_dir: None,
     };
 
     // Iniciar watcher en thread separado
@@ -727,6 +912,10 @@ fn start_file_watcher(config: PyWatcherConfig, callback: PyObject) -> PyResult<(
 ///
 /// Similar a `start_file_watcher` pero procesa automáticamente los archivos
 /// usando el parser de Rust y llama al callback con los resúmenes de manos.
+/// Solo se parsean las manos añadidas desde el último evento, así que cada
+/// llamada recibe únicamente las manos nuevas de la sesión en curso. Los
+/// archivos que el parser no consigue leer repetidamente se mueven a
+/// `quarantine/` dentro del directorio vigilado.
 ///
 /// # Argumentos
 /// * `config` - Configuración del watcher
@@ -757,23 +946,24 @@ fn start_file_watcher_with_parsing(config: PyWatcherConfig, callback: PyObject)
         max_retries: config.max_retries,
         retry_delay_ms: config.retry_delay_ms,
         use_exponential_backoff: true,
+        heroes: parse_heroes(config.heroes.clone())?,
+        quarantine_dir: Some(FileQuarantine::dir_for(&watch_path)),
     };
 
     thread::spawn(move || {
         let watcher = FileWatcher::new(rust_config);
 
-        let result = watcher.start(move |file_path| {
-            // Parsear el archivo detectado
-            let result = process_files_parallel(vec![file_path]);
-
-            // Convertir a PyHandSummary
-            let summaries: Vec<PyHandSummary> = result
-                .results
-                .into_iter()
-                .filter_map(|file_result| file_result.result.ok())
-                .flat_map(|parse_result| parse_result.hands)
-                .map(|hand| convert_hand_to_summary(&hand))
+        let result = watcher.start_tailing(move |update| {
+            // Convertir las manos nuevas a PyHandSummary
+            let summaries: Vec<PyHandSummary> = update
+                .result
+                .hands
+                .iter()
+                .map(convert_hand_to_summary)
                 .collect();
+            if summaries.is_empty() {
+                return;
+            }
 
             // Llamar al callback Python con las manos
             Python::with_gil(|py| {
@@ -791,6 +981,117 @@ fn start_file_watcher_with_parsing(config: PyWatcherConfig, callback: PyObject)
     Ok(())
 }
 
+// ============================================================================
+// CUARENTENA DE ARCHIVOS
+// ============================================================================
+
+/// Archivo en cuarentena expuesto a Python
+#[pyclass]
+#[derive(Clone)]
+pub struct PyQuarantinedFile {
+    /// Ruta original del archivo
+    #[pyo3(get)]
+    pub original_path: String,
+    /// Ruta dentro de la cuarentena
+    #[pyo3(get)]
+    pub quarantined_path: String,
+    /// Fallos acumulados al apartarlo
+    #[pyo3(get)]
+    pub failures: u32,
+    /// Motivo del último fallo
+    #[pyo3(get)]
+    pub reason: String,
+    /// Momento en que se apartó (RFC 3339)
+    #[pyo3(get)]
+    pub quarantined_at: String,
+}
+
+#[pymethods]
+impl PyQuarantinedFile {
+    fn __repr__(&self) -> String {
+        format!(
+            "QuarantinedFile(path='{}', failures={}, reason='{}')",
+            self.original_path, self.failures, self.reason
+        )
+    }
+}
+
+impl From<QuarantineEntry> for PyQuarantinedFile {
+    fn from(entry: QuarantineEntry) -> Self {
+        Self {
+            original_path: entry.original_path.to_string_lossy().to_string(),
+            quarantined_path: entry.quarantined_path.to_string_lossy().to_string(),
+            failures: entry.failures,
+            reason: entry.reason,
+            quarantined_at: entry.quarantined_at,
+        }
+    }
+}
+
+/// Abre la cuarentena de un directorio de historiales.
+fn open_quarantine(history_dir: &str) -> PyResult<FileQuarantine> {
+    let dir = FileQuarantine::dir_for(&PathBuf::from(history_dir));
+    FileQuarantine::open(&dir).map_err(|e| {
+        PyIOError::new_err(format!(
+            "[{}] No se puede leer la cuarentena {}: {}",
+            ERR_IO_ERROR,
+            dir.display(),
+            e
+        ))
+    })
+}
+
+/// Lista los archivos en cuarentena de un directorio de historiales.
+///
+/// # Argumentos
+/// * `history_dir` - Directorio de historiales (la cuarentena es su
+///   subdirectorio `quarantine/`)
+///
+/// # Errores
+/// * `IOError` (101) - Si no se puede leer la cuarentena
+#[pyfunction]
+#[pyo3(signature = (history_dir))]
+fn list_quarantined_files(history_dir: String) -> PyResult<Vec<PyQuarantinedFile>> {
+    let entries = open_quarantine(&history_dir)?
+        .list()
+        .map_err(|e| PyIOError::new_err(format!("[{}] {}", ERR_IO_ERROR, e)))?;
+    Ok(entries.into_iter().map(PyQuarantinedFile::from).collect())
+}
+
+/// Vuelve a parsear los archivos en cuarentena (p.ej. tras actualizar el
+/// parser).
+///
+/// Los que ya se parsean bien vuelven a su ruta original; el resto sigue en
+/// cuarentena y cuenta en `failed_files`.
+///
+/// # Argumentos
+/// * `history_dir` - Directorio de historiales
+/// * `heroes` - Nicknames del héroe (`nick` o `Sala:nick`)
+///
+/// # Errores
+/// * `IOError` (101) - Si no se puede leer la cuarentena o mover un archivo
+/// * `ValueError` (103) - Si algún alias de héroe es inválido
+#[pyfunction]
+#[pyo3(signature = (history_dir, heroes = None))]
+fn reimport_quarantined_files(
+    history_dir: String,
+    heroes: Option<Vec<String>>,
+) -> PyResult<PyParseResult> {
+    let heroes = parse_heroes(heroes.unwrap_or_default())?;
+    let mut quarantine = open_quarantine(&history_dir)?;
+
+    let result = ParallelProcessor::new(ProcessingConfig::default().with_heroes(heroes))
+        .reimport_quarantined(&mut quarantine)
+        .map_err(|e| PyIOError::new_err(format!("[{}] {}", ERR_IO_ERROR, e)))?;
+
+    Ok(PyParseResult {
+        total_hands: result.total_hands,
+        successful_files: result.results.len() - result.quarantined.len(),
+        failed_files: result.quarantined.len(),
+        elapsed_ms: result.elapsed_ms as u64,
+    })
+}
+
 // ============================================================================
 // MÓDULO PYTHON
 // ============================================================================
@@ -811,11 +1112,17 @@ fn poker_ffi(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     // Funciones de equity
     m.add_function(wrap_pyfunction!(calculate_equity, m)?)?;
     m.add_function(wrap_pyfunction!(calculate_equity_multiway, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_equity_vs_range, m)?)?;
+    m.add_function(wrap_pyfunction!(calculate_range_vs_range, m)?)?;
 
     // File watcher
     m.add_function(wrap_pyfunction!(start_file_watcher, m)?)?;
     m.add_function(wrap_pyfunction!(start_file_watcher_with_parsing, m)?)?;
 
+    // Cuarentena de archivos
+    m.add_function(wrap_pyfunction!(list_quarantined_files, m)?)?;
+    m.add_function(wrap_pyfunction!(reimport_quarantined_files, m)?)?;
+
     // Utilidades
     m.add_function(wrap_pyfunction!(is_simd_available, m)?)?;
     m.add_function(wrap_pyfunction!(version, m)?)?;
@@ -828,6 +1135,7 @@ fn poker_ffi(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyDbStats>()?;
     m.add_class::<PyWatcherConfig>()?;
     m.add_class::<PyFileEvent>()?;
+    m.add_class::<PyQuarantinedFile>()?;
 
     Ok(())
 }
@@ -892,4 +1200,20 @@ mod tests {
         let result = parse_board("AhKd");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_parse_range() {
+        let range = parse_range("AA:1,AKs:0.5").unwrap();
+        assert_eq!(range.len(), 2);
+
+        assert!(parse_range("AA:1,AKx:0.5").is_err());
+        assert!(parse_range("AA:2").is_err());
+    }
+
+    #[test]
+    fn test_to_math_cards() {
+        let cards = to_math_cards(&parse_cards("AhKd").unwrap());
+        assert_eq!(cards.len(), 2);
+        assert_eq!(cards[0].to_string(), "Ah");
+    }
 }
@@ -9,6 +9,8 @@
 //! - Optimización SIMD AVX2 para evaluación paralela de manos
 //! - Paralelización con Rayon para aprovechar 16 threads del Ryzen 3800X
 //! - Early stopping cuando la convergencia < 0.1%
+//! - Equity mano-vs-rango y rango-vs-rango ponderada por combos
+//! - Equity de Omaha (PLO4/PLO5) heads-up y multiway
 //!
 //! ## Uso
 //!
@@ -27,9 +29,15 @@
 //! Objetivo: > 100K simulaciones/segundo en Ryzen 7 3800X
 
 mod monte_carlo;
+mod omaha;
+mod range_equity;
 mod simd;
 
 pub use monte_carlo::{
     calculate_equity, calculate_equity_multiway, simulate_single, EquityResult, MonteCarloConfig,
 };
+pub use omaha::{
+    calculate_omaha_equity, calculate_omaha_equity_multiway, calculate_omaha_equity_with_config,
+};
+pub use range_equity::{calculate_equity_vs_range, calculate_range_vs_range, WeightedCombo};
 pub use simd::{is_avx2_available, SimdEvaluator};
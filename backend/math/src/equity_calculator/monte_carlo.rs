@@ -2,8 +2,12 @@
 //!
 //! Implementación del simulador Monte Carlo para cálculo de equity.
 //! Optimizado para paralelización con Rayon y convergencia temprana.
+//!
+//! Cuando quedan pocos runouts posibles (turn, flop, o preflop heads-up con
+//! la tabla de lookup de 7 cartas cargada) se enumeran todos en lugar de
+//! muestrear, y el resultado es exacto (`is_exact = true`).
 
-use crate::hand_evaluator::{Card, HandRank, CARDS};
+use crate::hand_evaluator::{is_lookup_table_loaded, Card, HandRank, CARDS};
 use rand::prelude::*;
 use rand_chacha::ChaCha8Rng;
 use rayon::prelude::*;
@@ -24,8 +28,14 @@ pub struct MonteCarloConfig {
     pub use_simd: bool,
     /// Número de threads para paralelización (0 = auto)
     pub num_threads: usize,
+    /// Runouts restantes por debajo de los cuales se enumeran todos en lugar
+    /// de muestrear (0 = siempre Monte Carlo)
+    pub exact_enumeration_threshold: u64,
 }
 
+/// Runouts posibles preflop heads-up: C(48, 5)
+const PREFLOP_HEADS_UP_RUNOUTS: u64 = 1_712_304;
+
 impl Default for MonteCarloConfig {
     fn default() -> Self {
         MonteCarloConfig {
@@ -33,7 +43,8 @@ impl Default for MonteCarloConfig {
             convergence_threshold: 0.001, // 0.1%
             convergence_check_interval: 10_000,
             use_simd: true,
-            num_threads: 0, // Auto-detect
+            num_threads: 0,                      // Auto-detect
+            exact_enumeration_threshold: 50_000, // Flop y turn heads-up
         }
     }
 }
@@ -53,9 +64,24 @@ pub struct EquityResult {
     pub converged_early: bool,
     /// Error estándar estimado de la equity
     pub standard_error: f64,
+    /// Si el resultado es exacto (enumeración completa, sin muestreo)
+    pub is_exact: bool,
 }
 
 impl EquityResult {
+    /// Resultado para input inválido (sin simulaciones ejecutadas)
+    pub(crate) fn invalid() -> Self {
+        EquityResult {
+            hero_equity: 0.0,
+            villain_equity: 0.0,
+            tie_equity: 0.0,
+            simulations_run: 0,
+            converged_early: false,
+            standard_error: 1.0,
+            is_exact: false,
+        }
+    }
+
     /// Crea un resultado para un escenario sin cartas desconocidas
     pub fn deterministic(hero_wins: bool, tie: bool) -> Self {
         if tie {
@@ -66,6 +92,7 @@ impl EquityResult {
                 simulations_run: 1,
                 converged_early: true,
                 standard_error: 0.0,
+                is_exact: true,
             }
         } else if hero_wins {
             EquityResult {
@@ -75,6 +102,7 @@ impl EquityResult {
                 simulations_run: 1,
                 converged_early: true,
                 standard_error: 0.0,
+                is_exact: true,
             }
         } else {
             EquityResult {
@@ -84,6 +112,7 @@ impl EquityResult {
                 simulations_run: 1,
                 converged_early: true,
                 standard_error: 0.0,
+                is_exact: true,
             }
         }
     }
@@ -143,25 +172,11 @@ pub fn calculate_equity_with_config(
 
     // Validar input
     if hero.len() != 2 || villain.len() != 2 {
-        return EquityResult {
-            hero_equity: 0.0,
-            villain_equity: 0.0,
-            tie_equity: 0.0,
-            simulations_run: 0,
-            converged_early: false,
-            standard_error: 1.0,
-        };
+        return EquityResult::invalid();
     }
 
     if board.len() > 5 {
-        return EquityResult {
-            hero_equity: 0.0,
-            villain_equity: 0.0,
-            tie_equity: 0.0,
-            simulations_run: 0,
-            converged_early: false,
-            standard_error: 1.0,
-        };
+        return EquityResult::invalid();
     }
 
     // Si el board está completo (5 cartas), es determinístico
@@ -169,8 +184,129 @@ pub fn calculate_equity_with_config(
         return calculate_deterministic(&hero, &villain, &board);
     }
 
+    let remaining_cards = remaining_deck(&[&hero, &villain, &board]);
+    let cards_needed = 5 - board.len();
+
+    // Pocos runouts restantes: enumeración exacta
+    let runouts = binomial(remaining_cards.len() as u64, cards_needed as u64);
+    if should_enumerate(runouts, config) {
+        return enumerate_runouts(&hero, &villain, &board, &remaining_cards);
+    }
+
     // Ejecutar Monte Carlo paralelo
-    run_monte_carlo_parallel(&hero, &villain, &board, config)
+    run_monte_carlo_parallel(&hero, &villain, &board, &remaining_cards, config)
+}
+
+/// Cartas del deck que no están en ninguno de los grupos conocidos
+pub(super) fn remaining_deck(known: &[&[Card]]) -> Vec<Card> {
+    CARDS
+        .iter()
+        .copied()
+        .filter(|c| !known.iter().any(|group| group.contains(c)))
+        .collect()
+}
+
+/// Coeficiente binomial C(n, k)
+pub(super) fn binomial(n: u64, k: u64) -> u64 {
+    if k > n {
+        return 0;
+    }
+    (0..k).fold(1u64, |acc, i| acc * (n - i) / (i + 1))
+}
+
+/// Decide si enumerar todos los runouts en lugar de muestrear.
+///
+/// Con la tabla de lookup de 7 cartas cargada, la evaluación es O(1) y
+/// también se enumera el preflop heads-up (~1.7M runouts).
+fn should_enumerate(runouts: u64, config: &MonteCarloConfig) -> bool {
+    if config.exact_enumeration_threshold == 0 {
+        return false;
+    }
+
+    runouts <= config.exact_enumeration_threshold
+        || (is_lookup_table_loaded() && runouts <= PREFLOP_HEADS_UP_RUNOUTS)
+}
+
+/// Enumera todos los runouts posibles en paralelo (equity exacta).
+fn enumerate_runouts(
+    hero: &[Card],
+    villain: &[Card],
+    board: &[Card],
+    deck: &[Card],
+) -> EquityResult {
+    enumerate_with(deck, 5 - board.len(), |evaluator, runout| {
+        compare_runout(evaluator, hero, villain, board, runout)
+    })
+}
+
+/// Recorre todos los runouts de `cards_needed` cartas de `deck` y agrega el
+/// resultado de `compare` (equity exacta).
+///
+/// Paraleliza con Rayon por la primera carta del runout; cada tarea recorre
+/// las combinaciones restantes en orden lexicográfico.
+pub(super) fn enumerate_with<F>(deck: &[Card], cards_needed: usize, compare: F) -> EquityResult
+where
+    F: Fn(&SimdEvaluator, &[Card]) -> std::cmp::Ordering + Sync,
+{
+    let (hero_wins, villain_wins, ties) = (0..deck.len())
+        .into_par_iter()
+        .map(|first| {
+            let evaluator = SimdEvaluator::new();
+            let mut runout = [deck[first]; 5];
+            let mut counts = (0u64, 0u64, 0u64);
+
+            for_each_combination(
+                deck,
+                first + 1,
+                1,
+                cards_needed,
+                &mut runout,
+                &mut |runout| match compare(&evaluator, runout) {
+                    std::cmp::Ordering::Greater => counts.0 += 1,
+                    std::cmp::Ordering::Less => counts.1 += 1,
+                    std::cmp::Ordering::Equal => counts.2 += 1,
+                },
+            );
+
+            counts
+        })
+        .reduce(|| (0, 0, 0), |a, b| (a.0 + b.0, a.1 + b.1, a.2 + b.2));
+
+    let total = (hero_wins + villain_wins + ties) as f64;
+    if total == 0.0 {
+        return EquityResult::invalid();
+    }
+
+    EquityResult {
+        hero_equity: (hero_wins as f64 + ties as f64 * 0.5) / total,
+        villain_equity: (villain_wins as f64 + ties as f64 * 0.5) / total,
+        tie_equity: ties as f64 / total,
+        simulations_run: total as u32,
+        converged_early: false,
+        standard_error: 0.0,
+        is_exact: true,
+    }
+}
+
+/// Recorre las combinaciones de `k` cartas de `deck[start..]`, completando
+/// `buffer` a partir de la posición `depth`.
+pub(super) fn for_each_combination<F: FnMut(&[Card])>(
+    deck: &[Card],
+    start: usize,
+    depth: usize,
+    k: usize,
+    buffer: &mut [Card; 5],
+    visit: &mut F,
+) {
+    if depth == k {
+        visit(&buffer[..k]);
+        return;
+    }
+
+    for (i, &card) in deck.iter().enumerate().skip(start) {
+        buffer[depth] = card;
+        for_each_combination(deck, i + 1, depth + 1, k, buffer, visit);
+    }
 }
 
 /// Calcula equity para un board completo (determinístico)
@@ -206,23 +342,45 @@ fn run_monte_carlo_parallel(
     hero: &[Card],
     villain: &[Card],
     board: &[Card],
+    remaining_cards: &[Card],
     config: &MonteCarloConfig,
 ) -> EquityResult {
-    // Cartas conocidas (remover del deck)
-    let mut known_cards: Vec<Card> = Vec::with_capacity(9);
-    known_cards.extend_from_slice(hero);
-    known_cards.extend_from_slice(villain);
-    known_cards.extend_from_slice(board);
+    let cards_needed = 5 - board.len();
 
-    // Cartas restantes del deck
-    let remaining_cards: Vec<Card> = CARDS
-        .iter()
-        .copied()
-        .filter(|c| !known_cards.contains(c))
-        .collect();
+    run_simulations(remaining_cards, config, |rng, evaluator, deck| {
+        // Fisher-Yates parcial para las cartas que necesitamos
+        for i in 0..cards_needed {
+            let j = rng.gen_range(i..deck.len());
+            deck.swap(i, j);
+        }
 
-    let cards_needed = 5 - board.len();
+        Some(compare_runout(
+            evaluator,
+            hero,
+            villain,
+            board,
+            &deck[..cards_needed],
+        ))
+    })
+}
 
+/// Bucle Monte Carlo paralelo compartido por todos los modos de equity.
+///
+/// Reparte `config.num_simulations` entre los threads de Rayon, cada uno con
+/// su propio RNG y una copia local de `deck`. En cada iteración `simulate`
+/// devuelve el resultado del héroe frente al villano, o `None` si la muestra
+/// se descarta (por ejemplo, combos de rango incompatibles).
+///
+/// El thread 0 verifica la convergencia cada `convergence_check_interval`
+/// simulaciones y detiene el resto mediante un flag atómico.
+pub(super) fn run_simulations<F>(
+    deck: &[Card],
+    config: &MonteCarloConfig,
+    simulate: F,
+) -> EquityResult
+where
+    F: Fn(&mut ChaCha8Rng, &SimdEvaluator, &mut [Card]) -> Option<std::cmp::Ordering> + Sync,
+{
     // Contadores atómicos para resultados
     let hero_wins = AtomicU64::new(0);
     let villain_wins = AtomicU64::new(0);
@@ -249,7 +407,7 @@ fn run_monte_carlo_parallel(
         let evaluator = SimdEvaluator::new();
 
         // Copia local del deck restante
-        let mut deck = remaining_cards.clone();
+        let mut deck = deck.to_vec();
 
         let mut local_hero_wins = 0u64;
         let mut local_villain_wins = 0u64;
@@ -259,49 +417,18 @@ fn run_monte_carlo_parallel(
         // Para tracking de convergencia
         let mut prev_equity = 0.5f64;
 
-        // Buffers para manos
-        let mut hero_hand = [Card::from_index(0).unwrap(); 7];
-        let mut villain_hand = [Card::from_index(0).unwrap(); 7];
-
-        // Copiar cartas conocidas
-        hero_hand[0] = hero[0];
-        hero_hand[1] = hero[1];
-        villain_hand[0] = villain[0];
-        villain_hand[1] = villain[1];
-
-        for (i, &card) in board.iter().enumerate() {
-            hero_hand[i + 2] = card;
-            villain_hand[i + 2] = card;
-        }
-
         for sim_idx in 0..sims_per_thread {
             // Check early stopping
             if should_stop.load(Ordering::Relaxed) {
                 break;
             }
 
-            // Fisher-Yates parcial para las cartas que necesitamos
-            for i in 0..cards_needed {
-                let j = rng.gen_range(i..deck.len());
-                deck.swap(i, j);
-            }
-
-            // Completar las manos con las cartas del runout
-            for (idx, card) in deck.iter().take(cards_needed).enumerate() {
-                let card_idx = board.len() + 2 + idx;
-                hero_hand[card_idx] = *card;
-                villain_hand[card_idx] = *card;
-            }
-
-            // Evaluar manos
-            let hero_rank = evaluator.evaluate_7cards(&hero_hand);
-            let villain_rank = evaluator.evaluate_7cards(&villain_hand);
-
             // Comparar
-            match hero_rank.cmp(&villain_rank) {
-                std::cmp::Ordering::Greater => local_hero_wins += 1,
-                std::cmp::Ordering::Less => local_villain_wins += 1,
-                std::cmp::Ordering::Equal => local_ties += 1,
+            match simulate(&mut rng, &evaluator, &mut deck) {
+                Some(std::cmp::Ordering::Greater) => local_hero_wins += 1,
+                Some(std::cmp::Ordering::Less) => local_villain_wins += 1,
+                Some(std::cmp::Ordering::Equal) => local_ties += 1,
+                None => continue,
             }
 
             local_sims += 1;
@@ -337,6 +464,10 @@ fn run_monte_carlo_parallel(
     let total_sims = total_simulations.load(Ordering::Relaxed) as f64;
     let converged = should_stop.load(Ordering::Relaxed);
 
+    if total_sims == 0.0 {
+        return EquityResult::invalid();
+    }
+
     let hero_equity = (total_hero + total_ties * 0.5) / total_sims;
     let villain_equity = (total_villain + total_ties * 0.5) / total_sims;
     let tie_equity = total_ties / total_sims;
@@ -351,9 +482,41 @@ fn run_monte_carlo_parallel(
         simulations_run: total_sims as u32,
         converged_early: converged,
         standard_error,
+        is_exact: false,
     }
 }
 
+/// Compara héroe y villano sobre el board completado con `runout`.
+///
+/// Devuelve `Greater` si gana el héroe, `Less` si gana el villano y
+/// `Equal` en caso de empate.
+#[inline]
+pub(super) fn compare_runout(
+    evaluator: &SimdEvaluator,
+    hero: &[Card],
+    villain: &[Card],
+    board: &[Card],
+    runout: &[Card],
+) -> std::cmp::Ordering {
+    let mut hero_hand = [Card::from_index(0).unwrap(); 7];
+    let mut villain_hand = [Card::from_index(0).unwrap(); 7];
+
+    hero_hand[0] = hero[0];
+    hero_hand[1] = hero[1];
+    villain_hand[0] = villain[0];
+    villain_hand[1] = villain[1];
+
+    for (i, &card) in board.iter().chain(runout.iter()).enumerate() {
+        hero_hand[i + 2] = card;
+        villain_hand[i + 2] = card;
+    }
+
+    let hero_rank = evaluator.evaluate_7cards(&hero_hand);
+    let villain_rank = evaluator.evaluate_7cards(&villain_hand);
+
+    hero_rank.cmp(&villain_rank)
+}
+
 /// Simula una sola mano y devuelve el resultado
 ///
 /// # Arguments
@@ -712,13 +875,88 @@ mod tests {
 
     #[test]
     fn test_standard_error_decreases() {
-        // Más simulaciones = menor error estándar
-        let result_small = calculate_equity(&["As", "Ah"], &["Ks", "Kh"], &[], 1_000);
-        let result_large = calculate_equity(&["As", "Ah"], &["Ks", "Kh"], &[], 50_000);
+        // Más simulaciones = menor error estándar (forzando Monte Carlo)
+        let config = |num_simulations| MonteCarloConfig {
+            num_simulations,
+            exact_enumeration_threshold: 0,
+            ..Default::default()
+        };
+        let result_small =
+            calculate_equity_with_config(&["As", "Ah"], &["Ks", "Kh"], &[], &config(1_000));
+        let result_large =
+            calculate_equity_with_config(&["As", "Ah"], &["Ks", "Kh"], &[], &config(50_000));
 
         assert!(
             result_large.standard_error < result_small.standard_error,
             "More simulations should reduce standard error"
         );
     }
+
+    #[test]
+    fn test_turn_is_exact() {
+        // 44 cartas restantes = 44 runouts
+        let result = calculate_equity(
+            &["As", "Ah"],
+            &["Ks", "Kh"],
+            &["2c", "7d", "9h", "Jc"],
+            1_000,
+        );
+
+        assert!(result.is_exact);
+        assert_eq!(result.simulations_run, 44);
+        assert_eq!(result.standard_error, 0.0);
+        // El villano solo gana con uno de los 2 reyes restantes
+        assert!((result.villain_equity - 2.0 / 44.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_flop_is_exact_and_deterministic() {
+        let hero = ["Ah", "Kh"];
+        let villain = ["Qs", "Qd"];
+        let board = ["2h", "7h", "Tc"];
+
+        let first = calculate_equity(&hero, &villain, &board, 1_000);
+        let second = calculate_equity(&hero, &villain, &board, 50_000);
+
+        assert!(first.is_exact);
+        assert_eq!(first.simulations_run, 990); // C(45, 2)
+        assert_eq!(first.standard_error, 0.0);
+        assert_eq!(first.hero_equity, second.hero_equity);
+        assert!((first.hero_equity + first.villain_equity - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_exact_matches_monte_carlo() {
+        let hero = ["Ah", "Kh"];
+        let villain = ["Qs", "Qd"];
+        let board = ["2h", "7h", "Tc"];
+
+        let exact = calculate_equity(&hero, &villain, &board, 1_000);
+        let sampled = calculate_equity_with_config(
+            &hero,
+            &villain,
+            &board,
+            &MonteCarloConfig {
+                num_simulations: 100_000,
+                exact_enumeration_threshold: 0,
+                ..Default::default()
+            },
+        );
+
+        assert!(!sampled.is_exact);
+        assert!(
+            (exact.hero_equity - sampled.hero_equity).abs() < 0.01,
+            "exact {:.4} vs sampled {:.4}",
+            exact.hero_equity,
+            sampled.hero_equity
+        );
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(binomial(48, 5), PREFLOP_HEADS_UP_RUNOUTS);
+        assert_eq!(binomial(45, 2), 990);
+        assert_eq!(binomial(44, 1), 44);
+        assert_eq!(binomial(3, 5), 0);
+    }
 }
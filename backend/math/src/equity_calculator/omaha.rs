@@ -0,0 +1,357 @@
+//! # Equity en Omaha
+//!
+//! Equity para Pot-Limit Omaha (PLO4/PLO5) con la regla de dos cartas
+//! propias y tres del board (`evaluate_omaha`).
+//!
+//! Comparte con Hold'em el bucle Monte Carlo paralelo y la enumeración
+//! exacta de runouts. Cada evaluación de Omaha recorre hasta 100 manos de
+//! 5 cartas, así que el preflop siempre se muestrea; flop y turn se
+//! enumeran por debajo de `exact_enumeration_threshold`.
+
+use crate::hand_evaluator::{
+    evaluate_omaha_unchecked, Card, OMAHA_MAX_HOLE_CARDS, OMAHA_MIN_HOLE_CARDS,
+};
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+use rayon::prelude::*;
+
+use super::monte_carlo::{
+    binomial, enumerate_with, for_each_combination, remaining_deck, run_simulations, EquityResult,
+    MonteCarloConfig,
+};
+
+/// Calcula la equity de una mano de Omaha contra otra
+///
+/// # Arguments
+/// * `hero_cards` - Cartas del héroe (4 o 5 cartas)
+/// * `villain_cards` - Cartas del villano (4 o 5 cartas)
+/// * `board_cards` - Cartas comunitarias (0-5 cartas)
+/// * `num_simulations` - Número de simulaciones a ejecutar
+///
+/// # Example
+/// ```rust,ignore
+/// use poker_math::equity_calculator::calculate_omaha_equity;
+///
+/// let result = calculate_omaha_equity(
+///     &["As", "Ah", "Ks", "Kh"],
+///     &["7c", "6c", "5d", "4d"],
+///     &[],
+///     10000,
+/// );
+/// ```
+pub fn calculate_omaha_equity(
+    hero_cards: &[&str],
+    villain_cards: &[&str],
+    board_cards: &[&str],
+    num_simulations: u32,
+) -> EquityResult {
+    let config = MonteCarloConfig {
+        num_simulations,
+        ..Default::default()
+    };
+
+    calculate_omaha_equity_with_config(hero_cards, villain_cards, board_cards, &config)
+}
+
+/// Calcula la equity de Omaha con configuración personalizada
+pub fn calculate_omaha_equity_with_config(
+    hero_cards: &[&str],
+    villain_cards: &[&str],
+    board_cards: &[&str],
+    config: &MonteCarloConfig,
+) -> EquityResult {
+    let hero = parse_cards(hero_cards);
+    let villain = parse_cards(villain_cards);
+    let board = parse_cards(board_cards);
+
+    if !is_omaha_hand(&hero) || !is_omaha_hand(&villain) || board.len() > 5 {
+        return EquityResult::invalid();
+    }
+
+    // Board completo: determinístico
+    if board.len() == 5 {
+        return match compare_omaha(&hero, &villain, &board, &[]) {
+            std::cmp::Ordering::Greater => EquityResult::deterministic(true, false),
+            std::cmp::Ordering::Less => EquityResult::deterministic(false, false),
+            std::cmp::Ordering::Equal => EquityResult::deterministic(false, true),
+        };
+    }
+
+    let remaining_cards = remaining_deck(&[&hero, &villain, &board]);
+    let cards_needed = 5 - board.len();
+
+    // Pocos runouts restantes: enumeración exacta
+    let runouts = binomial(remaining_cards.len() as u64, cards_needed as u64);
+    if config.exact_enumeration_threshold > 0 && runouts <= config.exact_enumeration_threshold {
+        return enumerate_with(&remaining_cards, cards_needed, |_, runout| {
+            compare_omaha(&hero, &villain, &board, runout)
+        });
+    }
+
+    run_simulations(&remaining_cards, config, |rng, _, deck| {
+        // Fisher-Yates parcial para las cartas que necesitamos
+        for i in 0..cards_needed {
+            let j = rng.gen_range(i..deck.len());
+            deck.swap(i, j);
+        }
+
+        Some(compare_omaha(
+            &hero,
+            &villain,
+            &board,
+            &deck[..cards_needed],
+        ))
+    })
+}
+
+/// Calcula equity de Omaha en un escenario multiway (2+ jugadores)
+///
+/// Los empates se reparten solo entre los jugadores empatados. Con pocos
+/// runouts restantes (flop o turn) el resultado es exacto.
+///
+/// # Arguments
+/// * `hands` - Vector de manos (4 o 5 cartas cada una)
+/// * `board` - Cartas comunitarias
+/// * `num_simulations` - Número de simulaciones
+///
+/// # Returns
+/// * Vector de equities para cada jugador
+pub fn calculate_omaha_equity_multiway(
+    hands: &[&[&str]],
+    board: &[&str],
+    num_simulations: u32,
+) -> Vec<f64> {
+    let parsed_hands: Vec<Vec<Card>> = hands.iter().map(|h| parse_cards(h)).collect();
+    let board_cards = parse_cards(board);
+
+    if parsed_hands.iter().any(|h| !is_omaha_hand(h)) || board_cards.len() > 5 {
+        return vec![0.0; hands.len()];
+    }
+
+    let known: Vec<&[Card]> = parsed_hands
+        .iter()
+        .map(Vec::as_slice)
+        .chain(std::iter::once(board_cards.as_slice()))
+        .collect();
+    let remaining_cards = remaining_deck(&known);
+    let cards_needed = 5 - board_cards.len();
+    let num_players = parsed_hands.len();
+
+    let (shares, total) = if cards_needed == 0 {
+        let mut shares = vec![0.0; num_players];
+        add_showdown_shares(&parsed_hands, &board_cards, &[], &mut shares);
+        (shares, 1.0)
+    } else if binomial(remaining_cards.len() as u64, cards_needed as u64)
+        <= MonteCarloConfig::default().exact_enumeration_threshold
+    {
+        enumerate_multiway(&parsed_hands, &board_cards, &remaining_cards, cards_needed)
+    } else {
+        sample_multiway(
+            &parsed_hands,
+            &board_cards,
+            &remaining_cards,
+            cards_needed,
+            num_simulations,
+        )
+    };
+
+    if total == 0.0 {
+        return vec![0.0; num_players];
+    }
+
+    shares.into_iter().map(|s| s / total).collect()
+}
+
+/// Enumera todos los runouts multiway; devuelve (botes ganados, runouts)
+fn enumerate_multiway(
+    hands: &[Vec<Card>],
+    board: &[Card],
+    deck: &[Card],
+    cards_needed: usize,
+) -> (Vec<f64>, f64) {
+    (0..deck.len())
+        .into_par_iter()
+        .map(|first| {
+            let mut runout = [deck[first]; 5];
+            let mut shares = vec![0.0; hands.len()];
+            let mut count = 0.0;
+
+            for_each_combination(deck, first + 1, 1, cards_needed, &mut runout, &mut |r| {
+                add_showdown_shares(hands, board, r, &mut shares);
+                count += 1.0;
+            });
+
+            (shares, count)
+        })
+        .reduce(|| (vec![0.0; hands.len()], 0.0), merge_shares)
+}
+
+/// Muestrea runouts multiway en paralelo; devuelve (botes ganados, muestras)
+fn sample_multiway(
+    hands: &[Vec<Card>],
+    board: &[Card],
+    deck: &[Card],
+    cards_needed: usize,
+    num_simulations: u32,
+) -> (Vec<f64>, f64) {
+    let num_threads = rayon::current_num_threads();
+    let sims_per_thread = (num_simulations / num_threads as u32).max(1);
+
+    (0..num_threads)
+        .into_par_iter()
+        .map(|thread_id| {
+            let mut rng = ChaCha8Rng::seed_from_u64(thread_id as u64 * 54321 + 98765);
+            let mut deck = deck.to_vec();
+            let mut shares = vec![0.0; hands.len()];
+
+            for _ in 0..sims_per_thread {
+                // Fisher-Yates parcial
+                for i in 0..cards_needed {
+                    let j = rng.gen_range(i..deck.len());
+                    deck.swap(i, j);
+                }
+                add_showdown_shares(hands, board, &deck[..cards_needed], &mut shares);
+            }
+
+            (shares, sims_per_thread as f64)
+        })
+        .reduce(|| (vec![0.0; hands.len()], 0.0), merge_shares)
+}
+
+/// Suma un bote repartido entre los ganadores del showdown
+fn add_showdown_shares(hands: &[Vec<Card>], board: &[Card], runout: &[Card], shares: &mut [f64]) {
+    let full_board = complete_board(board, runout);
+    let ranks: Vec<_> = hands
+        .iter()
+        .map(|hand| evaluate_omaha_unchecked(hand, &full_board))
+        .collect();
+
+    let Some(best) = ranks.iter().max() else {
+        return;
+    };
+    let winners = ranks.iter().filter(|rank| *rank == best).count() as f64;
+
+    for (share, rank) in shares.iter_mut().zip(&ranks) {
+        if rank == best {
+            *share += 1.0 / winners;
+        }
+    }
+}
+
+fn merge_shares(mut a: (Vec<f64>, f64), b: (Vec<f64>, f64)) -> (Vec<f64>, f64) {
+    for (x, y) in a.0.iter_mut().zip(&b.0) {
+        *x += y;
+    }
+    (a.0, a.1 + b.1)
+}
+
+/// Compara héroe y villano de Omaha sobre el board completado con `runout`
+#[inline]
+fn compare_omaha(
+    hero: &[Card],
+    villain: &[Card],
+    board: &[Card],
+    runout: &[Card],
+) -> std::cmp::Ordering {
+    let full_board = complete_board(board, runout);
+    evaluate_omaha_unchecked(hero, &full_board).cmp(&evaluate_omaha_unchecked(villain, &full_board))
+}
+
+/// Board de 5 cartas a partir del board conocido y el runout
+#[inline]
+fn complete_board(board: &[Card], runout: &[Card]) -> [Card; 5] {
+    let mut full_board = [Card::from_index(0).unwrap(); 5];
+    for (slot, &card) in full_board.iter_mut().zip(board.iter().chain(runout)) {
+        *slot = card;
+    }
+    full_board
+}
+
+fn is_omaha_hand(cards: &[Card]) -> bool {
+    (OMAHA_MIN_HOLE_CARDS..=OMAHA_MAX_HOLE_CARDS).contains(&cards.len())
+}
+
+fn parse_cards(cards: &[&str]) -> Vec<Card> {
+    cards.iter().filter_map(|s| s.parse().ok()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_omaha_river_uses_two_hole_cards() {
+        // En Hold'em el As daría color al héroe; en Omaha solo tiene as alto
+        // y la pareja de sietes del villano gana.
+        let result = calculate_omaha_equity(
+            &["As", "Qd", "3h", "4c"],
+            &["7c", "7d", "2h", "3d"],
+            &["2s", "5s", "8s", "9s", "Kd"],
+            1000,
+        );
+
+        assert!(result.is_exact);
+        assert_eq!(result.hero_equity, 0.0);
+        assert_eq!(result.villain_equity, 1.0);
+    }
+
+    #[test]
+    fn test_omaha_turn_is_exact() {
+        let result = calculate_omaha_equity(
+            &["As", "Ah", "Kd", "Qc"],
+            &["Jh", "Th", "9c", "8c"],
+            &["Ad", "7h", "2h", "3s"],
+            1000,
+        );
+
+        assert!(result.is_exact);
+        assert_eq!(result.simulations_run, 40);
+        assert!((result.hero_equity + result.villain_equity - 1.0).abs() < 1e-9);
+        // Trío de ases contra proyecto de color y escalera: el héroe es favorito
+        assert!(result.hero_equity > 0.5);
+    }
+
+    #[test]
+    fn test_omaha_preflop_symmetric() {
+        // Manos equivalentes con palos intercambiados: ~50%
+        let result = calculate_omaha_equity(
+            &["As", "Ah", "Kd", "Kc"],
+            &["Ad", "Ac", "Ks", "Kh"],
+            &[],
+            20_000,
+        );
+
+        assert!(!result.is_exact);
+        assert!(
+            (result.hero_equity - 0.5).abs() < 0.05,
+            "Equity simétrica esperada ~50%, got {:.2}%",
+            result.hero_equity * 100.0
+        );
+    }
+
+    #[test]
+    fn test_omaha_multiway() {
+        let hands: [&[&str]; 3] = [
+            &["As", "Ah", "Kd", "Qc"],
+            &["Jh", "Th", "9c", "8c", "2d"],
+            &["Ks", "Kc", "Qs", "Js"],
+        ];
+
+        // Flop: enumeración exacta, las equities suman 1
+        let equities = calculate_omaha_equity_multiway(&hands, &["Ad", "7h", "6h"], 5000);
+        assert_eq!(equities.len(), 3);
+        assert!((equities.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(equities[0] > equities[2]);
+
+        // River: trío de ases gana todo el bote
+        let equities = calculate_omaha_equity_multiway(&hands, &["Ad", "7h", "6d", "3c", "2s"], 10);
+        assert_eq!(equities, vec![1.0, 0.0, 0.0]);
+
+        // Mano de Hold'em: input inválido
+        let invalid: [&[&str]; 2] = [&["As", "Ah"], &["Ks", "Kh", "Qs", "Qh"]];
+        assert_eq!(
+            calculate_omaha_equity_multiway(&invalid, &[], 10),
+            vec![0.0, 0.0]
+        );
+    }
+}
@@ -0,0 +1,420 @@
+//! # Equity contra rangos
+//!
+//! Cálculo de equity mano-vs-rango y rango-vs-rango sobre el mismo bucle
+//! Monte Carlo paralelo (`MonteCarloConfig`, Rayon y early stopping).
+//!
+//! Cada combo del rango tiene un peso (su frecuencia en el rango). En cada
+//! simulación se muestrea un combo proporcional a su peso, descartando los
+//! combos que comparten cartas con el board, con la mano del héroe o con el
+//! combo del otro rango (card removal). El runout se reparte después del
+//! deck restante, de modo que la distribución conjunta es exacta.
+//!
+//! Con el board completo (river) el resultado se calcula por enumeración
+//! ponderada de todos los combos, sin muestreo.
+
+use crate::hand_evaluator::{Card, HandRank, CARDS};
+use rand::Rng;
+use std::cmp::Ordering;
+
+use super::monte_carlo::{compare_runout, run_simulations, EquityResult, MonteCarloConfig};
+use super::simd::SimdEvaluator;
+
+/// Intentos máximos de muestreo por simulación antes de descartarla
+/// cuando los combos de ambos rangos colisionan.
+const MAX_SAMPLE_ATTEMPTS: u32 = 64;
+
+/// Combo concreto de un rango con su peso (frecuencia).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeightedCombo {
+    /// Cartas del combo.
+    pub cards: [Card; 2],
+    /// Peso del combo en el rango (0.0 - 1.0).
+    pub weight: f64,
+}
+
+/// Máscara de bits de cartas (bit = índice 0-51 de la carta).
+#[inline]
+fn card_mask(cards: &[Card]) -> u64 {
+    cards
+        .iter()
+        .fold(0u64, |mask, card| mask | (1u64 << card.index()))
+}
+
+/// Rango preparado para muestreo ponderado (pesos acumulados).
+struct WeightedSampler {
+    combos: Vec<([Card; 2], u64)>,
+    weights: Vec<f64>,
+    cumulative: Vec<f64>,
+    total_weight: f64,
+}
+
+impl WeightedSampler {
+    /// Filtra combos con peso positivo que no usan cartas de `dead_mask`.
+    ///
+    /// Devuelve `None` si no queda ningún combo vivo.
+    fn new(range: &[WeightedCombo], dead_mask: u64) -> Option<Self> {
+        let mut combos = Vec::with_capacity(range.len());
+        let mut weights = Vec::with_capacity(range.len());
+        let mut cumulative = Vec::with_capacity(range.len());
+        let mut total_weight = 0.0;
+
+        for combo in range {
+            let mask = card_mask(&combo.cards);
+            let valid = combo.weight.is_finite()
+                && combo.weight > 0.0
+                && combo.cards[0] != combo.cards[1]
+                && mask & dead_mask == 0;
+
+            if valid {
+                total_weight += combo.weight;
+                combos.push((combo.cards, mask));
+                weights.push(combo.weight);
+                cumulative.push(total_weight);
+            }
+        }
+
+        if combos.is_empty() {
+            None
+        } else {
+            Some(Self {
+                combos,
+                weights,
+                cumulative,
+                total_weight,
+            })
+        }
+    }
+
+    /// Muestrea un combo proporcional a su peso.
+    #[inline]
+    fn sample<R: Rng>(&self, rng: &mut R) -> ([Card; 2], u64) {
+        let target = rng.gen::<f64>() * self.total_weight;
+        let idx = self
+            .cumulative
+            .partition_point(|&c| c <= target)
+            .min(self.combos.len() - 1);
+        self.combos[idx]
+    }
+}
+
+/// Valida el board: máximo 5 cartas y sin duplicados.
+fn board_mask(board: &[Card]) -> Option<u64> {
+    let mask = card_mask(board);
+    (board.len() <= 5 && mask.count_ones() as usize == board.len()).then_some(mask)
+}
+
+/// Fisher-Yates parcial que salta las cartas bloqueadas por `blocked`.
+///
+/// Deja el runout en las primeras `cards_needed` posiciones del deck.
+#[inline]
+fn deal_runout<R: Rng>(rng: &mut R, deck: &mut [Card], cards_needed: usize, blocked: u64) {
+    for i in 0..cards_needed {
+        loop {
+            let j = rng.gen_range(i..deck.len());
+            if blocked & (1u64 << deck[j].index()) == 0 {
+                deck.swap(i, j);
+                break;
+            }
+        }
+    }
+}
+
+/// Calcula la equity de una mano concreta contra un rango ponderado.
+///
+/// # Arguments
+/// * `hero` - Cartas del héroe
+/// * `villain_range` - Combos del villano con su peso
+/// * `board` - Cartas comunitarias (0-5 cartas)
+/// * `config` - Configuración Monte Carlo
+///
+/// # Returns
+/// * `EquityResult` ponderado por combos. Si el input es inválido o el rango
+///   queda vacío tras card removal, `simulations_run = 0`.
+///
+/// # Example
+/// ```rust
+/// use poker_math::{calculate_equity_vs_range, Card, MonteCarloConfig, WeightedCombo};
+///
+/// let c = |s: &str| s.parse::<Card>().unwrap();
+/// let villain = [
+///     WeightedCombo { cards: [c("Ks"), c("Kh")], weight: 1.0 },
+///     WeightedCombo { cards: [c("Qs"), c("Qh")], weight: 1.0 },
+/// ];
+/// let config = MonteCarloConfig { num_simulations: 20_000, ..Default::default() };
+///
+/// let result = calculate_equity_vs_range(&[c("Ac"), c("Ad")], &villain, &[], &config);
+/// assert!(result.hero_equity > 0.75);
+/// ```
+pub fn calculate_equity_vs_range(
+    hero: &[Card; 2],
+    villain_range: &[WeightedCombo],
+    board: &[Card],
+    config: &MonteCarloConfig,
+) -> EquityResult {
+    let Some(board_mask) = board_mask(board) else {
+        return EquityResult::invalid();
+    };
+
+    let hero_mask = card_mask(hero);
+    if hero[0] == hero[1] || hero_mask & board_mask != 0 {
+        return EquityResult::invalid();
+    }
+
+    let Some(villain) = WeightedSampler::new(villain_range, board_mask | hero_mask) else {
+        return EquityResult::invalid();
+    };
+
+    // Board completo: enumeración ponderada exacta
+    if board.len() == 5 {
+        let hero_sampler = WeightedSampler {
+            combos: vec![(*hero, hero_mask)],
+            weights: vec![1.0],
+            cumulative: vec![1.0],
+            total_weight: 1.0,
+        };
+        return enumerate_river(&hero_sampler, &villain, board);
+    }
+
+    let deck: Vec<Card> = CARDS
+        .iter()
+        .copied()
+        .filter(|c| (board_mask | hero_mask) & (1u64 << c.index()) == 0)
+        .collect();
+    let cards_needed = 5 - board.len();
+
+    run_simulations(&deck, config, |rng, evaluator, deck| {
+        let (villain_cards, villain_mask) = villain.sample(rng);
+        deal_runout(rng, deck, cards_needed, villain_mask);
+
+        Some(compare_runout(
+            evaluator,
+            hero,
+            &villain_cards,
+            board,
+            &deck[..cards_needed],
+        ))
+    })
+}
+
+/// Calcula la equity de un rango contra otro rango, ambos ponderados.
+///
+/// Cada simulación muestrea un combo de cada rango (rechazando pares que
+/// comparten cartas) y un runout del deck restante.
+///
+/// # Returns
+/// * `EquityResult` desde la perspectiva del rango del héroe. Si el input es
+///   inválido o no existe ningún par de combos compatible, `simulations_run = 0`.
+pub fn calculate_range_vs_range(
+    hero_range: &[WeightedCombo],
+    villain_range: &[WeightedCombo],
+    board: &[Card],
+    config: &MonteCarloConfig,
+) -> EquityResult {
+    let Some(board_mask) = board_mask(board) else {
+        return EquityResult::invalid();
+    };
+
+    let (Some(hero), Some(villain)) = (
+        WeightedSampler::new(hero_range, board_mask),
+        WeightedSampler::new(villain_range, board_mask),
+    ) else {
+        return EquityResult::invalid();
+    };
+
+    // Sin ningún par compatible el muestreo por rechazo nunca terminaría
+    let has_compatible_pair = hero
+        .combos
+        .iter()
+        .any(|(_, h)| villain.combos.iter().any(|(_, v)| h & v == 0));
+    if !has_compatible_pair {
+        return EquityResult::invalid();
+    }
+
+    if board.len() == 5 {
+        return enumerate_river(&hero, &villain, board);
+    }
+
+    let deck: Vec<Card> = CARDS
+        .iter()
+        .copied()
+        .filter(|c| board_mask & (1u64 << c.index()) == 0)
+        .collect();
+    let cards_needed = 5 - board.len();
+
+    run_simulations(&deck, config, |rng, evaluator, deck| {
+        for _ in 0..MAX_SAMPLE_ATTEMPTS {
+            let (hero_cards, hero_mask) = hero.sample(rng);
+            let (villain_cards, villain_mask) = villain.sample(rng);
+            if hero_mask & villain_mask != 0 {
+                continue;
+            }
+
+            deal_runout(rng, deck, cards_needed, hero_mask | villain_mask);
+
+            return Some(compare_runout(
+                evaluator,
+                &hero_cards,
+                &villain_cards,
+                board,
+                &deck[..cards_needed],
+            ));
+        }
+        None
+    })
+}
+
+/// Enumeración ponderada exacta con el board completo.
+///
+/// Cada combo se evalúa una sola vez; después se comparan todos los pares
+/// compatibles ponderando por `peso_hero * peso_villano`.
+fn enumerate_river(
+    hero: &WeightedSampler,
+    villain: &WeightedSampler,
+    board: &[Card],
+) -> EquityResult {
+    let evaluator = SimdEvaluator::new();
+
+    let rank_combo = |cards: &[Card; 2]| -> HandRank {
+        let mut hand = [cards[0]; 7];
+        hand[1] = cards[1];
+        hand[2..].copy_from_slice(board);
+        evaluator.evaluate_7cards(&hand)
+    };
+
+    let hero_ranks: Vec<HandRank> = hero.combos.iter().map(|(c, _)| rank_combo(c)).collect();
+    let villain_ranks: Vec<HandRank> = villain.combos.iter().map(|(c, _)| rank_combo(c)).collect();
+
+    let mut hero_weight = 0.0;
+    let mut villain_weight = 0.0;
+    let mut tie_weight = 0.0;
+    let mut pairs = 0u32;
+
+    for (h, (_, hero_mask)) in hero.combos.iter().enumerate() {
+        for (v, (_, villain_mask)) in villain.combos.iter().enumerate() {
+            if hero_mask & villain_mask != 0 {
+                continue;
+            }
+
+            let weight = hero.weights[h] * villain.weights[v];
+            match hero_ranks[h].cmp(&villain_ranks[v]) {
+                Ordering::Greater => hero_weight += weight,
+                Ordering::Less => villain_weight += weight,
+                Ordering::Equal => tie_weight += weight,
+            }
+            pairs += 1;
+        }
+    }
+
+    let total = hero_weight + villain_weight + tie_weight;
+    if total == 0.0 {
+        return EquityResult::invalid();
+    }
+
+    EquityResult {
+        hero_equity: (hero_weight + tie_weight * 0.5) / total,
+        villain_equity: (villain_weight + tie_weight * 0.5) / total,
+        tie_equity: tie_weight / total,
+        simulations_run: pairs,
+        converged_early: false,
+        standard_error: 0.0,
+        is_exact: true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::equity_calculator::calculate_equity;
+
+    fn card(s: &str) -> Card {
+        s.parse().unwrap()
+    }
+
+    fn combo(a: &str, b: &str, weight: f64) -> WeightedCombo {
+        WeightedCombo {
+            cards: [card(a), card(b)],
+            weight,
+        }
+    }
+
+    fn config(num_simulations: u32) -> MonteCarloConfig {
+        MonteCarloConfig {
+            num_simulations,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_single_combo_range_matches_hand_vs_hand() {
+        let range = [combo("Ks", "Kh", 1.0)];
+        let vs_range =
+            calculate_equity_vs_range(&[card("As"), card("Ah")], &range, &[], &config(50_000));
+        let vs_hand = calculate_equity(&["As", "Ah"], &["Ks", "Kh"], &[], 50_000);
+
+        assert!(
+            (vs_range.hero_equity - vs_hand.hero_equity).abs() < 0.02,
+            "vs rango {:.3} vs mano {:.3}",
+            vs_range.hero_equity,
+            vs_hand.hero_equity
+        );
+    }
+
+    #[test]
+    fn test_weights_shift_equity() {
+        // AA vs {KK, AKs}: contra KK ~82%, contra AKs ~88%
+        let hero = [card("As"), card("Ah")];
+        let mostly_kk = [combo("Kd", "Kc", 1.0), combo("Ad", "Kd", 0.01)];
+        let mostly_ak = [combo("Kd", "Kc", 0.01), combo("Ad", "Kd", 1.0)];
+
+        let vs_kk = calculate_equity_vs_range(&hero, &mostly_kk, &[], &config(50_000));
+        let vs_ak = calculate_equity_vs_range(&hero, &mostly_ak, &[], &config(50_000));
+
+        assert!(vs_kk.hero_equity > 0.78 && vs_kk.hero_equity < 0.86);
+        assert!(vs_ak.hero_equity > vs_kk.hero_equity);
+    }
+
+    #[test]
+    fn test_card_removal_against_hero_and_board() {
+        // Todos los combos del villano chocan con el héroe o el board
+        let hero = [card("As"), card("Ah")];
+        let range = [combo("As", "Kd", 1.0), combo("Qc", "Qd", 1.0)];
+        let board = [card("Qc"), card("7d"), card("2h")];
+
+        let result = calculate_equity_vs_range(&hero, &range, &board, &config(1_000));
+        assert_eq!(result.simulations_run, 0);
+    }
+
+    #[test]
+    fn test_river_enumeration_is_exact() {
+        // Board: Ks 9d 5c 3h 2s. AA gana a KQ, pierde contra 55 (set)
+        let board = ["Ks", "9d", "5c", "3h", "2s"].map(card);
+        let range = [combo("Kd", "Qd", 1.0), combo("5d", "5h", 1.0)];
+
+        let result =
+            calculate_equity_vs_range(&[card("Ac"), card("Ad")], &range, &board, &config(1));
+        assert_eq!(result.simulations_run, 2);
+        assert_eq!(result.standard_error, 0.0);
+        assert!(result.is_exact);
+        assert!((result.hero_equity - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_range_vs_range() {
+        let premium = [combo("As", "Ah", 1.0), combo("Ks", "Kh", 1.0)];
+        let weak = [combo("7c", "2d", 1.0), combo("8c", "3d", 1.0)];
+
+        let result = calculate_range_vs_range(&premium, &weak, &[], &config(30_000));
+        assert!(result.simulations_run > 0);
+        assert!(result.hero_equity > 0.80);
+        assert!((result.hero_equity + result.villain_equity - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_range_vs_range_without_compatible_pairs() {
+        let hero = [combo("As", "Ah", 1.0)];
+        let villain = [combo("As", "Kd", 1.0), combo("Ah", "Qd", 1.0)];
+
+        let result = calculate_range_vs_range(&hero, &villain, &[], &config(1_000));
+        assert_eq!(result.simulations_run, 0);
+    }
+}
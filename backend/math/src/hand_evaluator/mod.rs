@@ -5,6 +5,7 @@
 //! ## Características
 //! - Evaluación O(1) para 5 cartas usando lookup tables
 //! - Soporte para 5, 6 y 7 cartas
+//! - Omaha (PLO4/PLO5): exactamente 2 cartas propias y 3 del board
 //! - Rankings compatibles con el estándar de poker (1-7462)
 //!
 //! ## Uso
@@ -37,6 +38,7 @@ mod evaluator;
 mod hand_rank;
 mod lookup;
 pub mod lookup_7cards;
+mod omaha;
 
 // Re-exports públicos
 pub use cards::{Card, Deck, Rank, Suit, CARDS, PRIMES};
@@ -46,6 +48,8 @@ pub use lookup_7cards::{
     cards_to_index, evaluate_7cards_lookup, generate_lookup_table, index_to_cards,
     is_lookup_table_loaded, TOTAL_7CARD_COMBOS,
 };
+pub(crate) use omaha::evaluate_omaha_unchecked;
+pub use omaha::{evaluate_omaha, OMAHA_MAX_HOLE_CARDS, OMAHA_MIN_HOLE_CARDS};
 
 /// Evalúa una mano desde strings de cartas
 ///
@@ -0,0 +1,118 @@
+//! # Evaluador de Omaha
+//!
+//! En Omaha la mano final usa exactamente dos cartas propias y tres del
+//! board. A diferencia de Hold'em no basta con evaluar las 7+ cartas juntas:
+//! con un board de cuatro cartas de picas y una sola pica en la mano no hay
+//! color.
+//!
+//! Se evalúan todas las combinaciones C(propias, 2) × C(board, 3):
+//! - PLO4 con river: 6 × 10 = 60 manos de 5 cartas
+//! - PLO5 con river: 10 × 10 = 100 manos de 5 cartas
+
+use super::cards::Card;
+use super::evaluator::evaluate_5cards;
+use super::hand_rank::HandRank;
+
+/// Número mínimo de cartas propias en Omaha (PLO4)
+pub const OMAHA_MIN_HOLE_CARDS: usize = 4;
+
+/// Número máximo de cartas propias en Omaha (PLO5)
+pub const OMAHA_MAX_HOLE_CARDS: usize = 5;
+
+/// Evalúa la mejor mano de Omaha (2 cartas propias + 3 del board)
+///
+/// # Arguments
+/// * `hole` - Cartas propias (4 en PLO4, 5 en PLO5)
+/// * `board` - Cartas comunitarias (3-5 cartas)
+///
+/// # Returns
+/// * `None` si el número de cartas no es válido para Omaha
+///
+/// # Example
+/// ```rust
+/// use poker_math::hand_evaluator::{evaluate_omaha, Card};
+///
+/// let cards = |s: &str| -> Vec<Card> {
+///     s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+/// };
+///
+/// // Una sola pica en la mano: no hay color aunque el board tenga cuatro
+/// let rank = evaluate_omaha(&cards("As Kd Qh Jc"), &cards("2s 5s 8s 9s Td")).unwrap();
+/// assert!(!rank.is_flush());
+/// ```
+pub fn evaluate_omaha(hole: &[Card], board: &[Card]) -> Option<HandRank> {
+    if !(OMAHA_MIN_HOLE_CARDS..=OMAHA_MAX_HOLE_CARDS).contains(&hole.len())
+        || !(3..=5).contains(&board.len())
+    {
+        return None;
+    }
+
+    Some(evaluate_omaha_unchecked(hole, board))
+}
+
+/// Igual que `evaluate_omaha` sin validar el número de cartas.
+///
+/// Requiere al menos 2 cartas propias y 3 del board; pensado para los bucles
+/// de equity, donde el input ya está validado.
+#[inline]
+pub(crate) fn evaluate_omaha_unchecked(hole: &[Card], board: &[Card]) -> HandRank {
+    let mut best = HandRank::new(HandRank::WORST);
+
+    for i in 0..hole.len() {
+        for j in i + 1..hole.len() {
+            for a in 0..board.len() {
+                for b in a + 1..board.len() {
+                    for c in b + 1..board.len() {
+                        let rank =
+                            evaluate_5cards(&[hole[i], hole[j], board[a], board[b], board[c]]);
+                        if rank > best {
+                            best = rank;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(s: &str) -> Vec<Card> {
+        s.split_whitespace().map(|c| c.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_requires_two_hole_cards_for_flush() {
+        // Cuatro picas en el board y una en la mano: sin color
+        let one_spade = evaluate_omaha(&cards("As Kd Qh Jc"), &cards("2s 5s 8s 9s Td")).unwrap();
+        assert!(!one_spade.is_flush());
+
+        // Dos picas en la mano: color al as
+        let two_spades = evaluate_omaha(&cards("As Ks Qh Jc"), &cards("2s 5s 8s 9d Td")).unwrap();
+        assert!(two_spades.is_flush());
+    }
+
+    #[test]
+    fn test_requires_three_board_cards() {
+        // Cuatro ases en la mano no son póker: solo se usan dos
+        let rank = evaluate_omaha(&cards("As Ah Ad Ac"), &cards("2s 7h 9d Jc 4s")).unwrap();
+        assert!(rank.is_one_pair());
+
+        // Trío en el board + pareja en la mano: full house
+        let rank = evaluate_omaha(&cards("Kh Kd 2c 3c"), &cards("9s 9h 9d Jc 4s")).unwrap();
+        assert!(rank.is_full_house());
+    }
+
+    #[test]
+    fn test_plo5_and_invalid_input() {
+        let rank = evaluate_omaha(&cards("As Ks Qh Jc 2d"), &cards("Ts 9s 8s")).unwrap();
+        assert!(rank.is_flush());
+
+        assert!(evaluate_omaha(&cards("As Ks"), &cards("Ts 9s 8s")).is_none());
+        assert!(evaluate_omaha(&cards("As Ks Qh Jc"), &cards("Ts 9s")).is_none());
+    }
+}
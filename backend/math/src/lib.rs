@@ -17,7 +17,7 @@
 //!
 //! ## Módulos
 //!
-//! - `hand_evaluator`: Evaluador de manos de 5-7 cartas
+//! - `hand_evaluator`: Evaluador de manos de 5-7 cartas y de Omaha
 //! - `equity_calculator`: Simulador Monte Carlo para cálculo de equity
 
 pub mod equity_calculator;
@@ -26,12 +26,14 @@ pub mod hand_evaluator;
 // Re-exports para conveniencia - Hand Evaluator
 pub use hand_evaluator::{
     cards_to_index, evaluate, evaluate_5cards, evaluate_6cards, evaluate_7cards,
-    evaluate_7cards_lookup, evaluate_from_strings, generate_lookup_table, index_to_cards,
-    is_lookup_table_loaded, Card, Deck, HandCategory, HandRank, Rank, Suit, TOTAL_7CARD_COMBOS,
+    evaluate_7cards_lookup, evaluate_from_strings, evaluate_omaha, generate_lookup_table,
+    index_to_cards, is_lookup_table_loaded, Card, Deck, HandCategory, HandRank, Rank, Suit,
+    TOTAL_7CARD_COMBOS,
 };
 
 // Re-exports para conveniencia - Equity Calculator
 pub use equity_calculator::{
-    calculate_equity, calculate_equity_multiway, is_avx2_available, EquityResult, MonteCarloConfig,
-    SimdEvaluator,
+    calculate_equity, calculate_equity_multiway, calculate_equity_vs_range, calculate_omaha_equity,
+    calculate_omaha_equity_multiway, calculate_range_vs_range, is_avx2_available, EquityResult,
+    MonteCarloConfig, SimdEvaluator, WeightedCombo,
 };
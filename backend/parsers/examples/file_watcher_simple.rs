@@ -40,7 +40,7 @@ fn main() {
                 let result = parser.parse(&content);
 
                 println!("Manos parseadas: {}", result.hands.len());
-                println!("Errores: {}", result.errors.len());
+                println!("Errores: {}", result.error_count);
 
                 // Mostrar información de la primera mano si existe
                 if let Some(hand) = result.hands.first() {
@@ -52,13 +52,13 @@ fn main() {
     println!("Manos parseadas: {}", result.hands.len());
     println!("Errores encontrados: {}", result.error_count);
 
-    if !result.errors.is_empty() {
-        println!("\nErrores:");
-        for (i, error) in result.errors.iter().take(10).enumerate() {
-            println!("  {}. {}", i + 1, error);
+    if !result.diagnostics.is_empty() {
+        println!("\nDiagnósticos:");
+        for (i, diagnostic) in result.diagnostics.iter().take(10).enumerate() {
+            println!("  {}. {}", i + 1, diagnostic);
         }
-        if result.errors.len() > 10 {
-            println!("  ... y {} errores más", result.errors.len() - 10);
+        if result.diagnostics.len() > 10 {
+            println!("  ... y {} diagnósticos más", result.diagnostics.len() - 10);
         }
     }
 
@@ -82,7 +82,7 @@ fn main() {
             println!("  Rake: {:.2}€", hand.pot.rake_cents as f64 / 100.0);
 
             // Buscar si thesmoy participó
-            let hero_participated = hand.players.iter().any(|p| p.name == "thesmoy");
+            let hero_participated = hand.players.iter().any(|p| &*p.name == "thesmoy");
 
             if hero_participated {
                 if let Some(hero_cards) = &hand.hero_cards {
@@ -103,7 +103,7 @@ fn main() {
         let hands_with_hero: usize = result
             .hands
             .iter()
-            .filter(|h| h.players.iter().any(|p| p.name == "thesmoy"))
+            .filter(|h| h.players.iter().any(|p| &*p.name == "thesmoy"))
             .count();
 
         let total_pot: i64 = result.hands.iter().map(|h| h.pot.total_cents).sum();
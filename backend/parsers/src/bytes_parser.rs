@@ -11,9 +11,15 @@
 //! - **Aritmética de enteros**: Parser de centavos sin floats
 //! - **Zero allocations**: Todas las funciones operan sobre slices
 
+/// Marca de orden de bytes UTF-8 que algunas salas (Winamax) escriben al
+/// inicio de los historiales.
+pub const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
 /// Tokens comunes en historiales de Winamax (como bytes).
 pub mod tokens {
     pub const WINAMAX_POKER: &[u8] = b"Winamax Poker";
+    pub const POKERSTARS: &[u8] = b"PokerStars";
+    pub const GGPOKER_HAND: &[u8] = b"Poker Hand #";
     pub const CASHGAME: &[u8] = b"CashGame";
     pub const TOURNAMENT: &[u8] = b"Tournament";
     pub const TABLE: &[u8] = b"Table: ";
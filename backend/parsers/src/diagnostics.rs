@@ -0,0 +1,273 @@
+//! Diagnósticos del parsing con ubicación y categoría.
+//!
+//! Cada incidencia del parser se registra como un `ParseDiagnostic` con su
+//! severidad, su categoría (`DiagnosticKind`), la línea del archivo, el
+//! estado de la FSM y el `hand_id` de la mano en curso. Así, cuando un cambio
+//! de formato de la sala rompe la importación, se puede localizar la línea y
+//! la sección de la mano que ya no se reconoce.
+//!
+//! La ruta del archivo la añade quien conoce el archivo (`ParallelProcessor`,
+//! `FileTailer`) con `ParseResult::set_path`.
+//!
+//! ## Ejemplo
+//!
+//! ```text
+//! history.txt:1042: warning [unknown line] Unrecognized action: thesmoy lurks (hand 21819158-393-1765807340, Preflop)
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::types::ParserState;
+
+/// Gravedad de un diagnóstico.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Severity {
+    /// Informativo: no afecta a las manos importadas.
+    Info,
+    /// La mano se importó, pero puede tener datos incompletos.
+    Warning,
+    /// La mano o el archivo se importaron con datos incorrectos o no se
+    /// importaron.
+    Error,
+}
+
+impl Severity {
+    /// Nombre en minúsculas para informes.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Categoría de un diagnóstico.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum DiagnosticKind {
+    /// Línea que la FSM no reconoce en el estado actual.
+    UnknownLine,
+    /// Cantidad que no se pudo leer.
+    BadAmount,
+    /// Carta con rango o palo inválido.
+    BadCard,
+    /// El bote no cuadra: lo invertido no es igual a lo cobrado más el rake.
+    InconsistentPot,
+    /// Mano sin jugadores sentados.
+    MissingPlayers,
+    /// Cabecera de resumen de torneo inválida.
+    InvalidSummary,
+    /// Archivo de una sala no soportada.
+    UnknownFormat,
+}
+
+impl DiagnosticKind {
+    /// Severidad por defecto de la categoría.
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::UnknownLine | Self::InconsistentPot => Severity::Warning,
+            Self::BadAmount
+            | Self::BadCard
+            | Self::MissingPlayers
+            | Self::InvalidSummary
+            | Self::UnknownFormat => Severity::Error,
+        }
+    }
+
+    /// Nombre legible para informes.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::UnknownLine => "unknown line",
+            Self::BadAmount => "bad amount",
+            Self::BadCard => "bad card",
+            Self::InconsistentPot => "inconsistent pot",
+            Self::MissingPlayers => "missing players",
+            Self::InvalidSummary => "invalid summary",
+            Self::UnknownFormat => "unknown format",
+        }
+    }
+}
+
+/// Incidencia encontrada al parsear un historial.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseDiagnostic {
+    /// Gravedad.
+    pub severity: Severity,
+    /// Categoría.
+    pub kind: DiagnosticKind,
+    /// Descripción, normalmente con el texto de la línea.
+    pub message: String,
+    /// Archivo de origen, si se conoce.
+    pub path: Option<PathBuf>,
+    /// Número de línea (desde 1), si la incidencia corresponde a una línea.
+    pub line: Option<usize>,
+    /// Estado de la FSM al detectar la incidencia.
+    pub state: ParserState,
+    /// Mano en curso, si la hay.
+    pub hand_id: Option<String>,
+}
+
+impl ParseDiagnostic {
+    /// Crea un diagnóstico con la severidad por defecto de su categoría.
+    pub fn new(kind: DiagnosticKind, message: impl Into<String>) -> Self {
+        Self {
+            severity: kind.severity(),
+            kind,
+            message: message.into(),
+            path: None,
+            line: None,
+            state: ParserState::Initial,
+            hand_id: None,
+        }
+    }
+
+    /// Establece la severidad.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Establece la línea y el estado de la FSM.
+    pub fn at(mut self, line: usize, state: ParserState) -> Self {
+        self.line = Some(line);
+        self.state = state;
+        self
+    }
+
+    /// Establece la mano en curso (se ignora un `hand_id` vacío).
+    pub fn in_hand(mut self, hand_id: &str) -> Self {
+        self.hand_id = (!hand_id.is_empty()).then(|| hand_id.to_string());
+        self
+    }
+
+    /// Indica si es de severidad `Error`.
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for ParseDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.path, self.line) {
+            (Some(path), Some(line)) => write!(f, "{}:{}: ", path.display(), line)?,
+            (Some(path), None) => write!(f, "{}: ", path.display())?,
+            (None, Some(line)) => write!(f, "line {}: ", line)?,
+            (None, None) => {}
+        }
+        write!(
+            f,
+            "{} [{}] {}",
+            self.severity.name(),
+            self.kind.name(),
+            self.message
+        )?;
+        match &self.hand_id {
+            Some(hand_id) => write!(f, " (hand {}, {:?})", hand_id, self.state),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Resumen agregado de diagnósticos (por severidad y por categoría).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiagnosticSummary {
+    /// Diagnósticos `Info`.
+    pub infos: usize,
+    /// Diagnósticos `Warning`.
+    pub warnings: usize,
+    /// Diagnósticos `Error`.
+    pub errors: usize,
+    /// Número de diagnósticos por categoría.
+    pub by_kind: BTreeMap<DiagnosticKind, usize>,
+    /// Archivos con al menos un diagnóstico `Error`.
+    pub files_with_errors: usize,
+}
+
+impl DiagnosticSummary {
+    /// Acumula los diagnósticos de un archivo.
+    pub fn add_file<'a>(&mut self, diagnostics: impl IntoIterator<Item = &'a ParseDiagnostic>) {
+        let errors_before = self.errors;
+        for diagnostic in diagnostics {
+            match diagnostic.severity {
+                Severity::Info => self.infos += 1,
+                Severity::Warning => self.warnings += 1,
+                Severity::Error => self.errors += 1,
+            }
+            *self.by_kind.entry(diagnostic.kind).or_insert(0) += 1;
+        }
+        if self.errors > errors_before {
+            self.files_with_errors += 1;
+        }
+    }
+
+    /// Número total de diagnósticos.
+    pub fn total(&self) -> usize {
+        self.infos + self.warnings + self.errors
+    }
+
+    /// Indica si no hay ningún diagnóstico.
+    pub fn is_empty(&self) -> bool {
+        self.total() == 0
+    }
+}
+
+impl fmt::Display for DiagnosticSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} errors ({} files), {} warnings, {} infos",
+            self.errors, self.files_with_errors, self.warnings, self.infos
+        )?;
+        for (kind, count) in &self.by_kind {
+            write!(f, "\n  {}: {}", kind.name(), count)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnostic_display() {
+        let mut diagnostic = ParseDiagnostic::new(DiagnosticKind::BadAmount, "calls abc€")
+            .at(42, ParserState::Flop)
+            .in_hand("1-2-3");
+        assert!(diagnostic.is_error());
+        assert_eq!(
+            diagnostic.to_string(),
+            "line 42: error [bad amount] calls abc€ (hand 1-2-3, Flop)"
+        );
+
+        diagnostic.path = Some(PathBuf::from("history.txt"));
+        diagnostic.hand_id = None;
+        assert_eq!(
+            diagnostic.with_severity(Severity::Info).to_string(),
+            "history.txt:42: info [bad amount] calls abc€"
+        );
+    }
+
+    #[test]
+    fn test_summary_counts_by_severity_and_kind() {
+        let unknown = ParseDiagnostic::new(DiagnosticKind::UnknownLine, "???");
+        let card = ParseDiagnostic::new(DiagnosticKind::BadCard, "Xx");
+
+        let mut summary = DiagnosticSummary::default();
+        summary.add_file([&unknown, &unknown]);
+        summary.add_file([&card]);
+        summary.add_file([]);
+
+        assert_eq!(summary.total(), 3);
+        assert_eq!(summary.warnings, 2);
+        assert_eq!(summary.errors, 1);
+        assert_eq!(summary.files_with_errors, 1);
+        assert_eq!(summary.by_kind[&DiagnosticKind::UnknownLine], 2);
+        assert!(summary
+            .to_string()
+            .starts_with("1 errors (1 files), 2 warnings"));
+    }
+}
@@ -0,0 +1,154 @@
+//! Registro persistente de archivos de historial ya procesados.
+//!
+//! `FileLedger` guarda, por archivo, el `TailState` del tail incremental:
+//! tamaño y fecha de modificación en la última lectura, hash de la cabecera,
+//! offset de la última mano completa y manos importadas. Se persiste como JSON
+//! en un archivo sidecar para que al reiniciar el launcher solo se lean los
+//! archivos nuevos o modificados (ver `FileTailer::reconcile`).
+//!
+//! ## Formato
+//!
+//! ```json
+//! {
+//!   "files": {
+//!     "C:\\...\\history\\20251215_Nice 09(123456)_real_holdem_no-limit.txt": {
+//!       "offset": 48213,
+//!       "head_len": 1024,
+//!       "head_hash": "9e107d9d372bb6826bd81d3542a419d6",
+//!       "hands": 71,
+//!       "lines": 994,
+//!       "size": 48213,
+//!       "mtime_ms": 1765807700000
+//!     }
+//!   }
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::file_tail::{TailState, TailUpdate};
+
+/// Registro de archivos procesados y su estado de lectura.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileLedger {
+    files: BTreeMap<PathBuf, TailState>,
+}
+
+impl FileLedger {
+    /// Crea un registro vacío.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Carga el registro desde disco.
+    ///
+    /// Si el archivo no existe devuelve un registro vacío.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read(path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Guarda el registro en disco.
+    ///
+    /// Escribe a un archivo temporal y lo renombra, para que un cierre a
+    /// mitad de escritura no deje un registro corrupto.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_vec_pretty(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, json)?;
+        fs::rename(&tmp, path)
+    }
+
+    /// Estado de un archivo, si está registrado.
+    pub fn get(&self, path: &Path) -> Option<&TailState> {
+        self.files.get(path)
+    }
+
+    /// Registra (o reemplaza) el estado de un archivo.
+    pub fn insert(&mut self, path: PathBuf, state: TailState) {
+        self.files.insert(path, state);
+    }
+
+    /// Registra el estado resultante de una lectura incremental.
+    pub fn record(&mut self, update: &TailUpdate) {
+        self.insert(update.path.clone(), update.state.clone());
+    }
+
+    /// Elimina un archivo del registro.
+    pub fn remove(&mut self, path: &Path) -> Option<TailState> {
+        self.files.remove(path)
+    }
+
+    /// Número de archivos registrados.
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Indica si el registro está vacío.
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Itera los archivos registrados en orden de ruta.
+    pub fn iter(&self) -> impl Iterator<Item = (&PathBuf, &TailState)> {
+        self.files.iter()
+    }
+
+    /// Manos importadas en total.
+    pub fn total_hands(&self) -> usize {
+        self.files.values().map(|state| state.hands).sum()
+    }
+
+    /// Estado mutable de un archivo, creándolo si no existe.
+    pub(crate) fn entry(&mut self, path: &Path) -> &mut TailState {
+        self.files.entry(path.to_path_buf()).or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let dir = TempDir::new().unwrap();
+        let ledger_path = dir.path().join("watcher_ledger.json");
+
+        // Sin archivo: registro vacío
+        assert!(FileLedger::load(&ledger_path).unwrap().is_empty());
+
+        let mut ledger = FileLedger::new();
+        ledger.insert(
+            PathBuf::from("history/a.txt"),
+            TailState {
+                offset: 120,
+                head_len: 120,
+                head_hash: "abc".to_string(),
+                hands: 2,
+                lines: 26,
+                size: 130,
+                mtime_ms: 1_765_807_700_000,
+            },
+        );
+        ledger.save(&ledger_path).unwrap();
+
+        let loaded = FileLedger::load(&ledger_path).unwrap();
+        assert_eq!(loaded, ledger);
+        assert_eq!(loaded.total_hands(), 2);
+        assert!(!dir.path().join("watcher_ledger.json.tmp").exists());
+
+        fs::write(&ledger_path, b"{not json").unwrap();
+        let err = FileLedger::load(&ledger_path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,410 @@
+//! Cuarentena de archivos que el parser no consigue procesar.
+//!
+//! Un archivo falla cuando el parser entra en pánico al leerlo (el pánico se
+//! aísla con `catch_unwind`, ver [`catch_parser_panic`]) o cuando no es un
+//! historial de ninguna sala soportada. Los errores de lectura no cuentan:
+//! suelen ser bloqueos temporales y ya tienen sus reintentos.
+//!
+//! `FileQuarantine` cuenta los fallos de cada archivo entre intentos y entre
+//! ejecuciones (`failures.json`). Al llegar a `max_failures` mueve el archivo
+//! a `history/quarantine/` junto con un sidecar JSON con el motivo y los
+//! diagnósticos del último intento, para analizarlo a mano. Un intento
+//! correcto reinicia el contador.
+//!
+//! Tras corregir el parser, `ParallelProcessor::reimport_quarantined` vuelve
+//! a parsear los archivos apartados y devuelve a su ruta original los que ya
+//! se leen bien.
+//!
+//! ## Estructura
+//!
+//! ```text
+//! history/
+//! ├── 20251215_Nice 09(123456)_real_holdem_no-limit.txt
+//! └── quarantine/
+//!     ├── failures.json
+//!     ├── 20251216_Nice 10(123457)_real_holdem_no-limit.txt
+//!     └── 20251216_Nice 10(123457)_real_holdem_no-limit.txt.quarantine.json
+//! ```
+//!
+//! ## Uso
+//!
+//! ```rust,no_run
+//! use poker_parsers::file_quarantine::FileQuarantine;
+//! use std::path::Path;
+//!
+//! let dir = FileQuarantine::dir_for(Path::new("history"));
+//! let mut quarantine = FileQuarantine::open(dir).unwrap();
+//! for entry in quarantine.list().unwrap() {
+//!     println!("{}: {}", entry.original_path.display(), entry.reason);
+//!     quarantine.restore(&entry).unwrap();
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+use crate::diagnostics::{DiagnosticKind, ParseDiagnostic};
+use crate::parallel_processor::{FileProcessingError, FileProcessingResult};
+use crate::types::ParseResult;
+
+/// Subdirectorio de cuarentena dentro del directorio de historiales.
+pub const QUARANTINE_DIR: &str = "quarantine";
+
+/// Fallos tras los que un archivo va a cuarentena.
+pub const DEFAULT_MAX_FAILURES: u32 = 3;
+
+/// Contadores de fallos, dentro del directorio de cuarentena.
+const FAILURES_FILE: &str = "failures.json";
+
+/// Sufijo del sidecar de cada archivo apartado.
+const SIDECAR_SUFFIX: &str = ".quarantine.json";
+
+/// Ejecuta el parser aislando un posible pánico.
+///
+/// Si el parser entra en pánico devuelve el mensaje como error, de modo que
+/// un archivo problemático no tumba el hilo que lo procesa.
+pub fn catch_parser_panic<T>(parse: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(parse)).map_err(|payload| panic_message(&*payload))
+}
+
+/// Mensaje de un pánico (`panic!` con `&str` o con `String`).
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+/// Motivo por el que un archivo no se pudo parsear.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileFailure {
+    /// Descripción del fallo.
+    pub reason: String,
+    /// Diagnósticos del intento, si el parser llegó a terminar.
+    pub diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl FileFailure {
+    /// Fallo sin diagnósticos (p.ej. un pánico).
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Fallo de un resultado de parsing: archivo de formato desconocido.
+    pub fn from_parse(result: &ParseResult) -> Option<Self> {
+        let unknown = result.hands.is_empty()
+            && result.summaries.is_empty()
+            && result
+                .diagnostics
+                .iter()
+                .any(|d| d.kind == DiagnosticKind::UnknownFormat);
+
+        unknown.then(|| Self {
+            reason: "Unknown hand history format".to_string(),
+            diagnostics: result.diagnostics.clone(),
+        })
+    }
+
+    /// Fallo de un archivo procesado: pánico del parser o formato desconocido.
+    pub fn from_file(result: &FileProcessingResult) -> Option<Self> {
+        match &result.result {
+            Ok(parsed) => Self::from_parse(parsed),
+            Err(error @ FileProcessingError::Panic(_)) => Some(Self::new(error.to_string())),
+            Err(_) => None,
+        }
+    }
+}
+
+/// Archivo apartado en cuarentena (contenido del sidecar).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuarantineEntry {
+    /// Ruta original del archivo.
+    pub original_path: PathBuf,
+    /// Ruta del archivo dentro de la cuarentena.
+    pub quarantined_path: PathBuf,
+    /// Fallos acumulados al apartarlo.
+    pub failures: u32,
+    /// Motivo del último fallo.
+    pub reason: String,
+    /// Diagnósticos del último intento.
+    pub diagnostics: Vec<ParseDiagnostic>,
+    /// Momento en que se apartó (RFC 3339).
+    pub quarantined_at: String,
+}
+
+/// Fallos acumulados de un archivo que aún no está en cuarentena.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FailureRecord {
+    /// Intentos fallidos seguidos.
+    pub count: u32,
+    /// Motivo del último fallo.
+    pub last_error: String,
+}
+
+/// Contadores de fallos y directorio de cuarentena.
+#[derive(Debug, Clone)]
+pub struct FileQuarantine {
+    dir: PathBuf,
+    max_failures: u32,
+    failures: BTreeMap<PathBuf, FailureRecord>,
+}
+
+impl FileQuarantine {
+    /// Abre una cuarentena cargando sus contadores de fallos.
+    ///
+    /// El directorio se crea con el primer fallo registrado.
+    pub fn open(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        let failures = match fs::read(dir.join(FAILURES_FILE)) {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(invalid_data)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e),
+        };
+
+        Ok(Self {
+            dir,
+            max_failures: DEFAULT_MAX_FAILURES,
+            failures,
+        })
+    }
+
+    /// Directorio de cuarentena de un directorio de historiales.
+    pub fn dir_for(history_dir: &Path) -> PathBuf {
+        history_dir.join(QUARANTINE_DIR)
+    }
+
+    /// Establece los fallos tras los que un archivo va a cuarentena.
+    pub fn with_max_failures(mut self, max_failures: u32) -> Self {
+        self.max_failures = max_failures.max(1);
+        self
+    }
+
+    /// Directorio de cuarentena.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Fallos acumulados de un archivo.
+    pub fn failures(&self, path: &Path) -> u32 {
+        self.failures.get(path).map_or(0, |record| record.count)
+    }
+
+    /// Registra el resultado de procesar un archivo.
+    ///
+    /// Un fallo suma al contador (y puede apartar el archivo); un parsing
+    /// correcto lo reinicia. Los errores de lectura no cambian nada.
+    pub fn record(&mut self, result: &FileProcessingResult) -> io::Result<Option<QuarantineEntry>> {
+        match FileFailure::from_file(result) {
+            Some(failure) => self.record_failure(&result.path, failure),
+            None if result.result.is_ok() => {
+                self.record_success(&result.path)?;
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Suma un fallo a un archivo.
+    ///
+    /// Al llegar a `max_failures` mueve el archivo a la cuarentena, escribe
+    /// su sidecar y devuelve la entrada.
+    pub fn record_failure(
+        &mut self,
+        path: &Path,
+        failure: FileFailure,
+    ) -> io::Result<Option<QuarantineEntry>> {
+        let record = self.failures.entry(path.to_path_buf()).or_default();
+        record.count += 1;
+        record.last_error = failure.reason.clone();
+        let count = record.count;
+
+        if count < self.max_failures {
+            self.save()?;
+            return Ok(None);
+        }
+
+        let entry = self.quarantine(path, count, failure)?;
+        self.failures.remove(path);
+        self.save()?;
+        Ok(Some(entry))
+    }
+
+    /// Reinicia el contador de un archivo que se parseó bien.
+    pub fn record_success(&mut self, path: &Path) -> io::Result<()> {
+        if self.failures.remove(path).is_some() {
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    /// Archivos en cuarentena, ordenados por ruta.
+    pub fn list(&self) -> io::Result<Vec<QuarantineEntry>> {
+        let dir = match fs::read_dir(&self.dir) {
+            Ok(dir) => dir,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+
+        let mut entries = Vec::new();
+        for file in dir {
+            let path = file?.path();
+            if path.to_string_lossy().ends_with(SIDECAR_SUFFIX) {
+                let entry: QuarantineEntry =
+                    serde_json::from_slice(&fs::read(&path)?).map_err(invalid_data)?;
+                entries.push(entry);
+            }
+        }
+        entries.sort_by(|a, b| a.quarantined_path.cmp(&b.quarantined_path));
+        Ok(entries)
+    }
+
+    /// Devuelve un archivo a su ruta original y borra su sidecar.
+    ///
+    /// Falla si ya existe otro archivo en la ruta original.
+    pub fn restore(&mut self, entry: &QuarantineEntry) -> io::Result<PathBuf> {
+        if entry.original_path.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("File already exists: {}", entry.original_path.display()),
+            ));
+        }
+
+        fs::rename(&entry.quarantined_path, &entry.original_path)?;
+        fs::remove_file(sidecar_path(&entry.quarantined_path))?;
+        self.record_success(&entry.original_path)?;
+        Ok(entry.original_path.clone())
+    }
+
+    /// Mueve un archivo a la cuarentena y escribe su sidecar.
+    fn quarantine(
+        &self,
+        path: &Path,
+        failures: u32,
+        failure: FileFailure,
+    ) -> io::Result<QuarantineEntry> {
+        let name = path.file_name().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Not a file: {}", path.display()),
+            )
+        })?;
+        fs::create_dir_all(&self.dir)?;
+        let quarantined_path = self.dir.join(name);
+        fs::rename(path, &quarantined_path)?;
+
+        let entry = QuarantineEntry {
+            original_path: path.to_path_buf(),
+            quarantined_path,
+            failures,
+            reason: failure.reason,
+            diagnostics: failure.diagnostics,
+            quarantined_at: chrono::Utc::now().to_rfc3339(),
+        };
+        write_json(&sidecar_path(&entry.quarantined_path), &entry)?;
+        Ok(entry)
+    }
+
+    /// Guarda los contadores de fallos.
+    fn save(&self) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        write_json(&self.dir.join(FAILURES_FILE), &self.failures)
+    }
+}
+
+/// Sidecar de un archivo en cuarentena.
+fn sidecar_path(quarantined_path: &Path) -> PathBuf {
+    let mut path = quarantined_path.as_os_str().to_owned();
+    path.push(SIDECAR_SUFFIX);
+    PathBuf::from(path)
+}
+
+/// Escribe JSON a un temporal y lo renombra, como `FileLedger::save`.
+fn write_json<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    let json = serde_json::to_vec_pretty(value).map_err(invalid_data)?;
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, json)?;
+    fs::rename(&tmp, path)
+}
+
+fn invalid_data(e: serde_json::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_catch_parser_panic() {
+        assert_eq!(catch_parser_panic(|| 42), Ok(42));
+        assert_eq!(
+            catch_parser_panic(|| -> u32 { panic!("bad seat") }),
+            Err("bad seat".to_string())
+        );
+        let seat = 7;
+        assert_eq!(
+            catch_parser_panic(|| -> u32 { panic!("bad seat {}", seat) }),
+            Err("bad seat 7".to_string())
+        );
+    }
+
+    #[test]
+    fn test_quarantine_after_max_failures_and_restore() {
+        let history = TempDir::new().unwrap();
+        let file = history.path().join("broken.txt");
+        fs::write(&file, "not a hand history").unwrap();
+        let dir = FileQuarantine::dir_for(history.path());
+
+        let mut quarantine = FileQuarantine::open(&dir).unwrap();
+        assert!(quarantine
+            .record_failure(&file, FileFailure::new("Parser panic: boom"))
+            .unwrap()
+            .is_none());
+
+        // El contador sobrevive a reabrir la cuarentena
+        let mut quarantine = FileQuarantine::open(&dir).unwrap();
+        assert_eq!(quarantine.failures(&file), 1);
+        quarantine.record_success(&file).unwrap();
+        assert_eq!(FileQuarantine::open(&dir).unwrap().failures(&file), 0);
+
+        let mut quarantine = FileQuarantine::open(&dir).unwrap().with_max_failures(2);
+        quarantine
+            .record_failure(&file, FileFailure::new("Parser panic: boom"))
+            .unwrap();
+        let entry = quarantine
+            .record_failure(&file, FileFailure::new("Parser panic: boom again"))
+            .unwrap()
+            .expect("second failure quarantines the file");
+
+        assert!(!file.exists());
+        assert_eq!(entry.quarantined_path, dir.join("broken.txt"));
+        assert_eq!(entry.failures, 2);
+        assert_eq!(entry.reason, "Parser panic: boom again");
+        assert_eq!(quarantine.failures(&file), 0);
+        assert_eq!(quarantine.list().unwrap(), vec![entry.clone()]);
+
+        assert_eq!(quarantine.restore(&entry).unwrap(), file);
+        assert_eq!(fs::read_to_string(&file).unwrap(), "not a hand history");
+        assert!(quarantine.list().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_format_is_a_failure() {
+        let unknown = crate::history_parser::parse_history_bytes(b"hello\nworld\n");
+        let failure = FileFailure::from_parse(&unknown).unwrap();
+        assert_eq!(failure.diagnostics.len(), 1);
+
+        let empty = crate::history_parser::parse_history_bytes(b"\n\n");
+        assert!(FileFailure::from_parse(&empty).is_none());
+    }
+}
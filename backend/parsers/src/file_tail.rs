@@ -0,0 +1,621 @@
+//! Lectura incremental (tail) de historiales que se están escribiendo.
+//!
+//! Durante una sesión la sala añade cada mano nueva al final del mismo
+//! archivo. `FileTailer` recuerda por archivo el offset hasta el que ya se
+//! parseó y, en cada sondeo, lee solo los bytes añadidos y parsea las manos
+//! completas. La mano que aún se está escribiendo se queda fuera del offset y
+//! se vuelve a leer en el siguiente sondeo.
+//!
+//! ## Límite de mano completa
+//!
+//! Las manos se separan con líneas en blanco en todas las salas soportadas,
+//! así que una mano está completa cuando le sigue una línea en blanco. Los
+//! resúmenes de torneo se escriben de una vez y no terminan necesariamente en
+//! línea en blanco: en ellos basta con que la línea esté completa.
+//!
+//! ## Truncado y rotación
+//!
+//! Además del offset se guarda un hash MD5 de la cabecera del archivo (sus
+//! primeros `HEAD_LEN` bytes). Si el archivo mide menos que lo ya leído se
+//! considera truncado, y si la cabecera cambia se considera rotado (otro
+//! archivo con el mismo nombre). En ambos casos se vuelve a leer desde el
+//! principio.
+//!
+//! ## Fallos del parser
+//!
+//! El parsing se aísla con `catch_unwind`: si el parser entra en pánico,
+//! `poll` devuelve un error `InvalidData` sin avanzar el offset. Con
+//! `with_quarantine`, los archivos que fallan repetidamente se mueven a la
+//! cuarentena (ver `file_quarantine`) y se olvidan.
+//!
+//! ## Persistencia
+//!
+//! Los estados viven en un [`FileLedger`] que puede guardarse en disco. Al
+//! arrancar, `reconcile` recorre el directorio y lee solo los archivos nuevos
+//! o cuyo tamaño o fecha de modificación cambió desde la última lectura.
+//!
+//! ## Uso
+//!
+//! ```rust,no_run
+//! use poker_parsers::file_tail::FileTailer;
+//! use std::path::Path;
+//!
+//! let mut tailer = FileTailer::new();
+//! let path = Path::new("history/20251215_Nice 09(123456)_real_holdem_no-limit.txt");
+//! if let Some(update) = tailer.poll(path).unwrap() {
+//!     println!("{} manos nuevas", update.result.hands.len());
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, Metadata};
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use crate::bytes_parser::UTF8_BOM;
+use crate::file_ledger::FileLedger;
+use crate::file_quarantine::{catch_parser_panic, FileFailure, FileQuarantine};
+use crate::file_watcher::FileWatcher;
+use crate::hero::HeroConfig;
+use crate::history_parser::parse_history_bytes_with_heroes;
+use crate::tournament_summary::SUMMARY_HEADER;
+use crate::types::ParseResult;
+
+/// Bytes de cabecera usados para detectar la rotación de un archivo.
+pub const HEAD_LEN: u64 = 1024;
+
+/// Estado de lectura de un archivo.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TailState {
+    /// Offset (en bytes) hasta el final de la última mano completa parseada.
+    pub offset: u64,
+    /// Bytes de cabecera cubiertos por `head_hash`.
+    pub head_len: u64,
+    /// Hash MD5 de los primeros `head_len` bytes del archivo.
+    pub head_hash: String,
+    /// Manos emitidas desde el último reinicio.
+    pub hands: usize,
+    /// Líneas hasta `offset` (para numerar las líneas de los diagnósticos).
+    #[serde(default)]
+    pub lines: usize,
+    /// Tamaño del archivo en la última lectura.
+    pub size: u64,
+    /// Fecha de modificación en la última lectura (ms desde epoch).
+    pub mtime_ms: u64,
+}
+
+impl TailState {
+    /// Indica si el archivo no cambió desde la última lectura.
+    pub fn matches(&self, metadata: &Metadata) -> bool {
+        self.size == metadata.len() && self.mtime_ms == mtime_ms(metadata)
+    }
+}
+
+/// Motivo por el que se leyó un archivo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TailChange {
+    /// Primera lectura del archivo: se parsea desde el principio.
+    Opened,
+    /// Se añadieron datos tras el offset anterior.
+    Appended,
+    /// El archivo mide menos que lo ya leído: se parsea desde el principio.
+    Truncated,
+    /// La cabecera cambió (archivo reemplazado): se parsea desde el principio.
+    Rotated,
+}
+
+impl TailChange {
+    /// Indica si el archivo se volvió a leer desde el principio.
+    pub fn is_restart(&self) -> bool {
+        matches!(self, Self::Truncated | Self::Rotated)
+    }
+}
+
+/// Manos nuevas de un archivo tras un sondeo.
+#[derive(Debug, Clone)]
+pub struct TailUpdate {
+    /// Ruta del archivo.
+    pub path: PathBuf,
+    /// Motivo de la lectura.
+    pub change: TailChange,
+    /// Offset desde el que se leyó.
+    pub from_offset: u64,
+    /// Offset tras la última mano completa.
+    pub to_offset: u64,
+    /// Manos, resúmenes y diagnósticos de los bytes nuevos (con la ruta y
+    /// la línea dentro del archivo).
+    pub result: ParseResult,
+    /// Estado del archivo tras la lectura (para persistirlo en el ledger).
+    pub state: TailState,
+}
+
+/// Resultado del escaneo de arranque de un directorio.
+#[derive(Debug, Default)]
+pub struct Reconciliation {
+    /// Lecturas de archivos nuevos o modificados.
+    pub updates: Vec<TailUpdate>,
+    /// Archivos sin cambios desde la última lectura.
+    pub unchanged: usize,
+    /// Archivos registrados que ya no existen (se eliminan del ledger).
+    pub removed: Vec<PathBuf>,
+    /// Archivos que no se pudieron leer.
+    pub errors: Vec<String>,
+}
+
+/// Lector incremental de historiales.
+#[derive(Debug, Default)]
+pub struct FileTailer {
+    files: FileLedger,
+    heroes: HeroConfig,
+    quarantine: Option<FileQuarantine>,
+}
+
+impl FileTailer {
+    /// Crea un tailer sin archivos conocidos.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Crea un tailer que continúa desde un ledger persistido.
+    pub fn with_ledger(ledger: FileLedger) -> Self {
+        Self {
+            files: ledger,
+            heroes: HeroConfig::default(),
+            quarantine: None,
+        }
+    }
+
+    /// Establece los nicknames del héroe para las manos que se parseen.
+    pub fn with_heroes(mut self, heroes: HeroConfig) -> Self {
+        self.heroes = heroes;
+        self
+    }
+
+    /// Cuenta los fallos del parser y aparta a cuarentena los archivos que
+    /// fallan repetidamente.
+    pub fn with_quarantine(mut self, quarantine: FileQuarantine) -> Self {
+        self.quarantine = Some(quarantine);
+        self
+    }
+
+    /// Cuarentena de archivos, si está activada.
+    pub fn quarantine(&self) -> Option<&FileQuarantine> {
+        self.quarantine.as_ref()
+    }
+
+    /// Ledger con el estado de todos los archivos conocidos.
+    pub fn ledger(&self) -> &FileLedger {
+        &self.files
+    }
+
+    /// Devuelve el ledger consumiendo el tailer.
+    pub fn into_ledger(self) -> FileLedger {
+        self.files
+    }
+
+    /// Estado de lectura de un archivo, si ya se sondeó.
+    pub fn state(&self, path: &Path) -> Option<&TailState> {
+        self.files.get(path)
+    }
+
+    /// Olvida un archivo (p.ej. al borrarse); el siguiente sondeo lo lee entero.
+    pub fn forget(&mut self, path: &Path) -> Option<TailState> {
+        self.files.remove(path)
+    }
+
+    /// Escaneo de arranque: lee los historiales nuevos o modificados de un
+    /// directorio y olvida los registrados que ya no existen.
+    ///
+    /// Los archivos cuyo tamaño y fecha de modificación coinciden con el
+    /// ledger no se abren.
+    pub fn reconcile(&mut self, dir: &Path) -> io::Result<Reconciliation> {
+        let mut report = Reconciliation::default();
+
+        let mut files: Vec<PathBuf> = fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| FileWatcher::is_txt_file(path) && path.is_file())
+            .collect();
+        files.sort();
+
+        for path in files {
+            let unchanged = match (self.files.get(&path), fs::metadata(&path)) {
+                (Some(state), Ok(metadata)) => state.matches(&metadata),
+                _ => false,
+            };
+            if unchanged {
+                report.unchanged += 1;
+                continue;
+            }
+
+            match self.poll(&path) {
+                Ok(Some(update)) => report.updates.push(update),
+                Ok(None) => {}
+                Err(e) => report.errors.push(format!("{}: {}", path.display(), e)),
+            }
+        }
+
+        report.removed = self
+            .files
+            .iter()
+            .map(|(path, _)| path)
+            .filter(|path| path.parent() == Some(dir) && !path.exists())
+            .cloned()
+            .collect();
+        for path in &report.removed {
+            self.files.remove(path);
+        }
+
+        Ok(report)
+    }
+
+    /// Lee los bytes añadidos a un archivo y parsea las manos completas.
+    ///
+    /// Devuelve `None` si no hay ninguna mano nueva completa ni el archivo se
+    /// truncó o rotó. Un pánico del parser se devuelve como error
+    /// `InvalidData`, igual que el archivo apartado a cuarentena.
+    pub fn poll(&mut self, path: &Path) -> io::Result<Option<TailUpdate>> {
+        let polled = self.read_appended(path);
+        let Some(quarantine) = self.quarantine.as_mut() else {
+            return polled;
+        };
+
+        let failure = match &polled {
+            Ok(Some(update)) => FileFailure::from_parse(&update.result),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData => {
+                Some(FileFailure::new(e.to_string()))
+            }
+            Ok(None) | Err(_) => return polled,
+        };
+        let Some(failure) = failure else {
+            quarantine.record_success(path)?;
+            return polled;
+        };
+
+        match quarantine.record_failure(path, failure)? {
+            Some(entry) => {
+                self.files.remove(path);
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "Moved to quarantine after {} failures ({}): {}",
+                        entry.failures,
+                        entry.reason,
+                        entry.quarantined_path.display()
+                    ),
+                ))
+            }
+            None => polled,
+        }
+    }
+
+    /// Lee y parsea los bytes añadidos (ver `poll`).
+    fn read_appended(&mut self, path: &Path) -> io::Result<Option<TailUpdate>> {
+        let mut file = File::open(path)?;
+        let metadata = file.metadata()?;
+        let len = metadata.len();
+
+        let mut head = Vec::new();
+        (&mut file).take(HEAD_LEN).read_to_end(&mut head)?;
+
+        let change = match self.files.get(path) {
+            None => TailChange::Opened,
+            Some(state) if len < state.offset || (head.len() as u64) < state.head_len => {
+                TailChange::Truncated
+            }
+            Some(state) if head_hash(&head[..state.head_len as usize]) != state.head_hash => {
+                TailChange::Rotated
+            }
+            Some(_) => TailChange::Appended,
+        };
+
+        let state = self.files.entry(path);
+        if change != TailChange::Appended {
+            *state = TailState::default();
+        }
+        if state.head_len < head.len() as u64 {
+            state.head_len = head.len() as u64;
+            state.head_hash = head_hash(&head);
+        }
+
+        let from_offset = state.offset;
+        let mut appended = Vec::new();
+        if len > from_offset {
+            file.seek(SeekFrom::Start(from_offset))?;
+            file.read_to_end(&mut appended)?;
+        }
+        let complete = complete_len(&appended, from_offset == 0, is_summary(&head));
+        if complete == 0 && change == TailChange::Appended {
+            state.size = from_offset + appended.len() as u64;
+            state.mtime_ms = mtime_ms(&metadata);
+            return Ok(None);
+        }
+
+        // Si el parser entra en pánico el estado no cambia: se reintenta
+        let heroes = &self.heroes;
+        let mut result =
+            catch_parser_panic(|| parse_history_bytes_with_heroes(&appended[..complete], heroes))
+                .map_err(|panic| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Parser panic: {}", panic),
+                )
+            })?;
+        state.size = from_offset + appended.len() as u64;
+        state.mtime_ms = mtime_ms(&metadata);
+        result.set_path(path);
+        for diagnostic in &mut result.diagnostics {
+            diagnostic.line = diagnostic.line.map(|line| line + state.lines);
+        }
+        state.lines += appended[..complete].iter().filter(|&&b| b == b'\n').count();
+        state.offset += complete as u64;
+        state.hands += result.hands.len();
+
+        Ok(Some(TailUpdate {
+            path: path.to_path_buf(),
+            change,
+            from_offset,
+            to_offset: state.offset,
+            result,
+            state: state.clone(),
+        }))
+    }
+}
+
+/// Fecha de modificación en ms desde epoch (0 si el sistema no la ofrece).
+fn mtime_ms(metadata: &Metadata) -> u64 {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |elapsed| elapsed.as_millis() as u64)
+}
+
+/// Hash MD5 (hex) de la cabecera de un archivo.
+fn head_hash(head: &[u8]) -> String {
+    format!("{:x}", md5::compute(head))
+}
+
+/// Indica si la cabecera corresponde a un resumen de torneo de Winamax.
+fn is_summary(head: &[u8]) -> bool {
+    let head = head.strip_prefix(UTF8_BOM).unwrap_or(head);
+    head.starts_with(SUMMARY_HEADER.as_bytes())
+}
+
+/// Longitud del prefijo de `bytes` que contiene solo manos completas.
+///
+/// Con `whole_lines` basta con cortar tras el último salto de línea; si no,
+/// el corte se hace tras la última línea en blanco. `at_start` indica que
+/// `bytes` empieza al principio del archivo, donde no hay línea anterior.
+fn complete_len(bytes: &[u8], at_start: bool, whole_lines: bool) -> usize {
+    let mut complete = 0;
+    let mut line_start = 0;
+    for (i, &b) in bytes.iter().enumerate() {
+        if b != b'\n' {
+            continue;
+        }
+        let line = &bytes[line_start..i];
+        let blank = line.iter().all(u8::is_ascii_whitespace);
+        // Una línea en blanco al inicio del archivo no cierra ninguna mano
+        if whole_lines || (blank && (line_start > 0 || !at_start)) {
+            complete = i + 1;
+        }
+        line_start = i + 1;
+    }
+    complete
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{self, OpenOptions};
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    fn hand(n: u32) -> String {
+        format!(
+            "Winamax Poker - CashGame - HandId: #21819158-{n}-1765807700 - Holdem no limit (0.01€/0.02€) - 2025/12/15 14:11:00 UTC\n\
+Table: 'Nice 09' 5-max (real money) Seat #1 is the button\n\
+Seat 1: villain (2€)\n\
+Seat 2: thesmoy (2€)\n\
+*** ANTE/BLINDS ***\n\
+villain posts small blind 0.01€\n\
+thesmoy posts big blind 0.02€\n\
+*** PRE-FLOP ***\n\
+villain folds\n\
+thesmoy collected 0.03€ from pot\n\
+*** SUMMARY ***\n\
+Total pot 0.03€ | No rake\n\
+Seat 2: thesmoy (big blind) won 0.03€\n\n"
+        )
+    }
+
+    fn append(path: &Path, content: &str) {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+    }
+
+    fn hand_ids(update: &TailUpdate) -> Vec<&str> {
+        update
+            .result
+            .hands
+            .iter()
+            .map(|h| h.hand_id.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_poll_emits_only_appended_complete_hands() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("session.txt");
+        let mut tailer = FileTailer::new();
+
+        append(&path, &format!("\u{feff}{}", hand(1)));
+        let update = tailer.poll(&path).unwrap().unwrap();
+        assert_eq!(update.change, TailChange::Opened);
+        assert_eq!(hand_ids(&update), vec!["21819158-1-1765807700"]);
+        assert!(update.result.diagnostics.is_empty());
+
+        // Sin cambios no hay nada que emitir
+        assert!(tailer.poll(&path).unwrap().is_none());
+
+        // Una mano a medio escribir no se emite ni avanza el offset
+        let second = hand(2);
+        let (head, tail) = second.split_at(200);
+        append(&path, head);
+        assert!(tailer.poll(&path).unwrap().is_none());
+        let offset = tailer.state(&path).unwrap().offset;
+
+        append(&path, tail);
+        append(&path, &hand(3));
+        let update = tailer.poll(&path).unwrap().unwrap();
+        assert_eq!(update.change, TailChange::Appended);
+        assert_eq!(update.from_offset, offset);
+        assert_eq!(update.to_offset, fs::metadata(&path).unwrap().len());
+        assert_eq!(
+            hand_ids(&update),
+            vec!["21819158-2-1765807700", "21819158-3-1765807700"]
+        );
+        assert_eq!(tailer.state(&path).unwrap().hands, 3);
+
+        // Los diagnósticos llevan la ruta y la línea dentro del archivo
+        append(
+            &path,
+            &hand(4).replace("*** PRE-FLOP ***\n", "*** PRE-FLOP ***\nvillain juggles\n"),
+        );
+        let update = tailer.poll(&path).unwrap().unwrap();
+        let diagnostic = &update.result.diagnostics[0];
+        assert_eq!(diagnostic.path.as_deref(), Some(path.as_path()));
+        assert_eq!(diagnostic.line, Some(3 * 14 + 9));
+        assert_eq!(tailer.state(&path).unwrap().lines, 4 * 14 + 1);
+    }
+
+    #[test]
+    fn test_poll_detects_truncation_and_rotation() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("session.txt");
+        let mut tailer = FileTailer::new();
+
+        fs::write(&path, hand(1) + &hand(2)).unwrap();
+        assert_eq!(tailer.poll(&path).unwrap().unwrap().result.hands.len(), 2);
+
+        // Truncado: se vuelve a leer desde el principio
+        fs::write(&path, hand(1)).unwrap();
+        let update = tailer.poll(&path).unwrap().unwrap();
+        assert_eq!(update.change, TailChange::Truncated);
+        assert_eq!(update.from_offset, 0);
+        assert_eq!(hand_ids(&update), vec!["21819158-1-1765807700"]);
+
+        // Rotado: mismo nombre, contenido distinto y más largo
+        fs::write(&path, hand(7) + &hand(8)).unwrap();
+        let update = tailer.poll(&path).unwrap().unwrap();
+        assert_eq!(update.change, TailChange::Rotated);
+        assert!(update.change.is_restart());
+        assert_eq!(
+            hand_ids(&update),
+            vec!["21819158-7-1765807700", "21819158-8-1765807700"]
+        );
+
+        tailer.forget(&path);
+        assert_eq!(
+            tailer.poll(&path).unwrap().unwrap().change,
+            TailChange::Opened
+        );
+    }
+
+    #[test]
+    fn test_poll_quarantines_unparseable_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("notes.txt");
+        let quarantine = FileQuarantine::open(FileQuarantine::dir_for(dir.path()))
+            .unwrap()
+            .with_max_failures(2);
+        let mut tailer = FileTailer::new().with_quarantine(quarantine);
+
+        append(&path, "shopping list\n\n");
+        assert!(tailer.poll(&path).unwrap().unwrap().result.hands.is_empty());
+        assert_eq!(tailer.quarantine().unwrap().failures(&path), 1);
+
+        append(&path, "more notes\n\n");
+        let err = tailer.poll(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("quarantine"));
+        assert!(!path.exists());
+        assert!(tailer.state(&path).is_none());
+
+        let entries = tailer.quarantine().unwrap().list().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].original_path, path);
+    }
+
+    #[test]
+    fn test_reconcile_reads_only_new_or_changed_files() {
+        let dir = TempDir::new().unwrap();
+        let ledger_path = dir.path().join("watcher_ledger.json");
+        let a = dir.path().join("a.txt");
+        let b = dir.path().join("b.txt");
+        fs::write(&a, hand(1)).unwrap();
+        fs::write(&b, hand(2)).unwrap();
+        fs::write(dir.path().join("notes.log"), hand(9)).unwrap();
+
+        let mut tailer = FileTailer::new();
+        let first = tailer.reconcile(dir.path()).unwrap();
+        assert_eq!(first.updates.len(), 2);
+        assert_eq!(first.unchanged, 0);
+        tailer.ledger().save(&ledger_path).unwrap();
+
+        // Reinicio: se continúa desde el ledger persistido
+        append(&b, &hand(3));
+        let c = dir.path().join("c.txt");
+        fs::write(&c, hand(4)).unwrap();
+        fs::remove_file(&a).unwrap();
+
+        let mut tailer = FileTailer::with_ledger(FileLedger::load(&ledger_path).unwrap());
+        let second = tailer.reconcile(dir.path()).unwrap();
+        assert_eq!(second.unchanged, 0);
+        assert_eq!(second.removed, vec![a.clone()]);
+        assert!(second.errors.is_empty());
+
+        let updates: Vec<(&Path, TailChange, Vec<&str>)> = second
+            .updates
+            .iter()
+            .map(|u| (u.path.as_path(), u.change, hand_ids(u)))
+            .collect();
+        assert_eq!(
+            updates,
+            vec![
+                (
+                    b.as_path(),
+                    TailChange::Appended,
+                    vec!["21819158-3-1765807700"]
+                ),
+                (
+                    c.as_path(),
+                    TailChange::Opened,
+                    vec!["21819158-4-1765807700"]
+                ),
+            ]
+        );
+        assert_eq!(tailer.state(&b).unwrap().hands, 2);
+        assert_eq!(tailer.ledger().len(), 2);
+
+        // Sin cambios no se abre ningún archivo
+        let third = tailer.reconcile(dir.path()).unwrap();
+        assert!(third.updates.is_empty());
+        assert_eq!(third.unchanged, 2);
+    }
+
+    #[test]
+    fn test_complete_len() {
+        let content = b"a\nb\n\nc\n";
+        assert_eq!(complete_len(content, true, false), 5);
+        assert_eq!(complete_len(content, true, true), 7);
+        assert_eq!(complete_len(b"\nhand", true, false), 0);
+        assert_eq!(complete_len(b"\nhand", false, false), 1);
+        assert_eq!(complete_len(b"partial", false, true), 0);
+    }
+}
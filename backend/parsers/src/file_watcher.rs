@@ -1,12 +1,16 @@
 //! Sistema de file watching para detectar nuevos historiales automáticamente.
 //!
 //! Este módulo implementa un watcher de archivos usando el crate `notify` para
-//! detectar automáticamente nuevos archivos de historial de Winamax en Windows.
+//! detectar automáticamente nuevos archivos de historial en Windows. La sala
+//! (Winamax, PokerStars o GGPoker) se detecta al parsear cada archivo, por lo
+//! que basta con vigilar la carpeta de historiales de cada cliente.
 //!
 //! ## Características
 //!
 //! - **File Watching**: Usa `notify::RecommendedWatcher` para compatibilidad multiplataforma
 //! - **Deduplicación**: Hash MD5 para evitar procesamiento duplicado
+//! - **Tail incremental**: `start_tailing` parsea solo las manos añadidas a
+//!   cada archivo (ver [`FileTailer`](crate::file_tail::FileTailer))
 //! - **Cola de Procesamiento**: `mpsc::channel` para manejo asíncrono
 //! - **Retry Logic**: Manejo de archivos bloqueados con backoff exponencial
 //! - **Integración**: Se conecta con `ParallelProcessor` para procesamiento multihilo
@@ -27,6 +31,19 @@
 //!     println!("Nuevo archivo detectado: {:?}", file_path);
 //! });
 //! ```
+//!
+//! Para recibir solo las manos nuevas mientras se juega:
+//!
+//! ```rust,no_run
+//! use poker_parsers::file_watcher::{FileWatcher, WatcherConfig};
+//!
+//! let watcher = FileWatcher::new(WatcherConfig::default());
+//! watcher.start_tailing(|update| {
+//!     for hand in update.result.hands {
+//!         println!("Nueva mano: {}", hand.hand_id);
+//!     }
+//! });
+//! ```
 
 use notify::{
     Config, Error as NotifyError, Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher,
@@ -41,6 +58,10 @@ use std::thread;
 use std::time::Duration;
 use tracing::{debug, error, info, warn};
 
+use crate::file_ledger::FileLedger;
+use crate::file_quarantine::FileQuarantine;
+use crate::file_tail::{FileTailer, TailUpdate};
+use crate::hero::HeroConfig;
 use crate::parallel_processor::ParallelProcessor;
 
 /// Configuración del file watcher.
@@ -54,6 +75,11 @@ pub struct WatcherConfig {
     pub retry_delay_ms: u64,
     /// Usar backoff exponencial para retries.
     pub use_exponential_backoff: bool,
+    /// Nicknames del héroe para las manos leídas en modo tail.
+    pub heroes: HeroConfig,
+    /// Directorio de cuarentena para los archivos que el parser no consigue
+    /// leer en modo tail (`None` = sin cuarentena).
+    pub quarantine_dir: Option<PathBuf>,
 }
 
 impl Default for WatcherConfig {
@@ -65,6 +91,8 @@ impl Default for WatcherConfig {
             max_retries: 3,
             retry_delay_ms: 100,
             use_exponential_backoff: true,
+            heroes: HeroConfig::default(),
+            quarantine_dir: None,
         }
     }
 }
@@ -124,6 +152,7 @@ pub struct FileWatcher {
     processed_hashes: Arc<Mutex<HashSet<String>>>,
     file_queue_tx: Sender<FileEvent>,
     file_queue_rx: Arc<Mutex<Receiver<FileEvent>>>,
+    ledger: FileLedger,
 }
 
 impl FileWatcher {
@@ -135,9 +164,19 @@ impl FileWatcher {
             processed_hashes: Arc::new(Mutex::new(HashSet::new())),
             file_queue_tx: tx,
             file_queue_rx: Arc::new(Mutex::new(rx)),
+            ledger: FileLedger::new(),
         }
     }
 
+    /// Continúa el modo tail desde un ledger persistido.
+    ///
+    /// Los archivos registrados se leen desde su último offset en lugar de
+    /// desde el principio. Solo afecta a `start_tailing`.
+    pub fn with_ledger(mut self, ledger: FileLedger) -> Self {
+        self.ledger = ledger;
+        self
+    }
+
     /// Inicia el file watcher con un callback para procesar archivos.
     ///
     /// El callback recibe la ruta del archivo detectado.
@@ -208,6 +247,108 @@ impl FileWatcher {
         }
     }
 
+    /// Inicia el file watcher en modo tail incremental.
+    ///
+    /// En lugar de hashear y re-parsear el archivo entero en cada evento, se
+    /// lee solo lo añadido desde la última mano completa y el callback recibe
+    /// las manos nuevas. Los archivos truncados o rotados se vuelven a leer
+    /// desde el principio. Cada `TailUpdate` incluye el estado del archivo
+    /// para que el consumidor lo registre en su `FileLedger` una vez procesado.
+    /// Esta función bloquea el hilo actual.
+    pub fn start_tailing<F>(self, mut callback: F) -> Result<(), WatcherError>
+    where
+        F: FnMut(TailUpdate) + Send + 'static,
+    {
+        if !self.config.watch_path.exists() {
+            return Err(WatcherError::IoError(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("Watch path does not exist: {:?}", self.config.watch_path),
+            )));
+        }
+
+        let (notify_tx, notify_rx) = mpsc::channel();
+        let mut watcher = RecommendedWatcher::new(notify_tx, Config::default())?;
+        watcher.watch(&self.config.watch_path, RecursiveMode::NonRecursive)?;
+
+        info!(
+            path = %self.config.watch_path.display(),
+            "File watcher started in tail mode"
+        );
+
+        // Thread 1: Filtrar eventos de notify y encolar rutas
+        let (path_tx, path_rx) = mpsc::channel::<(PathBuf, bool)>();
+        thread::spawn(move || {
+            for event_result in notify_rx {
+                match event_result {
+                    Ok(event) => {
+                        let removed = match event.kind {
+                            EventKind::Create(_) | EventKind::Modify(_) => false,
+                            EventKind::Remove(_) => true,
+                            _ => continue,
+                        };
+                        for path in event.paths {
+                            if Self::is_txt_file(&path) && path_tx.send((path, removed)).is_err() {
+                                return;
+                            }
+                        }
+                    }
+                    Err(e) => error!(error = %e, "Notify error"),
+                }
+            }
+        });
+
+        // Thread 2: Leer lo añadido a cada archivo (el tailer vive aquí)
+        let config = self.config.clone();
+        let ledger = self.ledger;
+        thread::spawn(move || {
+            let mut tailer = FileTailer::with_ledger(ledger).with_heroes(config.heroes.clone());
+            if let Some(dir) = &config.quarantine_dir {
+                match FileQuarantine::open(dir) {
+                    Ok(quarantine) => tailer = tailer.with_quarantine(quarantine),
+                    Err(e) => {
+                        error!(path = %dir.display(), error = %e, "Failed to open file quarantine")
+                    }
+                }
+            }
+            for (path, removed) in path_rx {
+                if removed {
+                    tailer.forget(&path);
+                    continue;
+                }
+
+                match Self::with_retry(&path, &config, || tailer.poll(&path)) {
+                    Ok(Some(update)) => {
+                        if update.change.is_restart() {
+                            warn!(
+                                file_path = %path.display(),
+                                change = ?update.change,
+                                "File truncated or rotated, reading from the start"
+                            );
+                        }
+                        debug!(
+                            file_path = %path.display(),
+                            from_offset = update.from_offset,
+                            to_offset = update.to_offset,
+                            new_hands = update.result.hands.len(),
+                            "Appended hands parsed"
+                        );
+                        callback(update);
+                    }
+                    Ok(None) => {}
+                    Err(e) => error!(
+                        file_path = %path.display(),
+                        error = %e,
+                        "Failed to tail file"
+                    ),
+                }
+            }
+        });
+
+        loop {
+            thread::sleep(Duration::from_secs(1));
+        }
+    }
+
     /// Inicia el watcher con integración directa al ParallelProcessor.
     ///
     /// Los archivos detectados se procesan automáticamente usando Rayon.
@@ -280,7 +421,7 @@ impl FileWatcher {
     }
 
     /// Verifica si un archivo es .txt.
-    fn is_txt_file(path: &Path) -> bool {
+    pub(crate) fn is_txt_file(path: &Path) -> bool {
         path.extension()
             .and_then(|ext| ext.to_str())
             .map(|ext| ext.eq_ignore_ascii_case("txt"))
@@ -292,22 +433,35 @@ impl FileWatcher {
         path: &Path,
         config: &WatcherConfig,
     ) -> Result<FileEvent, WatcherError> {
+        let hash = Self::with_retry(path, config, || Self::read_and_hash_file(path))?;
+        Ok(FileEvent {
+            path: path.to_path_buf(),
+            hash,
+            timestamp: std::time::SystemTime::now(),
+        })
+    }
+
+    /// Ejecuta una lectura del archivo reintentando mientras esté bloqueado.
+    fn with_retry<T>(
+        path: &Path,
+        config: &WatcherConfig,
+        mut read: impl FnMut() -> io::Result<T>,
+    ) -> Result<T, WatcherError> {
         let mut attempt = 0;
         let mut delay = config.retry_delay_ms;
 
         loop {
-            match Self::read_and_hash_file(path) {
-                Ok(hash) => {
-                    return Ok(FileEvent {
-                        path: path.to_path_buf(),
-                        hash,
-                        timestamp: std::time::SystemTime::now(),
-                    });
+            match read() {
+                Ok(value) => return Ok(value),
+                // Fallo del parser, no un bloqueo: reintentar no sirve
+                Err(e) if e.kind() == io::ErrorKind::InvalidData => {
+                    return Err(WatcherError::IoError(e));
                 }
                 Err(e) if attempt < config.max_retries => {
                     // Archivo bloqueado, reintentar
                     warn!(
                         file_path = %path.display(),
+                        error = %e,
                         attempt = attempt + 1,
                         max_retries = config.max_retries,
                         delay_ms = delay,
@@ -379,6 +533,18 @@ impl FileWatcherBuilder {
         self
     }
 
+    /// Establece los nicknames del héroe.
+    pub fn heroes(mut self, heroes: HeroConfig) -> Self {
+        self.config.heroes = heroes;
+        self
+    }
+
+    /// Activa la cuarentena de archivos en modo tail.
+    pub fn quarantine_dir(mut self, dir: PathBuf) -> Self {
+        self.config.quarantine_dir = Some(dir);
+        self
+    }
+
     /// Construye el file watcher.
     pub fn build(self) -> FileWatcher {
         FileWatcher::new(self.config)
@@ -1,16 +1,89 @@
 //! Máquina de Estados Finitos (FSM) para parsing de historiales Winamax.
 //!
-//! Este módulo implementa un parser eficiente que procesa líneas de historial
-//! usando string slicing y prefijos, evitando Regex en hot loops para
-//! maximizar el rendimiento en el Ryzen 3800X.
-
+//! El parser es incremental y trabaja directamente sobre bytes:
+//!
+//! - `feed` acepta trozos arbitrarios del archivo (bloques de lectura o lo
+//!   que se ha añadido a un historial en vivo). Procesa las líneas completas
+//!   y guarda la última línea parcial hasta el siguiente trozo.
+//! - `take_hands` devuelve las manos completadas hasta el momento, sin
+//!   esperar al final del archivo.
+//! - `finish` cierra la mano y el resumen en curso y devuelve el resultado.
+//!
+//! Las líneas se analizan con las primitivas de `bytes_parser` sin
+//! convertirlas a `String`. Los nombres de jugador se decodifican una vez
+//! por archivo en una tabla de nombres internados (`Arc<str>` compartidos
+//! por jugadores, acciones y cobros) y las acciones se asocian a los
+//! jugadores sentados por prefijo, por lo que un nombre que contenga una
+//! palabra clave (`folds`, `calls`...) no rompe la línea.
+//!
+//! Las palabras de cada línea dependen del idioma del cliente (inglés,
+//! francés o español, ver `winamax_locale`). El idioma se detecta una vez por
+//! archivo en la primera cabecera de torneo o línea de mesa, o se fija con
+//! `with_locale`; hasta detectarlo se usa el inglés.
+//!
+//! ```rust
+//! use poker_parsers::WinamaxParser;
+//!
+//! let history = "Winamax Poker - CashGame - HandId: #1-1-1 - Holdem no limit (0.01€/0.02€) - 2025/12/15 14:02:20 UTC\n\
+//!                Table: 'Nice 09' 5-max (real money) Seat #1 is the button\n\
+//!                Seat 1: thesmoy (2€)\n";
+//!
+//! let mut parser = WinamaxParser::new();
+//! let (first, second) = history.as_bytes().split_at(60);
+//! parser.feed(first);
+//! parser.feed(second);
+//!
+//! let result = parser.finish();
+//! assert_eq!(&*result.hands[0].players[0].name, "thesmoy");
+//! ```
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::io::{self, Read};
+use std::sync::Arc;
+
+use crate::bytes_parser::{find_bytes, parse_amount_cents, starts_with_bytes, tokens, UTF8_BOM};
+use crate::diagnostics::{DiagnosticKind, ParseDiagnostic, Severity};
+use crate::hero::HeroConfig;
+use crate::history_parser::HandHistoryParser;
+use crate::pot_resolver::PotResolution;
+use crate::tournament_summary::{
+    parse_buy_in, split_name_and_id, TournamentSummary, SUMMARY_HEADER,
+};
 use crate::types::{
-    Action, ActionType, Card, GameType, ParseResult, ParsedHand, ParserState, Player, Position,
-    Street,
+    Action, ActionType, Card, GameType, GameVariant, HoleCards, ParseResult, ParsedHand,
+    ParserState, Player, Site, Street, TournamentFormat, TournamentInfo,
 };
+use crate::winamax_locale::{WinamaxGrammar, WinamaxLocale};
+
+/// Tamaño de bloque de `parse_reader`.
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Tabla de nombres de jugador internados.
+///
+/// Cada nombre se decodifica (UTF-8 con pérdidas) una sola vez por archivo y
+/// se comparte como `Arc<str>`: jugadores, acciones y cobros de todas las
+/// manos apuntan al mismo nombre sin volver a reservar memoria.
+#[derive(Debug, Default)]
+struct NameTable {
+    names: HashMap<Vec<u8>, Arc<str>>,
+}
 
-/// Hero por defecto según configuración del proyecto.
-const HERO_NAME: &str = "thesmoy";
+impl NameTable {
+    /// Devuelve el nombre internado, decodificándolo si es nuevo.
+    fn intern(&mut self, bytes: &[u8]) -> Arc<str> {
+        if let Some(name) = self.names.get(bytes) {
+            return Arc::clone(name);
+        }
+        let name: Arc<str> = text(bytes).into();
+        self.names.insert(bytes.to_vec(), Arc::clone(&name));
+        name
+    }
+
+    fn clear(&mut self) {
+        self.names.clear();
+    }
+}
 
 /// Parser FSM para historiales de Winamax.
 #[derive(Debug)]
@@ -23,8 +96,24 @@ pub struct WinamaxParser {
     current_street: Street,
     /// Manos completadas.
     hands: Vec<ParsedHand>,
-    /// Errores encontrados durante el parsing.
-    errors: Vec<String>,
+    /// Diagnósticos encontrados durante el parsing.
+    diagnostics: Vec<ParseDiagnostic>,
+    /// Número de la última línea procesada (desde 1).
+    line_no: usize,
+    /// Resumen de torneo en construcción.
+    current_summary: Option<TournamentSummary>,
+    /// Resúmenes de torneo completados.
+    summaries: Vec<TournamentSummary>,
+    /// Línea parcial del último trozo recibido (sin salto de línea).
+    pending: Vec<u8>,
+    /// Nombres de jugador internados del archivo actual.
+    names: NameTable,
+    /// Idioma del archivo actual (`None` hasta detectarlo).
+    locale: Option<WinamaxLocale>,
+    /// Idioma fijado con `with_locale`; desactiva la detección.
+    forced_locale: Option<WinamaxLocale>,
+    /// Nicknames del héroe (vacío = el jugador de `Dealt to`).
+    heroes: HeroConfig,
 }
 
 impl Default for WinamaxParser {
@@ -41,31 +130,130 @@ impl WinamaxParser {
             current_hand: ParsedHand::default(),
             current_street: Street::Preflop,
             hands: Vec::new(),
-            errors: Vec::new(),
+            diagnostics: Vec::new(),
+            line_no: 0,
+            current_summary: None,
+            summaries: Vec::new(),
+            pending: Vec::new(),
+            names: NameTable::default(),
+            locale: None,
+            forced_locale: None,
+            heroes: HeroConfig::default(),
         }
     }
 
+    /// Marca como héroe a los jugadores con estos nicknames.
+    ///
+    /// Sin nicknames, el héroe es el jugador de `Dealt to`.
+    pub fn with_heroes(mut self, heroes: HeroConfig) -> Self {
+        self.heroes = heroes;
+        self
+    }
+
+    /// Fija el idioma de los historiales en lugar de detectarlo por archivo.
+    pub fn with_locale(mut self, locale: WinamaxLocale) -> Self {
+        self.locale = Some(locale);
+        self.forced_locale = Some(locale);
+        self
+    }
+
+    /// Idioma del archivo actual, si ya se ha detectado o fijado.
+    pub fn locale(&self) -> Option<WinamaxLocale> {
+        self.locale
+    }
+
     /// Parsea un archivo completo de historial.
     pub fn parse(&mut self, content: &str) -> ParseResult {
-        for line in content.lines() {
-            self.process_line(line);
+        self.parse_bytes(content.as_bytes())
+    }
+
+    /// Parsea un archivo completo de historial sin validar UTF-8.
+    pub fn parse_bytes(&mut self, content: &[u8]) -> ParseResult {
+        self.feed(content);
+        self.finish()
+    }
+
+    /// Parsea un historial leyéndolo por bloques, sin cargarlo entero en memoria.
+    pub fn parse_reader<R: Read>(&mut self, mut reader: R) -> io::Result<ParseResult> {
+        let mut buffer = vec![0u8; READ_CHUNK_SIZE];
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => self.feed(&buffer[..read]),
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(self.finish())
+    }
+
+    /// Procesa un trozo del historial.
+    ///
+    /// Las líneas completas se procesan en el acto; la última línea, si no
+    /// termina en salto de línea, queda pendiente hasta el siguiente trozo o
+    /// hasta `finish`.
+    pub fn feed(&mut self, chunk: &[u8]) {
+        let mut rest = chunk;
+
+        // Completar la línea partida entre el trozo anterior y este
+        if !self.pending.is_empty() {
+            let Some(newline) = rest.iter().position(|&b| b == b'\n') else {
+                self.pending.extend_from_slice(rest);
+                return;
+            };
+            self.pending.extend_from_slice(&rest[..newline]);
+            let mut line = std::mem::take(&mut self.pending);
+            self.process_line(&line);
+            line.clear();
+            self.pending = line;
+            rest = &rest[newline + 1..];
+        }
+
+        while let Some(newline) = rest.iter().position(|&b| b == b'\n') {
+            self.process_line(&rest[..newline]);
+            rest = &rest[newline + 1..];
+        }
+        self.pending.extend_from_slice(rest);
+    }
+
+    /// Bytes recibidos que aún no forman una línea completa.
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Devuelve las manos completadas hasta ahora.
+    ///
+    /// La mano en curso no se incluye hasta que termina su resumen.
+    pub fn take_hands(&mut self) -> Vec<ParsedHand> {
+        std::mem::take(&mut self.hands)
+    }
+
+    /// Cierra el historial: procesa la línea pendiente, finaliza la mano y el
+    /// resumen en curso y devuelve todo lo que no se haya recogido con
+    /// `take_hands`.
+    pub fn finish(&mut self) -> ParseResult {
+        if !self.pending.is_empty() {
+            let line = std::mem::take(&mut self.pending);
+            self.process_line(&line);
         }
 
         // Finalizar última mano si existe
         if !self.current_hand.hand_id.is_empty() {
             self.finalize_hand();
         }
+        self.finalize_summary();
 
-        ParseResult {
-            hands: std::mem::take(&mut self.hands),
-            error_count: self.errors.len(),
-            errors: std::mem::take(&mut self.errors),
-        }
+        ParseResult::new(
+            std::mem::take(&mut self.hands),
+            std::mem::take(&mut self.diagnostics),
+            std::mem::take(&mut self.summaries),
+        )
     }
 
     /// Procesa una línea individual según el estado actual.
-    fn process_line(&mut self, line: &str) {
-        let line = line.trim();
+    fn process_line(&mut self, line: &[u8]) {
+        self.line_no += 1;
+        let line = trim_line(line);
 
         // Líneas vacías indican fin de mano
         if line.is_empty() {
@@ -75,28 +263,46 @@ impl WinamaxParser {
             return;
         }
 
+        // Resúmenes de torneo: no son manos, se acumulan aparte
+        if starts_with_bytes(line, SUMMARY_HEADER.as_bytes()) {
+            self.start_summary(&text(line));
+            return;
+        }
+
         // Detectar transiciones de estado por prefijos
-        if line.starts_with("Winamax Poker - ") {
+        if starts_with_bytes(line, tokens::WINAMAX_POKER) {
+            self.finalize_summary();
             self.start_new_hand(line);
             return;
         }
 
-        if line.starts_with("Table: ") {
+        if let Some(summary) = self.current_summary.as_mut() {
+            summary.parse_line(&text(line));
+            return;
+        }
+
+        if self.state == ParserState::Header {
+            self.detect_locale(line);
+        }
+        let grammar = self.grammar();
+
+        if starts_with_bytes(line, grammar.table) {
             self.parse_table_info(line);
             return;
         }
 
-        if line.starts_with("Seat ") && line.contains(':') && !line.contains("won") {
+        // Las líneas "Seat X: ..." del resumen no son asientos
+        if self.state == ParserState::Seats && starts_with_bytes(line, grammar.seat) {
             self.parse_seat_info(line);
             return;
         }
 
-        if line.starts_with("*** ") {
+        if starts_with_bytes(line, b"*** ") {
             self.handle_section_marker(line);
             return;
         }
 
-        if line.starts_with("Dealt to ") {
+        if starts_with_bytes(line, grammar.dealt_to) {
             self.parse_dealt_cards(line);
             return;
         }
@@ -110,12 +316,33 @@ impl WinamaxParser {
             | ParserState::River
             | ParserState::Showdown => self.parse_action(line),
             ParserState::Summary => self.parse_summary_line(line),
-            _ => {}
+            // Fuera de una mano la línea no afecta a ninguna mano importada
+            ParserState::Initial => self.report_with(
+                DiagnosticKind::UnknownLine,
+                Severity::Info,
+                format!("Line outside a hand: {}", text(line)),
+            ),
+            ParserState::Header | ParserState::Seats => self.report(
+                DiagnosticKind::UnknownLine,
+                format!("Unrecognized line: {}", text(line)),
+            ),
+        }
+    }
+
+    /// Detecta el idioma del archivo si aún no se conoce.
+    fn detect_locale(&mut self, line: &[u8]) {
+        if self.locale.is_none() {
+            self.locale = WinamaxLocale::detect(line);
         }
     }
 
+    /// Gramática del idioma actual (inglés mientras no se detecte).
+    fn grammar(&self) -> &'static WinamaxGrammar {
+        self.locale.unwrap_or_default().grammar()
+    }
+
     /// Inicia el parsing de una nueva mano.
-    fn start_new_hand(&mut self, line: &str) {
+    fn start_new_hand(&mut self, line: &[u8]) {
         // Si hay una mano anterior sin finalizar, guardarla
         if !self.current_hand.hand_id.is_empty() {
             self.finalize_hand();
@@ -124,550 +351,534 @@ impl WinamaxParser {
         self.current_hand = ParsedHand::default();
         self.current_street = Street::Preflop;
         self.state = ParserState::Header;
+        self.detect_locale(line);
 
         // Detectar tipo de juego
-        if line.contains("CashGame") {
+        let grammar = self.grammar();
+        if find_bytes(line, tokens::CASHGAME).is_some() {
             self.current_hand.game_type = GameType::CashGame;
-        } else if line.contains("Tournament") {
+        } else if find_bytes(line, grammar.tournament).is_some() {
             self.current_hand.game_type = GameType::Tournament;
+            self.current_hand.tournament = Some(Self::parse_tournament_header(grammar, line));
         }
 
+        // Lo que sigue al HandId (el nombre del torneo puede contener "Omaha")
+        let game_from = find_bytes(line, b"HandId: #").unwrap_or(0);
+        let game = &line[game_from..];
+        self.current_hand.variant = GameVariant::from_header(&text(game));
+
         // Extraer HandId: #XXXX-XXX-XXXXXXXXXX
-        if let Some(start) = line.find("HandId: #") {
-            let start = start + 9; // Saltar "HandId: #"
-            if let Some(end) = line[start..].find(" - ") {
-                self.current_hand.hand_id = line[start..start + end].to_string();
+        if let Some(id) = game.strip_prefix(b"HandId: #") {
+            if let Some(end) = find_bytes(id, b" - ") {
+                self.current_hand.hand_id = text(&id[..end]).into_owned();
             }
         }
 
-        // Extraer blinds: (0.01€/0.02€)
-        if let Some(start) = line.find('(') {
-            if let Some(end) = line[start..].find(')') {
-                let blinds_str = &line[start + 1..start + end];
-                self.parse_blinds_from_header(blinds_str);
+        // Extraer blinds: (0.01€/0.02€), en torneo (10/20) o (25/100/200)
+        if let Some(start) = game.iter().position(|&b| b == b'(') {
+            if let Some(end) = game[start..].iter().position(|&b| b == b')') {
+                self.parse_blinds_from_header(&game[start + 1..start + end]);
             }
         }
 
         // Extraer timestamp: 2025/12/15 14:02:20 UTC
-        if let Some(idx) = line.rfind(" - ") {
-            let timestamp_part = &line[idx + 3..];
-            self.current_hand.timestamp = timestamp_part.trim().to_string();
+        if let Some(idx) = rfind_bytes(line, b" - ") {
+            self.current_hand.timestamp = text(trim_line(&line[idx + 3..])).into_owned();
+        }
+    }
+
+    /// Parsea los datos de torneo de la cabecera.
+    ///
+    /// Formato: `Tournament "Expresso" buyIn: 0.93€ + 0.07€ level: 1 - HandId: ...`
+    fn parse_tournament_header(grammar: &WinamaxGrammar, line: &[u8]) -> TournamentInfo {
+        let name = find_bytes(line, grammar.tournament)
+            .map(|idx| &line[idx + grammar.tournament.len()..])
+            .and_then(|rest| rest.iter().position(|&b| b == b'"').map(|end| &rest[..end]))
+            .map(|name| text(name).into_owned())
+            .unwrap_or_default();
+
+        let (buy_in_cents, fee_cents) = find_bytes(line, b"buyIn: ")
+            .map(|idx| {
+                let rest = &line[idx + 7..];
+                let end = find_bytes(rest, grammar.level)
+                    .or_else(|| find_bytes(rest, b" - "))
+                    .unwrap_or(rest.len());
+                parse_buy_in(&text(&rest[..end]))
+            })
+            .unwrap_or((0, 0));
+
+        let level = find_bytes(line, grammar.level)
+            .map(|idx| parse_digits(&line[idx + grammar.level.len()..]))
+            .unwrap_or(0);
+
+        TournamentInfo {
+            tournament_id: String::new(),
+            format: TournamentFormat::from_name(&name),
+            name,
+            buy_in_cents,
+            fee_cents,
+            level,
+            bb_ante: false,
         }
     }
 
     /// Parsea información de blinds desde la cabecera.
-    fn parse_blinds_from_header(&mut self, blinds_str: &str) {
-        // Formato: "0.01€/0.02€" o "1€/2€"
-        let parts: Vec<&str> = blinds_str.split('/').collect();
-        if parts.len() == 2 {
-            self.current_hand.small_blind_cents = self.parse_amount(parts[0]);
-            self.current_hand.big_blind_cents = self.parse_amount(parts[1]);
-
-            // Detectar play money
-            if blinds_str.contains('v') || blinds_str.contains("chips") {
-                self.current_hand.is_play_money = true;
-            }
+    fn parse_blinds_from_header(&mut self, blinds: &[u8]) {
+        // Formato: "0.01€/0.02€", "1€/2€" o en torneo "ante/sb/bb" en fichas
+        let mut parts = blinds.split(|&b| b == b'/');
+        let (ante, sb, bb) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(sb), Some(bb), None, None) => (None, sb, bb),
+            (Some(ante), Some(sb), Some(bb), None) => (Some(ante), sb, bb),
+            _ => return,
+        };
+
+        self.current_hand.small_blind_cents = self.parse_amount(sb);
+        self.current_hand.big_blind_cents = self.parse_amount(bb);
+        if let Some(ante) = ante {
+            self.current_hand.ante_cents = self.parse_amount(ante);
+        }
+
+        // Detectar play money
+        if blinds.contains(&b'v') || find_bytes(blinds, b"chips").is_some() {
+            self.current_hand.is_play_money = true;
         }
     }
 
     /// Parsea información de la mesa.
-    fn parse_table_info(&mut self, line: &str) {
+    fn parse_table_info(&mut self, line: &[u8]) {
         // Formato: Table: 'Nice 09' 5-max (real money) Seat #3 is the button
         self.state = ParserState::Seats;
 
         // Extraer nombre de mesa
-        if let Some(start) = line.find('\'') {
-            if let Some(end) = line[start + 1..].find('\'') {
-                self.current_hand.table_name = line[start + 1..start + 1 + end].to_string();
+        if let Some(start) = line.iter().position(|&b| b == b'\'') {
+            let name = &line[start + 1..];
+            if let Some(end) = name.iter().position(|&b| b == b'\'') {
+                self.current_hand.table_name = text(&name[..end]).into_owned();
             }
         }
 
-        // Extraer max players (5-max, 6-max)
-        if line.contains("5-max") {
-            self.current_hand.max_players = 5;
-        } else if line.contains("6-max") {
-            self.current_hand.max_players = 6;
+        // ID del torneo: 'Expresso(654321987)#0'
+        if let Some(tournament) = self.current_hand.tournament.as_mut() {
+            if let Some((_, id)) = split_name_and_id(&self.current_hand.table_name) {
+                tournament.tournament_id = id.to_string();
+            }
         }
 
-        // Extraer posición del botón: Seat #X is the button
-        if let Some(idx) = line.find("Seat #") {
-            let start = idx + 6;
-            if let Some(end_idx) = line[start..].find(' ') {
-                if let Ok(seat) = line[start..start + end_idx].parse::<u8>() {
-                    self.current_hand.button_seat = seat;
+        // Extraer max players (3-max, 5-max, 6-max, 9-max...)
+        if let Some(idx) = find_bytes(line, b"-max") {
+            let digits_start = line[..idx]
+                .iter()
+                .rposition(|b| !b.is_ascii_digit())
+                .map_or(0, |i| i + 1);
+            if digits_start < idx {
+                if let Ok(max_players) = u8::try_from(parse_digits(&line[digits_start..idx])) {
+                    self.current_hand.max_players = max_players;
                 }
             }
         }
+
+        // Extraer posición del botón: Seat #X is the button
+        let button_seat = self.grammar().button_seat;
+        if let Some(idx) = find_bytes(line, button_seat) {
+            if let Ok(seat) = u8::try_from(parse_digits(&line[idx + button_seat.len()..])) {
+                self.current_hand.button_seat = seat;
+            }
+        }
     }
 
     /// Parsea información de un asiento/jugador.
-    fn parse_seat_info(&mut self, line: &str) {
+    fn parse_seat_info(&mut self, line: &[u8]) {
         // Formato: Seat 1: captainogue (1.76€)
-        let parts: Vec<&str> = line.splitn(2, ": ").collect();
-        if parts.len() != 2 {
+        // Torneo: Seat 1: thesmoy (20000) o Seat 1: thesmoy (20000, 0.50€ bounty)
+        let Some(colon) = find_bytes(line, b": ") else {
             return;
-        }
+        };
 
         // Extraer número de asiento
-        let seat_str = parts[0].trim_start_matches("Seat ");
-        let seat: u8 = match seat_str.parse() {
-            Ok(s) => s,
-            Err(_) => return,
+        let seat_str = &line[self.grammar().seat.len()..colon];
+        if seat_str.is_empty() || !seat_str.iter().all(u8::is_ascii_digit) {
+            return;
+        }
+        let Ok(seat) = u8::try_from(parse_digits(seat_str)) else {
+            return;
         };
 
         // Separar nombre y stack
-        let rest = parts[1];
-        if let Some(paren_start) = rest.rfind('(') {
-            let name = rest[..paren_start].trim().to_string();
-            let stack_str = &rest[paren_start + 1..rest.len() - 1];
-            let stack_cents = self.parse_amount(stack_str);
-            let is_hero = name == HERO_NAME;
-
-            let player = Player {
-                name,
-                seat,
-                stack_cents,
-                position: None, // Se calcula después
-                hole_cards: None,
-                is_hero,
-            };
-
-            self.current_hand.players.push(player);
+        let rest = &line[colon + 2..];
+        let Some(paren_start) = rest.iter().rposition(|&b| b == b'(') else {
+            return;
+        };
+        let stack = &rest[paren_start + 1..rest.len().saturating_sub(1).max(paren_start + 1)];
+        let stack = find_bytes(stack, b", ").map_or(stack, |end| &stack[..end]);
+        if !stack.iter().any(u8::is_ascii_digit) {
+            self.report(
+                DiagnosticKind::BadAmount,
+                format!("Invalid stack: {}", text(line)),
+            );
         }
+        let stack_cents = self.parse_amount(stack);
+
+        let name = self.names.intern(trim_line(&rest[..paren_start]));
+        let is_hero = self.heroes.is_hero(Site::Winamax, &name);
+
+        self.current_hand.players.push(Player {
+            name,
+            seat,
+            stack_cents,
+            position: None, // Se calcula después
+            hole_cards: None,
+            is_hero,
+        });
     }
 
     /// Maneja marcadores de sección (*** XXX ***).
-    fn handle_section_marker(&mut self, line: &str) {
-        if line.contains("ANTE/BLINDS") {
+    fn handle_section_marker(&mut self, line: &[u8]) {
+        let grammar = self.grammar();
+        let has = |token: &[u8]| find_bytes(line, token).is_some();
+
+        if has(grammar.ante_blinds) {
             self.state = ParserState::Blinds;
-            self.calculate_positions();
-        } else if line.contains("PRE-FLOP") {
+        } else if has(grammar.pre_flop) {
             self.state = ParserState::Preflop;
             self.current_street = Street::Preflop;
-        } else if line.contains("FLOP") && !line.contains("PRE") {
+        } else if has(grammar.flop) {
             self.state = ParserState::Flop;
             self.current_street = Street::Flop;
-            self.parse_board_cards(line);
-        } else if line.contains("TURN") {
+            self.parse_board_cards(line, false);
+        } else if has(grammar.turn) {
             self.state = ParserState::Turn;
             self.current_street = Street::Turn;
-            self.parse_board_cards(line);
-        } else if line.contains("RIVER") {
+            self.parse_board_cards(line, true);
+        } else if has(grammar.river) {
             self.state = ParserState::River;
             self.current_street = Street::River;
-            self.parse_board_cards(line);
-        } else if line.contains("SHOW DOWN") {
+            self.parse_board_cards(line, true);
+        } else if has(grammar.show_down) {
             self.state = ParserState::Showdown;
-        } else if line.contains("SUMMARY") {
+        } else if has(grammar.summary) {
             self.state = ParserState::Summary;
         }
     }
 
-    /// Calcula las posiciones de los jugadores basado en el botón.
+    /// Calcula las posiciones de los jugadores basado en el botón, el tamaño
+    /// de la mesa y los asientos en juego (con ciega, ante o alguna acción),
+    /// así que se llama al cerrar la mano.
     fn calculate_positions(&mut self) {
-        let button_seat = self.current_hand.button_seat;
-        let num_players = self.current_hand.players.len();
+        self.current_hand.assign_positions();
+    }
 
-        if num_players == 0 {
+    /// Parsea las cartas repartidas al dueño del historial.
+    ///
+    /// Winamax solo escribe `Dealt to` para la cuenta del historial: es el
+    /// héroe si no hay nicknames configurados o si es uno de ellos.
+    fn parse_dealt_cards(&mut self, line: &[u8]) {
+        // Formato: Dealt to thesmoy [8d 8s] (4 o 5 cartas en Omaha)
+        let Some(cards) = self.parse_hole_cards(line) else {
             return;
-        }
-
-        // Ordenar asientos a partir del botón
-        let mut seats: Vec<u8> = self.current_hand.players.iter().map(|p| p.seat).collect();
-        seats.sort();
-
-        // Encontrar índice del botón en la lista ordenada
-        let btn_idx = seats.iter().position(|&s| s == button_seat).unwrap_or(0);
-
-        // Asignar posiciones en orden desde el botón
-        let positions_6max = [
-            Position::Button,
-            Position::SmallBlind,
-            Position::BigBlind,
-            Position::UTG,
-            Position::MP,
-            Position::CO,
-        ];
-
-        let positions_5max = [
-            Position::Button,
-            Position::SmallBlind,
-            Position::BigBlind,
-            Position::MP,
-            Position::CO,
-        ];
-
-        let positions: &[Position] = if num_players <= 5 {
-            &positions_5max[..num_players.min(5)]
-        } else {
-            &positions_6max[..num_players.min(6)]
         };
-
-        for (i, pos) in positions.iter().enumerate() {
-            let seat_idx = (btn_idx + i) % num_players;
-            let target_seat = seats[seat_idx];
-
-            if let Some(player) = self
-                .current_hand
-                .players
-                .iter_mut()
-                .find(|p| p.seat == target_seat)
-            {
-                player.position = Some(*pos);
-            }
+        let rest = &line[self.grammar().dealt_to.len()..];
+        let name = text(trim_line(
+            find_bytes(rest, b" [").map_or(rest, |end| &rest[..end]),
+        ));
+
+        let Some(player) = self
+            .current_hand
+            .players
+            .iter_mut()
+            .find(|p| *p.name == *name)
+        else {
+            return;
+        };
+        if self.heroes.is_dealt_hero(Site::Winamax, &player.name) {
+            player.is_hero = true;
+        }
+        if player.is_hero {
+            self.current_hand.hero_cards = Some(cards.clone());
         }
+        player.hole_cards = Some(cards);
     }
 
-    /// Parsea las cartas repartidas al héroe.
-    fn parse_dealt_cards(&mut self, line: &str) {
-        // Formato: Dealt to thesmoy [8d 8s]
-        if let Some(bracket_start) = line.find('[') {
-            if let Some(bracket_end) = line.find(']') {
-                let cards_str = &line[bracket_start + 1..bracket_end];
-                let cards: Vec<&str> = cards_str.split_whitespace().collect();
+    /// Extrae las cartas propias del primer grupo entre corchetes.
+    ///
+    /// Solo se aceptan si el número de cartas coincide con la variante de la
+    /// mano (2 en Hold'em, 4 en PLO4, 5 en PLO5).
+    fn parse_hole_cards(&mut self, line: &[u8]) -> Option<HoleCards> {
+        let start = line.iter().position(|&b| b == b'[')? + 1;
+        let end = start + line[start..].iter().position(|&b| b == b']')?;
+        let cards = line[start..end]
+            .split(|&b| b == b' ')
+            .filter(|token| !token.is_empty())
+            .map(|token| self.parse_card(token))
+            .collect::<Option<HoleCards>>()?;
+
+        (cards.len() == self.current_hand.variant.hole_card_count()).then_some(cards)
+    }
 
-                if cards.len() == 2 {
-                    if let (Some(c1), Some(c2)) = (Card::parse(cards[0]), Card::parse(cards[1])) {
-                        self.current_hand.hero_cards = Some([c1.clone(), c2.clone()]);
+    /// Parsea cartas del board desde marcadores de sección.
+    ///
+    /// Formato FLOP: `*** FLOP *** [6d Qc 7s]`; en TURN y RIVER
+    /// (`*** TURN *** [6d Qc 7s][2c]`) solo son nuevas las del último corchete.
+    fn parse_board_cards(&mut self, line: &[u8], last_group_only: bool) {
+        let groups = line.split(|&b| b == b'[').skip(1).filter_map(|group| {
+            group
+                .iter()
+                .position(|&b| b == b']')
+                .map(|end| &group[..end])
+        });
+
+        let new_cards: Vec<&[u8]> = if last_group_only {
+            groups.last().into_iter().collect()
+        } else {
+            groups.collect()
+        };
 
-                        // Asignar cartas al jugador héroe
-                        if let Some(hero) = self.current_hand.players.iter_mut().find(|p| p.is_hero)
-                        {
-                            hero.hole_cards = Some([c1, c2]);
-                        }
-                    }
+        for group in new_cards {
+            for token in group.split(|&b| b == b' ').filter(|t| !t.is_empty()) {
+                if let Some(card) = self.parse_card(token) {
+                    self.current_hand.board.push(card);
                 }
             }
         }
     }
 
-    /// Parsea cartas del board desde marcadores de sección.
-    fn parse_board_cards(&mut self, line: &str) {
-        // Formato FLOP: *** FLOP *** [6d Qc 7s]
-        // Formato TURN: *** TURN *** [6d Qc 7s][2c]
-        // Formato RIVER: *** RIVER *** [6d Qc 7s 2c][Kh]
-
-        // Buscar todos los grupos de cartas entre corchetes
-        let mut i = 0;
-        while let Some(start) = line[i..].find('[') {
-            let abs_start = i + start + 1;
-            if let Some(end) = line[abs_start..].find(']') {
-                let cards_str = &line[abs_start..abs_start + end];
-
-                // Solo agregar cartas nuevas (las del último corchete en TURN/RIVER)
-                if line.contains("TURN") || line.contains("RIVER") {
-                    // En TURN/RIVER, solo tomar el último corchete
-                    let is_last = line[abs_start + end..].find('[').is_none();
-                    if is_last {
-                        for card_str in cards_str.split_whitespace() {
-                            if let Some(card) = Card::parse(card_str) {
-                                self.current_hand.board.push(card);
-                            }
-                        }
-                    }
-                } else {
-                    // En FLOP, tomar todas las cartas
-                    for card_str in cards_str.split_whitespace() {
-                        if let Some(card) = Card::parse(card_str) {
-                            self.current_hand.board.push(card);
-                        }
-                    }
-                }
-
-                i = abs_start + end + 1;
-            } else {
-                break;
-            }
+    /// Parsea una carta, registrando un diagnóstico si no es válida.
+    fn parse_card(&mut self, token: &[u8]) -> Option<Card> {
+        let card = card_from_bytes(token);
+        if card.is_none() {
+            self.report(
+                DiagnosticKind::BadCard,
+                format!("Invalid card: {}", text(token)),
+            );
         }
+        card
     }
 
     /// Parsea acciones de ciegas.
-    fn parse_blind_action(&mut self, line: &str) {
-        if line.contains("posts small blind") || line.contains("poste la petite blinde") {
-            self.parse_blind(line, ActionType::PostSmallBlind);
-        } else if line.contains("posts big blind") || line.contains("poste la grosse blinde") {
-            self.parse_blind(line, ActionType::PostBigBlind);
-        } else if line.contains("posts ante") || line.contains("poste l'ante") {
-            self.parse_blind(line, ActionType::PostAnte);
-        }
-    }
+    fn parse_blind_action(&mut self, line: &[u8]) {
+        let grammar = self.grammar();
+        let Some(action_type) = grammar
+            .blind_actions()
+            .iter()
+            .find(|(token, _)| find_bytes(line, token).is_some())
+            .map(|(_, action_type)| *action_type)
+        else {
+            self.report(
+                DiagnosticKind::UnknownLine,
+                format!("Unrecognized blind action: {}", text(line)),
+            );
+            return;
+        };
 
-    /// Parsea una acción de ciega específica.
-    fn parse_blind(&mut self, line: &str, action_type: ActionType) {
-        let player_name = self.extract_player_name(line);
-        let amount = self.extract_amount_from_line(line);
+        let (player_name, rest) = self.split_player(line);
+        let amount = self.parse_first_amount(rest);
 
-        let action = Action {
+        self.current_hand.actions.push(Action {
             player_name,
             action_type,
             amount_cents: Some(amount),
-            is_all_in: line.contains("all-in"),
+            is_all_in: find_bytes(line, grammar.all_in).is_some(),
             street: Street::Preflop,
-        };
-
-        self.current_hand.actions.push(action);
+        });
     }
 
     /// Parsea una acción de juego.
-    fn parse_action(&mut self, line: &str) {
-        let player_name = self.extract_player_name(line);
+    fn parse_action(&mut self, line: &[u8]) {
+        let (player_name, rest) = self.split_player(line);
         if player_name.is_empty() {
-            // Puede ser línea de collected o shows
-            if line.contains("collected") {
-                self.parse_collect(line);
-            } else if line.contains("shows") {
-                self.parse_show(line);
-            }
+            self.report(
+                DiagnosticKind::UnknownLine,
+                format!("Unrecognized line: {}", text(line)),
+            );
             return;
         }
 
-        let is_all_in = line.contains("all-in");
+        let grammar = self.grammar();
+        let is_all_in = find_bytes(rest, grammar.all_in).is_some();
 
-        // Detectar tipo de acción (multi-idioma)
-        let (action_type, amount) = if line.contains(" folds") || line.contains(" passe") {
+        // Detectar tipo de acción en el idioma del archivo
+        let keyword = |word: &[u8]| starts_with_bytes(rest, word);
+        let (action_type, amount) = if keyword(grammar.folds) {
             (ActionType::Fold, None)
-        } else if line.contains(" checks") || line.contains(" parole") {
+        } else if keyword(grammar.checks) {
             (ActionType::Check, None)
-        } else if line.contains(" calls ") || line.contains(" suit ") {
-            let amt = self.extract_amount_from_line(line);
-            (ActionType::Call, Some(amt))
-        } else if line.contains(" bets ") || line.contains(" mise ") {
-            let amt = self.extract_amount_from_line(line);
-            (ActionType::Bet, Some(amt))
-        } else if line.contains(" raises ") || line.contains(" relance ") {
-            // Para raises, extraer el monto total (después de "to" o "à")
-            let amt = self.extract_raise_amount(line);
-            (ActionType::Raise, Some(amt))
-        } else if line.contains("collected") {
-            self.parse_collect(line);
+        } else if keyword(grammar.calls) {
+            (ActionType::Call, Some(self.parse_first_amount(rest)))
+        } else if keyword(grammar.bets) {
+            (ActionType::Bet, Some(self.parse_first_amount(rest)))
+        } else if keyword(grammar.raises) {
+            // Para raises, el monto total de la calle (después de "to", "à" o "a")
+            (ActionType::Raise, Some(self.extract_raise_amount(rest)))
+        } else if keyword(grammar.collected) {
+            self.parse_collect(player_name, rest);
             return;
-        } else if line.contains("shows") {
-            self.parse_show(line);
+        } else if keyword(grammar.shows) {
+            self.parse_show(player_name, rest);
             return;
         } else {
+            self.report(
+                DiagnosticKind::UnknownLine,
+                format!("Unrecognized action: {}", text(line)),
+            );
             return;
         };
 
-        let action = Action {
+        self.current_hand.actions.push(Action {
             player_name,
             action_type,
             amount_cents: amount,
             is_all_in,
             street: self.current_street,
-        };
-
-        self.current_hand.actions.push(action);
+        });
     }
 
-    /// Parsea una acción de mostrar cartas.
-    fn parse_show(&mut self, line: &str) {
-        let player_name = self.extract_player_name_before_keyword(line, "shows");
-        if player_name.is_empty() {
-            return;
-        }
-
-        // Extraer cartas mostradas
-        if let Some(bracket_start) = line.find('[') {
-            if let Some(bracket_end) = line.find(']') {
-                let cards_str = &line[bracket_start + 1..bracket_end];
-                let cards: Vec<&str> = cards_str.split_whitespace().collect();
-
-                if cards.len() == 2 {
-                    if let (Some(c1), Some(c2)) = (Card::parse(cards[0]), Card::parse(cards[1])) {
-                        // Asignar cartas al jugador correspondiente
-                        if let Some(player) = self
-                            .current_hand
-                            .players
-                            .iter_mut()
-                            .find(|p| p.name == player_name)
-                        {
-                            player.hole_cards = Some([c1, c2]);
-                        }
-                    }
-                }
+    /// Registra las cartas mostradas por un jugador.
+    fn parse_show(&mut self, player_name: Arc<str>, rest: &[u8]) {
+        // Asignar las cartas al jugador correspondiente
+        if let Some(cards) = self.parse_hole_cards(rest) {
+            if let Some(player) = self
+                .current_hand
+                .players
+                .iter_mut()
+                .find(|p| p.name == player_name)
+            {
+                player.hole_cards = Some(cards);
             }
         }
 
-        let action = Action {
+        self.current_hand.actions.push(Action {
             player_name,
             action_type: ActionType::Show,
             amount_cents: None,
             is_all_in: false,
             street: self.current_street,
-        };
-
-        self.current_hand.actions.push(action);
+        });
     }
 
-    /// Parsea una acción de recolectar bote.
-    fn parse_collect(&mut self, line: &str) {
-        let player_name = self.extract_player_name_before_keyword(line, "collected");
-        if player_name.is_empty() {
-            return;
-        }
-
-        let amount = self.extract_amount_from_line(line);
+    /// Registra un cobro del bote.
+    fn parse_collect(&mut self, player_name: Arc<str>, rest: &[u8]) {
+        let amount = self.parse_first_amount(rest);
 
         // Agregar a ganadores
         self.current_hand
             .pot
             .winners
-            .push((player_name.clone(), amount));
+            .push((Arc::clone(&player_name), amount));
 
-        let action = Action {
+        self.current_hand.actions.push(Action {
             player_name,
             action_type: ActionType::Collect,
             amount_cents: Some(amount),
             is_all_in: false,
             street: self.current_street,
-        };
-
-        self.current_hand.actions.push(action);
+        });
     }
 
     /// Parsea una línea de la sección SUMMARY.
-    fn parse_summary_line(&mut self, line: &str) {
-        if line.starts_with("Total pot") {
-            // Formato: Total pot 0.91€ | Rake 0.03€
-            // O: Total pot 0.08€ | No rake
-            let parts: Vec<&str> = line.split('|').collect();
-
-            if !parts.is_empty() {
-                // Extraer total pot
-                let pot_part = parts[0];
-                if let Some(idx) = pot_part.find("Total pot") {
-                    let amount_str = pot_part[idx + 9..].trim();
-                    self.current_hand.pot.total_cents = self.parse_amount(amount_str);
-                }
-            }
+    fn parse_summary_line(&mut self, line: &[u8]) {
+        // Formato: Total pot 0.91€ | Rake 0.03€
+        // O: Total pot 0.08€ | No rake
+        // Las líneas "Seat X: player won/showed..." se ignoran (ya tenemos
+        // la info de collected) y el board ya se parseó en los marcadores
+        let grammar = self.grammar();
+        let Some(rest) = line.strip_prefix(grammar.total_pot) else {
+            return;
+        };
 
-            if parts.len() > 1 {
-                let rake_part = parts[1].trim();
-                if rake_part.starts_with("Rake") {
-                    let amount_str = rake_part.trim_start_matches("Rake").trim();
-                    self.current_hand.pot.rake_cents = self.parse_amount(amount_str);
-                }
-            }
-        } else if line.starts_with("Board:") {
-            // Board ya parseado desde marcadores de sección, pero validar
-            // Formato: Board: [6d Qc 7s 2c]
-        }
-        // Las líneas "Seat X: player won/showed..." se ignoran (ya tenemos info de collected)
-    }
-
-    /// Extrae el nombre del jugador desde el inicio de una línea de acción.
-    fn extract_player_name(&self, line: &str) -> String {
-        // El nombre termina en el primer espacio seguido de una palabra clave de acción
-        let keywords = [
-            " folds",
-            " checks",
-            " calls ",
-            " bets ",
-            " raises ",
-            " passe",
-            " parole",
-            " suit ",
-            " mise ",
-            " relance ",
-            " posts ",
-            " poste ",
-            " collected",
-            " shows",
-        ];
-
-        for keyword in keywords {
-            if let Some(idx) = line.find(keyword) {
-                return line[..idx].to_string();
+        let mut parts = rest.split(|&b| b == b'|');
+        if let Some(pot) = parts.next() {
+            self.current_hand.pot.total_cents = self.parse_amount(pot);
+        }
+        if let Some(rake) = parts.next().map(trim_line) {
+            if let Some(amount) = rake.strip_prefix(grammar.rake) {
+                self.current_hand.pot.rake_cents = self.parse_amount(amount);
             }
         }
-
-        String::new()
     }
 
-    /// Extrae el nombre del jugador antes de una palabra clave específica.
-    fn extract_player_name_before_keyword(&self, line: &str, keyword: &str) -> String {
-        if let Some(idx) = line.find(keyword) {
-            return line[..idx].trim().to_string();
+    /// Separa el nombre del jugador del resto de la línea de acción.
+    ///
+    /// Se busca el jugador sentado más largo cuyo nombre sea prefijo de la
+    /// línea; si no hay ninguno, el nombre termina en la primera palabra clave
+    /// de acción. Devuelve un nombre vacío si no se reconoce la línea.
+    fn split_player<'a>(&mut self, line: &'a [u8]) -> (Arc<str>, &'a [u8]) {
+        let seated = self
+            .current_hand
+            .players
+            .iter()
+            .filter(|p| {
+                line.len() > p.name.len()
+                    && starts_with_bytes(line, p.name.as_bytes())
+                    && line[p.name.len()] == b' '
+            })
+            .max_by_key(|p| p.name.len());
+        if let Some(player) = seated {
+            return (Arc::clone(&player.name), &line[player.name.len() + 1..]);
         }
-        String::new()
-    }
-
-    /// Extrae el monto de una línea de acción.
-    fn extract_amount_from_line(&self, line: &str) -> i64 {
-        // Buscar el primer número después de una palabra clave de cantidad
-        let keywords = [
-            "calls ",
-            "bets ",
-            "raises ",
-            "blind ",
-            "collected ",
-            "suit ",
-            "mise ",
-            "relance ",
-            "blinde ",
-        ];
 
-        for keyword in keywords {
-            if let Some(idx) = line.find(keyword) {
-                let start = idx + keyword.len();
-                let rest = &line[start..];
-                return self.parse_first_amount(rest);
-            }
+        match self
+            .grammar()
+            .action_keywords()
+            .iter()
+            .find_map(|keyword| find_word(line, keyword))
+        {
+            Some(end) if end > 0 => (self.names.intern(&line[..end]), &line[end + 1..]),
+            _ => (Arc::from(""), line),
         }
-
-        0
     }
 
-    /// Extrae el monto total de un raise (después de "to" o "à").
-    fn extract_raise_amount(&self, line: &str) -> i64 {
+    /// Extrae el monto total de un raise (después de "to", "à" o "a").
+    fn extract_raise_amount(&mut self, rest: &[u8]) -> i64 {
         // Formato: "raises 0.04€ to 0.06€" -> extraer 0.06€
-        // Formato FR: "relance 0.04€ à 0.06€" -> extraer 0.06€
-        if let Some(idx) = line.find(" to ") {
-            let rest = &line[idx + 4..];
-            return self.parse_first_amount(rest);
-        }
-        if let Some(idx) = line.find(" à ") {
-            let rest = &line[idx + 4..];
-            return self.parse_first_amount(rest);
+        // Formato FR: "relance 0,04€ à 0,06€" -> extraer 0,06€
+        let separator = self.grammar().raise_to;
+        match find_bytes(rest, separator).map(|idx| idx + separator.len()) {
+            Some(start) => self.parse_first_amount(&rest[start..]),
+            // Fallback: extraer cualquier monto
+            None => self.parse_first_amount(rest),
         }
-
-        // Fallback: extraer cualquier monto
-        self.extract_amount_from_line(line)
     }
 
-    /// Parsea el primer monto encontrado en un string.
-    fn parse_first_amount(&self, s: &str) -> i64 {
-        let mut num_str = String::new();
-        let mut found_digit = false;
-
-        for c in s.chars() {
-            if c.is_ascii_digit() || c == '.' || c == ',' {
-                num_str.push(if c == ',' { '.' } else { c });
-                found_digit = true;
-            } else if found_digit {
-                break;
-            }
-        }
+    /// Parsea el primer monto encontrado en una línea.
+    fn parse_first_amount(&mut self, bytes: &[u8]) -> i64 {
+        let Some(start) = bytes.iter().position(u8::is_ascii_digit) else {
+            self.report(
+                DiagnosticKind::BadAmount,
+                format!("Missing amount: {}", text(bytes)),
+            );
+            return 0;
+        };
+        let len = bytes[start..]
+            .iter()
+            .position(|&b| !(b.is_ascii_digit() || b == b'.' || b == b','))
+            .unwrap_or(bytes.len() - start);
 
-        self.parse_amount(&num_str)
+        self.parse_amount(&bytes[start..start + len])
     }
 
-    /// Convierte un string de cantidad a centavos.
-    fn parse_amount(&self, s: &str) -> i64 {
-        // Limpiar símbolo de moneda y espacios
-        let cleaned: String = s
-            .chars()
-            .filter(|c| c.is_ascii_digit() || *c == '.' || *c == ',')
-            .map(|c| if c == ',' { '.' } else { c })
-            .collect();
-
-        if let Ok(amount) = cleaned.parse::<f64>() {
-            (amount * 100.0).round() as i64
+    /// Convierte una cantidad a centavos (fichas en torneos).
+    fn parse_amount(&self, bytes: &[u8]) -> i64 {
+        let cents = parse_amount_cents(bytes);
+        if self.current_hand.is_chips() {
+            cents / 100
         } else {
-            0
+            cents
         }
     }
 
+    /// Registra un diagnóstico en la línea y el estado actuales.
+    fn report(&mut self, kind: DiagnosticKind, message: String) {
+        self.report_with(kind, kind.severity(), message);
+    }
+
+    /// Igual que `report` con una severidad distinta a la de la categoría.
+    fn report_with(&mut self, kind: DiagnosticKind, severity: Severity, message: String) {
+        let diagnostic = ParseDiagnostic::new(kind, message)
+            .with_severity(severity)
+            .at(self.line_no, self.state)
+            .in_hand(&self.current_hand.hand_id);
+        self.diagnostics.push(diagnostic);
+    }
+
     /// Finaliza la mano actual y la agrega a la lista de manos completadas.
     fn finalize_hand(&mut self) {
         if self.current_hand.hand_id.is_empty() {
@@ -676,13 +887,65 @@ impl WinamaxParser {
 
         // Validaciones básicas
         if self.current_hand.players.is_empty() {
-            self.errors
-                .push(format!("Hand {} has no players", self.current_hand.hand_id));
+            self.report(
+                DiagnosticKind::MissingPlayers,
+                format!("Hand {} has no players", self.current_hand.hand_id),
+            );
+        }
+
+        self.resolve_antes();
+        self.calculate_positions();
+
+        if let Err(mismatch) = PotResolution::from_hand(&self.current_hand).validate() {
+            self.report(DiagnosticKind::InconsistentPot, mismatch.to_string());
         }
 
         self.hands.push(std::mem::take(&mut self.current_hand));
         self.state = ParserState::Initial;
-        self.current_hand = ParsedHand::default();
+    }
+
+    /// Completa el ante de la mano a partir de las acciones de ante.
+    ///
+    /// Si solo un jugador pone ante en una mesa de 3 o más, es un BB ante:
+    /// la ciega grande paga el ante de toda la mesa.
+    fn resolve_antes(&mut self) {
+        let hand = &mut self.current_hand;
+        let antes: Vec<i64> = hand
+            .actions
+            .iter()
+            .filter(|a| a.action_type == ActionType::PostAnte)
+            .filter_map(|a| a.amount_cents)
+            .collect();
+
+        if hand.ante_cents == 0 {
+            hand.ante_cents = antes.iter().copied().max().unwrap_or(0);
+        }
+        if let Some(tournament) = hand.tournament.as_mut() {
+            tournament.bb_ante = antes.len() == 1 && hand.players.len() > 2;
+        }
+    }
+
+    /// Inicia un resumen de torneo, cerrando la mano o resumen anterior.
+    fn start_summary(&mut self, line: &str) {
+        if !self.current_hand.hand_id.is_empty() {
+            self.finalize_hand();
+        }
+        self.finalize_summary();
+
+        self.current_summary = TournamentSummary::from_header(line);
+        if self.current_summary.is_none() {
+            self.report(
+                DiagnosticKind::InvalidSummary,
+                format!("Invalid tournament summary header: {}", line),
+            );
+        }
+    }
+
+    /// Guarda el resumen de torneo en construcción.
+    fn finalize_summary(&mut self) {
+        if let Some(summary) = self.current_summary.take() {
+            self.summaries.push(summary);
+        }
     }
 
     /// Resetea el parser para procesar un nuevo archivo.
@@ -691,23 +954,104 @@ impl WinamaxParser {
         self.current_hand = ParsedHand::default();
         self.current_street = Street::Preflop;
         self.hands.clear();
-        self.errors.clear();
+        self.diagnostics.clear();
+        self.line_no = 0;
+        self.current_summary = None;
+        self.summaries.clear();
+        self.pending.clear();
+        self.names.clear();
+        self.locale = self.forced_locale;
+    }
+}
+
+impl HandHistoryParser for WinamaxParser {
+    fn site(&self) -> Site {
+        Site::Winamax
+    }
+
+    fn parse(&mut self, content: &str) -> ParseResult {
+        WinamaxParser::parse(self, content)
+    }
+
+    fn parse_bytes(&mut self, content: &[u8]) -> ParseResult {
+        WinamaxParser::parse_bytes(self, content)
+    }
+
+    fn reset(&mut self) {
+        WinamaxParser::reset(self)
+    }
+}
+
+/// Vista de texto de un slice de bytes (sin copia si es UTF-8 válido).
+fn text(bytes: &[u8]) -> Cow<'_, str> {
+    String::from_utf8_lossy(bytes)
+}
+
+/// Quita espacios, `\r` y la marca BOM de los extremos de una línea.
+fn trim_line(line: &[u8]) -> &[u8] {
+    let line = line.strip_prefix(UTF8_BOM).unwrap_or(line);
+    let start = line
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(line.len());
+    let end = line
+        .iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map_or(start, |i| i + 1);
+    &line[start..end]
+}
+
+/// Posición del espacio que precede a la primera aparición de `word` como
+/// palabra (precedida de un espacio).
+fn find_word(line: &[u8], word: &[u8]) -> Option<usize> {
+    (1..line.len())
+        .find(|&i| line[i - 1] == b' ' && line[i..].starts_with(word))
+        .map(|i| i - 1)
+}
+
+/// Última aparición de un patrón de bytes.
+fn rfind_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .rposition(|window| window == needle)
+}
+
+/// Entero formado por los dígitos iniciales (0 si no hay).
+fn parse_digits(bytes: &[u8]) -> u32 {
+    bytes
+        .iter()
+        .take_while(|b| b.is_ascii_digit())
+        .fold(0u32, |acc, b| {
+            acc.saturating_mul(10).saturating_add((b - b'0') as u32)
+        })
+}
+
+/// Carta a partir de un token de dos bytes (`Ah`, `Td`).
+fn card_from_bytes(token: &[u8]) -> Option<Card> {
+    match token {
+        [rank, suit] => Some(Card {
+            rank: *rank as char,
+            suit: *suit as char,
+        })
+        .filter(Card::is_valid),
+        _ => None,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::Position;
 
     #[test]
     fn test_parse_amount() {
         let parser = WinamaxParser::new();
 
-        assert_eq!(parser.parse_amount("0.02€"), 2);
-        assert_eq!(parser.parse_amount("1.50€"), 150);
-        assert_eq!(parser.parse_amount("100€"), 10000);
-        assert_eq!(parser.parse_amount("0.01"), 1);
-        assert_eq!(parser.parse_amount("2,50€"), 250);
+        assert_eq!(parser.parse_amount("0.02€".as_bytes()), 2);
+        assert_eq!(parser.parse_amount("1.50€".as_bytes()), 150);
+        assert_eq!(parser.parse_amount("100€".as_bytes()), 10000);
+        assert_eq!(parser.parse_amount(b"0.01"), 1);
+        assert_eq!(parser.parse_amount("2,50€".as_bytes()), 250);
     }
 
     #[test]
@@ -779,10 +1123,89 @@ Seat 3: CucleBen (button) won 0.91€
         assert_eq!(hand.pot.total_cents, 91);
         assert_eq!(hand.pot.rake_cents, 3);
         assert_eq!(hand.pot.winners.len(), 1);
-        assert_eq!(hand.pot.winners[0].0, "CucleBen");
+        assert_eq!(&*hand.pot.winners[0].0, "CucleBen");
         assert_eq!(hand.pot.winners[0].1, 91);
     }
 
+    #[test]
+    fn test_player_names_are_shared() {
+        let content = include_str!("../tests/fixtures/winamax/locale_en.txt");
+        let hands = WinamaxParser::new().parse(content).hands;
+        assert_eq!(hands.len(), 3);
+
+        // Acciones y cobros reutilizan el nombre del jugador sentado
+        for hand in &hands {
+            let seated = |name: &Arc<str>| hand.players.iter().any(|p| Arc::ptr_eq(&p.name, name));
+            assert!(hand.actions.iter().all(|a| seated(&a.player_name)));
+            assert!(hand.pot.winners.iter().all(|(name, _)| seated(name)));
+        }
+        // y el mismo jugador comparte nombre entre manos del archivo
+        let name = |hand: &ParsedHand| {
+            Arc::clone(
+                &hand
+                    .players
+                    .iter()
+                    .find(|p| &*p.name == "thesmoy")
+                    .unwrap()
+                    .name,
+            )
+        };
+        assert!(Arc::ptr_eq(&name(&hands[0]), &name(&hands[2])));
+    }
+
+    #[test]
+    fn test_configured_heroes() {
+        let content = "Winamax Poker - CashGame - HandId: #21819158-401-1765807700 - Holdem no limit (0.01€/0.02€) - 2025/12/15 14:12:00 UTC
+Table: 'Nice 09' 5-max (real money) Seat #1 is the button
+Seat 1: smoy_alt (2€)
+Seat 2: verlan4 (2€)
+Seat 3: thesmoy (2€)
+*** ANTE/BLINDS ***
+verlan4 posts small blind 0.01€
+thesmoy posts big blind 0.02€
+Dealt to thesmoy [8d 8s]
+*** PRE-FLOP ***
+smoy_alt folds
+verlan4 folds
+thesmoy collected 0.03€ from pot
+*** SUMMARY ***
+Total pot 0.03€ | No rake
+
+";
+        let hero_names = |heroes: HeroConfig| {
+            let hand = WinamaxParser::new()
+                .with_heroes(heroes)
+                .parse(content)
+                .hands
+                .remove(0);
+            let names: Vec<String> = hand
+                .players
+                .iter()
+                .filter(|p| p.is_hero)
+                .map(|p| p.name.to_string())
+                .collect();
+            (names, hand.hero_cards.is_some())
+        };
+
+        // Sin configuración: el héroe es el de "Dealt to".
+        assert_eq!(
+            hero_names(HeroConfig::new()),
+            (vec!["thesmoy".into()], true)
+        );
+
+        // Varios alias: se marcan todos los que están sentados.
+        let heroes = HeroConfig::parse(["thesmoy", "Winamax:smoy_alt"]).unwrap();
+        assert_eq!(
+            hero_names(heroes),
+            (vec!["smoy_alt".into(), "thesmoy".into()], true)
+        );
+
+        // Un alias de otra sala no cuenta, y "Dealt to" no convierte en héroe
+        // a quien no está en la configuración.
+        let heroes = HeroConfig::parse(["PokerStars:smoy_alt", "verlan4"]).unwrap();
+        assert_eq!(hero_names(heroes), (vec!["verlan4".into()], false));
+    }
+
     #[test]
     fn test_parse_showdown_hand() {
         let content = r#"Winamax Poker - CashGame - HandId: #21819158-400-1765807600 - Holdem no limit (0.01€/0.02€) - 2025/12/15 14:10:00 UTC
@@ -824,7 +1247,7 @@ Seat 2: thesmoy (button) won 0.32€
         let hand = &result.hands[0];
 
         // Verificar que el showdown registró las cartas de ambos jugadores
-        let player1 = hand.players.iter().find(|p| p.name == "Player1").unwrap();
+        let player1 = hand.players.iter().find(|p| &*p.name == "Player1").unwrap();
         assert!(player1.hole_cards.is_some());
         let p1_cards = player1.hole_cards.as_ref().unwrap();
         assert_eq!(p1_cards[0].rank, 'Q');
@@ -833,6 +1256,437 @@ Seat 2: thesmoy (button) won 0.32€
         assert_eq!(hand.board.len(), 5);
     }
 
+    #[test]
+    fn test_incremental_feed() {
+        let content = r#"Winamax Poker - CashGame - HandId: #21819158-410-1765807700 - Holdem no limit (0.01€/0.02€) - 2025/12/15 14:11:00 UTC
+Table: 'Nice 09' 5-max (real money) Seat #1 is the button
+Seat 1: Bob calls (2€)
+Seat 2: thesmoy (2€)
+*** ANTE/BLINDS ***
+Bob calls posts small blind 0.01€
+thesmoy posts big blind 0.02€
+Dealt to thesmoy [Ah Kh]
+*** PRE-FLOP ***
+Bob calls raises 0.04€ to 0.06€
+thesmoy folds
+Bob calls collected 0.04€ from pot
+*** SUMMARY ***
+Total pot 0.04€ | No rake
+Seat 1: Bob calls (button) won 0.04€
+
+Winamax Poker - CashGame - HandId: #21819158-411-1765807760 - Holdem no limit (0.01€/0.02€) - 2025/12/15 14:12:00 UTC
+Table: 'Nice 09' 5-max (real money) Seat #2 is the button
+Seat 1: Bob calls (2.02€)
+Seat 2: thesmoy (1.98€)
+*** ANTE/BLINDS ***
+thesmoy posts small blind 0.01€
+Bob calls posts big blind 0.02€
+*** PRE-FLOP ***
+thesmoy folds
+Bob calls collected 0.02€ from pot
+*** SUMMARY ***
+Total pot 0.02€ | No rake
+"#;
+
+        let expected = WinamaxParser::new().parse(content);
+        assert_eq!(expected.hands.len(), 2);
+
+        // El nombre contiene una palabra clave: se reconoce por el asiento
+        let raise = &expected.hands[0].actions[2];
+        assert_eq!(&*raise.player_name, "Bob calls");
+        assert_eq!(raise.action_type, ActionType::Raise);
+        assert_eq!(raise.amount_cents, Some(6));
+
+        // Trozos de 7 bytes: cortan líneas y el símbolo € a mitad
+        let mut parser = WinamaxParser::new();
+        let mut streamed = Vec::new();
+        for chunk in content.as_bytes().chunks(7) {
+            parser.feed(chunk);
+            streamed.extend(parser.take_hands());
+        }
+        // La primera mano se entrega en cuanto termina su resumen
+        assert_eq!(streamed.len(), 1);
+        streamed.extend(parser.finish().hands);
+
+        let as_json = |hands: &[ParsedHand]| serde_json::to_string(hands).unwrap();
+        assert_eq!(as_json(&streamed), as_json(&expected.hands));
+
+        let from_reader = WinamaxParser::new()
+            .parse_reader(content.as_bytes())
+            .unwrap();
+        assert_eq!(as_json(&from_reader.hands), as_json(&expected.hands));
+    }
+
+    #[test]
+    fn test_parse_plo_hands() {
+        let content = r#"Winamax Poker - CashGame - HandId: #21819158-500-1765808000 - Omaha pot limit (0.05€/0.10€) - 2025/12/15 14:20:00 UTC
+Table: 'Lille 03' 6-max (real money) Seat #1 is the button
+Seat 1: thesmoy (10€)
+Seat 2: Player1 (10€)
+*** ANTE/BLINDS ***
+thesmoy posts small blind 0.05€
+Player1 posts big blind 0.10€
+Dealt to thesmoy [Ah As Kd Qd]
+*** PRE-FLOP ***
+thesmoy raises 0.20€ to 0.30€
+Player1 calls 0.20€
+*** FLOP *** [2c 7h Ad]
+Player1 checks
+thesmoy bets 0.60€
+Player1 calls 0.60€
+*** TURN *** [2c 7h Ad][9s]
+Player1 checks
+thesmoy checks
+*** RIVER *** [2c 7h Ad 9s][3c]
+Player1 checks
+thesmoy checks
+*** SHOW DOWN ***
+thesmoy shows [Ah As Kd Qd] (Three of a kind : Aces)
+Player1 shows [Jc Tc 8h 4d] (Straight : Ace to 5)
+thesmoy collected 1.75€ from pot
+*** SUMMARY ***
+Total pot 1.75€ | Rake 0.05€
+Board: [2c 7h Ad 9s 3c]
+Seat 1: thesmoy (button) won 1.75€
+
+Winamax Poker - CashGame - HandId: #21819158-501-1765808100 - Omaha5 pot limit (0.05€/0.10€) - 2025/12/15 14:21:40 UTC
+Table: 'Lille 03' 6-max (real money) Seat #2 is the button
+Seat 1: thesmoy (10€)
+Seat 2: Player1 (10€)
+*** ANTE/BLINDS ***
+Player1 posts small blind 0.05€
+thesmoy posts big blind 0.10€
+Dealt to thesmoy [Ah Kh 9c 8c 2s]
+*** PRE-FLOP ***
+Player1 folds
+thesmoy collected 0.10€ from pot
+*** SUMMARY ***
+Total pot 0.10€ | No rake
+Seat 1: thesmoy (big blind) won 0.10€
+
+"#;
+
+        let mut parser = WinamaxParser::new();
+        let result = parser.parse(content);
+        assert_eq!(result.hands.len(), 2);
+
+        let plo4 = &result.hands[0];
+        assert_eq!(plo4.variant, GameVariant::PLO4);
+        assert_eq!(plo4.hero_cards.as_ref().map(Vec::len), Some(4));
+        let villain = plo4.players.iter().find(|p| &*p.name == "Player1").unwrap();
+        let villain_cards = villain.hole_cards.as_ref().unwrap();
+        assert_eq!(villain_cards.len(), 4);
+        assert_eq!(villain_cards[3], Card::parse("4d").unwrap());
+
+        let plo5 = &result.hands[1];
+        assert_eq!(plo5.variant, GameVariant::PLO5);
+        assert_eq!(plo5.hero_cards.as_ref().map(Vec::len), Some(5));
+
+        // En Hold'em una línea con 4 cartas no es válida
+        let mut holdem = WinamaxParser::new();
+        let result = holdem.parse(&content.replacen("Omaha pot limit", "Holdem no limit", 1));
+        assert_eq!(result.hands[0].variant, GameVariant::Holdem);
+        assert!(result.hands[0].hero_cards.is_none());
+    }
+
+    #[test]
+    fn test_parse_expresso_hand() {
+        let content = r#"Winamax Poker - Tournament "Expresso" buyIn: 0.93€ + 0.07€ level: 2 - HandId: #654321987-12-1765807400 - Holdem no limit (15/30) - 2025/12/15 14:03:20 UTC
+Table: 'Expresso(654321987)#0' 3-max (real money) Seat #1 is the button
+Seat 1: thesmoy (520)
+Seat 2: Villain1 (480)
+Seat 3: Villain2 (500)
+*** ANTE/BLINDS ***
+Villain1 posts small blind 15
+Villain2 posts big blind 30
+Dealt to thesmoy [Ah Kd]
+*** PRE-FLOP ***
+thesmoy raises 30 to 60
+Villain1 folds
+Villain2 calls 30
+*** FLOP *** [2c 7d Ks]
+Villain2 checks
+thesmoy bets 60
+Villain2 folds
+thesmoy collected 135 from pot
+*** SUMMARY ***
+Total pot 135 | No rake
+Board: [2c 7d Ks]
+Seat 1: thesmoy (button) won 135
+
+"#;
+
+        let mut parser = WinamaxParser::new();
+        let result = parser.parse(content);
+        assert_eq!(result.hands.len(), 1);
+
+        let hand = &result.hands[0];
+        assert_eq!(hand.game_type, GameType::Tournament);
+        assert!(hand.is_chips());
+        assert_eq!(hand.max_players, 3);
+        assert_eq!(hand.small_blind_cents, 15);
+        assert_eq!(hand.big_blind_cents, 30);
+        assert_eq!(hand.players[0].stack_cents, 520);
+        assert_eq!(hand.pot.total_cents, 135);
+        assert_eq!(hand.pot.winners[0].1, 135);
+
+        let tournament = hand.tournament.as_ref().unwrap();
+        assert_eq!(tournament.tournament_id, "654321987");
+        assert_eq!(&*tournament.name, "Expresso");
+        assert_eq!(tournament.format, TournamentFormat::Expresso);
+        assert_eq!(tournament.buy_in_cents, 93);
+        assert_eq!(tournament.fee_cents, 7);
+        assert_eq!(tournament.level, 2);
+        assert!(!tournament.bb_ante);
+    }
+
+    #[test]
+    fn test_parse_mtt_hand_with_antes_and_summary() {
+        let content = r#"Winamax Poker - Tournament "MONSTER KO" buyIn: 2.25€ + 2.25€ + 0.50€ level: 7 - HandId: #812345678-210-1765831000 - Holdem no limit (25/100/200) - 2025/12/15 20:45:00 UTC
+Table: 'MONSTER KO(812345678)#034' 6-max (real money) Seat #1 is the button
+Seat 1: Alpha (15400, 2.25€ bounty)
+Seat 2: thesmoy (21000, 2.25€ bounty)
+Seat 3: Bravo (9800, 2.25€ bounty)
+*** ANTE/BLINDS ***
+Alpha posts ante 25
+thesmoy posts ante 25
+Bravo posts ante 25
+thesmoy posts small blind 100
+Bravo posts big blind 200
+Dealt to thesmoy [Qs Qh]
+*** PRE-FLOP ***
+Alpha folds
+thesmoy raises 400 to 600
+Bravo folds
+thesmoy collected 475 from pot
+*** SUMMARY ***
+Total pot 475 | No rake
+Seat 2: thesmoy (small blind) won 475
+
+Winamax Poker - Tournament summary : MONSTER KO(812345678)
+Player : thesmoy
+Buy-In : 2.25€ + 2.25€ + 0.50€
+Registered players : 1250
+Prizepool : 5625€
+Tournament started 2025/12/15 20:00:00 UTC
+You finished in 23rd place
+You won 18.40€ + Bounty 6.75€
+"#;
+
+        let mut parser = WinamaxParser::new();
+        let result = parser.parse(content);
+        assert_eq!(result.hands.len(), 1);
+        assert_eq!(result.error_count, 0);
+
+        let hand = &result.hands[0];
+        assert_eq!(hand.ante_cents, 25);
+        assert_eq!(hand.big_blind_cents, 200);
+        assert_eq!(hand.players[1].stack_cents, 21000);
+        let antes = hand
+            .actions
+            .iter()
+            .filter(|a| a.action_type == ActionType::PostAnte)
+            .count();
+        assert_eq!(antes, 3);
+
+        let tournament = hand.tournament.as_ref().unwrap();
+        assert_eq!(tournament.tournament_id, "812345678");
+        assert_eq!(tournament.format, TournamentFormat::MTT);
+        assert_eq!(tournament.buy_in_cents, 450);
+        assert_eq!(tournament.level, 7);
+        assert!(!tournament.bb_ante);
+
+        assert_eq!(result.summaries.len(), 1);
+        let summary = &result.summaries[0];
+        assert_eq!(summary.tournament_id, "812345678");
+        assert_eq!(summary.finish_position, Some(23));
+        assert_eq!(summary.prize_won_cents, 1840);
+    }
+
+    #[test]
+    fn test_parse_bb_ante() {
+        let content = r#"Winamax Poker - Tournament "Sit&Go 6-max" buyIn: 4.60€ + 0.40€ level: 4 - HandId: #700000001-5-1765831000 - Holdem no limit (100/200) - 2025/12/15 21:00:00 UTC
+Table: 'Sit&Go 6-max(700000001)#0' 6-max (real money) Seat #1 is the button
+Seat 1: Alpha (3000)
+Seat 2: Bravo (3000)
+Seat 3: thesmoy (3000)
+*** ANTE/BLINDS ***
+thesmoy posts ante 200
+Bravo posts small blind 100
+thesmoy posts big blind 200
+*** PRE-FLOP ***
+Alpha folds
+Bravo folds
+thesmoy collected 500 from pot
+*** SUMMARY ***
+Total pot 500 | No rake
+
+"#;
+
+        let mut parser = WinamaxParser::new();
+        let hand = parser.parse(content).hands.remove(0);
+
+        let tournament = hand.tournament.as_ref().unwrap();
+        assert_eq!(tournament.format, TournamentFormat::SNG);
+        assert!(tournament.bb_ante);
+        assert_eq!(hand.ante_cents, 200);
+    }
+
+    /// Mano sin acción con `seats` ocupados en una mesa `max`-max.
+    fn positions_for(max: u8, seats: &[u8], button: u8) -> Vec<(u8, Option<Position>)> {
+        let mut content = format!(
+            "Winamax Poker - CashGame - HandId: #1-1-1 - Holdem no limit (0.01€/0.02€) - 2025/12/15 14:02:20 UTC\n\
+             Table: 'Test' {}-max (real money) Seat #{} is the button\n",
+            max, button
+        );
+        for seat in seats {
+            content.push_str(&format!("Seat {}: p{} (2€)\n", seat, seat));
+        }
+        content.push_str("*** ANTE/BLINDS ***\n");
+
+        let mut parser = WinamaxParser::new();
+        let hand = parser.parse(&content).hands.remove(0);
+        assert_eq!(hand.max_players, max);
+        hand.players.iter().map(|p| (p.seat, p.position)).collect()
+    }
+
+    #[test]
+    fn test_positions_by_table_size() {
+        use Position::*;
+
+        // Heads-up: el botón es la ciega pequeña
+        assert_eq!(
+            positions_for(2, &[1, 2], 2),
+            vec![(1, Some(BigBlind)), (2, Some(Button))]
+        );
+
+        // 5-max: se omite UTG
+        let five: Vec<_> = positions_for(5, &[1, 2, 3, 4, 5], 1)
+            .into_iter()
+            .map(|(_, p)| p.unwrap())
+            .collect();
+        assert_eq!(five, vec![Button, SmallBlind, BigBlind, MP, CO]);
+
+        // 9-max completa
+        let nine: Vec<_> = positions_for(9, &[1, 2, 3, 4, 5, 6, 7, 8, 9], 9)
+            .into_iter()
+            .map(|(_, p)| p.unwrap())
+            .collect();
+        assert_eq!(
+            nine,
+            vec![SmallBlind, BigBlind, UTG, UTG1, UTG2, LJ, HJ, CO, Button]
+        );
+
+        // 9-max con 6 jugadores: nomenclatura full ring sin posiciones tempranas
+        let six: Vec<_> = positions_for(9, &[1, 3, 4, 6, 8, 9], 1)
+            .into_iter()
+            .map(|(_, p)| p.unwrap())
+            .collect();
+        assert_eq!(six, vec![Button, SmallBlind, BigBlind, LJ, HJ, CO]);
+
+        // 8-max completa
+        assert_eq!(
+            Position::table_order(8, 8),
+            vec![Button, SmallBlind, BigBlind, UTG, UTG1, LJ, HJ, CO]
+        );
+
+        // Botón muerto: el último asiento ocupado antes del botón
+        assert_eq!(
+            positions_for(6, &[1, 2, 4, 5], 3),
+            vec![
+                (1, Some(CO)),
+                (2, Some(Button)),
+                (4, Some(SmallBlind)),
+                (5, Some(BigBlind))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sitting_out_seat_has_no_position() {
+        use Position::*;
+
+        // Bastionkid32 está sentado pero no juega: la BB es verlan4
+        let content = "Winamax Poker - CashGame - HandId: #21819158-464-1765811143 - Holdem no limit (0.01€/0.02€) - 2025/12/15 15:05:43 UTC
+Table: 'Nice 09' 5-max (real money) Seat #4 is the button
+Seat 1: Bastionkid32 (2.23€)
+Seat 2: verlan4 (2.48€)
+Seat 3: tikabo (2.03€)
+Seat 4: D0LLIPRANE (1.87€)
+Seat 5: thesmoy (4.04€)
+*** ANTE/BLINDS ***
+thesmoy posts small blind 0.01€
+verlan4 posts big blind 0.02€
+Dealt to thesmoy [4c 8c]
+*** PRE-FLOP *** 
+tikabo folds
+D0LLIPRANE raises 0.03€ to 0.05€
+thesmoy folds
+verlan4 calls 0.03€
+*** FLOP *** [Jh Kc Kh]
+verlan4 checks
+D0LLIPRANE bets 0.06€
+verlan4 folds
+D0LLIPRANE collected 0.16€ from pot
+*** SUMMARY ***
+Total pot 0.16€ | Rake 0.01€
+Board: [Jh Kc Kh]
+Seat 4: D0LLIPRANE (button) won 0.16€
+";
+        let hand = WinamaxParser::new().parse(content).hands.remove(0);
+        let positions: Vec<_> = hand
+            .players
+            .iter()
+            .map(|p| (&*p.name, p.position))
+            .collect();
+        assert_eq!(
+            positions,
+            vec![
+                ("Bastionkid32", None),
+                ("verlan4", Some(BigBlind)),
+                ("tikabo", Some(CO)),
+                ("D0LLIPRANE", Some(Button)),
+                ("thesmoy", Some(SmallBlind)),
+            ]
+        );
+
+        // Ciega pequeña muerta: Zarzas no juega y nadie pone la SB
+        let content = "Winamax Poker - CashGame - HandId: #21819158-531-1765814229 - Holdem no limit (0.01€/0.02€) - 2025/12/15 15:57:09 UTC
+Table: 'Nice 09' 5-max (real money) Seat #3 is the button
+Seat 1: yan1n (3.61€)
+Seat 2: _Belen_ (2.06€)
+Seat 3: bielxvf (2.52€)
+Seat 4: Zarzas (0.48€)
+Seat 5: thesmoy (3.82€)
+*** ANTE/BLINDS ***
+thesmoy posts big blind 0.02€
+Dealt to thesmoy [5c Ks]
+*** PRE-FLOP *** 
+yan1n folds
+_Belen_ folds
+bielxvf folds
+thesmoy collected 0.02€ from pot
+*** SUMMARY ***
+Total pot 0.02€ | No rake
+Seat 5: thesmoy (big blind) won 0.02€
+";
+        let hand = WinamaxParser::new().parse(content).hands.remove(0);
+        let positions: Vec<_> = hand.players.iter().map(|p| p.position).collect();
+        assert_eq!(
+            positions,
+            vec![Some(MP), Some(CO), Some(Button), None, Some(BigBlind)]
+        );
+    }
+
+    #[test]
+    fn test_position_labels_roundtrip() {
+        for position in Position::table_order(9, 9) {
+            assert_eq!(Position::from_label(position.label()), Some(position));
+        }
+        assert_eq!(Position::from_label("MP"), Some(Position::MP));
+        assert_eq!(Position::from_label("EP"), None);
+    }
+
     #[test]
     fn test_card_parse() {
         assert!(Card::parse("Ah").is_some());
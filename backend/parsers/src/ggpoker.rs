@@ -0,0 +1,203 @@
+//! Parser de historiales de GGPoker.
+//!
+//! GGPoker exporta sus historiales en formato PokerStars con cabecera
+//! `Poker Hand #`. El parsing lo hace la FSM compartida (`stars_format`); las
+//! comisiones extra del resumen (`Jackpot`, `Bingo`...) se suman al rake.
+
+use crate::hero::HeroConfig;
+use crate::history_parser::HandHistoryParser;
+use crate::stars_format::StarsFormatParser;
+use crate::types::{ParseResult, Site};
+
+/// Parser FSM para historiales de GGPoker.
+#[derive(Debug)]
+pub struct GGPokerParser {
+    inner: StarsFormatParser,
+}
+
+impl Default for GGPokerParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GGPokerParser {
+    /// Crea un nuevo parser en estado inicial.
+    pub fn new() -> Self {
+        Self {
+            inner: StarsFormatParser::new(Site::GGPoker),
+        }
+    }
+
+    /// Marca como héroe a los jugadores con estos nicknames.
+    pub fn with_heroes(mut self, heroes: HeroConfig) -> Self {
+        self.inner.set_heroes(heroes);
+        self
+    }
+
+    /// Parsea un archivo completo de historial.
+    pub fn parse(&mut self, content: &str) -> ParseResult {
+        self.inner.parse(content)
+    }
+
+    /// Resetea el parser para procesar un nuevo archivo.
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+impl HandHistoryParser for GGPokerParser {
+    fn site(&self) -> Site {
+        self.inner.site()
+    }
+
+    fn parse(&mut self, content: &str) -> ParseResult {
+        GGPokerParser::parse(self, content)
+    }
+
+    fn reset(&mut self) {
+        GGPokerParser::reset(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pot_resolver::PotResolution;
+    use crate::types::{GameType, GameVariant, Position};
+    use crate::validation::HandValidator;
+
+    const CASH_HANDS: &str = r#"Poker Hand #RC2143658709: Hold'em No Limit ($0.02/$0.05) - 2025/01/15 20:15:30
+Table 'RushAndCash12345' 6-max Seat #1 is the button
+Seat 1: 4f8a2b1c ($5.00 in chips)
+Seat 2: Hero ($5.12 in chips)
+Seat 3: 9c7d6e5f ($4.80 in chips)
+Hero: posts small blind $0.02
+9c7d6e5f: posts big blind $0.05
+*** HOLE CARDS ***
+Dealt to 4f8a2b1c 
+Dealt to Hero [Jh Jd]
+Dealt to 9c7d6e5f 
+4f8a2b1c: raises $0.10 to $0.15
+Hero: raises $0.35 to $0.50
+9c7d6e5f: folds
+4f8a2b1c: calls $0.35
+*** FLOP *** [Jc 8s 3d]
+Hero: bets $0.60
+4f8a2b1c: calls $0.60
+*** TURN *** [Jc 8s 3d] [2h]
+Hero: bets $3.90 and is all-in
+4f8a2b1c: calls $3.90 and is all-in
+Hero: shows [Jh Jd] (Three of a Kind)
+4f8a2b1c: shows [Ah Ac] (a Pair of Aces)
+*** RIVER *** [Jc 8s 3d 2h] [5c]
+*** SHOWDOWN ***
+Hero collected $9.50 from pot
+*** SUMMARY ***
+Total pot $10.05 | Rake $0.50 | Jackpot $0.05 | Bingo $0 | Fortune $0 | Tax $0
+Board [Jc 8s 3d 2h 5c]
+Seat 2: Hero (small blind) showed [Jh Jd] and won ($9.50)
+
+Poker Hand #HD2143658710: Omaha Pot Limit ($0.05/$0.10) - 2025/01/15 20:16:02
+Table 'NLO 6' 6-max Seat #2 is the button
+Seat 1: Hero ($10 in chips)
+Seat 2: 1a2b3c4d ($10 in chips)
+Hero: posts small blind $0.05
+1a2b3c4d: posts big blind $0.10
+*** HOLE CARDS ***
+Dealt to Hero [As Ks Qd Jd]
+Dealt to 1a2b3c4d 
+Hero: folds
+Uncalled bet ($0.05) returned to 1a2b3c4d
+1a2b3c4d collected $0.10 from pot
+*** SUMMARY ***
+Total pot $0.10 | Rake $0 | Jackpot $0 | Bingo $0 | Fortune $0 | Tax $0
+"#;
+
+    const TOURNAMENT_HAND: &str = r#"Poker Hand #TM3344556677: Tournament #187654321, Bounty Hunters Special $10.80 Hold'em No Limit - Level10(400/800(100)) - 2025/03/09 06:30:00
+Table '12' 8-max Seat #3 is the button
+Seat 3: Hero (24,500 in chips)
+Seat 5: 7e6d5c4b (18,000 in chips)
+Hero: posts the ante 100
+7e6d5c4b: posts the ante 100
+Hero: posts small blind 400
+7e6d5c4b: posts big blind 800
+*** HOLE CARDS ***
+Dealt to Hero [Kc Kh]
+Dealt to 7e6d5c4b 
+Hero: raises 1,200 to 2,000
+7e6d5c4b: folds
+Uncalled bet (1,200) returned to Hero
+Hero collected 1,800 from pot
+*** SUMMARY ***
+Total pot 1,800 | Rake 0 | Jackpot 0 | Bingo 0 | Fortune 0 | Tax 0
+"#;
+
+    #[test]
+    fn test_parse_cash_hands() {
+        let mut parser = GGPokerParser::new();
+        let result = parser.parse(CASH_HANDS);
+
+        assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+        assert_eq!(result.hands.len(), 2);
+
+        let hand = &result.hands[0];
+        assert_eq!(hand.site, Site::GGPoker);
+        assert_eq!(hand.hand_id, "RC2143658709");
+        assert_eq!(hand.timestamp, "2025/01/15 20:15:30 UTC");
+
+        // El héroe es el único jugador con cartas en "Dealt to"
+        let hero = hand.players.iter().find(|p| p.is_hero).unwrap();
+        assert_eq!(&*hero.name, "Hero");
+        assert_eq!(hero.position, Some(Position::SmallBlind));
+        let opponent = hand
+            .players
+            .iter()
+            .find(|p| &*p.name == "4f8a2b1c")
+            .unwrap();
+        assert!(!opponent.is_hero);
+        assert_eq!(opponent.hole_cards.as_ref().map(Vec::len), Some(2));
+
+        // Rake + jackpot se descuentan del bote
+        assert_eq!(hand.pot.rake_cents, 55);
+        assert_eq!(hand.pot.total_cents, 950);
+        assert_eq!(hand.board.len(), 5);
+        assert!(PotResolution::from_hand(hand).validate().is_ok());
+        assert!(HandValidator::new().is_valid(hand));
+
+        let omaha = &result.hands[1];
+        assert_eq!(omaha.variant, GameVariant::PLO4);
+        assert_eq!(omaha.hero_cards.as_ref().map(Vec::len), Some(4));
+        assert!(PotResolution::from_hand(omaha).validate().is_ok());
+        assert!(HandValidator::new().is_valid(omaha));
+    }
+
+    #[test]
+    fn test_parse_tournament_hand() {
+        let mut parser = GGPokerParser::new();
+        let result = parser.parse(TOURNAMENT_HAND);
+
+        assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+        let hand = &result.hands[0];
+        assert_eq!(hand.game_type, GameType::Tournament);
+        assert_eq!(
+            (
+                hand.small_blind_cents,
+                hand.big_blind_cents,
+                hand.ante_cents
+            ),
+            (400, 800, 100)
+        );
+
+        let tournament = hand.tournament.as_ref().unwrap();
+        assert_eq!(tournament.tournament_id, "187654321");
+        assert_eq!(&*tournament.name, "Bounty Hunters Special");
+        assert_eq!(tournament.buy_in_cents, 1080);
+        assert_eq!(tournament.level, 10);
+
+        let hero = hand.players.iter().find(|p| p.is_hero).unwrap();
+        assert_eq!(hero.stack_cents, 24_500);
+        assert!(PotResolution::from_hand(hand).validate().is_ok());
+        assert!(HandValidator::new().is_valid(hand));
+    }
+}
@@ -0,0 +1,202 @@
+//! Identidad del héroe: los nicknames del usuario en cada sala.
+//!
+//! Un mismo jugador puede tener varias cuentas (`thesmoy` en Winamax,
+//! `thesmoy_ps` en PokerStars...). `HeroConfig` reúne todos esos nicknames y
+//! los parsers marcan `Player::is_hero` en los jugadores que coinciden. En la
+//! base de datos cada nickname es un `PlayerAlias` del mismo `Player` héroe.
+//!
+//! Un alias puede limitarse a una sala (`Winamax:thesmoy`) o valer para
+//! cualquiera (`thesmoy`). Con un `HeroConfig` vacío el héroe es el jugador
+//! de la línea `Dealt to`, que las salas solo escriben con cartas para el
+//! dueño del historial.
+//!
+//! ```rust
+//! use poker_parsers::{HeroConfig, Site};
+//!
+//! let heroes = HeroConfig::parse(["thesmoy", "PokerStars:thesmoy_ps"]).unwrap();
+//! assert!(heroes.is_hero(Site::Winamax, "thesmoy"));
+//! assert!(heroes.is_hero(Site::PokerStars, "thesmoy_ps"));
+//! assert!(!heroes.is_hero(Site::GGPoker, "thesmoy_ps"));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::Site;
+
+/// Salas que se aceptan como prefijo de un alias.
+const SITES: [Site; 3] = [Site::Winamax, Site::PokerStars, Site::GGPoker];
+
+/// Nickname del héroe, opcionalmente limitado a una sala.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct HeroAlias {
+    /// Sala del nickname (`None` = cualquier sala).
+    pub site: Option<Site>,
+    /// Nickname exacto en la sala.
+    pub nickname: String,
+}
+
+impl HeroAlias {
+    /// Alias válido en cualquier sala.
+    pub fn any_site(nickname: impl Into<String>) -> Self {
+        Self {
+            site: None,
+            nickname: nickname.into(),
+        }
+    }
+
+    /// Alias de una sala concreta.
+    pub fn for_site(site: Site, nickname: impl Into<String>) -> Self {
+        Self {
+            site: Some(site),
+            nickname: nickname.into(),
+        }
+    }
+
+    /// Indica si el alias corresponde a un jugador de una sala.
+    pub fn matches(&self, site: Site, name: &str) -> bool {
+        self.site.map_or(true, |s| s == site) && self.nickname == name
+    }
+}
+
+impl fmt::Display for HeroAlias {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.site {
+            Some(site) => write!(f, "{}:{}", site.name(), self.nickname),
+            None => write!(f, "{}", self.nickname),
+        }
+    }
+}
+
+/// Error al leer un alias de héroe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeroAliasError(pub String);
+
+impl fmt::Display for HeroAliasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid hero alias: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for HeroAliasError {}
+
+impl FromStr for HeroAlias {
+    type Err = HeroAliasError;
+
+    /// Lee `nickname` o `Sala:nickname` (sala sin distinguir mayúsculas).
+    ///
+    /// Si lo anterior a `:` no es una sala conocida, todo es el nickname.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let alias = s
+            .split_once(':')
+            .and_then(|(site, nickname)| {
+                SITES
+                    .into_iter()
+                    .find(|candidate| candidate.name().eq_ignore_ascii_case(site.trim()))
+                    .map(|site| HeroAlias::for_site(site, nickname.trim()))
+            })
+            .unwrap_or_else(|| HeroAlias::any_site(s));
+
+        if alias.nickname.is_empty() {
+            return Err(HeroAliasError(s.to_string()));
+        }
+        Ok(alias)
+    }
+}
+
+/// Conjunto de nicknames del héroe.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HeroConfig {
+    aliases: Vec<HeroAlias>,
+}
+
+impl HeroConfig {
+    /// Configuración vacía: el héroe se detecta por `Dealt to`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lee una lista de alias (`nickname` o `Sala:nickname`).
+    pub fn parse<I, S>(aliases: I) -> Result<Self, HeroAliasError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        aliases.into_iter().try_fold(Self::new(), |config, alias| {
+            Ok(config.with_alias(alias.as_ref().parse()?))
+        })
+    }
+
+    /// Añade un alias (los repetidos se ignoran).
+    pub fn with_alias(mut self, alias: HeroAlias) -> Self {
+        if !self.aliases.contains(&alias) {
+            self.aliases.push(alias);
+        }
+        self
+    }
+
+    /// Añade un nickname válido en cualquier sala.
+    pub fn with_nickname(self, nickname: impl Into<String>) -> Self {
+        self.with_alias(HeroAlias::any_site(nickname))
+    }
+
+    /// Alias configurados.
+    pub fn aliases(&self) -> &[HeroAlias] {
+        &self.aliases
+    }
+
+    /// Indica si no hay alias (detección por `Dealt to`).
+    pub fn is_empty(&self) -> bool {
+        self.aliases.is_empty()
+    }
+
+    /// Indica si un jugador de una sala es el héroe.
+    pub fn is_hero(&self, site: Site, name: &str) -> bool {
+        self.aliases.iter().any(|alias| alias.matches(site, name))
+    }
+
+    /// Indica si el jugador de `Dealt to` debe marcarse como héroe: siempre
+    /// sin configuración, y si es uno de los alias en caso contrario.
+    pub fn is_dealt_hero(&self, site: Site, name: &str) -> bool {
+        self.is_empty() || self.is_hero(site, name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_aliases() {
+        let heroes = HeroConfig::parse(["thesmoy", " winamax : smoy2 ", "Bad:Name"]).unwrap();
+        assert_eq!(
+            heroes.aliases(),
+            &[
+                HeroAlias::any_site("thesmoy"),
+                HeroAlias::for_site(Site::Winamax, "smoy2"),
+                HeroAlias::any_site("Bad:Name"),
+            ]
+        );
+        assert!(heroes.is_hero(Site::GGPoker, "thesmoy"));
+        assert!(heroes.is_hero(Site::Winamax, "smoy2"));
+        assert!(!heroes.is_hero(Site::PokerStars, "smoy2"));
+        assert_eq!(heroes.aliases()[1].to_string(), "Winamax:smoy2");
+
+        assert!(HeroConfig::parse(["PokerStars:"]).is_err());
+        assert!(HeroConfig::parse([""]).is_err());
+    }
+
+    #[test]
+    fn test_empty_config_trusts_dealt_to() {
+        let empty = HeroConfig::new();
+        assert!(!empty.is_hero(Site::Winamax, "anyone"));
+        assert!(empty.is_dealt_hero(Site::Winamax, "anyone"));
+
+        let heroes = HeroConfig::new().with_nickname("thesmoy");
+        assert!(heroes.is_dealt_hero(Site::Winamax, "thesmoy"));
+        assert!(!heroes.is_dealt_hero(Site::Winamax, "friend"));
+    }
+}
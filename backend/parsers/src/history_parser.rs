@@ -0,0 +1,163 @@
+//! Abstracción multi-sala de los parsers de historiales.
+//!
+//! Cada sala tiene su propio parser (`WinamaxParser`, `PokerStarsParser`,
+//! `GGPokerParser`) y todos producen el mismo `ParsedHand`. La sala se
+//! detecta a partir de la primera línea no vacía del archivo:
+//!
+//! | Primera línea | Sala |
+//! | :--- | :--- |
+//! | `Winamax Poker - ...` | Winamax |
+//! | `PokerStars Hand #...`, `PokerStars Zoom Hand #...` | PokerStars |
+//! | `Poker Hand #...` | GGPoker |
+//!
+//! ## Uso
+//!
+//! ```rust
+//! use poker_parsers::{detect_site, parse_history, Site};
+//!
+//! let content = "PokerStars Hand #1: Hold'em No Limit ($0.05/$0.10 USD) - 2025/01/15 20:15:30 ET";
+//! assert_eq!(detect_site(content), Some(Site::PokerStars));
+//!
+//! // Mismo `ParseResult` para cualquier sala
+//! let result = parse_history(content);
+//! assert_eq!(result.hands[0].site, Site::PokerStars);
+//! ```
+
+use crate::bytes_parser::{starts_with_bytes, tokens, UTF8_BOM};
+use crate::diagnostics::{DiagnosticKind, ParseDiagnostic};
+use crate::ggpoker::GGPokerParser;
+use crate::hero::HeroConfig;
+use crate::pokerstars::PokerStarsParser;
+use crate::types::{ParseResult, Site};
+use crate::WinamaxParser;
+
+/// Parser de historiales de una sala concreta.
+pub trait HandHistoryParser {
+    /// Sala cuyos historiales entiende el parser.
+    fn site(&self) -> Site;
+
+    /// Parsea el contenido completo de un archivo de historial.
+    fn parse(&mut self, content: &str) -> ParseResult;
+
+    /// Parsea el contenido en bytes (UTF-8 inválido se sustituye).
+    fn parse_bytes(&mut self, content: &[u8]) -> ParseResult {
+        self.parse(&String::from_utf8_lossy(content))
+    }
+
+    /// Resetea el parser para procesar un nuevo archivo.
+    fn reset(&mut self);
+}
+
+/// Detecta la sala a partir de la primera línea no vacía del contenido.
+pub fn detect_site(content: &str) -> Option<Site> {
+    detect_site_bytes(content.as_bytes())
+}
+
+/// Igual que `detect_site` sobre bytes.
+pub fn detect_site_bytes(content: &[u8]) -> Option<Site> {
+    let first_line = content
+        .split(|&b| b == b'\n')
+        .map(|line| {
+            let line = line.strip_prefix(UTF8_BOM).unwrap_or(line);
+            let start = line.iter().position(|b| !b.is_ascii_whitespace())?;
+            Some(&line[start..])
+        })
+        .find_map(|line| line)?;
+
+    site_from_line(first_line)
+}
+
+/// Sala correspondiente a una línea de cabecera.
+pub fn site_from_line(line: &[u8]) -> Option<Site> {
+    if starts_with_bytes(line, tokens::WINAMAX_POKER) {
+        Some(Site::Winamax)
+    } else if starts_with_bytes(line, tokens::POKERSTARS) {
+        Some(Site::PokerStars)
+    } else if starts_with_bytes(line, tokens::GGPOKER_HAND) {
+        Some(Site::GGPoker)
+    } else {
+        None
+    }
+}
+
+/// Crea el parser de una sala.
+pub fn parser_for_site(site: Site) -> Box<dyn HandHistoryParser + Send> {
+    parser_for_site_with_heroes(site, &HeroConfig::default())
+}
+
+/// Crea el parser de una sala que marca como héroe a estos nicknames.
+pub fn parser_for_site_with_heroes(
+    site: Site,
+    heroes: &HeroConfig,
+) -> Box<dyn HandHistoryParser + Send> {
+    let heroes = heroes.clone();
+    match site {
+        Site::Winamax => Box::new(WinamaxParser::new().with_heroes(heroes)),
+        Site::PokerStars => Box::new(PokerStarsParser::new().with_heroes(heroes)),
+        Site::GGPoker => Box::new(GGPokerParser::new().with_heroes(heroes)),
+    }
+}
+
+/// Parsea un historial detectando automáticamente la sala.
+///
+/// Un contenido vacío devuelve un resultado sin manos; un formato
+/// desconocido, un resultado con un error.
+pub fn parse_history(content: &str) -> ParseResult {
+    parse_history_bytes(content.as_bytes())
+}
+
+/// Igual que `parse_history` sobre bytes, sin convertir a `String` los
+/// historiales de Winamax.
+pub fn parse_history_bytes(content: &[u8]) -> ParseResult {
+    parse_history_bytes_with_heroes(content, &HeroConfig::default())
+}
+
+/// Igual que `parse_history_bytes` con los nicknames del héroe.
+pub fn parse_history_bytes_with_heroes(content: &[u8], heroes: &HeroConfig) -> ParseResult {
+    match detect_site_bytes(content) {
+        Some(site) => parser_for_site_with_heroes(site, heroes).parse_bytes(content),
+        None if content.iter().all(u8::is_ascii_whitespace) => ParseResult::default(),
+        None => ParseResult::new(
+            Vec::new(),
+            vec![ParseDiagnostic::new(
+                DiagnosticKind::UnknownFormat,
+                "Unknown hand history format",
+            )],
+            Vec::new(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_site() {
+        assert_eq!(
+            detect_site("\u{feff}Winamax Poker - CashGame - HandId: #1-1-1"),
+            Some(Site::Winamax)
+        );
+        assert_eq!(
+            detect_site("\n\nPokerStars Zoom Hand #254458712345:  Hold'em No Limit"),
+            Some(Site::PokerStars)
+        );
+        assert_eq!(
+            detect_site("Poker Hand #RC2143658709: Hold'em No Limit ($0.02/$0.05)"),
+            Some(Site::GGPoker)
+        );
+        assert_eq!(detect_site("Full Tilt Poker Game #1"), None);
+        assert_eq!(detect_site(""), None);
+    }
+
+    #[test]
+    fn test_parse_history_unknown_format() {
+        let result = parse_history("Full Tilt Poker Game #1: Table Test");
+        assert!(result.hands.is_empty());
+        assert_eq!(result.error_count, 1);
+        assert_eq!(result.diagnostics[0].kind, DiagnosticKind::UnknownFormat);
+
+        let result = parse_history("  \n");
+        assert_eq!(result.error_count, 0);
+    }
+}
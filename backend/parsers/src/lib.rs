@@ -1,19 +1,39 @@
 //! # Poker Parsers
 //!
-//! Módulo de parsing ultra-rápido para historiales de Winamax.
+//! Módulo de parsing ultra-rápido para historiales de Winamax, PokerStars y
+//! GGPoker.
 //!
 //! Utiliza una Máquina de Estados Finitos (FSM) para procesar líneas de historiales
 //! de forma eficiente con paralelización multihilo mediante Rayon.
 //!
 //! ## Features
-//! - FSM para análisis de historiales Winamax
+//! - FSM incremental sobre bytes para historiales Winamax (acepta trozos
+//!   parciales: `feed`, `take_hands`, `finish`)
+//! - Héroe configurable con varios nicknames por sala (`HeroConfig`); sin
+//!   configuración, el jugador de `Dealt to`
+//! - Historiales Winamax en inglés, francés y español con detección del
+//!   idioma por archivo (`WinamaxLocale`)
+//! - Importadores de PokerStars y GGPoker con detección automática de sala
+//!   (`HandHistoryParser`, `parse_history`)
 //! - Lectura optimizada de archivos (std::fs::read + BufReader)
 //! - Parser basado en bytes sin Regex para máximo rendimiento
 //! - Paralelización con Rayon (16 threads en Ryzen 3800X)
 //! - Soporte para Cash Games NLHE 6-max
+//! - Pot-Limit Omaha de 4 y 5 cartas (PLO4/PLO5)
+//! - Torneos, Sit & Go y Expresso (fichas, antes, niveles y resúmenes)
 //! - File watching automático con notify (detección en tiempo real)
+//! - Lectura incremental de sesiones en curso: solo se parsean las manos
+//!   añadidas, con detección de truncado y rotación (`FileTailer`)
+//! - Ledger persistente de archivos procesados con escaneo de arranque
+//!   (`FileLedger`, `FileTailer::reconcile`)
 //! - Deduplicación con MD5 hash
+//! - Aislamiento de pánicos del parser por archivo y cuarentena de los
+//!   archivos que fallan repetidamente (`FileQuarantine`)
 //! - Retry logic para archivos bloqueados
+//! - Diagnósticos tipados con archivo, línea, estado de la FSM, mano,
+//!   categoría y severidad (`ParseDiagnostic`, `DiagnosticSummary`)
+//! - Validación de integridad de manos (fichas, orden de acción, subidas
+//!   mínimas, cartas duplicadas y ganadores) con cuarentena (`HandValidator`)
 //!
 //! ## Uso básico
 //!
@@ -50,25 +70,55 @@
 //! ```
 
 pub mod bytes_parser;
+pub mod diagnostics;
+pub mod file_ledger;
+pub mod file_quarantine;
 pub mod file_reader;
+pub mod file_tail;
 pub mod file_watcher;
 pub mod fsm;
+pub mod ggpoker;
+pub mod hero;
+pub mod history_parser;
 pub mod logging;
 pub mod parallel_processor;
+pub mod pokerstars;
+pub mod pot_resolver;
+mod stars_format;
 pub mod synthetic_generator;
+pub mod tournament_summary;
 pub mod types;
+pub mod validation;
+pub mod winamax_locale;
 
+pub use bytes_parser::UTF8_BOM;
+pub use diagnostics::{DiagnosticKind, DiagnosticSummary, ParseDiagnostic, Severity};
+pub use file_ledger::FileLedger;
+pub use file_quarantine::{FileFailure, FileQuarantine, QuarantineEntry};
+pub use file_tail::{FileTailer, Reconciliation, TailChange, TailState, TailUpdate};
 pub use file_watcher::{FileEvent, FileWatcher, FileWatcherBuilder, WatcherConfig, WatcherError};
 pub use fsm::WinamaxParser;
+pub use ggpoker::GGPokerParser;
+pub use hero::{HeroAlias, HeroAliasError, HeroConfig};
+pub use history_parser::{
+    detect_site, detect_site_bytes, parse_history, parse_history_bytes,
+    parse_history_bytes_with_heroes, parser_for_site, parser_for_site_with_heroes,
+    HandHistoryParser,
+};
 pub use parallel_processor::{
     process_files_parallel, process_files_parallel_with_progress, BatchProcessingResult,
     CancellationToken, FileProcessingError, FileProcessingResult, ParallelProcessor,
     ProcessingConfig, ProcessingProgress,
 };
+pub use pokerstars::PokerStarsParser;
+pub use pot_resolver::{PlayerPotResult, PotMismatch, PotResolution, SidePot};
 pub use synthetic_generator::{
     generate_synthetic_hands, GenerationResult, StakeLevel, SyntheticConfig, SyntheticGenerator,
 };
+pub use tournament_summary::TournamentSummary;
 pub use types::{
-    Action, ActionType, Card, GameType, ParseResult, ParsedHand, ParserState, Player, Position,
-    PotInfo, Street,
+    Action, ActionType, Card, GameType, GameVariant, HoleCards, ParseResult, ParsedHand,
+    ParserState, Player, Position, PotInfo, Site, Street, TournamentFormat, TournamentInfo,
 };
+pub use validation::{HandValidator, IntegrityRule, IntegrityViolation, QuarantinedHand};
+pub use winamax_locale::{WinamaxGrammar, WinamaxLocale};
@@ -9,6 +9,9 @@
 //! - **Granularidad de archivo**: Cada hilo procesa un archivo completo
 //! - **Sincronización**: Resultados agregados mediante canales o Arc<Mutex>
 //! - **Progreso**: Contador atómico para reportar avance
+//! - **Aislamiento**: Un pánico del parser solo falla su archivo
+//!   (`FileProcessingError::Panic`); con `quarantine_dir` los archivos que
+//!   fallan repetidamente se apartan a cuarentena (ver `file_quarantine`)
 //!
 //! ## Uso
 //!
@@ -26,13 +29,20 @@
 
 use rayon::prelude::*;
 use rayon::ThreadPoolBuilder;
+use std::io;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
+use tracing::warn;
 
+use crate::diagnostics::DiagnosticSummary;
+use crate::file_quarantine::{
+    catch_parser_panic, FileFailure, FileQuarantine, QuarantineEntry, DEFAULT_MAX_FAILURES,
+};
 use crate::file_reader::{read_file_optimized, FileContent};
+use crate::hero::HeroConfig;
+use crate::history_parser::parse_history_bytes_with_heroes;
 use crate::types::ParseResult;
-use crate::WinamaxParser;
 
 /// Número de hilos por defecto (optimizado para Ryzen 3800X).
 const DEFAULT_NUM_THREADS: usize = 16;
@@ -49,6 +59,12 @@ pub struct ProcessingConfig {
     pub stack_size: usize,
     /// Nombre del pool de hilos (para debugging).
     pub thread_name_prefix: String,
+    /// Nicknames del héroe para marcar `Player::is_hero`.
+    pub heroes: HeroConfig,
+    /// Directorio de cuarentena (`None` = sin cuarentena).
+    pub quarantine_dir: Option<PathBuf>,
+    /// Fallos tras los que un archivo va a cuarentena.
+    pub max_failures: u32,
 }
 
 impl Default for ProcessingConfig {
@@ -57,6 +73,9 @@ impl Default for ProcessingConfig {
             num_threads: DEFAULT_NUM_THREADS,
             stack_size: DEFAULT_STACK_SIZE,
             thread_name_prefix: "poker-parser".to_string(),
+            heroes: HeroConfig::default(),
+            quarantine_dir: None,
+            max_failures: DEFAULT_MAX_FAILURES,
         }
     }
 }
@@ -77,6 +96,18 @@ impl ProcessingConfig {
             ..Default::default()
         }
     }
+
+    /// Establece los nicknames del héroe.
+    pub fn with_heroes(mut self, heroes: HeroConfig) -> Self {
+        self.heroes = heroes;
+        self
+    }
+
+    /// Activa la cuarentena de archivos que fallan repetidamente.
+    pub fn with_quarantine(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.quarantine_dir = Some(dir.into());
+        self
+    }
 }
 
 /// Información de progreso del procesamiento.
@@ -108,6 +139,8 @@ pub enum FileProcessingError {
     IoError(String),
     /// Error de parsing.
     ParseError(String),
+    /// El parser entró en pánico con este archivo.
+    Panic(String),
 }
 
 impl std::fmt::Display for FileProcessingError {
@@ -115,6 +148,7 @@ impl std::fmt::Display for FileProcessingError {
         match self {
             Self::IoError(msg) => write!(f, "IO Error: {}", msg),
             Self::ParseError(msg) => write!(f, "Parse Error: {}", msg),
+            Self::Panic(msg) => write!(f, "Parser panic: {}", msg),
         }
     }
 }
@@ -132,6 +166,10 @@ pub struct BatchProcessingResult {
     pub successful_files: usize,
     /// Total de archivos con errores.
     pub failed_files: usize,
+    /// Resumen de los diagnósticos de parsing de todos los archivos.
+    pub diagnostics: DiagnosticSummary,
+    /// Archivos que están en cuarentena tras el batch.
+    pub quarantined: Vec<QuarantineEntry>,
     /// Tiempo total de procesamiento en milisegundos.
     pub elapsed_ms: u128,
 }
@@ -229,6 +267,8 @@ impl ParallelProcessor {
                 total_hands: 0,
                 successful_files: 0,
                 failed_files: 0,
+                diagnostics: DiagnosticSummary::default(),
+                quarantined: vec![],
                 elapsed_ms: 0,
             };
         }
@@ -265,7 +305,7 @@ impl ParallelProcessor {
                     }
 
                     // Procesar el archivo
-                    let result = process_single_file(&path);
+                    let result = process_single_file(&path, &self.config.heroes);
 
                     // Actualizar contadores
                     let is_error = result.result.is_err();
@@ -297,18 +337,92 @@ impl ParallelProcessor {
                     Err(_) => (hands, success),
                 });
 
+        let mut diagnostics = DiagnosticSummary::default();
+        for parse_result in results.iter().filter_map(|r| r.result.as_ref().ok()) {
+            diagnostics.add_file(&parse_result.diagnostics);
+        }
+
+        let quarantined = self.quarantine(&results);
+
         BatchProcessingResult {
             results,
             total_hands,
             successful_files,
             failed_files: errors.load(Ordering::SeqCst),
+            diagnostics,
+            quarantined,
             elapsed_ms: start_time.elapsed().as_millis(),
         }
     }
+
+    /// Vuelve a parsear los archivos en cuarentena (p.ej. tras corregir el
+    /// parser).
+    ///
+    /// Los que ya se parsean bien vuelven a su ruta original y su resultado
+    /// lleva esa ruta; el resto sigue en cuarentena y aparece en
+    /// `quarantined`.
+    pub fn reimport_quarantined(
+        &self,
+        quarantine: &mut FileQuarantine,
+    ) -> io::Result<BatchProcessingResult> {
+        let entries = quarantine.list()?;
+        let paths = entries
+            .iter()
+            .map(|entry| entry.quarantined_path.clone())
+            .collect();
+
+        // Los archivos ya están apartados: no se vuelven a contar sus fallos
+        let processor = Self::new(ProcessingConfig {
+            quarantine_dir: None,
+            ..self.config.clone()
+        });
+        let mut batch = processor.process_files(paths, None::<fn(ProcessingProgress)>);
+
+        for (entry, file) in entries.into_iter().zip(&mut batch.results) {
+            if file.result.is_err() || FileFailure::from_file(file).is_some() {
+                batch.quarantined.push(entry);
+                continue;
+            }
+            file.path = quarantine.restore(&entry)?;
+            if let Ok(parsed) = &mut file.result {
+                parsed.set_path(&file.path);
+            }
+        }
+        Ok(batch)
+    }
+
+    /// Registra los fallos del batch en la cuarentena configurada y devuelve
+    /// los archivos que se apartaron.
+    fn quarantine(&self, results: &[FileProcessingResult]) -> Vec<QuarantineEntry> {
+        let Some(dir) = &self.config.quarantine_dir else {
+            return Vec::new();
+        };
+        let mut quarantine = match FileQuarantine::open(dir) {
+            Ok(quarantine) => quarantine.with_max_failures(self.config.max_failures),
+            Err(e) => {
+                warn!(path = %dir.display(), error = %e, "Failed to open file quarantine");
+                return Vec::new();
+            }
+        };
+
+        results
+            .iter()
+            .filter_map(|result| {
+                quarantine.record(result).unwrap_or_else(|e| {
+                    warn!(
+                        file_path = %result.path.display(),
+                        error = %e,
+                        "Failed to update file quarantine"
+                    );
+                    None
+                })
+            })
+            .collect()
+    }
 }
 
 /// Procesa un archivo individual (llamado por cada hilo del pool).
-fn process_single_file(path: &PathBuf) -> FileProcessingResult {
+fn process_single_file(path: &PathBuf, heroes: &HeroConfig) -> FileProcessingResult {
     // Leer el archivo
     let file_content: FileContent = match read_file_optimized(path) {
         Ok(content) => content,
@@ -323,16 +437,19 @@ fn process_single_file(path: &PathBuf) -> FileProcessingResult {
 
     let file_size = file_content.size;
 
-    // Convertir bytes a string (lossy para evitar errores UTF-8)
-    let text = String::from_utf8_lossy(&file_content.bytes);
-
-    // Parsear con FSM
-    let mut parser = WinamaxParser::new();
-    let parse_result = parser.parse(&text);
+    // Parsear los bytes con la FSM de la sala detectada en la primera línea,
+    // aislando un posible pánico del parser
+    let result =
+        catch_parser_panic(|| parse_history_bytes_with_heroes(&file_content.bytes, heroes))
+            .map(|mut parse_result| {
+                parse_result.set_path(path);
+                parse_result
+            })
+            .map_err(FileProcessingError::Panic);
 
     FileProcessingResult {
         path: path.clone(),
-        result: Ok(parse_result),
+        result,
         file_size,
     }
 }
@@ -376,6 +493,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::diagnostics::DiagnosticKind;
     use std::io::Write;
     use tempfile::NamedTempFile;
 
@@ -420,7 +538,7 @@ mod tests {
     #[test]
     fn test_process_single_file_io_error() {
         let path = PathBuf::from("nonexistent_file_12345.txt");
-        let result = process_single_file(&path);
+        let result = process_single_file(&path, &HeroConfig::default());
 
         assert!(result.result.is_err());
         matches!(result.result, Err(FileProcessingError::IoError(_)));
@@ -443,12 +561,102 @@ thesmoy collected 0.03€ from pot
 Total pot 0.03€ | No rake
 "#;
         let file = create_test_file(content);
-        let result = process_single_file(&file.path().to_path_buf());
+        let result = process_single_file(&file.path().to_path_buf(), &HeroConfig::default());
 
         assert!(result.result.is_ok());
         assert!(result.file_size > 0);
     }
 
+    #[test]
+    fn test_processing_marks_configured_heroes() {
+        let content = "Winamax Poker - CashGame - HandId: #123-456-790 - Holdem no limit (0.01€/0.02€) - 2024/01/15 20:31:00 UTC
+Table: 'Test Table' 6-max Seat #1 is the button
+Seat 1: Player1 (2.00€)
+Seat 2: thesmoy (2.00€)
+*** ANTE/BLINDS ***
+Player1 posts small blind 0.01€
+thesmoy posts big blind 0.02€
+Dealt to thesmoy [Ah Kd]
+*** PRE-FLOP ***
+Player1 folds
+thesmoy collected 0.03€ from pot
+*** SUMMARY ***
+Total pot 0.03€ | No rake
+";
+        let file = create_test_file(content);
+        let heroes = HeroConfig::parse(["Winamax:Player1"]).unwrap();
+        let processor =
+            ParallelProcessor::new(ProcessingConfig::with_threads(1).with_heroes(heroes));
+        let result = processor.process_files(
+            vec![file.path().to_path_buf()],
+            None::<fn(ProcessingProgress)>,
+        );
+
+        let hand = &result.results[0].result.as_ref().unwrap().hands[0];
+        let heroes: Vec<&str> = hand
+            .players
+            .iter()
+            .filter(|p| p.is_hero)
+            .map(|p| &*p.name)
+            .collect();
+        assert_eq!(heroes, ["Player1"]);
+        assert!(hand.hero_cards.is_none());
+    }
+
+    #[test]
+    fn test_quarantine_and_reimport() {
+        let history = tempfile::TempDir::new().unwrap();
+        let file = history.path().join("broken.txt");
+        std::fs::write(&file, "not a hand history\n").unwrap();
+
+        let quarantine_dir = FileQuarantine::dir_for(history.path());
+        let config = ProcessingConfig {
+            max_failures: 2,
+            ..ProcessingConfig::with_threads(1).with_quarantine(&quarantine_dir)
+        };
+        let processor = ParallelProcessor::new(config);
+
+        let first = processor.process_files(vec![file.clone()], None::<fn(ProcessingProgress)>);
+        assert!(first.quarantined.is_empty());
+        assert!(file.exists());
+
+        let second = processor.process_files(vec![file.clone()], None::<fn(ProcessingProgress)>);
+        assert_eq!(second.quarantined.len(), 1);
+        assert_eq!(second.quarantined[0].reason, "Unknown hand history format");
+        assert!(!file.exists());
+
+        // Sin corregir nada el archivo sigue en cuarentena
+        let mut quarantine = FileQuarantine::open(&quarantine_dir).unwrap();
+        let batch = processor.reimport_quarantined(&mut quarantine).unwrap();
+        assert_eq!(batch.quarantined.len(), 1);
+        assert!(!file.exists());
+
+        // Tras el arreglo (aquí, el contenido) vuelve a su ruta original
+        std::fs::write(
+            &second.quarantined[0].quarantined_path,
+            "Winamax Poker - CashGame - HandId: #123-456-791 - Holdem no limit (0.01€/0.02€) - 2024/01/15 20:32:00 UTC
+Table: 'Test Table' 6-max Seat #1 is the button
+Seat 1: Player1 (2.00€)
+Seat 2: thesmoy (2.00€)
+*** ANTE/BLINDS ***
+Player1 posts small blind 0.01€
+thesmoy posts big blind 0.02€
+*** PRE-FLOP ***
+Player1 folds
+thesmoy collected 0.03€ from pot
+*** SUMMARY ***
+Total pot 0.03€ | No rake
+",
+        )
+        .unwrap();
+        let batch = processor.reimport_quarantined(&mut quarantine).unwrap();
+        assert!(batch.quarantined.is_empty());
+        assert_eq!(batch.total_hands, 1);
+        assert_eq!(batch.results[0].path, file);
+        assert!(file.exists());
+        assert!(quarantine.list().unwrap().is_empty());
+    }
+
     #[test]
     fn test_parallel_processing_with_progress() {
         let content = "Winamax Poker - Test file";
@@ -472,6 +680,48 @@ Total pot 0.03€ | No rake
         assert_eq!(progress_updates.load(Ordering::SeqCst), 2);
     }
 
+    #[test]
+    fn test_batch_diagnostics_summary() {
+        let content = "Winamax Poker - CashGame - HandId: #123-456-789 - Holdem no limit (0.01€/0.02€) - 2024/01/15 20:30:00 UTC
+Table: 'Test Table' 6-max Seat #1 is the button
+Seat 1: Player1 (2.00€)
+Seat 2: thesmoy (2.00€)
+*** ANTE/BLINDS ***
+Player1 posts small blind 0.01€
+thesmoy posts big blind 0.02€
+*** PRE-FLOP ***
+Player1 juggles
+Player1 folds
+thesmoy collected 0.03€ from pot
+*** SUMMARY ***
+Total pot 0.03€ | No rake
+";
+        let valid = create_test_file(content);
+        let unknown = create_test_file("Full Tilt Poker Game #1");
+
+        let processor = ParallelProcessor::new(ProcessingConfig::with_threads(2));
+        let result = processor.process_files(
+            vec![valid.path().to_path_buf(), unknown.path().to_path_buf()],
+            None::<fn(ProcessingProgress)>,
+        );
+
+        assert_eq!(result.diagnostics.warnings, 1);
+        assert_eq!(result.diagnostics.errors, 1);
+        assert_eq!(result.diagnostics.files_with_errors, 1);
+        assert_eq!(result.diagnostics.by_kind[&DiagnosticKind::UnknownLine], 1);
+        assert_eq!(
+            result.diagnostics.by_kind[&DiagnosticKind::UnknownFormat],
+            1
+        );
+
+        let parsed = result.results[0].result.as_ref().unwrap();
+        let diagnostic = &parsed.diagnostics[0];
+        assert_eq!(diagnostic.path.as_deref(), Some(valid.path()));
+        assert_eq!(diagnostic.line, Some(9));
+        assert_eq!(diagnostic.state, crate::types::ParserState::Preflop);
+        assert_eq!(diagnostic.hand_id.as_deref(), Some("123-456-789"));
+    }
+
     #[test]
     fn test_cancellation_stops_processing() {
         let content = "Winamax Poker - Test file";
@@ -0,0 +1,228 @@
+//! Parser de historiales de PokerStars.
+//!
+//! Convierte los historiales de texto de PokerStars (cash, Zoom y torneos) al
+//! mismo `ParsedHand` que produce `WinamaxParser`. El parsing lo hace la FSM
+//! compartida con GGPoker (`stars_format`).
+
+use crate::hero::HeroConfig;
+use crate::history_parser::HandHistoryParser;
+use crate::stars_format::StarsFormatParser;
+use crate::types::{ParseResult, Site};
+
+/// Parser FSM para historiales de PokerStars.
+#[derive(Debug)]
+pub struct PokerStarsParser {
+    inner: StarsFormatParser,
+}
+
+impl Default for PokerStarsParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PokerStarsParser {
+    /// Crea un nuevo parser en estado inicial.
+    pub fn new() -> Self {
+        Self {
+            inner: StarsFormatParser::new(Site::PokerStars),
+        }
+    }
+
+    /// Marca como héroe a los jugadores con estos nicknames.
+    pub fn with_heroes(mut self, heroes: HeroConfig) -> Self {
+        self.inner.set_heroes(heroes);
+        self
+    }
+
+    /// Parsea un archivo completo de historial.
+    pub fn parse(&mut self, content: &str) -> ParseResult {
+        self.inner.parse(content)
+    }
+
+    /// Resetea el parser para procesar un nuevo archivo.
+    pub fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+impl HandHistoryParser for PokerStarsParser {
+    fn site(&self) -> Site {
+        self.inner.site()
+    }
+
+    fn parse(&mut self, content: &str) -> ParseResult {
+        PokerStarsParser::parse(self, content)
+    }
+
+    fn reset(&mut self) {
+        PokerStarsParser::reset(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pot_resolver::PotResolution;
+    use crate::types::{ActionType, GameType, Position, Street, TournamentFormat};
+    use crate::validation::HandValidator;
+
+    const CASH_HAND: &str = r#"PokerStars Hand #254458712345:  Hold'em No Limit ($0.05/$0.10 USD) - 2025/01/15 20:15:30 CET [2025/01/15 14:15:30 ET]
+Table 'Aludra' 6-max Seat #3 is the button
+Seat 1: Villain1 ($10.00 in chips)
+Seat 2: Villain2 ($12.35 in chips)
+Seat 3: thesmoy ($10.00 in chips)
+Seat 5: Villain3 ($1,250.00 in chips)
+Seat 6: Villain4 ($5.00 in chips) is sitting out
+Villain3: posts small blind $0.05
+Villain1: posts big blind $0.10
+*** HOLE CARDS ***
+Dealt to thesmoy [Ah Kd]
+Villain2: folds
+thesmoy: raises $0.20 to $0.30
+Villain3: folds
+Villain1: calls $0.20
+*** FLOP *** [As 7d 2c]
+Villain1: checks
+thesmoy: bets $0.40
+Villain1 said, "nh: bets $5"
+Villain1: raises $1.20 to $1.60
+thesmoy: calls $1.20
+*** TURN *** [As 7d 2c] [9h]
+Villain1: bets $8.10 and is all-in
+thesmoy: folds
+Uncalled bet ($8.10) returned to Villain1
+Villain1 collected $3.68 from pot
+Villain1: doesn't show hand
+*** SUMMARY ***
+Total pot $3.85 | Rake $0.17
+Board [As 7d 2c 9h]
+Seat 1: Villain1 (big blind) collected ($3.68)
+Seat 3: thesmoy (button) folded on the Turn
+
+"#;
+
+    const TOURNAMENT_HAND: &str = r#"PokerStars Hand #254458799999: Tournament #3456789012, $1.00+$0.10 USD Hold'em No Limit - Level III (25/50) - 2025/07/01 9:05:00 ET
+Table '3456789012 7' 9-max Seat #1 is the button
+Seat 1: thesmoy (1500 in chips)
+Seat 4: Villain1 (2,400 in chips)
+Seat 8: Villain2 (1100 in chips)
+thesmoy: posts the ante 5
+Villain1: posts the ante 5
+Villain2: posts the ante 5
+Villain1: posts small blind 25
+Villain2: posts big blind 50
+*** HOLE CARDS ***
+Dealt to thesmoy [Qs Qh]
+thesmoy: raises 100 to 150
+Villain1: folds
+Villain2: raises 945 to 1095 and is all-in
+thesmoy: calls 945
+*** FLOP *** [2d 8c Ks]
+*** TURN *** [2d 8c Ks] [3h]
+*** RIVER *** [2d 8c Ks 3h] [Qd]
+*** SHOW DOWN ***
+Villain2: shows [Ac Kd] (a pair of Kings)
+thesmoy: shows [Qs Qh] (three of a kind, Queens)
+thesmoy collected 2230 from pot
+*** SUMMARY ***
+Total pot 2230 | Rake 0
+Board [2d 8c Ks 3h Qd]
+"#;
+
+    #[test]
+    fn test_parse_cash_hand() {
+        let mut parser = PokerStarsParser::new();
+        let result = parser.parse(CASH_HAND);
+
+        assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+        assert_eq!(result.hands.len(), 1);
+
+        let hand = &result.hands[0];
+        assert_eq!(hand.site, Site::PokerStars);
+        assert_eq!(hand.hand_id, "254458712345");
+        assert_eq!(hand.game_type, GameType::CashGame);
+        assert_eq!(hand.table_name, "Aludra");
+        assert_eq!(hand.timestamp, "2025/01/15 19:15:30 UTC");
+        assert_eq!((hand.small_blind_cents, hand.big_blind_cents), (5, 10));
+        assert_eq!(hand.button_seat, 3);
+
+        // El jugador ausente no cuenta; el separador de miles se ignora
+        assert_eq!(hand.players.len(), 4);
+        let villain3 = hand
+            .players
+            .iter()
+            .find(|p| &*p.name == "Villain3")
+            .unwrap();
+        assert_eq!(villain3.stack_cents, 125_000);
+        assert_eq!(villain3.position, Some(Position::SmallBlind));
+
+        let hero = hand.players.iter().find(|p| p.is_hero).unwrap();
+        assert_eq!(&*hero.name, "thesmoy");
+        assert_eq!(hero.position, Some(Position::Button));
+        assert_eq!(hand.hero_cards.as_ref().map(Vec::len), Some(2));
+
+        // El chat no genera acciones
+        let villain1_bets = hand
+            .actions
+            .iter()
+            .filter(|a| &*a.player_name == "Villain1" && a.action_type == ActionType::Bet)
+            .count();
+        assert_eq!(villain1_bets, 1);
+        let raise = hand
+            .actions
+            .iter()
+            .find(|a| a.street == Street::Flop && a.action_type == ActionType::Raise)
+            .unwrap();
+        assert_eq!(raise.amount_cents, Some(160));
+
+        assert_eq!(hand.board.len(), 4);
+        assert_eq!(hand.pot.total_cents, 368);
+        assert_eq!(hand.pot.rake_cents, 17);
+        // El bet no igualado se suma al cobro, como en Winamax
+        let collected: i64 = hand.pot.winners.iter().map(|(_, amount)| amount).sum();
+        assert_eq!(collected, 368 + 810);
+
+        assert!(PotResolution::from_hand(hand).validate().is_ok());
+        assert!(HandValidator::new().is_valid(hand));
+    }
+
+    #[test]
+    fn test_parse_tournament_hand() {
+        let mut parser = PokerStarsParser::new();
+        let result = parser.parse(TOURNAMENT_HAND);
+
+        assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+        let hand = &result.hands[0];
+        assert_eq!(hand.game_type, GameType::Tournament);
+        assert_eq!(hand.max_players, 9);
+        // Horario de verano: ET = UTC-4
+        assert_eq!(hand.timestamp, "2025/07/01 13:05:00 UTC");
+
+        // Torneos en fichas
+        assert_eq!((hand.small_blind_cents, hand.big_blind_cents), (25, 50));
+        assert_eq!(hand.ante_cents, 5);
+        let villain1 = hand
+            .players
+            .iter()
+            .find(|p| &*p.name == "Villain1")
+            .unwrap();
+        assert_eq!(villain1.stack_cents, 2400);
+
+        let tournament = hand.tournament.as_ref().unwrap();
+        assert_eq!(tournament.tournament_id, "3456789012");
+        assert_eq!(tournament.format, TournamentFormat::MTT);
+        assert_eq!((tournament.buy_in_cents, tournament.fee_cents), (100, 10));
+        assert_eq!(tournament.level, 3);
+
+        let villain2 = hand
+            .players
+            .iter()
+            .find(|p| &*p.name == "Villain2")
+            .unwrap();
+        assert_eq!(villain2.hole_cards.as_ref().map(Vec::len), Some(2));
+        assert_eq!(hand.board.len(), 5);
+        assert!(PotResolution::from_hand(hand).validate().is_ok());
+        assert!(HandValidator::new().is_valid(hand));
+    }
+}
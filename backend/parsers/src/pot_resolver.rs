@@ -0,0 +1,506 @@
+//! Reconstrucción de botes a partir del flujo de acciones.
+//!
+//! `PotInfo` solo guarda el total, el rake y los cobros, lo que no basta para
+//! repartir all-ins multiway con stacks desiguales. Este módulo reconstruye
+//! lo invertido por cada jugador, el bote principal, los side pots y la
+//! apuesta no igualada, y lo contrasta con los cobros del historial.
+//!
+//! ## Convenciones de Winamax
+//! - `calls`, `bets` y las ciegas indican la cantidad añadida
+//! - `raises X to Y` se guarda como `Y` (total de la calle)
+//! - La apuesta no igualada no se devuelve en una línea aparte: va incluida
+//!   en el `collected` del ganador
+//! - `Total pot` ya tiene el rake descontado
+//!
+//! Por tanto una mano cuadra cuando `invertido = cobrado + rake`.
+//!
+//! ## Uso
+//!
+//! ```rust,ignore
+//! use poker_parsers::PotResolution;
+//!
+//! let resolution = PotResolution::from_hand(&hand);
+//! resolution.validate()?;
+//! println!("Neto del héroe: {}", resolution.net_cents("thesmoy"));
+//! ```
+
+use crate::types::{ActionType, ParsedHand, Street};
+
+/// Un bote (principal o side pot) y los jugadores que pueden ganarlo.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SidePot {
+    /// Fichas del bote en centavos (antes de rake).
+    pub amount_cents: i64,
+    /// Jugadores vivos elegibles, en orden de asiento.
+    pub eligible: Vec<String>,
+}
+
+/// Resultado de un jugador en la mano.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerPotResult {
+    pub player_name: String,
+    /// Fichas puestas en el bote (ciegas, antes y apuestas).
+    pub invested_cents: i64,
+    /// Cobrado según el historial (incluye la apuesta no igualada).
+    pub collected_cents: i64,
+    /// `collected_cents - invested_cents`.
+    pub net_cents: i64,
+    /// Si se retiró de la mano.
+    pub folded: bool,
+    /// Si quedó all-in.
+    pub all_in: bool,
+}
+
+/// Inconsistencia entre los botes reconstruidos y los cobros.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PotMismatch {
+    /// `invertido != cobrado + rake`.
+    Totals {
+        invested_cents: i64,
+        collected_cents: i64,
+        rake_cents: i64,
+    },
+    /// Un jugador cobró más de lo que podía ganar con sus botes.
+    OverCollected {
+        player_name: String,
+        collected_cents: i64,
+        max_cents: i64,
+    },
+}
+
+impl std::fmt::Display for PotMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Totals {
+                invested_cents,
+                collected_cents,
+                rake_cents,
+            } => write!(
+                f,
+                "Pot mismatch: invested {} != collected {} + rake {}",
+                invested_cents, collected_cents, rake_cents
+            ),
+            Self::OverCollected {
+                player_name,
+                collected_cents,
+                max_cents,
+            } => write!(
+                f,
+                "Player '{}' collected {} but could win at most {}",
+                player_name, collected_cents, max_cents
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PotMismatch {}
+
+/// Botes reconstruidos de una mano.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PotResolution {
+    /// Bote principal seguido de los side pots.
+    pub pots: Vec<SidePot>,
+    /// Apuesta no igualada (jugador, centavos), fuera de `pots`.
+    pub uncalled: Option<(String, i64)>,
+    /// Resultado por jugador, en orden de asiento.
+    pub players: Vec<PlayerPotResult>,
+    /// Rake según el resumen.
+    pub rake_cents: i64,
+}
+
+impl PotResolution {
+    /// Reconstruye los botes de una mano.
+    pub fn from_hand(hand: &ParsedHand) -> Self {
+        let mut players: Vec<PlayerPotResult> = hand
+            .players
+            .iter()
+            .map(|p| {
+                let collected_cents: i64 = hand
+                    .pot
+                    .winners
+                    .iter()
+                    .filter(|(name, _)| *name == p.name)
+                    .map(|(_, amount)| amount)
+                    .sum();
+                let invested_cents = invested_cents(hand, &p.name);
+                PlayerPotResult {
+                    player_name: p.name.to_string(),
+                    invested_cents,
+                    collected_cents,
+                    net_cents: collected_cents - invested_cents,
+                    folded: hand
+                        .actions
+                        .iter()
+                        .any(|a| a.player_name == p.name && a.action_type == ActionType::Fold),
+                    all_in: hand.actions.iter().any(|a| {
+                        a.player_name == p.name
+                            && (a.is_all_in || a.action_type == ActionType::AllIn)
+                    }),
+                }
+            })
+            .collect();
+        players.sort_by_key(|p| {
+            hand.players
+                .iter()
+                .find(|s| *s.name == *p.player_name)
+                .map(|s| s.seat)
+        });
+
+        let mut contributions: Vec<i64> = players.iter().map(|p| p.invested_cents).collect();
+        let uncalled = take_uncalled(&players, &mut contributions);
+        let pots = build_pots(&players, &contributions);
+
+        Self {
+            pots,
+            uncalled,
+            players,
+            rake_cents: hand.pot.rake_cents,
+        }
+    }
+
+    /// Resultado de un jugador.
+    pub fn player(&self, player_name: &str) -> Option<&PlayerPotResult> {
+        self.players.iter().find(|p| p.player_name == player_name)
+    }
+
+    /// Neto de un jugador (0 si no jugó la mano).
+    pub fn net_cents(&self, player_name: &str) -> i64 {
+        self.player(player_name).map_or(0, |p| p.net_cents)
+    }
+
+    /// Total invertido por todos los jugadores.
+    pub fn total_invested_cents(&self) -> i64 {
+        self.players.iter().map(|p| p.invested_cents).sum()
+    }
+
+    /// Total cobrado por todos los jugadores.
+    pub fn total_collected_cents(&self) -> i64 {
+        self.players.iter().map(|p| p.collected_cents).sum()
+    }
+
+    /// Fichas de los botes con más de un jugador elegible.
+    pub fn contested_cents(&self) -> i64 {
+        self.pots
+            .iter()
+            .filter(|p| p.eligible.len() > 1)
+            .map(|p| p.amount_cents)
+            .sum()
+    }
+
+    /// Cobrado por un jugador sin su apuesta no igualada, que el historial
+    /// incluye en el `collected` pero nunca estuvo en un bote disputado.
+    pub fn won_cents(&self, player_name: &str) -> i64 {
+        let Some(player) = self.player(player_name) else {
+            return 0;
+        };
+        let returned = match &self.uncalled {
+            Some((name, amount)) if name == player_name => *amount,
+            _ => 0,
+        };
+        (player.collected_cents - returned).max(0)
+    }
+
+    /// Rake atribuible a un jugador, proporcional a lo que ganó de los botes
+    /// (`won_cents`): la apuesta no igualada no paga rake.
+    pub fn rake_share_cents(&self, player_name: &str) -> i64 {
+        let total: i64 = self
+            .players
+            .iter()
+            .map(|p| self.won_cents(&p.player_name))
+            .sum();
+        match self.won_cents(player_name) {
+            won if total > 0 => (self.rake_cents as i128 * won as i128 / total as i128) as i64,
+            _ => 0,
+        }
+    }
+
+    /// Diferencia `invertido - (cobrado + rake)`; 0 si la mano cuadra.
+    pub fn discrepancy_cents(&self) -> i64 {
+        self.total_invested_cents() - self.total_collected_cents() - self.rake_cents
+    }
+
+    /// Comprueba los botes contra los cobros del historial.
+    pub fn validate(&self) -> Result<(), PotMismatch> {
+        if self.discrepancy_cents() != 0 {
+            return Err(PotMismatch::Totals {
+                invested_cents: self.total_invested_cents(),
+                collected_cents: self.total_collected_cents(),
+                rake_cents: self.rake_cents,
+            });
+        }
+
+        for player in self.players.iter().filter(|p| p.collected_cents > 0) {
+            let max_cents = self
+                .pots
+                .iter()
+                .filter(|pot| pot.eligible.contains(&player.player_name))
+                .map(|pot| pot.amount_cents)
+                .sum::<i64>()
+                + match &self.uncalled {
+                    Some((name, amount)) if *name == player.player_name => *amount,
+                    _ => 0,
+                };
+            if player.collected_cents > max_cents {
+                return Err(PotMismatch::OverCollected {
+                    player_name: player.player_name.clone(),
+                    collected_cents: player.collected_cents,
+                    max_cents,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Indica si los botes cuadran con los cobros.
+    pub fn is_consistent(&self) -> bool {
+        self.validate().is_ok()
+    }
+}
+
+/// Fichas invertidas por un jugador en la mano.
+///
+/// Los raises del parser indican el total de la calle ("raises to"), por lo
+/// que la inversión de cada calle es el máximo comprometido, no la suma.
+pub fn invested_cents(hand: &ParsedHand, player_name: &str) -> i64 {
+    let mut invested = 0i64;
+    let mut street = Street::Preflop;
+    let mut committed = 0i64;
+
+    for action in hand
+        .actions
+        .iter()
+        .filter(|a| &*a.player_name == player_name)
+    {
+        if action.street != street {
+            invested += committed;
+            committed = 0;
+            street = action.street;
+        }
+
+        let amount = action.amount_cents.unwrap_or(0);
+        match action.action_type {
+            ActionType::PostAnte => invested += amount,
+            ActionType::PostSmallBlind
+            | ActionType::PostBigBlind
+            | ActionType::Call
+            | ActionType::Bet
+            | ActionType::AllIn => committed += amount,
+            ActionType::Raise => committed = committed.max(amount),
+            _ => {}
+        }
+    }
+
+    invested + committed
+}
+
+/// Separa la parte de la mayor contribución que nadie igualó.
+fn take_uncalled(players: &[PlayerPotResult], contributions: &mut [i64]) -> Option<(String, i64)> {
+    let (top, &top_amount) = contributions
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !players[*i].folded)
+        .max_by_key(|(_, &amount)| amount)?;
+    let second = contributions
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != top)
+        .map(|(_, &amount)| amount)
+        .max()
+        .unwrap_or(0);
+
+    let excess = top_amount - second;
+    if excess > 0 {
+        contributions[top] -= excess;
+        Some((players[top].player_name.clone(), excess))
+    } else {
+        None
+    }
+}
+
+/// Construye bote principal y side pots por niveles de contribución de los
+/// jugadores vivos. Las fichas de los retirados se reparten en los niveles
+/// que alcanzaron; el último nivel absorbe las que lo superen.
+fn build_pots(players: &[PlayerPotResult], contributions: &[i64]) -> Vec<SidePot> {
+    let live: Vec<usize> = (0..players.len())
+        .filter(|&i| !players[i].folded && contributions[i] > 0)
+        .collect();
+
+    let mut levels: Vec<i64> = live.iter().map(|&i| contributions[i]).collect();
+    levels.sort_unstable();
+    levels.dedup();
+
+    let mut pots = Vec::with_capacity(levels.len());
+    let mut previous = 0;
+    for (n, &level) in levels.iter().enumerate() {
+        let cap = if n + 1 == levels.len() {
+            i64::MAX
+        } else {
+            level
+        };
+        let amount_cents: i64 = contributions
+            .iter()
+            .map(|&c| c.min(cap) - c.min(previous))
+            .sum();
+        if amount_cents > 0 {
+            pots.push(SidePot {
+                amount_cents,
+                eligible: live
+                    .iter()
+                    .filter(|&&i| contributions[i] >= level)
+                    .map(|&i| players[i].player_name.clone())
+                    .collect(),
+            });
+        }
+        previous = level;
+    }
+
+    pots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Action, Player, PotInfo};
+
+    /// Mano real de Winamax (ver `fsm::tests::test_parse_simple_hand`).
+    fn winamax_hand() -> ParsedHand {
+        use ActionType::*;
+        use Street::*;
+
+        ParsedHand {
+            players: vec![
+                Player::new("captainogue", 1, 176),
+                Player::new("verlan4", 2, 224),
+                Player::new("CucleBen", 3, 182),
+                Player::new("D0LLIPRANE", 4, 293),
+                Player::new("thesmoy", 5, 200),
+            ],
+            actions: vec![
+                Action::new("D0LLIPRANE", PostSmallBlind, Some(1), Preflop),
+                Action::new("thesmoy", PostBigBlind, Some(2), Preflop),
+                Action::new("captainogue", Fold, None, Preflop),
+                Action::new("verlan4", Call, Some(2), Preflop),
+                Action::new("CucleBen", Raise, Some(6), Preflop),
+                Action::new("D0LLIPRANE", Fold, None, Preflop),
+                Action::new("thesmoy", Call, Some(4), Preflop),
+                Action::new("verlan4", Call, Some(4), Preflop),
+                Action::new("thesmoy", Fold, None, Flop),
+                Action::new("CucleBen", Bet, Some(19), Flop),
+                Action::new("verlan4", Call, Some(19), Flop),
+                Action::new("CucleBen", Bet, Some(37), Turn),
+                Action::new("verlan4", Fold, None, Turn),
+                Action::new("CucleBen", Collect, Some(91), Turn),
+            ],
+            pot: PotInfo {
+                total_cents: 91,
+                rake_cents: 3,
+                winners: vec![("CucleBen".into(), 91)],
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_winamax_hand_is_consistent() {
+        let resolution = PotResolution::from_hand(&winamax_hand());
+
+        assert_eq!(resolution.total_invested_cents(), 94);
+        assert_eq!(resolution.uncalled, Some(("CucleBen".to_string(), 37)));
+        assert_eq!(resolution.pots.len(), 1);
+        assert_eq!(resolution.pots[0].amount_cents, 57);
+        assert_eq!(resolution.pots[0].eligible, vec!["CucleBen".to_string()]);
+        assert_eq!(resolution.net_cents("CucleBen"), 91 - 62);
+        assert_eq!(resolution.net_cents("verlan4"), -25);
+        assert_eq!(resolution.net_cents("captainogue"), 0);
+        assert_eq!(resolution.rake_share_cents("CucleBen"), 3);
+        assert!(resolution.validate().is_ok());
+    }
+
+    #[test]
+    fn test_uncalled_bet_pays_no_rake() {
+        // Bastionkid32 pierde el showdown y recupera su subida no igualada
+        // como "side pot 1"; todo el rake sale del bote de verlan4.
+        let content = include_str!("../tests/fixtures/winamax/uncalled_side_pot.txt");
+        let hand = crate::WinamaxParser::new().parse(content).hands.remove(0);
+        let resolution = PotResolution::from_hand(&hand);
+
+        assert!(resolution.validate().is_ok());
+        assert_eq!(resolution.uncalled, Some(("Bastionkid32".to_string(), 150)));
+        assert_eq!(
+            resolution.player("Bastionkid32").unwrap().collected_cents,
+            150
+        );
+        assert_eq!(resolution.won_cents("Bastionkid32"), 0);
+        assert_eq!(resolution.won_cents("verlan4"), 261);
+        assert_eq!(resolution.rake_share_cents("Bastionkid32"), 0);
+        assert_eq!(resolution.rake_share_cents("verlan4"), 16);
+    }
+
+    #[test]
+    fn test_multiway_all_in_side_pots() {
+        // A all-in 100, B all-in 300, C paga 300, D ciega y fold
+        let hand = ParsedHand {
+            players: vec![
+                Player::new("A", 1, 100),
+                Player::new("B", 2, 300),
+                Player::new("C", 3, 1000),
+                Player::new("D", 4, 1000),
+            ],
+            actions: vec![
+                Action::new("C", ActionType::PostSmallBlind, Some(5), Street::Preflop),
+                Action::new("D", ActionType::PostBigBlind, Some(10), Street::Preflop),
+                Action::new("A", ActionType::Raise, Some(100), Street::Preflop).all_in(),
+                Action::new("B", ActionType::Raise, Some(300), Street::Preflop).all_in(),
+                Action::new("C", ActionType::Call, Some(295), Street::Preflop),
+                Action::new("D", ActionType::Fold, None, Street::Preflop),
+            ],
+            pot: PotInfo {
+                total_cents: 700,
+                rake_cents: 10,
+                // A gana el principal (310 - rake), B el side pot
+                winners: vec![("A".into(), 300), ("B".into(), 400)],
+            },
+            ..Default::default()
+        };
+        let resolution = PotResolution::from_hand(&hand);
+
+        assert_eq!(resolution.uncalled, None);
+        assert_eq!(
+            resolution.pots,
+            vec![
+                SidePot {
+                    amount_cents: 310,
+                    eligible: vec!["A".to_string(), "B".to_string(), "C".to_string()],
+                },
+                SidePot {
+                    amount_cents: 400,
+                    eligible: vec!["B".to_string(), "C".to_string()],
+                },
+            ]
+        );
+        assert_eq!(resolution.contested_cents(), 710);
+        assert!(resolution.player("A").unwrap().all_in);
+        assert!(resolution.player("D").unwrap().folded);
+        assert_eq!(resolution.net_cents("A"), 200);
+        assert_eq!(resolution.net_cents("C"), -300);
+        assert!(resolution.validate().is_ok());
+    }
+
+    #[test]
+    fn test_mismatch_is_reported() {
+        let mut hand = winamax_hand();
+        hand.pot.winners = vec![("CucleBen".into(), 80)];
+        assert!(matches!(
+            PotResolution::from_hand(&hand).validate(),
+            Err(PotMismatch::Totals { .. })
+        ));
+
+        // El que foldeó no puede cobrar
+        let mut hand = winamax_hand();
+        hand.pot.winners = vec![("verlan4".into(), 91)];
+        let err = PotResolution::from_hand(&hand).validate().unwrap_err();
+        assert!(matches!(err, PotMismatch::OverCollected { .. }));
+        assert!(err.to_string().contains("verlan4"));
+    }
+}
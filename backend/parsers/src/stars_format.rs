@@ -0,0 +1,809 @@
+//! FSM compartida para historiales con formato PokerStars.
+//!
+//! PokerStars y GGPoker exportan historiales de texto casi idénticos:
+//!
+//! ```text
+//! PokerStars Hand #254458712345:  Hold'em No Limit ($0.05/$0.10 USD) - 2025/01/15 20:15:30 CET [2025/01/15 14:15:30 ET]
+//! Table 'Aludra' 6-max Seat #3 is the button
+//! Seat 1: Player1 ($10.00 in chips)
+//! Player1: posts small blind $0.05
+//! *** HOLE CARDS ***
+//! Dealt to thesmoy [Ah Kd]
+//! thesmoy: raises $0.20 to $0.30
+//! Uncalled bet ($0.20) returned to thesmoy
+//! thesmoy collected $0.25 from pot
+//! *** SUMMARY ***
+//! Total pot $0.25 | Rake $0
+//! ```
+//!
+//! Las diferencias de GGPoker (cabecera `Poker Hand #`, `*** SHOWDOWN ***`,
+//! `Dealt to` sin cartas para los rivales y comisiones extra como `Jackpot`
+//! en el resumen) se resuelven en la misma FSM.
+//!
+//! ## Normalización a las convenciones de Winamax
+//! - La apuesta no igualada (`Uncalled bet ... returned to`) se suma al cobro
+//!   del jugador, como en el `collected` de Winamax
+//! - `Total pot` se guarda con rake y demás comisiones descontadas
+//! - El héroe es el jugador con cartas en `Dealt to`
+//! - Los timestamps se convierten a UTC (`ET` según el horario de verano de
+//!   EE. UU.); sin zona horaria se asume UTC
+//! - Las comas de los importes son separadores de miles
+//!
+//! ## Diagnósticos
+//! Se informan cantidades y cartas inválidas, manos sin jugadores y botes que
+//! no cuadran. Las líneas no reconocidas (chat, jugadores que se sientan o se
+//! levantan, `mucks hand`...) se ignoran sin diagnóstico: estos formatos
+//! tienen muchas más líneas informativas que Winamax.
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Weekday};
+use std::sync::Arc;
+
+use crate::diagnostics::{DiagnosticKind, ParseDiagnostic};
+use crate::hero::HeroConfig;
+use crate::history_parser::site_from_line;
+use crate::pot_resolver::PotResolution;
+use crate::tournament_summary::parse_buy_in;
+use crate::types::{
+    Action, ActionType, Card, GameType, GameVariant, HoleCards, ParseResult, ParsedHand,
+    ParserState, Player, Site, Street, TournamentFormat, TournamentInfo,
+};
+
+/// Formato de salida de los timestamps (el mismo que Winamax).
+const TIMESTAMP_FORMAT: &str = "%Y/%m/%d %H:%M:%S";
+
+/// Parser FSM para historiales con formato PokerStars.
+#[derive(Debug)]
+pub(crate) struct StarsFormatParser {
+    /// Sala de los historiales (PokerStars o GGPoker).
+    site: Site,
+    /// Estado actual del parser.
+    state: ParserState,
+    /// Mano actual en construcción.
+    current_hand: ParsedHand,
+    /// Calle actual para asignar acciones.
+    current_street: Street,
+    /// Manos completadas.
+    hands: Vec<ParsedHand>,
+    /// Diagnósticos encontrados durante el parsing.
+    diagnostics: Vec<ParseDiagnostic>,
+    /// Número de la última línea procesada (desde 1).
+    line_no: usize,
+    /// Nicknames del héroe (vacío = el jugador de `Dealt to`).
+    heroes: HeroConfig,
+}
+
+impl StarsFormatParser {
+    /// Crea un parser en estado inicial para la sala indicada.
+    pub(crate) fn new(site: Site) -> Self {
+        Self {
+            site,
+            state: ParserState::Initial,
+            current_hand: ParsedHand::default(),
+            current_street: Street::Preflop,
+            hands: Vec::new(),
+            diagnostics: Vec::new(),
+            line_no: 0,
+            heroes: HeroConfig::default(),
+        }
+    }
+
+    /// Establece los nicknames del héroe.
+    pub(crate) fn set_heroes(&mut self, heroes: HeroConfig) {
+        self.heroes = heroes;
+    }
+
+    /// Sala de los historiales.
+    pub(crate) fn site(&self) -> Site {
+        self.site
+    }
+
+    /// Parsea un archivo completo de historial.
+    pub(crate) fn parse(&mut self, content: &str) -> ParseResult {
+        for line in content.lines() {
+            self.process_line(line);
+        }
+
+        // Finalizar última mano si existe
+        if !self.current_hand.hand_id.is_empty() {
+            self.finalize_hand();
+        }
+
+        ParseResult::new(
+            std::mem::take(&mut self.hands),
+            std::mem::take(&mut self.diagnostics),
+            Vec::new(),
+        )
+    }
+
+    /// Resetea el parser para procesar un nuevo archivo.
+    pub(crate) fn reset(&mut self) {
+        self.state = ParserState::Initial;
+        self.current_hand = ParsedHand::default();
+        self.current_street = Street::Preflop;
+        self.hands.clear();
+        self.diagnostics.clear();
+        self.line_no = 0;
+    }
+
+    /// Procesa una línea individual según el estado actual.
+    fn process_line(&mut self, line: &str) {
+        self.line_no += 1;
+        let line = line.trim_start_matches('\u{feff}').trim();
+
+        // Líneas vacías tras el resumen indican fin de mano
+        if line.is_empty() {
+            if !self.current_hand.hand_id.is_empty() && self.state == ParserState::Summary {
+                self.finalize_hand();
+            }
+            return;
+        }
+
+        if site_from_line(line.as_bytes()) == Some(self.site) {
+            self.start_new_hand(line);
+            return;
+        }
+
+        if self.current_hand.hand_id.is_empty() {
+            return;
+        }
+
+        if line.starts_with("Table '") {
+            self.parse_table_info(line);
+            return;
+        }
+
+        if self.state == ParserState::Seats && line.starts_with("Seat ") {
+            self.parse_seat_info(line);
+            return;
+        }
+
+        if line.starts_with("*** ") {
+            self.handle_section_marker(line);
+            return;
+        }
+
+        if line.starts_with("Dealt to ") {
+            self.parse_dealt_cards(line);
+            return;
+        }
+
+        // Chat de la mesa: "Player1 said, \"gg\""
+        if line.contains(" said, \"") {
+            return;
+        }
+
+        if line.starts_with("Uncalled bet") {
+            self.parse_uncalled_bet(line);
+            return;
+        }
+
+        match self.state {
+            ParserState::Summary => self.parse_summary_line(line),
+            ParserState::Seats
+            | ParserState::Preflop
+            | ParserState::Flop
+            | ParserState::Turn
+            | ParserState::River
+            | ParserState::Showdown => self.parse_action(line),
+            _ => {}
+        }
+    }
+
+    /// Inicia el parsing de una nueva mano a partir de la cabecera.
+    ///
+    /// Formatos:
+    /// - `PokerStars Hand #1: Hold'em No Limit ($0.05/$0.10 USD) - ...`
+    /// - `PokerStars Hand #1: Tournament #2, $1.00+$0.10 USD Hold'em No Limit - Level I (10/20) - ...`
+    /// - `Poker Hand #TM1: Tournament #2, Bounty Hunters $10.80 Hold'em No Limit - Level10(400/800(100)) - ...`
+    fn start_new_hand(&mut self, line: &str) {
+        // Si hay una mano anterior sin finalizar, guardarla
+        if !self.current_hand.hand_id.is_empty() {
+            self.finalize_hand();
+        }
+
+        self.current_hand = ParsedHand {
+            site: self.site,
+            ..ParsedHand::default()
+        };
+        self.current_street = Street::Preflop;
+        self.state = ParserState::Header;
+
+        // Extraer HandId: "Hand #254458712345:"
+        let Some((_, after_id)) = line.split_once("Hand #") else {
+            return;
+        };
+        let (hand_id, game) = after_id.split_once(':').unwrap_or((after_id, ""));
+        self.current_hand.hand_id = hand_id.trim().to_string();
+        let game = game.trim();
+
+        if game.starts_with("Tournament #") {
+            self.current_hand.game_type = GameType::Tournament;
+            self.current_hand.tournament = Some(parse_tournament_header(game));
+        }
+        self.current_hand.variant = GameVariant::from_header(game);
+
+        // Ciegas: "($0.05/$0.10 USD)" en cash, "Level I (10/20)" en torneo
+        let blinds_from = game.find("Level").unwrap_or(0);
+        if let Some(start) = game[blinds_from..].find('(').map(|i| blinds_from + i) {
+            let end = game[start..].find(" - ").map_or(game.len(), |i| start + i);
+            self.parse_blinds(&game[start + 1..end]);
+        }
+
+        self.current_hand.timestamp = normalize_timestamp(line).unwrap_or_default();
+    }
+
+    /// Parsea las ciegas de la cabecera.
+    ///
+    /// Formatos: `$0.05/$0.10 USD)`, `10/20)`, `400/800(100))` (GGPoker, con
+    /// el ante entre paréntesis).
+    fn parse_blinds(&mut self, blinds_str: &str) {
+        let blinds_str = blinds_str.trim().trim_end_matches(')');
+        let (blinds, ante) = match blinds_str.split_once('(') {
+            Some((blinds, ante)) => (blinds, Some(ante)),
+            None => (blinds_str, None),
+        };
+
+        if let Some((sb, bb)) = blinds.split_once('/') {
+            self.current_hand.small_blind_cents = self.parse_amount(sb);
+            self.current_hand.big_blind_cents = self.parse_amount(bb);
+        }
+        if let Some(ante) = ante {
+            self.current_hand.ante_cents = self.parse_amount(ante);
+        }
+    }
+
+    /// Parsea información de la mesa.
+    fn parse_table_info(&mut self, line: &str) {
+        // Formato: Table 'Aludra' 6-max (Play Money) Seat #3 is the button
+        self.state = ParserState::Seats;
+
+        if let Some((_, rest)) = line.split_once('\'') {
+            if let Some((name, _)) = rest.split_once('\'') {
+                self.current_hand.table_name = name.to_string();
+            }
+        }
+
+        if let Some(idx) = line.find("-max") {
+            let digits_start = line[..idx]
+                .rfind(|c: char| !c.is_ascii_digit())
+                .map_or(0, |i| i + 1);
+            if let Ok(max_players) = line[digits_start..idx].parse::<u8>() {
+                self.current_hand.max_players = max_players;
+            }
+        }
+
+        if let Some((_, rest)) = line.split_once("Seat #") {
+            let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+            if let Ok(seat) = digits.parse() {
+                self.current_hand.button_seat = seat;
+            }
+        }
+
+        if line.contains("Play Money") {
+            self.current_hand.is_play_money = true;
+        }
+    }
+
+    /// Parsea información de un asiento/jugador.
+    fn parse_seat_info(&mut self, line: &str) {
+        // Formato: Seat 1: Player1 ($10.00 in chips)
+        // Torneo: Seat 1: Player1 (1500 in chips, $0.50 bounty)
+        // Los jugadores ausentes no reciben cartas y no cuentan para posiciones
+        if line.contains("is sitting out") || line.contains("out of hand") {
+            return;
+        }
+
+        let Some((seat_str, rest)) = line["Seat ".len()..].split_once(": ") else {
+            return;
+        };
+        let Ok(seat) = seat_str.trim().parse::<u8>() else {
+            return;
+        };
+        let Some(chips_idx) = rest.find(" in chips") else {
+            return;
+        };
+        let Some(paren_start) = rest[..chips_idx].rfind('(') else {
+            return;
+        };
+
+        let stack_cents = self.parse_amount(&rest[paren_start + 1..chips_idx]);
+        let name = rest[..paren_start].trim();
+        let is_hero = self.heroes.is_hero(self.site, name);
+        self.current_hand.players.push(Player {
+            name: name.into(),
+            seat,
+            stack_cents,
+            position: None, // Se calcula al finalizar la mano
+            hole_cards: None,
+            is_hero,
+        });
+    }
+
+    /// Maneja marcadores de sección (*** XXX ***).
+    fn handle_section_marker(&mut self, line: &str) {
+        // Run it twice: se conserva solo el primer board
+        if line.contains("SECOND") {
+            return;
+        }
+
+        if line.contains("HOLE CARDS") {
+            self.state = ParserState::Preflop;
+            self.current_street = Street::Preflop;
+        } else if line.contains("FLOP") {
+            self.state = ParserState::Flop;
+            self.current_street = Street::Flop;
+            self.parse_board_cards(line);
+        } else if line.contains("TURN") {
+            self.state = ParserState::Turn;
+            self.current_street = Street::Turn;
+            self.parse_board_cards(line);
+        } else if line.contains("RIVER") {
+            self.state = ParserState::River;
+            self.current_street = Street::River;
+            self.parse_board_cards(line);
+        } else if line.contains("SHOW DOWN") || line.contains("SHOWDOWN") {
+            self.state = ParserState::Showdown;
+        } else if line.contains("SUMMARY") {
+            self.state = ParserState::Summary;
+        }
+    }
+
+    /// Añade al board las cartas nuevas (último grupo entre corchetes).
+    ///
+    /// Formatos: `*** FLOP *** [2c 7d 9h]`, `*** TURN *** [2c 7d 9h] [Js]`.
+    fn parse_board_cards(&mut self, line: &str) {
+        let Some(start) = line.rfind('[') else {
+            return;
+        };
+        let Some(end) = line[start..].find(']') else {
+            return;
+        };
+        for token in line[start + 1..start + end].split_whitespace() {
+            if let Some(card) = self.parse_card(token) {
+                self.current_hand.board.push(card);
+            }
+        }
+    }
+
+    /// Parsea una carta, registrando un diagnóstico si no es válida.
+    fn parse_card(&mut self, token: &str) -> Option<Card> {
+        let card = Card::parse(token).filter(Card::is_valid);
+        if card.is_none() {
+            self.report(DiagnosticKind::BadCard, format!("Invalid card: {}", token));
+        }
+        card
+    }
+
+    /// Parsea las cartas repartidas.
+    ///
+    /// Sin nicknames configurados, el primer jugador con cartas visibles es el
+    /// héroe; GGPoker también escribe `Dealt to` sin cartas para los rivales.
+    fn parse_dealt_cards(&mut self, line: &str) {
+        // Formato: Dealt to thesmoy [Ah Kd]
+        let rest = &line["Dealt to ".len()..];
+        let Some((name, _)) = rest.split_once(" [") else {
+            return;
+        };
+        let Some(cards) = self.parse_hole_cards(rest) else {
+            return;
+        };
+
+        let auto_hero =
+            self.heroes.is_empty() && !self.current_hand.players.iter().any(|p| p.is_hero);
+        if let Some(player) = self
+            .current_hand
+            .players
+            .iter_mut()
+            .find(|p| &*p.name == name.trim())
+        {
+            if auto_hero {
+                player.is_hero = true;
+            }
+            if player.is_hero && self.current_hand.hero_cards.is_none() {
+                self.current_hand.hero_cards = Some(cards.clone());
+            }
+            player.hole_cards = Some(cards);
+        }
+    }
+
+    /// Extrae las cartas propias del primer grupo entre corchetes.
+    ///
+    /// Solo se aceptan si el número de cartas coincide con la variante.
+    fn parse_hole_cards(&mut self, line: &str) -> Option<HoleCards> {
+        let start = line.find('[')?;
+        let end = start + line[start..].find(']')?;
+        let cards = line[start + 1..end]
+            .split_whitespace()
+            .map(|token| self.parse_card(token))
+            .collect::<Option<HoleCards>>()?;
+
+        (cards.len() == self.current_hand.variant.hole_card_count()).then_some(cards)
+    }
+
+    /// Parsea una acción de juego (`Nombre: acción`) o un cobro.
+    fn parse_action(&mut self, line: &str) {
+        let action_part = line
+            .split_once(": ")
+            .filter(|(name, _)| self.current_hand.players.iter().any(|p| &*p.name == *name));
+        let Some((player_name, rest)) = action_part else {
+            // Cobros sin dos puntos: "thesmoy collected $0.25 from pot"
+            if let Some((name, amount)) = line.split_once(" collected ") {
+                let amount = self.parse_first_amount(amount);
+                self.push_collect(name.trim(), amount);
+            }
+            return;
+        };
+
+        let is_all_in = rest.contains("all-in");
+        let (action_type, amount) = if rest.starts_with("posts small & big blinds") {
+            // Ciega pequeña muerta + ciega grande
+            let total = self.parse_first_amount(rest);
+            let big_blind = self.current_hand.big_blind_cents.min(total);
+            if total > big_blind {
+                self.push_action(
+                    player_name,
+                    ActionType::PostAnte,
+                    Some(total - big_blind),
+                    false,
+                );
+            }
+            (ActionType::PostBigBlind, Some(big_blind))
+        } else if rest.starts_with("posts small blind") {
+            (
+                ActionType::PostSmallBlind,
+                Some(self.parse_first_amount(rest)),
+            )
+        } else if rest.starts_with("posts big blind") || rest.starts_with("posts straddle") {
+            // El straddle cuenta como una ciega grande adicional
+            (
+                ActionType::PostBigBlind,
+                Some(self.parse_first_amount(rest)),
+            )
+        } else if rest.starts_with("posts the ante") || rest.starts_with("posts ante") {
+            let ante = self.parse_first_amount(rest);
+            self.current_hand.ante_cents = self.current_hand.ante_cents.max(ante);
+            (ActionType::PostAnte, Some(ante))
+        } else if rest.starts_with("folds") {
+            (ActionType::Fold, None)
+        } else if rest.starts_with("checks") {
+            (ActionType::Check, None)
+        } else if rest.starts_with("calls ") {
+            (ActionType::Call, Some(self.parse_first_amount(rest)))
+        } else if rest.starts_with("bets ") {
+            (ActionType::Bet, Some(self.parse_first_amount(rest)))
+        } else if let Some((_, to)) = rest
+            .strip_prefix("raises ")
+            .and_then(|r| r.split_once(" to "))
+        {
+            // "raises $0.20 to $0.30" -> total de la calle
+            (ActionType::Raise, Some(self.parse_first_amount(to)))
+        } else if rest.starts_with("shows ") {
+            if let Some(cards) = self.parse_hole_cards(rest) {
+                if let Some(player) = self
+                    .current_hand
+                    .players
+                    .iter_mut()
+                    .find(|p| &*p.name == player_name)
+                {
+                    player.hole_cards = Some(cards);
+                }
+            }
+            (ActionType::Show, None)
+        } else {
+            return;
+        };
+
+        self.push_action(player_name, action_type, amount, is_all_in);
+    }
+
+    /// Suma la apuesta no igualada al cobro del jugador.
+    fn parse_uncalled_bet(&mut self, line: &str) {
+        // Formato: Uncalled bet ($0.20) returned to thesmoy
+        let Some((amount, name)) = line.split_once(" returned to ") else {
+            return;
+        };
+        let amount = self.parse_first_amount(amount);
+        self.push_collect(name.trim(), amount);
+    }
+
+    /// Registra un cobro como ganancia y acción `Collect`.
+    fn push_collect(&mut self, player_name: &str, amount: i64) {
+        if player_name.is_empty() || amount <= 0 {
+            return;
+        }
+        let name = self.shared_name(player_name);
+        self.current_hand.pot.winners.push((name, amount));
+        self.push_action(player_name, ActionType::Collect, Some(amount), false);
+    }
+
+    /// Nombre compartido con el jugador sentado, para no reservar memoria
+    /// por cada acción.
+    fn shared_name(&self, player_name: &str) -> Arc<str> {
+        self.current_hand
+            .players
+            .iter()
+            .find(|p| &*p.name == player_name)
+            .map_or_else(|| player_name.into(), |p| Arc::clone(&p.name))
+    }
+
+    fn push_action(
+        &mut self,
+        player_name: &str,
+        action_type: ActionType,
+        amount_cents: Option<i64>,
+        is_all_in: bool,
+    ) {
+        self.current_hand.actions.push(Action {
+            player_name: self.shared_name(player_name),
+            action_type,
+            amount_cents,
+            is_all_in,
+            street: self.current_street,
+        });
+    }
+
+    /// Parsea una línea de la sección SUMMARY.
+    fn parse_summary_line(&mut self, line: &str) {
+        // Formatos:
+        //   Total pot $2.60 | Rake $0.10
+        //   Total pot $5.50 Main pot $4. Side pot $1.50. | Rake $0.25
+        //   Total pot $1.20 | Rake $0.05 | Jackpot $0.01 | Bingo $0 (GGPoker)
+        let Some(rest) = line.strip_prefix("Total pot") else {
+            return;
+        };
+
+        let mut parts = rest.split('|');
+        let total = parts.next().map_or(0, |p| self.parse_first_amount(p));
+        let fees: i64 = parts.map(|p| self.parse_first_amount(p)).sum();
+
+        self.current_hand.pot.total_cents = total - fees;
+        self.current_hand.pot.rake_cents = fees;
+    }
+
+    /// Parsea el primer monto encontrado en un string.
+    fn parse_first_amount(&mut self, s: &str) -> i64 {
+        if !s.chars().any(|c| c.is_ascii_digit()) {
+            self.report(
+                DiagnosticKind::BadAmount,
+                format!("Missing amount: {}", s.trim()),
+            );
+            return 0;
+        }
+        let amount: String = s
+            .chars()
+            .skip_while(|c| !c.is_ascii_digit())
+            .take_while(|c| c.is_ascii_digit() || *c == '.' || *c == ',')
+            .collect();
+        self.parse_amount(&amount)
+    }
+
+    /// Convierte un string de cantidad a centavos (fichas en torneos).
+    fn parse_amount(&self, s: &str) -> i64 {
+        let cleaned: String = s
+            .chars()
+            .filter(|c| c.is_ascii_digit() || *c == '.')
+            .collect();
+        let cleaned = cleaned.trim_end_matches('.');
+
+        match cleaned.parse::<f64>() {
+            Ok(amount) if self.current_hand.is_chips() => amount.round() as i64,
+            Ok(amount) => (amount * 100.0).round() as i64,
+            Err(_) => 0,
+        }
+    }
+
+    /// Registra un diagnóstico en la línea y el estado actuales.
+    fn report(&mut self, kind: DiagnosticKind, message: String) {
+        let diagnostic = ParseDiagnostic::new(kind, message)
+            .at(self.line_no, self.state)
+            .in_hand(&self.current_hand.hand_id);
+        self.diagnostics.push(diagnostic);
+    }
+
+    /// Finaliza la mano actual y la agrega a la lista de manos completadas.
+    fn finalize_hand(&mut self) {
+        if self.current_hand.hand_id.is_empty() {
+            return;
+        }
+
+        if self.current_hand.players.is_empty() {
+            self.report(
+                DiagnosticKind::MissingPlayers,
+                format!("Hand {} has no players", self.current_hand.hand_id),
+            );
+        }
+
+        if let Err(mismatch) = PotResolution::from_hand(&self.current_hand).validate() {
+            self.report(DiagnosticKind::InconsistentPot, mismatch.to_string());
+        }
+
+        self.current_hand.assign_positions();
+
+        self.hands.push(std::mem::take(&mut self.current_hand));
+        self.state = ParserState::Initial;
+    }
+}
+
+/// Parsea los datos de torneo de la cabecera.
+///
+/// Formato: `Tournament #3456789012, $1.00+$0.10 USD Hold'em No Limit - Level I (10/20)`.
+/// GGPoker añade el nombre antes del buy-in.
+fn parse_tournament_header(game: &str) -> TournamentInfo {
+    let rest = &game["Tournament #".len()..];
+    let tournament_id: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+
+    // Descripción entre la coma y la variante: "[Nombre] $1.00+$0.10 USD"
+    let description = rest.split_once(", ").map_or("", |(_, d)| d);
+    let description = description.split(" - ").next().unwrap_or("");
+    let game_start = ["Hold'em", "5 Card Omaha", "Omaha", "PLO"]
+        .iter()
+        .filter_map(|name| description.find(name))
+        .min()
+        .unwrap_or(description.len());
+    let description = description[..game_start].trim();
+
+    let (name, buy_in) = match description.find(['$', '€', '£']) {
+        Some(idx) => (description[..idx].trim(), &description[idx..]),
+        None => (description, ""),
+    };
+    let (buy_in_cents, fee_cents) = parse_buy_in(buy_in);
+    let name = if name.eq_ignore_ascii_case("freeroll") {
+        String::new()
+    } else {
+        name.to_string()
+    };
+
+    TournamentInfo {
+        tournament_id,
+        format: TournamentFormat::from_name(&name),
+        name,
+        buy_in_cents,
+        fee_cents,
+        level: parse_level(game),
+        bb_ante: false,
+    }
+}
+
+/// Nivel de ciegas: `Level XV (100/200)` (romano) o `Level10(400/800)`.
+fn parse_level(game: &str) -> u32 {
+    let Some((_, rest)) = game.split_once("Level") else {
+        return 0;
+    };
+    let token: String = rest
+        .trim_start()
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .collect();
+
+    token.parse().unwrap_or_else(|_| parse_roman(&token))
+}
+
+/// Convierte un número romano (I-L) a entero; 0 si no es válido.
+fn parse_roman(s: &str) -> u32 {
+    let values: Vec<u32> = s
+        .chars()
+        .map(|c| match c {
+            'I' => 1,
+            'V' => 5,
+            'X' => 10,
+            'L' => 50,
+            'C' => 100,
+            _ => 0,
+        })
+        .collect();
+    if values.is_empty() || values.contains(&0) {
+        return 0;
+    }
+
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, &value)| match values.get(i + 1) {
+            Some(&next) if next > value => -(value as i64),
+            _ => value as i64,
+        })
+        .sum::<i64>() as u32
+}
+
+/// Timestamp de la cabecera normalizado a UTC (`2025/01/15 19:15:30 UTC`).
+///
+/// Prioriza una hora en UTC/GMT; si no, la hora `ET` convertida a UTC. Sin
+/// zona horaria (GGPoker) se toma la primera hora tal cual.
+fn normalize_timestamp(line: &str) -> Option<String> {
+    let candidates: Vec<(NaiveDateTime, &str)> = line
+        .split(" - ")
+        .flat_map(|segment| segment.split('['))
+        .filter_map(|candidate| {
+            let mut parts = candidate.trim().trim_end_matches(']').split_whitespace();
+            let date = parts.next()?;
+            let time = parts.next()?;
+            let zone = parts.next().unwrap_or("");
+            let timestamp =
+                NaiveDateTime::parse_from_str(&format!("{} {}", date, time), TIMESTAMP_FORMAT)
+                    .ok()?;
+            Some((timestamp, zone))
+        })
+        .collect();
+
+    let utc = candidates
+        .iter()
+        .find(|(_, zone)| matches!(*zone, "UTC" | "GMT"))
+        .map(|(ts, _)| *ts)
+        .or_else(|| {
+            candidates
+                .iter()
+                .find(|(_, zone)| *zone == "ET")
+                .map(|(ts, _)| eastern_to_utc(*ts))
+        })
+        .or_else(|| candidates.first().map(|(ts, _)| *ts))?;
+
+    Some(format!("{} UTC", utc.format(TIMESTAMP_FORMAT)))
+}
+
+/// Convierte la hora del este de EE. UU. (ET) a UTC.
+///
+/// Horario de verano (UTC-4) desde el segundo domingo de marzo hasta el
+/// primer domingo de noviembre a las 2:00; el resto del año UTC-5.
+fn eastern_to_utc(local: NaiveDateTime) -> NaiveDateTime {
+    let year = local.year();
+    let dst_bounds = NaiveDate::from_weekday_of_month_opt(year, 3, Weekday::Sun, 2)
+        .zip(NaiveDate::from_weekday_of_month_opt(
+            year,
+            11,
+            Weekday::Sun,
+            1,
+        ))
+        .and_then(|(start, end)| Some((start.and_hms_opt(2, 0, 0)?, end.and_hms_opt(2, 0, 0)?)));
+
+    let offset = match dst_bounds {
+        Some((start, end)) if local >= start && local < end => 4,
+        _ => 5,
+    };
+    local + Duration::hours(offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_timestamp() {
+        // Invierno: ET = UTC-5
+        assert_eq!(
+            normalize_timestamp(
+                "PokerStars Hand #1: Hold'em No Limit ($0.05/$0.10 USD) - 2025/01/15 20:15:30 CET [2025/01/15 14:15:30 ET]"
+            )
+            .as_deref(),
+            Some("2025/01/15 19:15:30 UTC")
+        );
+        // Verano: ET = UTC-4
+        assert_eq!(
+            normalize_timestamp(
+                "PokerStars Hand #1: Hold'em No Limit ($0.05/$0.10) - 2025/07/01 9:05:00 ET"
+            )
+            .as_deref(),
+            Some("2025/07/01 13:05:00 UTC")
+        );
+        // GGPoker: sin zona horaria
+        assert_eq!(
+            normalize_timestamp(
+                "Poker Hand #RC1: Hold'em No Limit ($0.02/$0.05) - 2025/01/15 20:15:30"
+            )
+            .as_deref(),
+            Some("2025/01/15 20:15:30 UTC")
+        );
+    }
+
+    #[test]
+    fn test_parse_level() {
+        assert_eq!(
+            parse_level("Tournament #1, Freeroll Hold'em No Limit - Level XIV (100/200)"),
+            14
+        );
+        assert_eq!(
+            parse_level("Tournament #1, $10 Hold'em No Limit - Level10(400/800(100))"),
+            10
+        );
+        assert_eq!(parse_level("Hold'em No Limit ($0.05/$0.10)"), 0);
+    }
+}
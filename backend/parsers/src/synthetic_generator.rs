@@ -35,9 +35,12 @@ use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
+use crate::pot_resolver::PotResolution;
 use crate::types::{
-    Action, ActionType, Card, GameType, ParsedHand, Player, Position, PotInfo, Street,
+    Action, ActionType, Card, GameType, GameVariant, HoleCards, ParsedHand, Player, Position,
+    PotInfo, Site, Street,
 };
 
 // ============================================================================
@@ -348,21 +351,21 @@ impl SyntheticGenerator {
             self.deal_cards(&mut deck, players_with_positions, rng);
 
         // Generar acciones
-        let (actions, pot_total, winners) = self.generate_actions(rng, &players_with_cards, stake);
+        let (actions, showdown) = self.generate_actions(rng, &players_with_cards, stake);
 
-        // Generar board (si hay acciones postflop)
-        let board = self.generate_board(&mut deck, &actions);
+        // Generar board (si hay acciones postflop o showdown)
+        let board = self.generate_board(&mut deck, &actions, showdown);
 
-        // Calcular rake (5% del bote, max 3BB)
-        let rake = (pot_total * 5 / 100).min(stake.big_blind_cents() * 3);
-
-        ParsedHand {
+        let mut hand = ParsedHand {
             hand_id,
+            site: Site::Winamax,
             game_type: GameType::CashGame,
+            variant: GameVariant::Holdem,
             table_name: format!("Synthetic Table {}", rng.gen_range(1..=100)),
             max_players: self.config.max_players,
             small_blind_cents: stake.small_blind_cents(),
             big_blind_cents: stake.big_blind_cents(),
+            ante_cents: 0,
             timestamp,
             button_seat,
             players: players_with_cards,
@@ -370,12 +373,17 @@ impl SyntheticGenerator {
             board,
             actions,
             pot: PotInfo {
-                total_cents: pot_total,
-                rake_cents: rake,
-                winners,
+                total_cents: 0,
+                rake_cents: 0,
+                winners: Vec::new(),
             },
             is_play_money: false,
-        }
+            tournament: None,
+        };
+
+        // Repartir el bote (incluye side pots y apuesta no igualada)
+        hand.pot = self.resolve_pot(rng, &hand);
+        hand
     }
 
     /// Genera un timestamp aleatorio en el rango configurado
@@ -392,7 +400,6 @@ impl SyntheticGenerator {
     /// Genera jugadores para la mano
     fn generate_players(&self, rng: &mut ChaCha8Rng, stake: StakeLevel) -> (Vec<Player>, u8) {
         let num_players = rng.gen_range(2..=self.config.max_players);
-        let button_seat = rng.gen_range(1..=num_players);
 
         // Lista de nombres de oponentes
         let opponent_names = [
@@ -413,6 +420,12 @@ impl SyntheticGenerator {
             "check_raise_cr",
         ];
 
+        // Nombres distintos: el parser y las estadisticas identifican por nombre
+        let opponents: Vec<&str> = opponent_names
+            .choose_multiple(rng, num_players as usize)
+            .copied()
+            .collect();
+
         let mut players = Vec::with_capacity(num_players as usize);
         let mut used_seats: Vec<u8> = Vec::new();
 
@@ -437,11 +450,7 @@ impl SyntheticGenerator {
                 if Some(i + 1) == hero_seat.map(|_| i + 1) && hero_present && i == 0 {
                     (self.config.hero_name.clone(), true)
                 } else {
-                    let name = opponent_names
-                        .choose(rng)
-                        .unwrap_or(&"opponent")
-                        .to_string();
-                    (name, false)
+                    (opponents[i as usize].to_string(), false)
                 };
 
             // Stack aleatorio entre 50BB y 200BB
@@ -449,7 +458,7 @@ impl SyntheticGenerator {
             let stack_cents = stack_bbs * stake.big_blind_cents();
 
             players.push(Player {
-                name,
+                name: name.into(),
                 seat,
                 stack_cents,
                 position: None,
@@ -461,10 +470,13 @@ impl SyntheticGenerator {
         // Ordenar por asiento
         players.sort_by_key(|p| p.seat);
 
+        // El boton siempre en un asiento ocupado
+        let button_seat = players[rng.gen_range(0..players.len())].seat;
+
         (players, button_seat)
     }
 
-    /// Asigna posiciones a los jugadores (6-max)
+    /// Asigna posiciones a los jugadores segun el tamaño de mesa
     fn assign_positions(&self, mut players: Vec<Player>, button_seat: u8) -> Vec<Player> {
         let num_players = players.len();
         if num_players == 0 {
@@ -478,18 +490,11 @@ impl SyntheticGenerator {
             .unwrap_or(0);
 
         // Asignar posiciones en orden desde el button
-        let positions_6max = [
-            Position::Button,
-            Position::SmallBlind,
-            Position::BigBlind,
-            Position::UTG,
-            Position::MP,
-            Position::CO,
-        ];
+        let positions = Position::table_order(self.config.max_players, num_players);
 
         for (i, player) in players.iter_mut().enumerate() {
             let pos_idx = (i + num_players - btn_idx) % num_players;
-            player.position = Some(positions_6max[pos_idx.min(5)]);
+            player.position = positions.get(pos_idx).copied();
         }
 
         players
@@ -517,14 +522,14 @@ impl SyntheticGenerator {
         deck: &mut Vec<Card>,
         mut players: Vec<Player>,
         _rng: &mut ChaCha8Rng,
-    ) -> (Vec<Player>, Option<[Card; 2]>) {
+    ) -> (Vec<Player>, Option<HoleCards>) {
         let mut hero_cards = None;
 
         for player in players.iter_mut() {
             if deck.len() >= 2 {
                 let card1 = deck.pop().unwrap();
                 let card2 = deck.pop().unwrap();
-                let cards = [card1.clone(), card2.clone()];
+                let cards = vec![card1, card2];
 
                 if player.is_hero {
                     hero_cards = Some(cards.clone());
@@ -537,26 +542,30 @@ impl SyntheticGenerator {
         (players, hero_cards)
     }
 
-    /// Genera el board basado en las acciones
-    fn generate_board(&self, deck: &mut Vec<Card>, actions: &[Action]) -> Vec<Card> {
+    /// Genera el board: las calles con acciones o, si hay showdown, las cinco
+    /// cartas (all-in antes del river)
+    fn generate_board(
+        &self,
+        deck: &mut Vec<Card>,
+        actions: &[Action],
+        showdown: bool,
+    ) -> Vec<Card> {
         let mut board = Vec::new();
 
         // Verificar si llegamos a cada street
-        let has_flop = actions.iter().any(|a| a.street == Street::Flop);
-        let has_turn = actions.iter().any(|a| a.street == Street::Turn);
-        let has_river = actions.iter().any(|a| a.street == Street::River);
+        let reached = |street: Street| showdown || actions.iter().any(|a| a.street == street);
 
-        if has_flop && deck.len() >= 3 {
+        if reached(Street::Flop) && deck.len() >= 3 {
             board.push(deck.pop().unwrap());
             board.push(deck.pop().unwrap());
             board.push(deck.pop().unwrap());
         }
 
-        if has_turn && !deck.is_empty() {
+        if reached(Street::Turn) && !deck.is_empty() {
             board.push(deck.pop().unwrap());
         }
 
-        if has_river && !deck.is_empty() {
+        if reached(Street::River) && !deck.is_empty() {
             board.push(deck.pop().unwrap());
         }
 
@@ -564,337 +573,259 @@ impl SyntheticGenerator {
     }
 
     /// Genera acciones realistas para la mano
+    ///
+    /// Simula rondas de apuestas completas en orden de asiento: ciegas desde
+    /// el boton, preflop desde la izquierda de la ciega grande y postflop
+    /// desde la izquierda del boton. Devuelve las acciones y si la mano llega
+    /// a showdown.
     fn generate_actions(
         &self,
         rng: &mut ChaCha8Rng,
         players: &[Player],
         stake: StakeLevel,
-    ) -> (Vec<Action>, i64, Vec<(String, i64)>) {
+    ) -> (Vec<Action>, bool) {
         let mut actions = Vec::new();
-        let mut pot = 0i64;
-        let mut player_bets: Vec<i64> = vec![0; players.len()];
-        let mut player_folded: Vec<bool> = vec![false; players.len()];
-        let bb = stake.big_blind_cents();
-        let sb = stake.small_blind_cents();
-
-        // Encontrar SB y BB
-        let sb_idx = players
-            .iter()
-            .position(|p| p.position == Some(Position::SmallBlind));
-        let bb_idx = players
-            .iter()
-            .position(|p| p.position == Some(Position::BigBlind));
+        let mut state = BettingState::new(players, stake.big_blind_cents());
+        let num_players = players.len();
 
-        // Postear ciegas
-        if let Some(idx) = sb_idx {
-            actions.push(Action {
-                player_name: players[idx].name.clone(),
-                action_type: ActionType::PostSmallBlind,
-                amount_cents: Some(sb),
-                is_all_in: false,
-                street: Street::Preflop,
-            });
-            pot += sb;
-            player_bets[idx] = sb;
-        }
+        // Ciegas: en heads-up el boton pone la ciega pequeña
+        let btn_idx = players
+            .iter()
+            .position(|p| p.position == Some(Position::Button))
+            .unwrap_or(0);
+        let (sb_idx, bb_idx) = if num_players == 2 {
+            (btn_idx, (btn_idx + 1) % num_players)
+        } else {
+            ((btn_idx + 1) % num_players, (btn_idx + 2) % num_players)
+        };
 
-        if let Some(idx) = bb_idx {
+        for (idx, action_type, blind) in [
+            (
+                sb_idx,
+                ActionType::PostSmallBlind,
+                stake.small_blind_cents(),
+            ),
+            (bb_idx, ActionType::PostBigBlind, stake.big_blind_cents()),
+        ] {
+            let amount = state.put(idx, blind);
             actions.push(Action {
                 player_name: players[idx].name.clone(),
-                action_type: ActionType::PostBigBlind,
-                amount_cents: Some(bb),
-                is_all_in: false,
+                action_type,
+                amount_cents: Some(amount),
+                is_all_in: state.all_in[idx],
                 street: Street::Preflop,
             });
-            pot += bb;
-            player_bets[idx] = bb;
         }
+        state.current_bet = state.committed.iter().copied().max().unwrap_or(0);
 
         // Simular preflop
-        let (preflop_actions, preflop_pot, preflop_folded) =
-            self.simulate_preflop(rng, players, &player_bets, &player_folded, stake);
-
-        actions.extend(preflop_actions);
-        pot += preflop_pot;
-        player_folded = preflop_folded;
-
-        // Contar jugadores activos
-        let active_players: Vec<usize> = player_folded
-            .iter()
-            .enumerate()
-            .filter(|(_, &folded)| !folded)
-            .map(|(i, _)| i)
-            .collect();
+        self.simulate_street(
+            rng,
+            players,
+            &mut state,
+            Street::Preflop,
+            (bb_idx + 1) % num_players,
+            &mut actions,
+        );
 
         // Si hay mas de 1 jugador, simular postflop
-        if active_players.len() > 1 {
-            for street in [Street::Flop, Street::Turn, Street::River] {
-                let active_count = player_folded.iter().filter(|&&f| !f).count();
-                if active_count <= 1 {
-                    break;
-                }
-
-                let (street_actions, street_pot, street_folded) =
-                    self.simulate_postflop(rng, players, &player_folded, stake, street);
-
-                actions.extend(street_actions);
-                pot += street_pot;
-                player_folded = street_folded;
+        for street in [Street::Flop, Street::Turn, Street::River] {
+            if state.live_count() <= 1 {
+                break;
+            }
+            state.next_street();
+
+            // Con todos all-in salvo uno no hay mas acciones
+            if state.active_count() > 1 {
+                self.simulate_street(
+                    rng,
+                    players,
+                    &mut state,
+                    street,
+                    (btn_idx + 1) % num_players,
+                    &mut actions,
+                );
             }
         }
 
-        // Determinar ganador
-        let active_players: Vec<&Player> = players
-            .iter()
-            .zip(player_folded.iter())
-            .filter(|(_, &folded)| !folded)
-            .map(|(p, _)| p)
-            .collect();
-
-        let winner = if active_players.len() == 1 {
-            active_players[0].name.clone()
-        } else if !active_players.is_empty() {
-            // Showdown: elegir ganador aleatorio
-            active_players
-                .choose(rng)
-                .map(|p| p.name.clone())
-                .unwrap_or_default()
-        } else {
-            players.first().map(|p| p.name.clone()).unwrap_or_default()
-        };
-
-        let winners = vec![(winner, pot)];
-
-        (actions, pot, winners)
+        (actions, state.live_count() > 1)
     }
 
-    /// Simula acciones preflop
-    fn simulate_preflop(
+    /// Simula una ronda de apuestas hasta que todos igualan o se retiran
+    fn simulate_street(
         &self,
         rng: &mut ChaCha8Rng,
         players: &[Player],
-        initial_bets: &[i64],
-        initial_folded: &[bool],
-        stake: StakeLevel,
-    ) -> (Vec<Action>, i64, Vec<bool>) {
-        let mut actions = Vec::new();
-        let mut pot = 0i64;
-        let mut player_bets = initial_bets.to_vec();
-        let mut player_folded = initial_folded.to_vec();
-        let bb = stake.big_blind_cents();
-        let mut current_bet = bb;
-        let mut raises_count = 0;
-
-        // Orden de accion preflop: UTG -> MP -> CO -> BTN -> SB -> BB
-        let action_order = [
-            Position::UTG,
-            Position::MP,
-            Position::CO,
-            Position::Button,
-            Position::SmallBlind,
-            Position::BigBlind,
-        ];
-
-        for position in action_order.iter() {
-            if let Some((idx, player)) = players
-                .iter()
-                .enumerate()
-                .find(|(_, p)| p.position == Some(*position))
-            {
-                if player_folded[idx] {
-                    continue;
-                }
+        state: &mut BettingState,
+        street: Street,
+        first_idx: usize,
+        actions: &mut Vec<Action>,
+    ) {
+        let num_players = players.len();
+        let bb = state.big_blind;
+        let mut pending: Vec<bool> = (0..num_players).map(|i| state.can_act(i)).collect();
+        let mut idx = first_idx;
+
+        while pending.iter().any(|&p| p) && state.live_count() > 1 {
+            if !pending[idx] || !state.can_act(idx) {
+                pending[idx] = false;
+                idx = (idx + 1) % num_players;
+                continue;
+            }
+            pending[idx] = false;
 
-                // Determinar accion
-                let action_type = if current_bet == bb && raises_count == 0 {
-                    // RFI situation
-                    self.choose_preflop_action(rng, &self.preflop_dist.rfi_weights)
-                } else if raises_count == 1 {
-                    // Facing raise
-                    self.choose_preflop_action(rng, &self.preflop_dist.vs_raise_weights)
-                } else {
-                    // Facing 3bet+
-                    self.choose_preflop_action(rng, &self.preflop_dist.vs_3bet_weights)
-                };
+            let to_call = state.current_bet - state.committed[idx];
+            let action_type = self.choose_action(rng, state, idx, players[idx].position, street);
 
-                match action_type {
-                    ActionType::Fold => {
-                        actions.push(Action {
-                            player_name: player.name.clone(),
-                            action_type: ActionType::Fold,
-                            amount_cents: None,
-                            is_all_in: false,
-                            street: Street::Preflop,
-                        });
-                        player_folded[idx] = true;
-                    }
-                    ActionType::Call => {
-                        let call_amount = current_bet - player_bets[idx];
-                        if call_amount > 0 {
-                            actions.push(Action {
-                                player_name: player.name.clone(),
-                                action_type: ActionType::Call,
-                                amount_cents: Some(call_amount),
-                                is_all_in: false,
-                                street: Street::Preflop,
-                            });
-                            pot += call_amount;
-                            player_bets[idx] = current_bet;
-                        }
-                    }
-                    ActionType::Raise => {
-                        let raise_size = if raises_count == 0 {
-                            // Open raise: 2.5-3x BB
-                            bb * rng.gen_range(25..=30) / 10
-                        } else {
-                            // 3bet/4bet: 3x previous
-                            current_bet * 3
-                        };
-                        let total_bet = raise_size.min(player.stack_cents);
-                        let to_add = total_bet - player_bets[idx];
-
-                        actions.push(Action {
-                            player_name: player.name.clone(),
-                            action_type: ActionType::Raise,
-                            amount_cents: Some(total_bet),
-                            is_all_in: total_bet >= player.stack_cents,
-                            street: Street::Preflop,
-                        });
-                        pot += to_add;
-                        player_bets[idx] = total_bet;
-                        current_bet = total_bet;
-                        raises_count += 1;
-                    }
-                    _ => {}
+            let (amount_cents, reopened) = match action_type {
+                ActionType::Fold => {
+                    state.folded[idx] = true;
+                    (None, false)
                 }
+                ActionType::Check => (None, false),
+                ActionType::Call => (Some(state.put(idx, to_call)), false),
+                ActionType::Bet => {
+                    // Bet size: 3-6 BB (simplificado)
+                    let amount = state.put(idx, bb * rng.gen_range(3..=6));
+                    state.last_raise = amount.max(bb);
+                    state.current_bet = state.committed[idx];
+                    state.raises += 1;
+                    (Some(amount), true)
+                }
+                _ => {
+                    let target = if street == Street::Preflop && state.raises == 0 {
+                        // Open raise: 2.5-3x BB
+                        bb * rng.gen_range(25..=30) / 10
+                    } else {
+                        // 3bet/4bet o raise postflop: 3x la apuesta
+                        state.current_bet * 3
+                    };
+                    let target = target.max(state.current_bet + state.last_raise);
+                    state.put(idx, target - state.committed[idx]);
+
+                    let total = state.committed[idx];
+                    state.last_raise = state.last_raise.max(total - state.current_bet);
+                    state.current_bet = total;
+                    state.raises += 1;
+                    (Some(total), true)
+                }
+            };
+
+            actions.push(Action {
+                player_name: players[idx].name.clone(),
+                action_type,
+                amount_cents,
+                is_all_in: amount_cents.is_some() && state.all_in[idx],
+                street,
+            });
 
-                // Limitar acciones
-                if raises_count >= 4 {
-                    break;
+            // Tras una apuesta o subida vuelven a hablar todos los demas
+            if reopened {
+                for (other, flag) in pending.iter_mut().enumerate() {
+                    *flag = other != idx && state.can_act(other);
                 }
             }
+            idx = (idx + 1) % num_players;
         }
-
-        (actions, pot, player_folded)
     }
 
-    /// Simula acciones postflop
-    fn simulate_postflop(
+    /// Elige una accion legal segun las distribuciones y la situacion
+    fn choose_action(
         &self,
         rng: &mut ChaCha8Rng,
-        players: &[Player],
-        initial_folded: &[bool],
-        stake: StakeLevel,
+        state: &BettingState,
+        idx: usize,
+        position: Option<Position>,
         street: Street,
-    ) -> (Vec<Action>, i64, Vec<bool>) {
-        let mut actions = Vec::new();
-        let mut pot = 0i64;
-        let mut player_folded = initial_folded.to_vec();
-        let bb = stake.big_blind_cents();
-        let mut current_bet = 0i64;
-
-        // Orden de accion postflop: SB -> BB -> UTG -> MP -> CO -> BTN
-        let action_order = [
-            Position::SmallBlind,
-            Position::BigBlind,
-            Position::UTG,
-            Position::MP,
-            Position::CO,
-            Position::Button,
-        ];
+    ) -> ActionType {
+        let to_call = state.current_bet - state.committed[idx];
+        let action_type = if street == Street::Preflop {
+            let weights = match state.raises {
+                0 => &self.preflop_dist.rfi_weights,      // RFI situation
+                1 => &self.preflop_dist.vs_raise_weights, // Facing raise
+                _ => &self.preflop_dist.vs_3bet_weights,  // Facing 3bet+
+            };
+            self.choose_preflop_action(rng, weights)
+        } else {
+            // Determinar si esta IP u OOP
+            let is_ip = matches!(position, Some(Position::Button | Position::CO));
+            let weights = match (to_call > 0, is_ip) {
+                (false, true) => &self.postflop_dist.ip_no_bet_weights,
+                (false, false) => &self.postflop_dist.oop_no_bet_weights,
+                (true, true) => &self.postflop_dist.ip_facing_bet_weights,
+                (true, false) => &self.postflop_dist.oop_facing_bet_weights,
+            };
+            self.choose_postflop_action(rng, weights)
+        };
 
-        for position in action_order.iter() {
-            if let Some((idx, player)) = players
-                .iter()
-                .enumerate()
-                .find(|(_, p)| p.position == Some(*position))
-            {
-                if player_folded[idx] {
-                    continue;
+        let can_raise = state.raises < MAX_RAISES && state.stacks[idx] > to_call;
+        match action_type {
+            // Sin apuesta que igualar no se abandona ni se iguala
+            ActionType::Fold | ActionType::Call if to_call == 0 => ActionType::Check,
+            ActionType::Raise | ActionType::Bet if !can_raise => {
+                if to_call > 0 {
+                    ActionType::Call
+                } else {
+                    ActionType::Check
                 }
+            }
+            ActionType::Raise if street != Street::Preflop && state.current_bet == 0 => {
+                ActionType::Bet
+            }
+            ActionType::Bet if state.current_bet > 0 => ActionType::Raise,
+            ActionType::Check if to_call > 0 => ActionType::Call,
+            other => other,
+        }
+    }
 
-                // Determinar si esta IP u OOP
-                let is_ip = matches!(position, Position::Button | Position::CO);
-
-                let action_type = if current_bet == 0 {
-                    // Sin apuesta
-                    let weights = if is_ip {
-                        &self.postflop_dist.ip_no_bet_weights
-                    } else {
-                        &self.postflop_dist.oop_no_bet_weights
-                    };
-                    self.choose_postflop_action(rng, weights)
-                } else {
-                    // Facing bet
-                    let weights = if is_ip {
-                        &self.postflop_dist.ip_facing_bet_weights
-                    } else {
-                        &self.postflop_dist.oop_facing_bet_weights
-                    };
-                    self.choose_postflop_action(rng, weights)
-                };
+    /// Reparte el bote: cada bote (principal y side pots) lo gana el mejor
+    /// clasificado de sus elegibles, la apuesta no igualada vuelve a su dueño
+    /// y el rake (5% del bote, max 3BB) se descuenta del bote mayor
+    fn resolve_pot(&self, rng: &mut ChaCha8Rng, hand: &ParsedHand) -> PotInfo {
+        let resolution = PotResolution::from_hand(hand);
+        let pot_total: i64 = resolution.pots.iter().map(|p| p.amount_cents).sum();
+        let rake = (pot_total * 5 / 100).min(hand.big_blind_cents * 3);
+
+        // Showdown: orden aleatorio de fuerza entre los jugadores vivos
+        let mut ranking: Vec<&str> = resolution
+            .players
+            .iter()
+            .filter(|p| !p.folded)
+            .map(|p| p.player_name.as_str())
+            .collect();
+        ranking.shuffle(rng);
 
-                match action_type {
-                    ActionType::Check => {
-                        actions.push(Action {
-                            player_name: player.name.clone(),
-                            action_type: ActionType::Check,
-                            amount_cents: None,
-                            is_all_in: false,
-                            street,
-                        });
-                    }
-                    ActionType::Bet => {
-                        // Bet size: 50-75% pot
-                        let bet_size = bb * rng.gen_range(3..=6); // Simplified
-                        actions.push(Action {
-                            player_name: player.name.clone(),
-                            action_type: ActionType::Bet,
-                            amount_cents: Some(bet_size),
-                            is_all_in: false,
-                            street,
-                        });
-                        pot += bet_size;
-                        current_bet = bet_size;
-                    }
-                    ActionType::Call => {
-                        actions.push(Action {
-                            player_name: player.name.clone(),
-                            action_type: ActionType::Call,
-                            amount_cents: Some(current_bet),
-                            is_all_in: false,
-                            street,
-                        });
-                        pot += current_bet;
-                    }
-                    ActionType::Raise => {
-                        let raise_size = current_bet * 3;
-                        actions.push(Action {
-                            player_name: player.name.clone(),
-                            action_type: ActionType::Raise,
-                            amount_cents: Some(raise_size),
-                            is_all_in: false,
-                            street,
-                        });
-                        pot += raise_size;
-                        current_bet = raise_size;
-                    }
-                    ActionType::Fold => {
-                        actions.push(Action {
-                            player_name: player.name.clone(),
-                            action_type: ActionType::Fold,
-                            amount_cents: None,
-                            is_all_in: false,
-                            street,
-                        });
-                        player_folded[idx] = true;
-                    }
-                    _ => {}
-                }
+        let largest = resolution
+            .pots
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, pot)| pot.amount_cents)
+            .map(|(i, _)| i);
+
+        let mut winners: Vec<(Arc<str>, i64)> = Vec::new();
+        let mut award =
+            |name: &str, amount: i64| match winners.iter_mut().find(|(n, _)| &**n == name) {
+                Some((_, total)) => *total += amount,
+                None => winners.push((name.into(), amount)),
+            };
+        for (i, pot) in resolution.pots.iter().enumerate() {
+            let amount = pot.amount_cents - if Some(i) == largest { rake } else { 0 };
+            if let Some(winner) = ranking
+                .iter()
+                .find(|name| pot.eligible.iter().any(|e| e == *name))
+            {
+                award(winner, amount);
             }
         }
+        if let Some((name, amount)) = &resolution.uncalled {
+            award(name, *amount);
+        }
 
-        (actions, pot, player_folded)
+        PotInfo {
+            total_cents: winners.iter().map(|(_, amount)| amount).sum(),
+            rake_cents: rake,
+            winners,
+        }
     }
 
     /// Elige accion preflop basada en pesos
@@ -922,6 +853,81 @@ impl SyntheticGenerator {
     }
 }
 
+// ============================================================================
+// BETTING STATE
+// ============================================================================
+
+/// Subidas maximas por calle (bet incluida)
+const MAX_RAISES: u32 = 4;
+
+/// Estado de las apuestas durante la simulacion de una mano
+#[derive(Debug, Clone)]
+struct BettingState {
+    /// Fichas restantes de cada jugador
+    stacks: Vec<i64>,
+    /// Comprometido en la calle actual
+    committed: Vec<i64>,
+    folded: Vec<bool>,
+    all_in: Vec<bool>,
+    big_blind: i64,
+    /// Apuesta a igualar en la calle
+    current_bet: i64,
+    /// Ultima subida completa (tamaño minimo de la siguiente)
+    last_raise: i64,
+    /// Apuestas y subidas en la calle
+    raises: u32,
+}
+
+impl BettingState {
+    fn new(players: &[Player], big_blind: i64) -> Self {
+        let num_players = players.len();
+        Self {
+            stacks: players.iter().map(|p| p.stack_cents).collect(),
+            committed: vec![0; num_players],
+            folded: vec![false; num_players],
+            all_in: vec![false; num_players],
+            big_blind,
+            current_bet: 0,
+            last_raise: big_blind,
+            raises: 0,
+        }
+    }
+
+    /// Pone fichas en el bote (limitado al stack) y devuelve lo puesto
+    fn put(&mut self, idx: usize, amount: i64) -> i64 {
+        let amount = amount.clamp(0, self.stacks[idx]);
+        self.stacks[idx] -= amount;
+        self.committed[idx] += amount;
+        if self.stacks[idx] == 0 {
+            self.all_in[idx] = true;
+        }
+        amount
+    }
+
+    /// Puede actuar: no se retiro ni esta all-in
+    fn can_act(&self, idx: usize) -> bool {
+        !self.folded[idx] && !self.all_in[idx]
+    }
+
+    /// Jugadores que no se retiraron
+    fn live_count(&self) -> usize {
+        self.folded.iter().filter(|&&f| !f).count()
+    }
+
+    /// Jugadores que todavia pueden actuar
+    fn active_count(&self) -> usize {
+        (0..self.folded.len()).filter(|&i| self.can_act(i)).count()
+    }
+
+    /// Empieza una nueva calle
+    fn next_street(&mut self) {
+        self.committed.iter_mut().for_each(|c| *c = 0);
+        self.current_bet = 0;
+        self.last_raise = self.big_blind;
+        self.raises = 0;
+    }
+}
+
 // ============================================================================
 // RESULT TYPES
 // ============================================================================
@@ -1058,6 +1064,19 @@ mod tests {
         assert!(hero_hands > 0, "Hero should be present in some hands");
     }
 
+    #[test]
+    fn test_generated_hands_pass_validation() {
+        let validator = crate::validation::HandValidator::new();
+        for max_players in [2, 6, 9] {
+            let mut config = SyntheticConfig::new(300).with_seed(7);
+            config.max_players = max_players;
+            for hand in SyntheticGenerator::new(config).generate_sequential() {
+                let violations = validator.validate(&hand);
+                assert!(violations.is_empty(), "{}: {:?}", hand.hand_id, violations);
+            }
+        }
+    }
+
     #[test]
     fn test_positions_assigned() {
         let config = SyntheticConfig::new(10).with_seed(42);
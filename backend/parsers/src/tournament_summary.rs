@@ -0,0 +1,239 @@
+//! Parser de los archivos de resumen de torneo de Winamax.
+//!
+//! Winamax genera un archivo `*_summary.txt` por torneo con el buy-in, los
+//! inscritos, el prize pool y el resultado del jugador:
+//!
+//! ```text
+//! Winamax Poker - Tournament summary : Expresso(654321987)
+//! Player : thesmoy
+//! Buy-In : 0.93€ + 0.07€
+//! Registered players : 3
+//! Prizepool : 5€
+//! Tournament started 2025/12/15 14:02:19 UTC
+//! You finished in 1st place
+//! You won 5€
+//! ```
+//!
+//! Todos los importes son dinero real y se normalizan a centavos.
+
+use crate::types::TournamentFormat;
+use serde::{Deserialize, Serialize};
+
+/// Prefijo de la primera línea de un resumen de torneo.
+pub const SUMMARY_HEADER: &str = "Winamax Poker - Tournament summary";
+
+/// Resultado de un jugador en un torneo según el archivo de resumen.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TournamentSummary {
+    /// ID del torneo en Winamax.
+    pub tournament_id: String,
+    /// Nombre del torneo.
+    pub name: String,
+    /// Formato (MTT, SNG o Expresso).
+    pub format: TournamentFormat,
+    /// Jugador al que pertenece el resumen.
+    pub player_name: String,
+    /// Buy-in sin comisión en centavos (incluye la parte de bounty).
+    pub buy_in_cents: i64,
+    /// Comisión de inscripción en centavos.
+    pub fee_cents: i64,
+    /// Jugadores inscritos.
+    pub registered_players: Option<i32>,
+    /// Prize pool total en centavos.
+    pub prize_pool_cents: Option<i64>,
+    /// Inicio del torneo (formato UTC del historial).
+    pub start_time: String,
+    /// Posición final del jugador.
+    pub finish_position: Option<i32>,
+    /// Premio cobrado en centavos (sin bounties).
+    pub prize_won_cents: i64,
+    /// Bounties cobrados en centavos.
+    pub bounty_won_cents: i64,
+}
+
+impl TournamentSummary {
+    /// Crea un resumen a partir de su línea de cabecera.
+    ///
+    /// Formato: `Winamax Poker - Tournament summary : Expresso(654321987)`
+    pub fn from_header(line: &str) -> Option<Self> {
+        let rest = line.trim().strip_prefix(SUMMARY_HEADER)?;
+        let rest = rest.trim_start().trim_start_matches(':').trim();
+        let (name, tournament_id) = split_name_and_id(rest)?;
+
+        Some(Self {
+            tournament_id: tournament_id.to_string(),
+            name: name.to_string(),
+            format: TournamentFormat::from_name(name),
+            player_name: String::new(),
+            buy_in_cents: 0,
+            fee_cents: 0,
+            registered_players: None,
+            prize_pool_cents: None,
+            start_time: String::new(),
+            finish_position: None,
+            prize_won_cents: 0,
+            bounty_won_cents: 0,
+        })
+    }
+
+    /// Parsea un archivo de resumen completo.
+    pub fn parse(content: &str) -> Option<Self> {
+        let mut lines = content
+            .lines()
+            .skip_while(|l| !l.starts_with(SUMMARY_HEADER));
+        let mut summary = Self::from_header(lines.next()?)?;
+        for line in lines {
+            summary.parse_line(line);
+        }
+        Some(summary)
+    }
+
+    /// Procesa una línea del cuerpo del resumen.
+    pub fn parse_line(&mut self, line: &str) {
+        let line = line.trim();
+
+        if let Some(value) = field_value(line, "Player") {
+            self.player_name = value.to_string();
+        } else if let Some(value) = field_value(line, "Buy-In") {
+            let (buy_in, fee) = parse_buy_in(value);
+            self.buy_in_cents = buy_in;
+            self.fee_cents = fee;
+        } else if let Some(value) = field_value(line, "Registered players") {
+            self.registered_players = value.parse().ok();
+        } else if let Some(value) = field_value(line, "Prizepool") {
+            self.prize_pool_cents = Some(parse_money(value));
+        } else if let Some(value) = line.strip_prefix("Tournament started ") {
+            self.start_time = value.trim().to_string();
+        } else if let Some(value) = line.strip_prefix("You finished in ") {
+            let digits: String = value.chars().take_while(|c| c.is_ascii_digit()).collect();
+            self.finish_position = digits.parse().ok();
+        } else if let Some(value) = line.strip_prefix("You won ") {
+            // Formato: "You won 5€" o "You won 12€ + Bounty 3€"
+            let mut parts = value.split('+');
+            self.prize_won_cents = parts.next().map(parse_money).unwrap_or(0);
+            self.bounty_won_cents = parts
+                .filter(|p| p.to_lowercase().contains("bounty"))
+                .map(parse_money)
+                .sum();
+        }
+    }
+
+    /// Coste total de inscripción (buy-in + comisión) en centavos.
+    pub fn total_cost_cents(&self) -> i64 {
+        self.buy_in_cents + self.fee_cents
+    }
+}
+
+/// Separa `Nombre(123456)` en nombre e ID.
+///
+/// Admite el sufijo de mesa de las manos: `Expresso(654321987)#0`.
+pub(crate) fn split_name_and_id(s: &str) -> Option<(&str, &str)> {
+    let open = s.rfind('(')?;
+    let close = open + s[open..].find(')')?;
+    let id = &s[open + 1..close];
+    if id.is_empty() || !id.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some((s[..open].trim(), id))
+}
+
+/// Parsea un buy-in `0.93€ + 0.07€` en (buy-in, comisión).
+///
+/// Con tres sumandos (torneos KO: premio + bounty + comisión) el buy-in
+/// incluye la parte de bounty. `Free`/`Freeroll` devuelve (0, 0).
+pub(crate) fn parse_buy_in(s: &str) -> (i64, i64) {
+    let amounts: Vec<i64> = s.split('+').map(parse_money).collect();
+    match amounts.as_slice() {
+        [] => (0, 0),
+        [buy_in] => (*buy_in, 0),
+        [parts @ .., fee] => (parts.iter().sum(), *fee),
+    }
+}
+
+/// Convierte un importe en euros (`0.93€`, `2,50€`) a centavos.
+pub(crate) fn parse_money(s: &str) -> i64 {
+    let cleaned: String = s
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit() || *c == '.' || *c == ',')
+        .map(|c| if c == ',' { '.' } else { c })
+        .collect();
+
+    cleaned
+        .parse::<f64>()
+        .map(|amount| (amount * 100.0).round() as i64)
+        .unwrap_or(0)
+}
+
+/// Valor de una línea `Clave : valor`.
+fn field_value<'a>(line: &'a str, key: &str) -> Option<&'a str> {
+    let rest = line.strip_prefix(key)?;
+    let value = rest.trim_start().strip_prefix(':')?;
+    Some(value.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_expresso_summary() {
+        let content = "Winamax Poker - Tournament summary : Expresso(654321987)
+Player : thesmoy
+Buy-In : 0.93€ + 0.07€
+Registered players : 3
+Type : sit&go
+Prizepool : 5€
+Tournament started 2025/12/15 14:02:19 UTC
+You played 7min 3s
+You finished in 1st place
+You won 5€
+";
+        let summary = TournamentSummary::parse(content).unwrap();
+
+        assert_eq!(summary.tournament_id, "654321987");
+        assert_eq!(summary.name, "Expresso");
+        assert_eq!(summary.format, TournamentFormat::Expresso);
+        assert_eq!(summary.player_name, "thesmoy");
+        assert_eq!(summary.buy_in_cents, 93);
+        assert_eq!(summary.fee_cents, 7);
+        assert_eq!(summary.total_cost_cents(), 100);
+        assert_eq!(summary.registered_players, Some(3));
+        assert_eq!(summary.prize_pool_cents, Some(500));
+        assert_eq!(summary.start_time, "2025/12/15 14:02:19 UTC");
+        assert_eq!(summary.finish_position, Some(1));
+        assert_eq!(summary.prize_won_cents, 500);
+        assert_eq!(summary.bounty_won_cents, 0);
+    }
+
+    #[test]
+    fn test_parse_knockout_summary() {
+        let content = "Winamax Poker - Tournament summary : MONSTER KO(812345678)
+Player : thesmoy
+Buy-In : 2.25€ + 2.25€ + 0.50€
+Registered players : 1250
+Prizepool : 5625€
+Tournament started 2025/12/15 20:00:00 UTC
+You finished in 23rd place
+You won 18.40€ + Bounty 6.75€
+";
+        let summary = TournamentSummary::parse(content).unwrap();
+
+        assert_eq!(summary.format, TournamentFormat::MTT);
+        assert_eq!(summary.buy_in_cents, 450);
+        assert_eq!(summary.fee_cents, 50);
+        assert_eq!(summary.finish_position, Some(23));
+        assert_eq!(summary.prize_won_cents, 1840);
+        assert_eq!(summary.bounty_won_cents, 675);
+    }
+
+    #[test]
+    fn test_split_name_and_id() {
+        assert_eq!(
+            split_name_and_id("Expresso(654321987)#0"),
+            Some(("Expresso", "654321987"))
+        );
+        assert_eq!(split_name_and_id("Nice 09"), None);
+        assert_eq!(parse_buy_in("Free"), (0, 0));
+    }
+}
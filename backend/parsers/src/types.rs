@@ -3,10 +3,14 @@
 //! Define todas las estructuras y enumeraciones necesarias para representar
 //! una mano de poker parseada, incluyendo acciones, jugadores y metadatos.
 
+use crate::diagnostics::ParseDiagnostic;
+use crate::tournament_summary::TournamentSummary;
 use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Arc;
 
 /// Estado actual del parser FSM.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum ParserState {
     /// Esperando inicio de una nueva mano.
     #[default]
@@ -40,6 +44,98 @@ pub enum GameType {
     Tournament,
 }
 
+/// Sala de póker que generó el historial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Site {
+    #[default]
+    Winamax,
+    PokerStars,
+    GGPoker,
+}
+
+impl Site {
+    /// Nombre de la sala (coincide con `SiteName` de la base de datos).
+    pub fn name(self) -> &'static str {
+        match self {
+            Site::Winamax => "Winamax",
+            Site::PokerStars => "PokerStars",
+            Site::GGPoker => "GGPoker",
+        }
+    }
+}
+
+/// Variante de poker de la mesa.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GameVariant {
+    /// Texas Hold'em (2 cartas propias).
+    #[default]
+    Holdem,
+    /// Pot-Limit Omaha de 4 cartas.
+    PLO4,
+    /// Pot-Limit Omaha de 5 cartas.
+    PLO5,
+}
+
+impl GameVariant {
+    /// Deduce la variante de la cabecera de la mano.
+    ///
+    /// Formatos: `Holdem no limit`, `Omaha pot limit`, `Omaha5 pot limit`
+    /// (Winamax), `5 Card Omaha Pot Limit` (PokerStars) y `PLO-5` (GGPoker).
+    pub fn from_header(line: &str) -> Self {
+        if line.contains("Omaha5")
+            || line.contains("Omaha 5")
+            || line.contains("5 Card Omaha")
+            || line.contains("PLO-5")
+            || line.contains("PLO5")
+        {
+            GameVariant::PLO5
+        } else if line.contains("Omaha") || line.contains("PLO") {
+            GameVariant::PLO4
+        } else {
+            GameVariant::Holdem
+        }
+    }
+
+    /// Número de cartas propias que recibe cada jugador.
+    pub fn hole_card_count(self) -> usize {
+        match self {
+            GameVariant::Holdem => 2,
+            GameVariant::PLO4 => 4,
+            GameVariant::PLO5 => 5,
+        }
+    }
+
+    /// Si la variante es Omaha (dos cartas propias y tres del board).
+    pub fn is_omaha(self) -> bool {
+        self != GameVariant::Holdem
+    }
+}
+
+/// Formato de torneo de Winamax.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TournamentFormat {
+    /// Torneo multimesa.
+    MTT,
+    /// Sit & Go de mesa única.
+    SNG,
+    /// Expresso (Sit & Go de 3 jugadores con premio aleatorio).
+    Expresso,
+}
+
+impl TournamentFormat {
+    /// Deduce el formato a partir del nombre del torneo.
+    pub fn from_name(name: &str) -> Self {
+        let lower = name.to_lowercase();
+        if lower.contains("expresso") {
+            TournamentFormat::Expresso
+        } else if lower.contains("sit&go") || lower.contains("sit & go") || lower.contains("sng") {
+            TournamentFormat::SNG
+        } else {
+            TournamentFormat::MTT
+        }
+    }
+}
+
 /// Calle de la mano de poker.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Street {
@@ -76,10 +172,13 @@ pub enum ActionType {
     Collect,
 }
 
-/// Posición en la mesa (6-max).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Posición en la mesa (heads-up a 9-max).
+///
+/// En mesas de hasta 6 jugadores se usan `UTG`, `MP` y `CO` antes del botón;
+/// en mesas de 7 a 9 (full ring) `UTG`, `UTG1`, `UTG2`, `LJ`, `HJ` y `CO`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Position {
-    /// Botón (dealer).
+    /// Botón (dealer). En heads-up también pone la ciega pequeña.
     Button,
     /// Ciega pequeña.
     SmallBlind,
@@ -87,12 +186,90 @@ pub enum Position {
     BigBlind,
     /// Under The Gun (primera posición).
     UTG,
+    /// UTG+1 (full ring).
+    UTG1,
+    /// UTG+2 (full ring).
+    UTG2,
+    /// Lojack (full ring).
+    LJ,
+    /// Hijack (full ring).
+    HJ,
     /// Middle Position.
     MP,
     /// Cut-Off (antes del botón).
     CO,
 }
 
+impl Position {
+    /// Posiciones de una mesa en orden desde el botón (sentido horario).
+    ///
+    /// `max_players` decide la nomenclatura (6-max o full ring) y `occupied`
+    /// cuántas posiciones hay: con menos jugadores se eliminan primero las
+    /// posiciones tempranas. En heads-up solo hay botón y ciega grande.
+    pub fn table_order(max_players: u8, occupied: usize) -> Vec<Position> {
+        use Position::*;
+
+        match occupied {
+            0 => Vec::new(),
+            1 => vec![Button],
+            2 => vec![Button, BigBlind],
+            _ => {
+                let mut order = vec![Button, SmallBlind, BigBlind];
+                let before_button = occupied - 3;
+
+                if max_players >= 7 || occupied > 6 {
+                    // Full ring: LJ/HJ/CO siempre, luego UTG, UTG+1, UTG+2
+                    let late = [LJ, HJ, CO];
+                    let early = [UTG, UTG1, UTG2];
+                    let late_count = before_button.min(late.len());
+                    let early_count = (before_button - late_count).min(early.len());
+                    order.extend_from_slice(&early[..early_count]);
+                    order.extend_from_slice(&late[late.len() - late_count..]);
+                } else {
+                    let short = [UTG, MP, CO];
+                    order.extend_from_slice(&short[short.len() - before_button.min(3)..]);
+                }
+
+                order
+            }
+        }
+    }
+
+    /// Etiqueta corta de la posición (la que usan las estadísticas).
+    pub fn label(self) -> &'static str {
+        match self {
+            Position::Button => "BTN",
+            Position::SmallBlind => "SB",
+            Position::BigBlind => "BB",
+            Position::UTG => "UTG",
+            Position::UTG1 => "UTG+1",
+            Position::UTG2 => "UTG+2",
+            Position::LJ => "LJ",
+            Position::HJ => "HJ",
+            Position::MP => "MP",
+            Position::CO => "CO",
+        }
+    }
+
+    /// Inverso de `label`.
+    pub fn from_label(label: &str) -> Option<Self> {
+        let position = match label {
+            "BTN" => Position::Button,
+            "SB" => Position::SmallBlind,
+            "BB" => Position::BigBlind,
+            "UTG" => Position::UTG,
+            "UTG+1" => Position::UTG1,
+            "UTG+2" => Position::UTG2,
+            "LJ" => Position::LJ,
+            "HJ" => Position::HJ,
+            "MP" => Position::MP,
+            "CO" => Position::CO,
+            _ => return None,
+        };
+        Some(position)
+    }
+}
+
 /// Representa una carta de poker.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Card {
@@ -103,6 +280,12 @@ pub struct Card {
 }
 
 impl Card {
+    /// Indica si el rango (`2`-`9`, `T`, `J`, `Q`, `K`, `A`) y el palo
+    /// (`c`, `d`, `h`, `s`) son válidos.
+    pub fn is_valid(&self) -> bool {
+        "23456789TJQKA".contains(self.rank) && "cdhs".contains(self.suit)
+    }
+
     /// Parsea una carta desde un string como "Ah" o "Td".
     pub fn parse(s: &str) -> Option<Self> {
         let chars: Vec<char> = s.chars().collect();
@@ -117,20 +300,24 @@ impl Card {
     }
 }
 
+/// Cartas propias de un jugador: 2 en Hold'em, 4 o 5 en Omaha.
+pub type HoleCards = Vec<Card>;
+
 /// Información de un jugador en la mano.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Player {
-    /// Nombre del jugador.
-    pub name: String,
-    /// Número de asiento (1-6).
+    /// Nombre del jugador, compartido con sus acciones y cobros.
+    pub name: Arc<str>,
+    /// Número de asiento (1-9).
     pub seat: u8,
     /// Stack inicial en centavos.
     pub stack_cents: i64,
     /// Posición calculada en la mesa.
     pub position: Option<Position>,
     /// Cartas del jugador (si son conocidas).
-    pub hole_cards: Option<[Card; 2]>,
-    /// Si es el héroe (thesmoy).
+    pub hole_cards: Option<HoleCards>,
+    /// Si es el héroe: uno de los alias de `HeroConfig` para la sala o, sin
+    /// configuración, el jugador de `Dealt to`.
     pub is_hero: bool,
 }
 
@@ -138,7 +325,7 @@ pub struct Player {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Action {
     /// Nombre del jugador que realiza la acción.
-    pub player_name: String,
+    pub player_name: Arc<str>,
     /// Tipo de acción.
     pub action_type: ActionType,
     /// Cantidad en centavos (si aplica).
@@ -149,6 +336,62 @@ pub struct Action {
     pub street: Street,
 }
 
+impl Player {
+    /// Crea un jugador sin posición, sin cartas y que no es el héroe.
+    pub fn new(name: impl Into<Arc<str>>, seat: u8, stack_cents: i64) -> Self {
+        Self {
+            name: name.into(),
+            seat,
+            stack_cents,
+            position: None,
+            hole_cards: None,
+            is_hero: false,
+        }
+    }
+
+    /// Marca al jugador como héroe.
+    pub fn hero(mut self) -> Self {
+        self.is_hero = true;
+        self
+    }
+
+    /// Fija la posición en la mesa.
+    pub fn with_position(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Fija las cartas del jugador.
+    pub fn with_cards(mut self, cards: HoleCards) -> Self {
+        self.hole_cards = Some(cards);
+        self
+    }
+}
+
+impl Action {
+    /// Crea una acción que no deja al jugador all-in.
+    pub fn new(
+        player_name: impl Into<Arc<str>>,
+        action_type: ActionType,
+        amount_cents: Option<i64>,
+        street: Street,
+    ) -> Self {
+        Self {
+            player_name: player_name.into(),
+            action_type,
+            amount_cents,
+            is_all_in: false,
+            street,
+        }
+    }
+
+    /// Marca la acción como all-in.
+    pub fn all_in(mut self) -> Self {
+        self.is_all_in = true;
+        self
+    }
+}
+
 /// Información del bote y resultados.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PotInfo {
@@ -157,7 +400,29 @@ pub struct PotInfo {
     /// Rake en centavos.
     pub rake_cents: i64,
     /// Ganadores y sus ganancias (nombre, cantidad en centavos).
-    pub winners: Vec<(String, i64)>,
+    pub winners: Vec<(Arc<str>, i64)>,
+}
+
+/// Datos del torneo extraídos de la cabecera de la mano.
+///
+/// El buy-in y la comisión son dinero real (centavos); las ciegas, antes y
+/// stacks de la mano se expresan en fichas.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TournamentInfo {
+    /// ID del torneo en Winamax (entre paréntesis en el nombre de mesa).
+    pub tournament_id: String,
+    /// Nombre del torneo.
+    pub name: String,
+    /// Formato (MTT, SNG o Expresso).
+    pub format: TournamentFormat,
+    /// Buy-in sin comisión en centavos.
+    pub buy_in_cents: i64,
+    /// Comisión de inscripción en centavos.
+    pub fee_cents: i64,
+    /// Nivel de ciegas.
+    pub level: u32,
+    /// Si el ante lo pone solo la ciega grande (BB ante).
+    pub bb_ante: bool,
 }
 
 /// Representa una mano de poker completa parseada.
@@ -165,24 +430,30 @@ pub struct PotInfo {
 pub struct ParsedHand {
     /// ID único de la mano (del historial de Winamax).
     pub hand_id: String,
+    /// Sala de origen del historial.
+    pub site: Site,
     /// Tipo de juego (Cash/Tournament).
     pub game_type: GameType,
+    /// Variante de poker (Hold'em, PLO4 o PLO5).
+    pub variant: GameVariant,
     /// Nombre de la mesa.
     pub table_name: String,
-    /// Número máximo de jugadores (5-max, 6-max).
+    /// Número máximo de jugadores (2-max a 9-max).
     pub max_players: u8,
     /// Ciega pequeña en centavos.
     pub small_blind_cents: i64,
     /// Ciega grande en centavos.
     pub big_blind_cents: i64,
+    /// Ante por jugador (0 si no hay).
+    pub ante_cents: i64,
     /// Timestamp de la mano (formato UTC).
     pub timestamp: String,
     /// Asiento del botón.
     pub button_seat: u8,
     /// Lista de jugadores en la mano.
     pub players: Vec<Player>,
-    /// Cartas del héroe (thesmoy).
-    pub hero_cards: Option<[Card; 2]>,
+    /// Cartas del héroe (ver `HeroConfig`), si se repartieron.
+    pub hero_cards: Option<HoleCards>,
     /// Cartas comunitarias en el board.
     pub board: Vec<Card>,
     /// Acciones de la mano organizadas por calle.
@@ -191,17 +462,90 @@ pub struct ParsedHand {
     pub pot: PotInfo,
     /// Si es dinero de juego (play money).
     pub is_play_money: bool,
+    /// Datos del torneo (solo en manos de torneo/Expresso).
+    pub tournament: Option<TournamentInfo>,
+}
+
+impl ParsedHand {
+    /// Indica si los importes de la mano son fichas de torneo.
+    ///
+    /// En ese caso los campos `*_cents` (ciegas, stacks, acciones y bote)
+    /// contienen fichas y no deben sumarse a resultados en dinero.
+    pub fn is_chips(&self) -> bool {
+        self.game_type == GameType::Tournament
+    }
+
+    /// Asigna las posiciones a partir del botón, el tamaño de la mesa y los
+    /// asientos en juego.
+    ///
+    /// Solo cuentan los jugadores que pusieron ciega o ante o actuaron: los
+    /// sentados que no juegan la mano (sit out) quedan sin posición y no
+    /// desplazan las ciegas. Si la mano no tiene acciones cuentan todos los
+    /// asientos. Con botón muerto (asiento vacío) se toma el último asiento
+    /// en juego antes del botón, y con ciega pequeña muerta (hay BB pero nadie
+    /// pone la SB) el primer jugador tras el botón es la BB.
+    pub fn assign_positions(&mut self) {
+        for player in &mut self.players {
+            player.position = None;
+        }
+
+        // Ordenar asientos a partir del botón
+        let mut seats: Vec<u8> = self
+            .players
+            .iter()
+            .filter(|p| {
+                self.actions.is_empty() || self.actions.iter().any(|a| a.player_name == p.name)
+            })
+            .map(|p| p.seat)
+            .collect();
+        seats.sort();
+
+        let num_players = seats.len();
+        if num_players == 0 {
+            return;
+        }
+
+        let btn_idx = seats
+            .iter()
+            .position(|&s| s == self.button_seat)
+            .or_else(|| seats.iter().rposition(|&s| s < self.button_seat))
+            .unwrap_or(num_players - 1);
+
+        // Asignar posiciones en orden desde el botón según el tamaño de mesa
+        let posted =
+            |action_type: ActionType| self.actions.iter().any(|a| a.action_type == action_type);
+        let dead_small_blind = num_players > 2
+            && posted(ActionType::PostBigBlind)
+            && !posted(ActionType::PostSmallBlind);
+        let mut positions = Position::table_order(
+            self.max_players,
+            num_players + usize::from(dead_small_blind),
+        );
+        if dead_small_blind {
+            positions.retain(|&p| p != Position::SmallBlind);
+        }
+
+        for (i, pos) in positions.iter().enumerate() {
+            let target_seat = seats[(btn_idx + i) % num_players];
+            if let Some(player) = self.players.iter_mut().find(|p| p.seat == target_seat) {
+                player.position = Some(*pos);
+            }
+        }
+    }
 }
 
 impl Default for ParsedHand {
     fn default() -> Self {
         Self {
             hand_id: String::new(),
+            site: Site::Winamax,
             game_type: GameType::CashGame,
+            variant: GameVariant::Holdem,
             table_name: String::new(),
             max_players: 6,
             small_blind_cents: 0,
             big_blind_cents: 0,
+            ante_cents: 0,
             timestamp: String::new(),
             button_seat: 0,
             players: Vec::new(),
@@ -214,17 +558,48 @@ impl Default for ParsedHand {
                 winners: Vec::new(),
             },
             is_play_money: false,
+            tournament: None,
         }
     }
 }
 
 /// Resultado del parsing de un archivo completo.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ParseResult {
     /// Manos parseadas exitosamente.
     pub hands: Vec<ParsedHand>,
-    /// Número de manos con errores.
+    /// Número de diagnósticos de severidad `Error`.
     pub error_count: usize,
-    /// Mensajes de error (si los hay).
-    pub errors: Vec<String>,
+    /// Diagnósticos del parsing (errores, avisos e informativos).
+    pub diagnostics: Vec<ParseDiagnostic>,
+    /// Resúmenes de torneo encontrados en el archivo.
+    pub summaries: Vec<TournamentSummary>,
+}
+
+impl ParseResult {
+    /// Crea un resultado contando los diagnósticos de severidad `Error`.
+    pub fn new(
+        hands: Vec<ParsedHand>,
+        diagnostics: Vec<ParseDiagnostic>,
+        summaries: Vec<TournamentSummary>,
+    ) -> Self {
+        Self {
+            hands,
+            error_count: diagnostics.iter().filter(|d| d.is_error()).count(),
+            diagnostics,
+            summaries,
+        }
+    }
+
+    /// Diagnósticos de severidad `Error`.
+    pub fn errors(&self) -> impl Iterator<Item = &ParseDiagnostic> {
+        self.diagnostics.iter().filter(|d| d.is_error())
+    }
+
+    /// Asigna el archivo de origen a todos los diagnósticos.
+    pub fn set_path(&mut self, path: &Path) {
+        for diagnostic in &mut self.diagnostics {
+            diagnostic.path = Some(path.to_path_buf());
+        }
+    }
 }
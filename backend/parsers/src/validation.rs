@@ -0,0 +1,730 @@
+//! Validación de integridad de manos parseadas.
+//!
+//! El parser reconoce líneas, pero no comprueba que la mano resultante tenga
+//! sentido. `HandValidator` reproduce el flujo de acciones y aplica una lista
+//! de reglas (`IntegrityRule`):
+//!
+//! - **Conservación de fichas**: nadie invierte más que su stack, los calls
+//!   igualan la apuesta, `invertido = cobrado + rake` y el bote del resumen
+//!   coincide con los cobros (`PotResolution`)
+//! - **Orden de acción**: turnos en orden de asiento, nadie actúa tras
+//!   retirarse o quedar all-in, checks y calls coherentes con la apuesta y
+//!   ninguna calle termina con jugadores pendientes de actuar
+//! - **Subida mínima**: apuestas de al menos la ciega grande y subidas de al
+//!   menos la subida anterior, salvo all-in
+//! - **Cartas duplicadas** entre el board y las cartas propias
+//! - **Ganadores en showdown**: quien cobra no se retiró y, si hubo showdown,
+//!   el board está completo
+//!
+//! Las manos que fallan se ponen en cuarentena (`QuarantinedHand`) con el
+//! motivo, en lugar de alimentar las estadísticas con datos incorrectos.
+//!
+//! ## Uso
+//!
+//! ```rust,ignore
+//! use poker_parsers::HandValidator;
+//!
+//! let (valid, quarantined) = HandValidator::new().partition(result.hands);
+//! for hand in &quarantined {
+//!     eprintln!("{}: {}", hand.hand_id(), hand.reason());
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::pot_resolver::PotResolution;
+use crate::types::{ActionType, Card, ParsedHand, Street};
+
+/// Regla de integridad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum IntegrityRule {
+    /// Stacks, apuestas, bote, rake y cobros cuadran.
+    ChipConservation,
+    /// Turnos y acciones legales en cada calle.
+    ActionOrder,
+    /// Tamaño mínimo de apuestas y subidas.
+    MinRaise,
+    /// Ninguna carta aparece dos veces.
+    DuplicateCards,
+    /// Los ganadores llegaron al final de la mano.
+    ShowdownWinners,
+}
+
+impl IntegrityRule {
+    /// Todas las reglas.
+    pub const ALL: [IntegrityRule; 5] = [
+        Self::ChipConservation,
+        Self::ActionOrder,
+        Self::MinRaise,
+        Self::DuplicateCards,
+        Self::ShowdownWinners,
+    ];
+
+    /// Nombre legible para informes.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::ChipConservation => "chip conservation",
+            Self::ActionOrder => "action order",
+            Self::MinRaise => "min raise",
+            Self::DuplicateCards => "duplicate cards",
+            Self::ShowdownWinners => "showdown winners",
+        }
+    }
+}
+
+/// Incumplimiento de una regla.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrityViolation {
+    /// Regla incumplida.
+    pub rule: IntegrityRule,
+    /// Descripción del problema.
+    pub message: String,
+}
+
+impl fmt::Display for IntegrityViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.rule.name(), self.message)
+    }
+}
+
+/// Mano apartada por no superar la validación.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuarantinedHand {
+    /// Reglas incumplidas.
+    pub violations: Vec<IntegrityViolation>,
+    /// Mano tal como salió del parser.
+    pub hand: ParsedHand,
+}
+
+impl QuarantinedHand {
+    /// ID de la mano.
+    pub fn hand_id(&self) -> &str {
+        &self.hand.hand_id
+    }
+
+    /// Motivo de la cuarentena (todas las reglas incumplidas).
+    pub fn reason(&self) -> String {
+        self.violations
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+/// Validador de integridad de manos.
+#[derive(Debug, Clone)]
+pub struct HandValidator {
+    rules: Vec<IntegrityRule>,
+}
+
+impl Default for HandValidator {
+    fn default() -> Self {
+        Self {
+            rules: IntegrityRule::ALL.to_vec(),
+        }
+    }
+}
+
+impl HandValidator {
+    /// Crea un validador con todas las reglas.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Crea un validador con las reglas indicadas.
+    pub fn with_rules(rules: impl IntoIterator<Item = IntegrityRule>) -> Self {
+        Self {
+            rules: rules.into_iter().collect(),
+        }
+    }
+
+    /// Reglas activas.
+    pub fn rules(&self) -> &[IntegrityRule] {
+        &self.rules
+    }
+
+    /// Comprueba una mano y devuelve las reglas incumplidas (vacío si es
+    /// válida).
+    pub fn validate(&self, hand: &ParsedHand) -> Vec<IntegrityViolation> {
+        let mut violations = Vec::new();
+        if self.rules.contains(&IntegrityRule::DuplicateCards) {
+            check_cards(hand, &mut violations);
+        }
+        Replay::new(hand).run(&mut violations);
+        if self.rules.contains(&IntegrityRule::ChipConservation) {
+            check_pot(hand, &mut violations);
+        }
+        violations.retain(|v| self.rules.contains(&v.rule));
+        violations
+    }
+
+    /// Indica si la mano supera todas las reglas activas.
+    pub fn is_valid(&self, hand: &ParsedHand) -> bool {
+        self.validate(hand).is_empty()
+    }
+
+    /// Separa las manos válidas de las que deben ir a cuarentena.
+    pub fn partition(&self, hands: Vec<ParsedHand>) -> (Vec<ParsedHand>, Vec<QuarantinedHand>) {
+        let mut valid = Vec::with_capacity(hands.len());
+        let mut quarantined = Vec::new();
+        for hand in hands {
+            let violations = self.validate(&hand);
+            if violations.is_empty() {
+                valid.push(hand);
+            } else {
+                quarantined.push(QuarantinedHand { violations, hand });
+            }
+        }
+        (valid, quarantined)
+    }
+}
+
+// ============================================================================
+// REGLAS
+// ============================================================================
+
+fn violation(rule: IntegrityRule, message: String) -> IntegrityViolation {
+    IntegrityViolation { rule, message }
+}
+
+/// Cartas repetidas entre el board y las cartas propias conocidas.
+fn check_cards(hand: &ParsedHand, violations: &mut Vec<IntegrityViolation>) {
+    if hand.board.len() > 5 {
+        violations.push(violation(
+            IntegrityRule::DuplicateCards,
+            format!("Board has {} cards", hand.board.len()),
+        ));
+    }
+
+    let mut seen: Vec<(&Card, &str)> = hand.board.iter().map(|c| (c, "board")).collect();
+    let hero_cards = hand
+        .hero_cards
+        .iter()
+        .filter(|cards| {
+            !hand
+                .players
+                .iter()
+                .any(|p| p.hole_cards.as_ref() == Some(cards))
+        })
+        .map(|cards| (cards, "hero"));
+    let hole_cards = hand
+        .players
+        .iter()
+        .filter_map(|p| p.hole_cards.as_ref().map(|cards| (cards, &*p.name)));
+
+    for (cards, owner) in hole_cards.chain(hero_cards) {
+        for card in cards {
+            if let Some((_, first)) = seen.iter().find(|(c, _)| *c == card) {
+                violations.push(violation(
+                    IntegrityRule::DuplicateCards,
+                    format!(
+                        "{}{} dealt to {} and {}",
+                        card.rank, card.suit, first, owner
+                    ),
+                ));
+            }
+            seen.push((card, owner));
+        }
+    }
+}
+
+/// Botes reconstruidos contra cobros, rake y total del resumen.
+fn check_pot(hand: &ParsedHand, violations: &mut Vec<IntegrityViolation>) {
+    let resolution = PotResolution::from_hand(hand);
+    if let Err(mismatch) = resolution.validate() {
+        violations.push(violation(
+            IntegrityRule::ChipConservation,
+            mismatch.to_string(),
+        ));
+    }
+
+    // Winamax incluye la apuesta no igualada en el total; PokerStars y
+    // GGPoker la devuelven aparte
+    let collected = resolution.total_collected_cents();
+    let uncalled = resolution
+        .uncalled
+        .as_ref()
+        .map_or(0, |(_, amount)| *amount);
+    if hand.pot.total_cents != collected && hand.pot.total_cents != collected - uncalled {
+        violations.push(violation(
+            IntegrityRule::ChipConservation,
+            format!(
+                "Total pot {} != collected {}",
+                hand.pot.total_cents, collected
+            ),
+        ));
+    }
+    if hand.pot.rake_cents < 0 || hand.pot.winners.iter().any(|(_, amount)| *amount < 0) {
+        violations.push(violation(
+            IntegrityRule::ChipConservation,
+            "Negative rake or collect".to_string(),
+        ));
+    }
+}
+
+// ============================================================================
+// REPRODUCCIÓN DEL FLUJO DE ACCIONES
+// ============================================================================
+
+/// Estado de un jugador durante la reproducción.
+#[derive(Debug, Clone, Default)]
+struct Seat {
+    /// Aparece en alguna acción (los sentados sin acciones están ausentes).
+    in_hand: bool,
+    /// Comprometido en la calle actual (ciegas incluidas).
+    committed: i64,
+    /// Invertido en calles anteriores y antes.
+    invested: i64,
+    folded: bool,
+    all_in: bool,
+    /// Ya actuó voluntariamente en la calle actual.
+    acted: bool,
+}
+
+impl Seat {
+    /// Puede actuar: sigue en la mano y no está all-in.
+    fn can_act(&self) -> bool {
+        self.in_hand && !self.folded && !self.all_in
+    }
+}
+
+/// Reproduce las acciones de una mano en orden de asiento.
+struct Replay<'a> {
+    hand: &'a ParsedHand,
+    /// Índices de `hand.players` ordenados por asiento.
+    order: Vec<usize>,
+    seats: Vec<Seat>,
+    street: Street,
+    /// Apuesta a igualar en la calle.
+    current_bet: i64,
+    /// Última subida completa (tamaño mínimo de la siguiente).
+    last_raise: i64,
+    /// Último jugador en actuar voluntariamente en la calle.
+    last_actor: Option<usize>,
+    /// Solo se informa el primer turno fuera de orden (los siguientes
+    /// suelen ser consecuencia del primero).
+    out_of_turn: bool,
+}
+
+impl<'a> Replay<'a> {
+    fn new(hand: &'a ParsedHand) -> Self {
+        let mut order: Vec<usize> = (0..hand.players.len()).collect();
+        order.sort_by_key(|&i| hand.players[i].seat);
+
+        let mut seats = vec![Seat::default(); hand.players.len()];
+        for action in &hand.actions {
+            if let Some(i) = hand
+                .players
+                .iter()
+                .position(|p| p.name == action.player_name)
+            {
+                seats[i].in_hand = true;
+            }
+        }
+
+        Self {
+            hand,
+            order,
+            seats,
+            street: Street::Preflop,
+            current_bet: 0,
+            last_raise: hand.big_blind_cents,
+            last_actor: None,
+            out_of_turn: false,
+        }
+    }
+
+    fn run(mut self, violations: &mut Vec<IntegrityViolation>) {
+        for action in &self.hand.actions {
+            let Some(i) = self
+                .hand
+                .players
+                .iter()
+                .position(|p| p.name == action.player_name)
+            else {
+                violations.push(violation(
+                    IntegrityRule::ActionOrder,
+                    format!("Action by unseated player {}", action.player_name),
+                ));
+                continue;
+            };
+
+            if matches!(action.action_type, ActionType::Show | ActionType::Collect) {
+                continue;
+            }
+
+            if street_index(action.street) < street_index(self.street) {
+                violations.push(violation(
+                    IntegrityRule::ActionOrder,
+                    format!(
+                        "{} acts on {:?} after {:?}",
+                        action.player_name, action.street, self.street
+                    ),
+                ));
+                continue;
+            }
+            if action.street != self.street {
+                self.next_street(action.street, violations);
+            }
+
+            let amount = action.amount_cents.unwrap_or(0);
+            if amount < 0 {
+                violations.push(violation(
+                    IntegrityRule::ChipConservation,
+                    format!("{} puts a negative amount {}", action.player_name, amount),
+                ));
+                continue;
+            }
+
+            match action.action_type {
+                ActionType::PostAnte => self.seats[i].invested += amount,
+                ActionType::PostSmallBlind | ActionType::PostBigBlind => {
+                    self.seats[i].committed += amount;
+                    self.current_bet = self.current_bet.max(self.seats[i].committed);
+                }
+                _ => self.voluntary(i, action.action_type, amount, violations),
+            }
+
+            let seat = &mut self.seats[i];
+            let stack = self.hand.players[i].stack_cents;
+            let total = seat.invested + seat.committed;
+            if stack > 0 && total > stack {
+                violations.push(violation(
+                    IntegrityRule::ChipConservation,
+                    format!(
+                        "{} puts in {} with a stack of {}",
+                        action.player_name, total, stack
+                    ),
+                ));
+            }
+            seat.all_in |= action.is_all_in
+                || action.action_type == ActionType::AllIn
+                || (stack > 0 && total >= stack);
+        }
+
+        self.check_winners(violations);
+    }
+
+    /// Acción voluntaria (check, call, bet, raise, fold o all-in).
+    fn voluntary(
+        &mut self,
+        i: usize,
+        action_type: ActionType,
+        amount: i64,
+        violations: &mut Vec<IntegrityViolation>,
+    ) {
+        let name = &*self.hand.players[i].name;
+        let stack = self.hand.players[i].stack_cents;
+
+        if self.seats[i].folded || self.seats[i].all_in {
+            violations.push(violation(
+                IntegrityRule::ActionOrder,
+                format!(
+                    "{} acts after {}",
+                    name,
+                    if self.seats[i].folded {
+                        "folding"
+                    } else {
+                        "going all-in"
+                    }
+                ),
+            ));
+        } else if let Some(expected) = self.expected_actor() {
+            if expected != i && !self.out_of_turn {
+                self.out_of_turn = true;
+                violations.push(violation(
+                    IntegrityRule::ActionOrder,
+                    format!(
+                        "{} acts out of turn on {:?} (expected {})",
+                        name, self.street, self.hand.players[expected].name
+                    ),
+                ));
+            }
+        }
+
+        let committed = self.seats[i].committed;
+        let to_call = self.current_bet - committed;
+        // Tras la acción: lo comprometido en la calle
+        let new_committed = match action_type {
+            ActionType::Raise => amount,
+            ActionType::Call | ActionType::Bet | ActionType::AllIn => committed + amount,
+            _ => committed,
+        };
+        let all_in = stack > 0 && self.seats[i].invested + new_committed >= stack;
+
+        match action_type {
+            ActionType::Fold => self.seats[i].folded = true,
+            ActionType::Check if to_call > 0 => violations.push(violation(
+                IntegrityRule::ActionOrder,
+                format!("{} checks facing a bet of {}", name, to_call),
+            )),
+            ActionType::Call if to_call <= 0 => violations.push(violation(
+                IntegrityRule::ActionOrder,
+                format!("{} calls with nothing to call", name),
+            )),
+            ActionType::Call if amount > to_call || (amount < to_call && !all_in) => violations
+                .push(violation(
+                    IntegrityRule::ChipConservation,
+                    format!("{} calls {} facing {}", name, amount, to_call),
+                )),
+            ActionType::Bet if self.current_bet > 0 => violations.push(violation(
+                IntegrityRule::ActionOrder,
+                format!("{} bets facing a bet of {}", name, self.current_bet),
+            )),
+            ActionType::Bet | ActionType::Raise | ActionType::AllIn
+                if new_committed > self.current_bet =>
+            {
+                let raise = new_committed - self.current_bet;
+                if raise < self.last_raise && !all_in {
+                    violations.push(violation(
+                        IntegrityRule::MinRaise,
+                        format!(
+                            "{} raises by {} (minimum {}) on {:?}",
+                            name, raise, self.last_raise, self.street
+                        ),
+                    ));
+                }
+                self.last_raise = self.last_raise.max(raise);
+                self.current_bet = new_committed;
+            }
+            ActionType::Raise => violations.push(violation(
+                IntegrityRule::MinRaise,
+                format!(
+                    "{} raises to {} without exceeding the bet of {}",
+                    name, amount, self.current_bet
+                ),
+            )),
+            _ => {}
+        }
+
+        self.seats[i].committed = new_committed;
+        self.seats[i].acted = true;
+        self.last_actor = Some(i);
+    }
+
+    /// Siguiente jugador en turno, si se puede deducir.
+    ///
+    /// Preflop la primera acción no se comprueba (straddles y ciegas de
+    /// entrada cambian quién abre); después de la primera acción, y en las
+    /// calles siguientes desde el botón, el turno avanza en orden de asiento
+    /// saltando a los retirados y a los all-in.
+    fn expected_actor(&self) -> Option<usize> {
+        let start = match self.last_actor {
+            Some(last) => self.hand.players[last].seat,
+            None if self.street != Street::Preflop => self.hand.button_seat,
+            None => return None,
+        };
+        let after = self
+            .order
+            .iter()
+            .position(|&i| self.hand.players[i].seat > start)
+            .unwrap_or(0);
+        (0..self.order.len())
+            .map(|k| self.order[(after + k) % self.order.len()])
+            .find(|&i| self.seats[i].can_act())
+    }
+
+    /// Cierra la calle actual y empieza la siguiente.
+    fn next_street(&mut self, street: Street, violations: &mut Vec<IntegrityViolation>) {
+        let active = self.seats.iter().filter(|s| s.can_act()).count();
+        for (i, seat) in self.seats.iter().enumerate() {
+            let pending = seat.committed < self.current_bet || (!seat.acted && active > 1);
+            if seat.can_act() && pending {
+                violations.push(violation(
+                    IntegrityRule::ActionOrder,
+                    format!(
+                        "{:?} ends before {} acts",
+                        self.street, self.hand.players[i].name
+                    ),
+                ));
+            }
+        }
+
+        for seat in &mut self.seats {
+            seat.invested += seat.committed;
+            seat.committed = 0;
+            seat.acted = false;
+        }
+        self.street = street;
+        self.current_bet = 0;
+        self.last_raise = self.hand.big_blind_cents;
+        self.last_actor = None;
+        self.out_of_turn = false;
+    }
+
+    /// Ganadores que se retiraron o showdown sin board completo.
+    fn check_winners(&self, violations: &mut Vec<IntegrityViolation>) {
+        for (name, _) in &self.hand.pot.winners {
+            match self.hand.players.iter().position(|p| p.name == *name) {
+                None => violations.push(violation(
+                    IntegrityRule::ShowdownWinners,
+                    format!("Winner {} is not seated", name),
+                )),
+                Some(i) if self.seats[i].folded => violations.push(violation(
+                    IntegrityRule::ShowdownWinners,
+                    format!("Winner {} folded", name),
+                )),
+                Some(_) => {}
+            }
+        }
+
+        let live = self.seats.iter().filter(|s| s.in_hand && !s.folded).count();
+        if live > 1 && self.hand.board.len() < 5 {
+            violations.push(violation(
+                IntegrityRule::ShowdownWinners,
+                format!(
+                    "Showdown between {} players with {} board cards",
+                    live,
+                    self.hand.board.len()
+                ),
+            ));
+        }
+    }
+}
+
+/// Orden de las calles.
+fn street_index(street: Street) -> u8 {
+    match street {
+        Street::Preflop => 0,
+        Street::Flop => 1,
+        Street::Turn => 2,
+        Street::River => 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fsm::WinamaxParser;
+    use crate::types::Action;
+
+    /// Mano real de Winamax (ver `fsm::tests::test_parse_simple_hand`).
+    fn winamax_hand() -> ParsedHand {
+        let content = "Winamax Poker - CashGame - HandId: #21819158-393-1765807340 - Holdem no limit (0.01€/0.02€) - 2025/12/15 14:02:20 UTC
+Table: 'Nice 09' 5-max (real money) Seat #3 is the button
+Seat 1: captainogue (1.76€)
+Seat 2: verlan4 (2.24€)
+Seat 3: CucleBen (1.82€)
+Seat 4: D0LLIPRANE (2.93€)
+Seat 5: thesmoy (2€)
+*** ANTE/BLINDS ***
+D0LLIPRANE posts small blind 0.01€
+thesmoy posts big blind 0.02€
+Dealt to thesmoy [8d 8s]
+*** PRE-FLOP ***
+captainogue folds
+verlan4 calls 0.02€
+CucleBen raises 0.04€ to 0.06€
+D0LLIPRANE folds
+thesmoy calls 0.04€
+verlan4 calls 0.04€
+*** FLOP *** [6d Qc 7s]
+thesmoy checks
+verlan4 checks
+CucleBen bets 0.19€
+thesmoy folds
+verlan4 calls 0.19€
+*** TURN *** [6d Qc 7s][2c]
+verlan4 checks
+CucleBen bets 0.37€
+verlan4 folds
+CucleBen collected 0.91€ from pot
+*** SUMMARY ***
+Total pot 0.91€ | Rake 0.03€
+Board: [6d Qc 7s 2c]
+Seat 3: CucleBen (button) won 0.91€
+
+";
+        WinamaxParser::new().parse(content).hands.remove(0)
+    }
+
+    fn rules(hand: &ParsedHand) -> Vec<IntegrityRule> {
+        let mut rules: Vec<IntegrityRule> = HandValidator::new()
+            .validate(hand)
+            .iter()
+            .map(|v| v.rule)
+            .collect();
+        rules.dedup();
+        rules
+    }
+
+    #[test]
+    fn test_valid_hand_passes() {
+        let hand = winamax_hand();
+        assert_eq!(HandValidator::new().validate(&hand), vec![]);
+    }
+
+    #[test]
+    fn test_each_rule_detects_its_violation() {
+        // Cobro de más: no cuadra con lo invertido
+        let mut hand = winamax_hand();
+        hand.pot.winners[0].1 += 10;
+        hand.pot.total_cents += 10;
+        assert_eq!(rules(&hand), vec![IntegrityRule::ChipConservation]);
+
+        // verlan4 actúa antes que captainogue
+        let mut hand = winamax_hand();
+        hand.actions.swap(2, 3);
+        assert_eq!(rules(&hand), vec![IntegrityRule::ActionOrder]);
+
+        // Subida de 0.06€ a 0.07€ tras la de 0.02€ a 0.06€
+        let mut hand = winamax_hand();
+        hand.actions.insert(
+            6,
+            Action::new("thesmoy", ActionType::Raise, Some(7), Street::Preflop),
+        );
+        hand.actions.retain(|a| {
+            !(&*a.player_name == "thesmoy"
+                && a.action_type == ActionType::Call
+                && a.street == Street::Preflop)
+        });
+        assert!(rules(&hand).contains(&IntegrityRule::MinRaise));
+
+        // El 8d del héroe también en el board
+        let mut hand = winamax_hand();
+        hand.board[0] = Card::parse("8d").unwrap();
+        assert_eq!(rules(&hand), vec![IntegrityRule::DuplicateCards]);
+
+        // Cobra un jugador que se retiró en el flop
+        let mut hand = winamax_hand();
+        hand.pot.winners[0].0 = "thesmoy".into();
+        assert!(rules(&hand).contains(&IntegrityRule::ShowdownWinners));
+    }
+
+    #[test]
+    fn test_showdown_requires_full_board() {
+        let mut hand = winamax_hand();
+        // Sin el fold del turn, verlan4 llega al final con 4 cartas de board
+        hand.actions
+            .retain(|a| !(&*a.player_name == "verlan4" && a.action_type == ActionType::Fold));
+        let violations =
+            HandValidator::with_rules([IntegrityRule::ShowdownWinners]).validate(&hand);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].to_string(),
+            "[showdown winners] Showdown between 2 players with 4 board cards"
+        );
+    }
+
+    #[test]
+    fn test_partition_quarantines_with_reason() {
+        let valid = winamax_hand();
+        let mut invalid = winamax_hand();
+        invalid.hand_id = "bad".to_string();
+        invalid
+            .actions
+            .retain(|a| a.action_type != ActionType::PostBigBlind);
+
+        let (valid, quarantined) = HandValidator::new().partition(vec![valid, invalid]);
+        assert_eq!(valid.len(), 1);
+        assert_eq!(quarantined.len(), 1);
+        assert_eq!(quarantined[0].hand_id(), "bad");
+        assert!(quarantined[0].reason().contains("[chip conservation]"));
+
+        // Sin la regla incumplida la mano pasa
+        let lenient = HandValidator::with_rules([IntegrityRule::DuplicateCards]);
+        assert!(lenient.is_valid(&quarantined[0].hand));
+    }
+}
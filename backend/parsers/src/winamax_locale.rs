@@ -0,0 +1,355 @@
+//! Gramática de los historiales Winamax por idioma.
+//!
+//! El cliente de Winamax escribe el historial en el idioma del usuario. La
+//! estructura de la mano es la misma en todos (cabecera, mesa, asientos,
+//! marcadores `*** ... ***`, acciones y resumen); solo cambian las palabras:
+//!
+//! | Línea | Inglés | Francés | Español |
+//! | :--- | :--- | :--- | :--- |
+//! | Torneo | `Tournament "X" ... level: 1` | `Tournoi "X" ... niveau: 1` | `Torneo "X" ... nivel: 1` |
+//! | Mesa | `Table: ... Seat #3 is the button` | `Table: ... Siège #3 est le bouton` | `Mesa: ... Asiento #3 es el botón` |
+//! | Asiento | `Seat 1: P (2€)` | `Siège 1: P (2€)` | `Asiento 1: P (2€)` |
+//! | Marcadores | `ANTE/BLINDS`, `PRE-FLOP`, `SHOW DOWN`, `SUMMARY` | `ANTE/BLINDES`, `PRÉ-FLOP`, `ABATTAGE`, `RÉSUMÉ` | `ANTE/CIEGAS`, `PRE-FLOP`, `MOSTRAR CARTAS`, `RESUMEN` |
+//! | Cartas | `Dealt to P [..]` | `Distribué à P [..]` | `Repartido a P [..]` |
+//! | Ciegas | `posts small blind`, `posts big blind`, `posts ante` | `poste la petite blinde`, `poste la grosse blinde`, `poste l'ante` | `pone la ciega pequeña`, `pone la ciega grande`, `pone el ante` |
+//! | Acciones | `folds`, `checks`, `calls`, `bets`, `raises X to Y` | `passe`, `parole`, `suit`, `mise`, `relance X à Y` | `se retira`, `pasa`, `iguala`, `apuesta`, `sube X a Y` |
+//! | All-in | `and is all-in` | `et est à tapis` | `y está all-in` |
+//! | Cobro | `collected X from pot`, `shows [..]` | `remporte X du pot`, `montre [..]` | `gana X del bote`, `muestra [..]` |
+//! | Resumen | `Total pot X \| Rake Y` | `Pot total X \| Rake Y` | `Bote total X \| Rake Y` |
+//!
+//! No se traducen la primera parte de la cabecera (`Winamax Poker - CashGame
+//! - HandId: #...`), las variantes (`Holdem no limit`), las cartas, las
+//! fechas ni los importes (la coma decimal de `0,02€` ya se acepta). Los
+//! resúmenes de torneo (`Tournament summary`) siguen en inglés.
+//!
+//! El idioma se detecta una vez por archivo con `WinamaxLocale::detect`, en la
+//! primera cabecera de torneo o línea de mesa.
+//!
+//! ```rust
+//! use poker_parsers::WinamaxLocale;
+//!
+//! let line = "Table: 'Nice 09' 5-max (argent réel) Siège #3 est le bouton";
+//! assert_eq!(WinamaxLocale::detect(line.as_bytes()), Some(WinamaxLocale::French));
+//! ```
+
+use crate::bytes_parser::{find_bytes, tokens};
+use crate::types::ActionType;
+
+/// Idioma del cliente que escribió el historial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WinamaxLocale {
+    #[default]
+    English,
+    French,
+    Spanish,
+}
+
+impl WinamaxLocale {
+    /// Idiomas soportados, en el orden en que se prueban al detectar.
+    pub const ALL: [WinamaxLocale; 3] = [
+        WinamaxLocale::English,
+        WinamaxLocale::French,
+        WinamaxLocale::Spanish,
+    ];
+
+    /// Gramática de las líneas en este idioma.
+    pub fn grammar(self) -> &'static WinamaxGrammar {
+        match self {
+            WinamaxLocale::English => &ENGLISH,
+            WinamaxLocale::French => &FRENCH,
+            WinamaxLocale::Spanish => &SPANISH,
+        }
+    }
+
+    /// Código ISO 639-1 del idioma.
+    pub fn code(self) -> &'static str {
+        match self {
+            WinamaxLocale::English => "en",
+            WinamaxLocale::French => "fr",
+            WinamaxLocale::Spanish => "es",
+        }
+    }
+
+    /// Detecta el idioma por una línea de cabecera de torneo o de mesa.
+    ///
+    /// Devuelve `None` si la línea no lo delata (cabecera de cash game,
+    /// asientos, acciones...).
+    pub fn detect(line: &[u8]) -> Option<Self> {
+        Self::ALL.into_iter().find(|locale| {
+            let grammar = locale.grammar();
+            find_bytes(line, grammar.is_the_button).is_some()
+                || find_bytes(line, grammar.tournament).is_some()
+        })
+    }
+}
+
+/// Palabras de cada tipo de línea en un idioma.
+///
+/// Los marcadores de sección son la palabra entre `***`; las palabras de
+/// acción son las que siguen al nombre del jugador.
+#[derive(Debug)]
+pub struct WinamaxGrammar {
+    /// Nombre de torneo en la cabecera: `Tournament "`
+    pub tournament: &'static [u8],
+    /// Nivel de ciegas en la cabecera de torneo: `level: `
+    pub level: &'static [u8],
+    /// Inicio de la línea de mesa: `Table: `
+    pub table: &'static [u8],
+    /// Asiento del botón en la línea de mesa: `Seat #`
+    pub button_seat: &'static [u8],
+    /// Final de la línea de mesa: ` is the button`
+    pub is_the_button: &'static [u8],
+    /// Inicio de una línea de asiento: `Seat `
+    pub seat: &'static [u8],
+    pub ante_blinds: &'static [u8],
+    pub pre_flop: &'static [u8],
+    pub flop: &'static [u8],
+    pub turn: &'static [u8],
+    pub river: &'static [u8],
+    pub show_down: &'static [u8],
+    pub summary: &'static [u8],
+    /// Cartas del héroe: `Dealt to `
+    pub dealt_to: &'static [u8],
+    pub posts_small_blind: &'static [u8],
+    pub posts_big_blind: &'static [u8],
+    pub posts_ante: &'static [u8],
+    pub folds: &'static [u8],
+    pub checks: &'static [u8],
+    pub calls: &'static [u8],
+    pub bets: &'static [u8],
+    pub raises: &'static [u8],
+    /// Separador del total de una subida: ` to `
+    pub raise_to: &'static [u8],
+    pub collected: &'static [u8],
+    pub shows: &'static [u8],
+    /// Marca de all-in al final de la acción
+    pub all_in: &'static [u8],
+    /// Inicio de la línea de bote del resumen: `Total pot`
+    pub total_pot: &'static [u8],
+    pub rake: &'static [u8],
+}
+
+impl WinamaxGrammar {
+    /// Acciones de ciegas y antes.
+    pub fn blind_actions(&self) -> [(&'static [u8], ActionType); 3] {
+        [
+            (self.posts_small_blind, ActionType::PostSmallBlind),
+            (self.posts_big_blind, ActionType::PostBigBlind),
+            (self.posts_ante, ActionType::PostAnte),
+        ]
+    }
+
+    /// Palabras que pueden seguir al nombre del jugador en una línea de acción.
+    pub fn action_keywords(&self) -> [&'static [u8]; 10] {
+        [
+            self.folds,
+            self.checks,
+            self.calls,
+            self.bets,
+            self.raises,
+            self.posts_small_blind,
+            self.posts_big_blind,
+            self.posts_ante,
+            self.collected,
+            self.shows,
+        ]
+    }
+}
+
+/// Gramática del cliente en inglés.
+pub static ENGLISH: WinamaxGrammar = WinamaxGrammar {
+    tournament: b"Tournament \"",
+    level: b"level: ",
+    table: tokens::TABLE,
+    button_seat: b"Seat #",
+    is_the_button: b" is the button",
+    seat: tokens::SEAT,
+    ante_blinds: b"ANTE/BLINDS",
+    pre_flop: b"PRE-FLOP",
+    flop: b"FLOP",
+    turn: b"TURN",
+    river: b"RIVER",
+    show_down: b"SHOW DOWN",
+    summary: b"SUMMARY",
+    dealt_to: tokens::DEALT_TO,
+    posts_small_blind: tokens::POSTS_SMALL,
+    posts_big_blind: tokens::POSTS_BIG,
+    posts_ante: tokens::POSTS_ANTE,
+    folds: b"folds",
+    checks: b"checks",
+    calls: b"calls ",
+    bets: b"bets ",
+    raises: b"raises ",
+    raise_to: b" to ",
+    collected: tokens::COLLECTED,
+    shows: tokens::SHOWS,
+    all_in: tokens::ALL_IN,
+    total_pot: tokens::TOTAL_POT,
+    rake: b"Rake",
+};
+
+/// Gramática del cliente en francés.
+pub static FRENCH: WinamaxGrammar = WinamaxGrammar {
+    tournament: b"Tournoi \"",
+    level: b"niveau: ",
+    table: tokens::TABLE,
+    button_seat: "Siège #".as_bytes(),
+    is_the_button: b" est le bouton",
+    seat: "Siège ".as_bytes(),
+    ante_blinds: b"ANTE/BLINDES",
+    pre_flop: "PRÉ-FLOP".as_bytes(),
+    flop: b"FLOP",
+    turn: b"TURN",
+    river: b"RIVER",
+    show_down: b"ABATTAGE",
+    summary: "RÉSUMÉ".as_bytes(),
+    dealt_to: "Distribué à ".as_bytes(),
+    posts_small_blind: tokens::POSTE_PETITE,
+    posts_big_blind: tokens::POSTE_GROSSE,
+    posts_ante: tokens::POSTE_ANTE,
+    folds: b"passe",
+    checks: b"parole",
+    calls: b"suit ",
+    bets: b"mise ",
+    raises: b"relance ",
+    raise_to: " à ".as_bytes(),
+    collected: b"remporte",
+    shows: b"montre",
+    all_in: "à tapis".as_bytes(),
+    total_pot: b"Pot total",
+    rake: b"Rake",
+};
+
+/// Gramática del cliente en español.
+pub static SPANISH: WinamaxGrammar = WinamaxGrammar {
+    tournament: b"Torneo \"",
+    level: b"nivel: ",
+    table: b"Mesa: ",
+    button_seat: b"Asiento #",
+    is_the_button: " es el botón".as_bytes(),
+    seat: b"Asiento ",
+    ante_blinds: b"ANTE/CIEGAS",
+    pre_flop: b"PRE-FLOP",
+    flop: b"FLOP",
+    turn: b"TURN",
+    river: b"RIVER",
+    show_down: b"MOSTRAR CARTAS",
+    summary: b"RESUMEN",
+    dealt_to: b"Repartido a ",
+    posts_small_blind: "pone la ciega pequeña".as_bytes(),
+    posts_big_blind: b"pone la ciega grande",
+    posts_ante: b"pone el ante",
+    folds: b"se retira",
+    checks: b"pasa",
+    calls: b"iguala ",
+    bets: b"apuesta ",
+    raises: b"sube ",
+    raise_to: b" a ",
+    collected: b"gana",
+    shows: b"muestra",
+    all_in: tokens::ALL_IN,
+    total_pot: b"Bote total",
+    rake: b"Rake",
+};
+
+// ============================================================================
+// TESTS
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::HandValidator;
+    use crate::WinamaxParser;
+
+    const FIXTURE_EN: &str = include_str!("../tests/fixtures/winamax/locale_en.txt");
+    const FIXTURE_FR: &str = include_str!("../tests/fixtures/winamax/locale_fr.txt");
+    const FIXTURE_ES: &str = include_str!("../tests/fixtures/winamax/locale_es.txt");
+
+    /// Parsea un fixture comprobando idioma, diagnósticos e integridad.
+    fn parse_fixture(content: &str, locale: WinamaxLocale) -> serde_json::Value {
+        let mut parser = WinamaxParser::new();
+        let result = parser.parse(content);
+        assert_eq!(parser.locale(), Some(locale));
+        assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+        assert_eq!(result.hands.len(), 3);
+        for hand in &result.hands {
+            assert!(HandValidator::new().is_valid(hand), "{}", hand.hand_id);
+        }
+        serde_json::to_value(&result.hands).unwrap()
+    }
+
+    #[test]
+    fn test_detect_locale() {
+        let cases: [(&str, Option<WinamaxLocale>); 5] = [
+            (
+                "Table: 'Nice 09' 5-max (real money) Seat #3 is the button",
+                Some(WinamaxLocale::English),
+            ),
+            (
+                "Mesa: 'Nice 09' 5-max (dinero real) Asiento #3 es el botón",
+                Some(WinamaxLocale::Spanish),
+            ),
+            (
+                "Winamax Poker - Tournoi \"Expresso\" buyIn: 0,93€ + 0,07€ niveau: 1 - HandId: #1-1-1",
+                Some(WinamaxLocale::French),
+            ),
+            (
+                "Winamax Poker - CashGame - HandId: #1-1-1 - Holdem no limit (0.01€/0.02€)",
+                None,
+            ),
+            ("Seat 1: thesmoy (2€)", None),
+        ];
+        for (line, expected) in cases {
+            assert_eq!(WinamaxLocale::detect(line.as_bytes()), expected, "{}", line);
+        }
+    }
+
+    #[test]
+    fn test_english_fixture() {
+        let hands = parse_fixture(FIXTURE_EN, WinamaxLocale::English);
+        assert_eq!(hands[2]["tournament"]["level"], 3);
+        assert_eq!(hands[1]["actions"][7]["is_all_in"], true);
+    }
+
+    #[test]
+    fn test_french_fixture() {
+        assert_eq!(
+            parse_fixture(FIXTURE_FR, WinamaxLocale::French),
+            parse_fixture(FIXTURE_EN, WinamaxLocale::English)
+        );
+    }
+
+    #[test]
+    fn test_spanish_fixture() {
+        assert_eq!(
+            parse_fixture(FIXTURE_ES, WinamaxLocale::Spanish),
+            parse_fixture(FIXTURE_EN, WinamaxLocale::English)
+        );
+    }
+
+    #[test]
+    fn test_locale_is_per_file() {
+        let mut parser = WinamaxParser::new();
+        parser.parse(FIXTURE_FR);
+        assert_eq!(parser.locale(), Some(WinamaxLocale::French));
+
+        parser.reset();
+        assert_eq!(parser.locale(), None);
+        let result = parser.parse(FIXTURE_ES);
+        assert_eq!(parser.locale(), Some(WinamaxLocale::Spanish));
+        assert_eq!(result.hands.len(), 3);
+    }
+
+    #[test]
+    fn test_forced_locale() {
+        let mut parser = WinamaxParser::new().with_locale(WinamaxLocale::Spanish);
+        parser.reset();
+        assert_eq!(parser.locale(), Some(WinamaxLocale::Spanish));
+
+        // Con el idioma fijado, un archivo en otro idioma no se reconoce
+        let result = parser.parse(FIXTURE_FR);
+        assert_eq!(parser.locale(), Some(WinamaxLocale::Spanish));
+        assert!(!result.diagnostics.is_empty());
+    }
+}
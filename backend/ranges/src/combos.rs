@@ -0,0 +1,236 @@
+//! Combinatoria de rangos: expansión de clases de mano a combos concretos.
+//!
+//! Cada clase de mano se expande a sus combinaciones de cartas reales
+//! (6 para parejas, 4 suited, 12 offsuit) usando `poker_math::Card`.
+//! Las cartas conocidas (board, cartas del hero, cartas muertas) bloquean
+//! los combos que las contienen (card removal).
+//!
+//! ## Ejemplo
+//!
+//! ```rust
+//! use poker_math::Card;
+//! use poker_ranges::{HandClass, Range};
+//!
+//! let range = Range::parse("AKs:1,AKo:1,KK:1").unwrap();
+//! let board: Vec<Card> = ["Kh", "7d", "2c"].iter().map(|c| c.parse().unwrap()).collect();
+//!
+//! // AK: 16 combos - 4 con el Kh = 12; KK: 6 combos - 3 con el Kh = 3
+//! assert_eq!(range.live_combos(&board), 15.0);
+//!
+//! let ak: HandClass = "AKo".parse().unwrap();
+//! assert_eq!(ak.live_combos(&board).count(), 9);
+//! ```
+
+use crate::range::{HandClass, HandClassKind, Range};
+use poker_math::{Card, Rank, Suit};
+
+/// Combo concreto de dos cartas.
+pub type Combo = [Card; 2];
+
+/// Combo concreto con su peso (frecuencia de la clase en el rango).
+///
+/// Es el tipo de entrada de `poker_math::calculate_equity_vs_range`.
+pub use poker_math::WeightedCombo;
+
+/// Máscara de bits de cartas (bit = `Card::index()`).
+#[inline]
+fn card_mask(cards: &[Card]) -> u64 {
+    cards
+        .iter()
+        .fold(0u64, |mask, card| mask | (1u64 << card.index()))
+}
+
+#[inline]
+fn make_card(rank: u8, suit: u8) -> Card {
+    // Los índices están siempre en 0-12 / 0-3 por construcción de HandClass
+    Card::new(
+        Rank::from_index(rank).expect("rank válido"),
+        Suit::from_index(suit).expect("suit válido"),
+    )
+}
+
+impl HandClass {
+    /// Clase de mano a la que pertenecen dos cartas concretas.
+    ///
+    /// Devuelve `None` si ambas cartas son la misma.
+    pub fn from_cards(card1: Card, card2: Card) -> Option<Self> {
+        if card1 == card2 {
+            return None;
+        }
+
+        let rank1 = card1.rank().index();
+        let rank2 = card2.rank().index();
+        let kind = if rank1 == rank2 {
+            HandClassKind::Pair
+        } else if card1.suit() == card2.suit() {
+            HandClassKind::Suited
+        } else {
+            HandClassKind::Offsuit
+        };
+
+        Self::new(rank1, rank2, kind)
+    }
+
+    /// Expande la clase a todos sus combos concretos (6, 4 o 12).
+    pub fn combos(&self) -> Vec<Combo> {
+        let (high, low) = (self.high(), self.low());
+        let mut combos = Vec::with_capacity(self.num_combos() as usize);
+
+        match self.kind() {
+            HandClassKind::Pair => {
+                for suit1 in 0..4 {
+                    for suit2 in (suit1 + 1)..4 {
+                        combos.push([make_card(high, suit2), make_card(low, suit1)]);
+                    }
+                }
+            }
+            HandClassKind::Suited => {
+                for suit in 0..4 {
+                    combos.push([make_card(high, suit), make_card(low, suit)]);
+                }
+            }
+            HandClassKind::Offsuit => {
+                for suit1 in 0..4 {
+                    for suit2 in (0..4).filter(|&s| s != suit1) {
+                        combos.push([make_card(high, suit1), make_card(low, suit2)]);
+                    }
+                }
+            }
+        }
+
+        combos
+    }
+
+    /// Combos de la clase que no contienen ninguna carta muerta.
+    pub fn live_combos(&self, dead_cards: &[Card]) -> impl Iterator<Item = Combo> {
+        let dead = card_mask(dead_cards);
+        self.combos()
+            .into_iter()
+            .filter(move |combo| card_mask(combo) & dead == 0)
+    }
+}
+
+impl Range {
+    /// Expande el rango a combos concretos ponderados, aplicando card removal.
+    ///
+    /// `dead_cards` incluye el board, las cartas del hero y cualquier carta
+    /// conocida. Los combos bloqueados se descartan.
+    pub fn expand(&self, dead_cards: &[Card]) -> Vec<WeightedCombo> {
+        let dead = card_mask(dead_cards);
+        let mut combos = Vec::new();
+
+        for (hand, weight) in self.iter() {
+            combos.extend(
+                hand.combos()
+                    .into_iter()
+                    .filter(|combo| card_mask(combo) & dead == 0)
+                    .map(|cards| WeightedCombo { cards, weight }),
+            );
+        }
+
+        combos
+    }
+
+    /// Número de combos vivos ponderado por frecuencia tras card removal.
+    pub fn live_combos(&self, dead_cards: &[Card]) -> f64 {
+        self.expand(dead_cards).iter().map(|c| c.weight).sum()
+    }
+
+    /// Número de combos vivos ponderado de una clase concreta del rango.
+    ///
+    /// Responde preguntas como "cuántos combos de AK tiene villain en este
+    /// board con K".
+    pub fn live_combos_of(&self, hand: HandClass, dead_cards: &[Card]) -> f64 {
+        hand.live_combos(dead_cards).count() as f64 * self.frequency(hand)
+    }
+
+    /// Frecuencia con la que el rango contiene un combo concreto.
+    pub fn combo_frequency(&self, card1: Card, card2: Card) -> f64 {
+        HandClass::from_cards(card1, card2)
+            .map(|hand| self.frequency(hand))
+            .unwrap_or(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(s: &[&str]) -> Vec<Card> {
+        s.iter().map(|c| c.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_expand_hand_classes() {
+        for (text, expected) in [("TT", 6), ("AKs", 4), ("72o", 12)] {
+            let hand: HandClass = text.parse().unwrap();
+            let combos = hand.combos();
+            assert_eq!(combos.len(), expected, "{}", text);
+
+            for combo in &combos {
+                assert_ne!(combo[0], combo[1]);
+                assert_eq!(HandClass::from_cards(combo[0], combo[1]), Some(hand));
+            }
+
+            let mask: Vec<u64> = combos.iter().map(|c| card_mask(c)).collect();
+            let mut unique = mask.clone();
+            unique.sort_unstable();
+            unique.dedup();
+            assert_eq!(unique.len(), mask.len(), "combos duplicados en {}", text);
+        }
+    }
+
+    #[test]
+    fn test_all_classes_cover_1326_combos() {
+        let mut seen = std::collections::HashSet::new();
+        for hand in HandClass::all() {
+            for combo in hand.combos() {
+                assert!(seen.insert(card_mask(&combo)));
+            }
+        }
+        assert_eq!(seen.len(), 1326);
+    }
+
+    #[test]
+    fn test_card_removal_with_board() {
+        let board = cards(&["Kh", "7d", "2c"]);
+        let range = Range::parse("AKs:1,AKo:0.5,KK:1,77:1,AA:1").unwrap();
+
+        let aks: HandClass = "AKs".parse().unwrap();
+        let ako: HandClass = "AKo".parse().unwrap();
+        let kk: HandClass = "KK".parse().unwrap();
+
+        assert_eq!(aks.live_combos(&board).count(), 3);
+        assert_eq!(ako.live_combos(&board).count(), 9);
+        assert_eq!(kk.live_combos(&board).count(), 3);
+
+        assert_eq!(range.live_combos_of(ako, &board), 4.5);
+        // AKs 3 + AKo 4.5 + KK 3 + 77 3 + AA 6
+        assert_eq!(range.live_combos(&board), 19.5);
+    }
+
+    #[test]
+    fn test_card_removal_with_hero_cards() {
+        let hero = cards(&["As", "Ah"]);
+        let range = Range::parse("AA:1,AKs:1").unwrap();
+
+        let combos = range.expand(&hero);
+        // AA: solo queda AdAc; AKs: quedan AdKd y AcKc
+        assert_eq!(combos.len(), 3);
+        assert!(combos
+            .iter()
+            .all(|c| !c.cards.contains(&hero[0]) && !c.cards.contains(&hero[1])));
+    }
+
+    #[test]
+    fn test_combo_frequency() {
+        let range = Range::parse("AKs:0.8,QQ:1").unwrap();
+        let [ah, kh, kd, qs, qc] = ["Ah", "Kh", "Kd", "Qs", "Qc"].map(|c| c.parse().unwrap());
+
+        assert_eq!(range.combo_frequency(ah, kh), 0.8);
+        assert_eq!(range.combo_frequency(kh, ah), 0.8);
+        assert_eq!(range.combo_frequency(ah, kd), 0.0);
+        assert_eq!(range.combo_frequency(qs, qc), 1.0);
+        assert_eq!(range.combo_frequency(qs, qs), 0.0);
+    }
+}
@@ -0,0 +1,1037 @@
+//! Parser de archivos HandRangeDSL.
+//!
+//! Carga archivos Markdown de rangos (como `docs/ranges/preflop-ranges.md`)
+//! y los convierte en una estructura tipada de situaciones y rangos.
+//!
+//! ## Estructura reconocida
+//!
+//! - **Frontmatter** opcional delimitado por `---` al inicio del archivo
+//!   (`title`, `category`, `position`, `situationId`, `benchmark_time`, `tags`).
+//! - `# Título` del documento.
+//! - `## Categoría` (ej: "Open raise + 3bet").
+//! - `### Situación [RANGE_ID]`: cada situación con su identificador opcional.
+//! - `#### Rango de <acción>` / `#### Not in range ...`: sección de acción.
+//! - ``**`AA:1,AKs:0.8,...`**``: rango de la sección de acción.
+//!
+//! Si el archivo tiene frontmatter y las secciones de acción aparecen sin
+//! situación previa (un archivo por situación, ver `docs/specs/range-spec.md`),
+//! se crea una situación implícita a partir del frontmatter.
+//!
+//! ## Validaciones
+//!
+//! Todos los errores incluyen línea y columna (1-based) del problema:
+//! formato de mano, frecuencias fuera de 0.0-1.0, espacios y manos duplicadas.
+//! La suma de frecuencias jugadas mayor que 1.0 dentro de una situación solo
+//! se comprueba si se activa con `RangeParser::with_sum_validation`, ya que
+//! los charts exportados pueden traer redondeos o errores de origen.
+//!
+//! ## Uso
+//!
+//! ```rust
+//! use poker_ranges::{RangeAction, RangeParser};
+//!
+//! let content = concat!(
+//!     "## Open raise\n",
+//!     "### Desde SB [SB_Open_Raise_01]\n",
+//!     "#### Rango de open raise a 2.6bb\n",
+//!     "**`AA:1,AKs:1,KQo:0.5`**\n",
+//! );
+//!
+//! let document = RangeParser::new().parse_str(content).unwrap();
+//! let situation = document.situation("SB_Open_Raise_01").unwrap();
+//! let range = situation.range(RangeAction::Raise);
+//!
+//! assert_eq!(range.frequency("KQo".parse().unwrap()), 0.5);
+//! ```
+
+use crate::range::{HandClass, Range, RangeAction, NUM_HAND_CLASSES};
+use std::fmt;
+use std::path::Path;
+
+/// Tolerancia al validar que la suma de frecuencias jugadas no supera 1.0.
+///
+/// Los rangos exportados de solvers redondean a 2 decimales, por lo que una
+/// mano repartida entre varias acciones puede sumar hasta 1.02.
+pub const FREQUENCY_SUM_TOLERANCE: f64 = 0.025;
+
+// ============================================================================
+// ERRORES
+// ============================================================================
+
+/// Tipo de error de parsing de rangos.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RangeErrorKind {
+    /// Mano con formato inválido (ej: `AKx`, `KAs`).
+    InvalidHand(String),
+    /// Entrada sin separador `:` entre mano y frecuencia.
+    MissingFrequency(String),
+    /// Frecuencia que no es un número.
+    InvalidFrequency(String),
+    /// Frecuencia fuera de 0.0-1.0.
+    FrequencyOutOfRange(f64),
+    /// Mano repetida dentro del mismo rango.
+    DuplicateHand(String),
+    /// Entrada vacía (ej: coma final o `,,`).
+    EmptyEntry,
+    /// Espacio dentro de la notación compacta.
+    UnexpectedWhitespace,
+    /// Bloque ``**`...`**`` sin cerrar correctamente.
+    UnterminatedRange,
+    /// Header "Rango de ..." con acción desconocida.
+    UnknownAction(String),
+    /// Rango sin sección de acción previa.
+    RangeOutsideSection,
+    /// Sección de acción sin situación (`###`) previa.
+    SectionOutsideSituation,
+    /// Suma de frecuencias jugadas de una mano mayor que 1.0.
+    FrequencySumExceeded { hand: String, sum: f64 },
+    /// Identificador de rango repetido en el documento.
+    DuplicateRangeId(String),
+    /// Frontmatter sin `---` de cierre.
+    UnterminatedFrontmatter,
+    /// Línea de frontmatter inválida.
+    InvalidFrontmatter(String),
+    /// Error de I/O al leer el archivo.
+    Io(String),
+}
+
+/// Error de parsing con posición exacta (línea y columna, 1-based).
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeParseError {
+    /// Línea del archivo (1-based).
+    pub line: usize,
+    /// Columna en caracteres (1-based).
+    pub column: usize,
+    /// Tipo de error.
+    pub kind: RangeErrorKind,
+}
+
+impl RangeParseError {
+    fn new(line: usize, column: usize, kind: RangeErrorKind) -> Self {
+        Self { line, column, kind }
+    }
+}
+
+impl fmt::Display for RangeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "línea {}, columna {}: ", self.line, self.column)?;
+        match &self.kind {
+            RangeErrorKind::InvalidHand(hand) => write!(f, "mano inválida '{}'", hand),
+            RangeErrorKind::MissingFrequency(entry) => {
+                write!(
+                    f,
+                    "falta la frecuencia en '{}' (formato mano:frecuencia)",
+                    entry
+                )
+            }
+            RangeErrorKind::InvalidFrequency(freq) => write!(f, "frecuencia inválida '{}'", freq),
+            RangeErrorKind::FrequencyOutOfRange(freq) => {
+                write!(f, "frecuencia {} fuera de rango (0.0-1.0)", freq)
+            }
+            RangeErrorKind::DuplicateHand(hand) => write!(f, "mano duplicada '{}'", hand),
+            RangeErrorKind::EmptyEntry => write!(f, "entrada vacía en el rango"),
+            RangeErrorKind::UnexpectedWhitespace => {
+                write!(f, "espacio no permitido en la notación de rango")
+            }
+            RangeErrorKind::UnterminatedRange => write!(f, "rango sin cerrar (se esperaba `**)"),
+            RangeErrorKind::UnknownAction(header) => {
+                write!(f, "acción desconocida en el header '{}'", header)
+            }
+            RangeErrorKind::RangeOutsideSection => {
+                write!(f, "rango fuera de una sección 'Rango de ...'")
+            }
+            RangeErrorKind::SectionOutsideSituation => {
+                write!(f, "sección de acción fuera de una situación (###)")
+            }
+            RangeErrorKind::FrequencySumExceeded { hand, sum } => {
+                write!(
+                    f,
+                    "la suma de frecuencias de '{}' es {:.2} (> 1.0)",
+                    hand, sum
+                )
+            }
+            RangeErrorKind::DuplicateRangeId(id) => write!(f, "ID de rango duplicado '{}'", id),
+            RangeErrorKind::UnterminatedFrontmatter => write!(f, "frontmatter sin cerrar (---)"),
+            RangeErrorKind::InvalidFrontmatter(msg) => write!(f, "frontmatter inválido: {}", msg),
+            RangeErrorKind::Io(msg) => write!(f, "error de I/O: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for RangeParseError {}
+
+// ============================================================================
+// DOCUMENTO
+// ============================================================================
+
+/// Metadatos del frontmatter YAML de un archivo de rangos.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Frontmatter {
+    /// Título descriptivo de la situación.
+    pub title: Option<String>,
+    /// Categoría (RFI, 3Bet, Squeeze...).
+    pub category: Option<String>,
+    /// Posición del jugador (SB, BB, BTN, CO, MP, UTG).
+    pub position: Option<String>,
+    /// Identificador único de la situación.
+    pub situation_id: Option<String>,
+    /// Tiempo de referencia en segundos.
+    pub benchmark_time: Option<u32>,
+    /// Etiquetas libres.
+    pub tags: Vec<String>,
+}
+
+/// Rango asociado a una sección de acción (`#### Rango de ...`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActionRange {
+    /// Acción mapeada desde el header.
+    pub action: RangeAction,
+    /// Texto original del header (ej: "Rango de open raise a 2.6bb").
+    pub label: String,
+    /// Tamaño de la acción en ciegas grandes, si el header lo indica.
+    pub size_bb: Option<f64>,
+    /// Frecuencias de las 169 clases de mano.
+    pub range: Range,
+    /// Línea del header en el archivo.
+    pub line: usize,
+}
+
+impl ActionRange {
+    /// Acción real de la sección: para secciones marginales, la acción del
+    /// resto del header ("marginal open raise" → `Raise`).
+    pub fn base_action(&self) -> RangeAction {
+        if self.action != RangeAction::Marginal {
+            return self.action;
+        }
+
+        let lower = self.label.to_lowercase();
+        let text = lower.strip_prefix("rango de").unwrap_or(&lower).trim();
+        text.strip_prefix("marginal")
+            .and_then(RangeAction::from_header)
+            .unwrap_or(RangeAction::Marginal)
+    }
+}
+
+/// Situación preflop (`### Desde X ... [RANGE_ID]`) con sus rangos por acción.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Situation {
+    /// Identificador entre corchetes (ej: `SB_Open_Raise_01`), si existe.
+    pub id: Option<String>,
+    /// Título de la situación sin el identificador.
+    pub title: String,
+    /// Categoría (`##`) a la que pertenece.
+    pub category: Option<String>,
+    /// Rangos por acción en el orden del archivo.
+    pub actions: Vec<ActionRange>,
+    /// Línea del header en el archivo.
+    pub line: usize,
+}
+
+impl Situation {
+    /// Rango combinado de una acción (suma de todas sus secciones).
+    pub fn range(&self, action: RangeAction) -> Range {
+        let mut combined = Range::new();
+        for action_range in self.actions.iter().filter(|a| a.action == action) {
+            for (hand, freq) in action_range.range.iter() {
+                combined.set_frequency(hand, combined.frequency(hand) + freq);
+            }
+        }
+        combined
+    }
+
+    /// Frecuencia con la que se juega una mano con una acción concreta.
+    pub fn frequency(&self, hand: HandClass, action: RangeAction) -> f64 {
+        self.actions
+            .iter()
+            .filter(|a| a.action == action)
+            .map(|a| a.range.frequency(hand))
+            .sum::<f64>()
+            .min(1.0)
+    }
+
+    /// Estrategia mixta de una mano: acciones jugadas con frecuencia > 0.
+    pub fn strategy(&self, hand: HandClass) -> Vec<(RangeAction, f64)> {
+        let mut strategy: Vec<(RangeAction, f64)> = Vec::new();
+        for action_range in self.actions.iter().filter(|a| a.action.is_played()) {
+            let freq = action_range.range.frequency(hand);
+            if freq <= 0.0 {
+                continue;
+            }
+            match strategy.iter_mut().find(|(a, _)| *a == action_range.action) {
+                Some((_, total)) => *total += freq,
+                None => strategy.push((action_range.action, freq)),
+            }
+        }
+        strategy
+    }
+
+    /// Frecuencia total de una acción, incluyendo sus secciones marginales.
+    pub fn played_frequency(&self, hand: HandClass, action: RangeAction) -> f64 {
+        self.actions
+            .iter()
+            .filter(|a| a.base_action() == action)
+            .map(|a| a.range.frequency(hand))
+            .sum::<f64>()
+            .min(1.0)
+    }
+
+    /// Indica si la situación tiene alguna sección (normal o marginal) de la acción.
+    pub fn has_action(&self, action: RangeAction) -> bool {
+        self.actions.iter().any(|a| a.base_action() == action)
+    }
+
+    /// Fold implícito: `1 - suma de frecuencias jugadas`.
+    pub fn fold_frequency(&self, hand: HandClass) -> f64 {
+        let played: f64 = self.strategy(hand).iter().map(|(_, freq)| freq).sum();
+        (1.0 - played).max(0.0)
+    }
+
+    /// Indica si la mano está marcada como "Not in range" en la situación.
+    pub fn is_not_in_range(&self, hand: HandClass) -> bool {
+        self.frequency(hand, RangeAction::NotInRange) > 0.0
+    }
+}
+
+/// Documento de rangos completo.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RangeDocument {
+    /// Frontmatter, si el archivo lo incluye.
+    pub frontmatter: Option<Frontmatter>,
+    /// Título `#` del documento.
+    pub title: Option<String>,
+    /// Situaciones en el orden del archivo.
+    pub situations: Vec<Situation>,
+}
+
+impl RangeDocument {
+    /// Busca una situación por su identificador.
+    pub fn situation(&self, id: &str) -> Option<&Situation> {
+        self.situations.iter().find(|s| s.id.as_deref() == Some(id))
+    }
+
+    /// Situaciones de una categoría (`##`).
+    pub fn situations_in_category<'a>(
+        &'a self,
+        category: &'a str,
+    ) -> impl Iterator<Item = &'a Situation> + 'a {
+        self.situations
+            .iter()
+            .filter(move |s| s.category.as_deref() == Some(category))
+    }
+
+    /// Número total de rangos (secciones de acción) del documento.
+    pub fn range_count(&self) -> usize {
+        self.situations.iter().map(|s| s.actions.len()).sum()
+    }
+}
+
+// ============================================================================
+// PARSER
+// ============================================================================
+
+/// Parser de archivos HandRangeDSL.
+#[derive(Debug, Clone)]
+pub struct RangeParser {
+    /// Validar que la suma de frecuencias jugadas por mano no supere 1.0.
+    validate_sums: bool,
+}
+
+impl Default for RangeParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RangeParser {
+    /// Crea un parser con las validaciones de sintaxis (sin validar sumas).
+    pub fn new() -> Self {
+        Self {
+            validate_sums: false,
+        }
+    }
+
+    /// Activa o desactiva la validación de suma de frecuencias.
+    pub fn with_sum_validation(mut self, enabled: bool) -> Self {
+        self.validate_sums = enabled;
+        self
+    }
+
+    /// Carga y parsea un archivo de rangos.
+    pub fn parse_file<P: AsRef<Path>>(&self, path: P) -> Result<RangeDocument, RangeParseError> {
+        let content = std::fs::read_to_string(path.as_ref())
+            .map_err(|e| RangeParseError::new(0, 0, RangeErrorKind::Io(e.to_string())))?;
+        self.parse_str(&content)
+    }
+
+    /// Parsea el contenido de un archivo de rangos.
+    pub fn parse_str(&self, content: &str) -> Result<RangeDocument, RangeParseError> {
+        let lines: Vec<&str> = content.lines().collect();
+        let mut document = RangeDocument::default();
+
+        let mut start = 0;
+        if lines.first().map(|l| l.trim()) == Some("---") {
+            let (frontmatter, body_start) = parse_frontmatter(&lines)?;
+            document.frontmatter = Some(frontmatter);
+            start = body_start;
+        }
+
+        let mut state = ParseState::default();
+
+        for (index, raw_line) in lines.iter().enumerate().skip(start) {
+            let line_no = index + 1;
+            let trimmed = raw_line.trim_start();
+            let indent = raw_line.chars().count() - trimmed.chars().count();
+
+            if let Some((level, text, text_col)) = parse_heading(trimmed) {
+                let column = indent + text_col;
+                if is_action_heading(text) {
+                    let action = RangeAction::from_header(text).ok_or_else(|| {
+                        RangeParseError::new(
+                            line_no,
+                            column,
+                            RangeErrorKind::UnknownAction(text.to_string()),
+                        )
+                    })?;
+
+                    if state.situation.is_none() {
+                        match &document.frontmatter {
+                            Some(frontmatter) => {
+                                state.situation = Some(implicit_situation(frontmatter, line_no))
+                            }
+                            None => {
+                                return Err(RangeParseError::new(
+                                    line_no,
+                                    column,
+                                    RangeErrorKind::SectionOutsideSituation,
+                                ))
+                            }
+                        }
+                    }
+
+                    state.open_action(ActionRange {
+                        action,
+                        label: text.to_string(),
+                        size_bb: parse_size_bb(text),
+                        range: Range::new(),
+                        line: line_no,
+                    });
+                    continue;
+                }
+
+                match level {
+                    1 => {
+                        state.close_situation(&mut document);
+                        document.title = Some(text.to_string());
+                    }
+                    2 => {
+                        state.close_situation(&mut document);
+                        state.category = Some(text.to_string());
+                    }
+                    3 => {
+                        state.close_situation(&mut document);
+                        let (title, id) = split_range_id(text);
+
+                        if let Some(id) = id {
+                            if !state.seen_ids.insert(id.to_string()) {
+                                let id_col = column + text.chars().count() - id.chars().count() - 1;
+                                return Err(RangeParseError::new(
+                                    line_no,
+                                    id_col,
+                                    RangeErrorKind::DuplicateRangeId(id.to_string()),
+                                ));
+                            }
+                        }
+
+                        state.situation = Some(Situation {
+                            id: id.map(str::to_string),
+                            title: title.to_string(),
+                            category: state.category.clone(),
+                            actions: Vec::new(),
+                            line: line_no,
+                        });
+                    }
+                    // Headers más profundos que no son de acción: notas
+                    _ => state.close_action(),
+                }
+                continue;
+            }
+
+            if let Some(rest) = trimmed.strip_prefix("**`") {
+                let range_col = indent + 4;
+                let body = rest.trim_end();
+                let notation = body.strip_suffix("`**").ok_or_else(|| {
+                    RangeParseError::new(line_no, indent + 1, RangeErrorKind::UnterminatedRange)
+                })?;
+
+                let entries = parse_range_entries(notation, line_no, range_col)?;
+                state.add_entries(entries, line_no, indent + 1, self.validate_sums)?;
+            }
+        }
+
+        state.close_situation(&mut document);
+        Ok(document)
+    }
+}
+
+/// Estado intermedio del parser mientras recorre las líneas.
+#[derive(Default)]
+struct ParseState {
+    category: Option<String>,
+    situation: Option<Situation>,
+    current_action: Option<ActionRange>,
+    /// Suma de frecuencias jugadas por mano en la situación actual.
+    played_sums: Option<Box<[f64; NUM_HAND_CLASSES]>>,
+    seen_ids: std::collections::HashSet<String>,
+}
+
+impl ParseState {
+    fn open_action(&mut self, action: ActionRange) {
+        self.close_action();
+        self.current_action = Some(action);
+    }
+
+    fn close_action(&mut self) {
+        if let (Some(situation), Some(action)) = (&mut self.situation, self.current_action.take()) {
+            situation.actions.push(action);
+        }
+    }
+
+    fn close_situation(&mut self, document: &mut RangeDocument) {
+        self.close_action();
+        if let Some(situation) = self.situation.take() {
+            document.situations.push(situation);
+        }
+        self.played_sums = None;
+    }
+
+    fn add_entries(
+        &mut self,
+        entries: Vec<RangeEntry>,
+        line: usize,
+        column: usize,
+        validate_sums: bool,
+    ) -> Result<(), RangeParseError> {
+        let action = match &mut self.current_action {
+            // Solo se admite un rango por sección de acción
+            Some(action) if action.range.is_empty() => action,
+            _ => {
+                return Err(RangeParseError::new(
+                    line,
+                    column,
+                    RangeErrorKind::RangeOutsideSection,
+                ))
+            }
+        };
+
+        let sums = self
+            .played_sums
+            .get_or_insert_with(|| Box::new([0.0; NUM_HAND_CLASSES]));
+
+        for entry in entries {
+            if action.action.is_played() {
+                let sum = &mut sums[entry.hand.index()];
+                *sum += entry.frequency;
+                if validate_sums && *sum > 1.0 + FREQUENCY_SUM_TOLERANCE {
+                    return Err(RangeParseError::new(
+                        line,
+                        entry.column,
+                        RangeErrorKind::FrequencySumExceeded {
+                            hand: entry.hand.to_string(),
+                            sum: *sum,
+                        },
+                    ));
+                }
+            }
+            action.range.set_frequency(entry.hand, entry.frequency);
+        }
+
+        Ok(())
+    }
+}
+
+/// Entrada individual `mano:frecuencia` con su columna de inicio.
+struct RangeEntry {
+    hand: HandClass,
+    frequency: f64,
+    column: usize,
+}
+
+/// Parsea la notación compacta de un rango (`AA:1,AKs:0.8,...`).
+///
+/// `line` y `column` indican la posición del primer carácter de `input`
+/// dentro del archivo, para reportar errores con posición absoluta.
+pub(crate) fn parse_range_notation(
+    input: &str,
+    line: usize,
+    column: usize,
+) -> Result<Range, RangeParseError> {
+    let mut range = Range::new();
+    for entry in parse_range_entries(input, line, column)? {
+        range.set_frequency(entry.hand, entry.frequency);
+    }
+    Ok(range)
+}
+
+fn parse_range_entries(
+    input: &str,
+    line: usize,
+    column: usize,
+) -> Result<Vec<RangeEntry>, RangeParseError> {
+    if let Some(offset) = input.chars().position(char::is_whitespace) {
+        return Err(RangeParseError::new(
+            line,
+            column + offset,
+            RangeErrorKind::UnexpectedWhitespace,
+        ));
+    }
+
+    let mut entries: Vec<RangeEntry> = Vec::new();
+    let mut seen = [false; NUM_HAND_CLASSES];
+    let mut entry_col = column;
+
+    for entry in input.split(',') {
+        let entry_len = entry.chars().count();
+
+        if entry.is_empty() {
+            return Err(RangeParseError::new(
+                line,
+                entry_col,
+                RangeErrorKind::EmptyEntry,
+            ));
+        }
+
+        let (hand_str, freq_str) = entry.split_once(':').ok_or_else(|| {
+            RangeParseError::new(
+                line,
+                entry_col,
+                RangeErrorKind::MissingFrequency(entry.to_string()),
+            )
+        })?;
+
+        let hand: HandClass = hand_str.parse().map_err(|_| {
+            RangeParseError::new(
+                line,
+                entry_col,
+                RangeErrorKind::InvalidHand(hand_str.to_string()),
+            )
+        })?;
+
+        let freq_col = entry_col + hand_str.chars().count() + 1;
+        let frequency: f64 = freq_str
+            .parse()
+            .ok()
+            .filter(|f: &f64| f.is_finite())
+            .ok_or_else(|| {
+                RangeParseError::new(
+                    line,
+                    freq_col,
+                    RangeErrorKind::InvalidFrequency(freq_str.to_string()),
+                )
+            })?;
+
+        if !(0.0..=1.0).contains(&frequency) {
+            return Err(RangeParseError::new(
+                line,
+                freq_col,
+                RangeErrorKind::FrequencyOutOfRange(frequency),
+            ));
+        }
+
+        if std::mem::replace(&mut seen[hand.index()], true) {
+            return Err(RangeParseError::new(
+                line,
+                entry_col,
+                RangeErrorKind::DuplicateHand(hand.to_string()),
+            ));
+        }
+
+        entries.push(RangeEntry {
+            hand,
+            frequency,
+            column: entry_col,
+        });
+        entry_col += entry_len + 1;
+    }
+
+    Ok(entries)
+}
+
+/// Detecta un header Markdown y devuelve (nivel, texto, columna del texto).
+fn parse_heading(line: &str) -> Option<(usize, &str, usize)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+
+    let rest = &line[level..];
+    if !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let text = rest.trim_start();
+    let text_col = line.chars().count() - text.chars().count() + 1;
+    Some((level, text.trim_end(), text_col))
+}
+
+/// Indica si un header corresponde a una sección de acción.
+fn is_action_heading(text: &str) -> bool {
+    let lower = text.to_lowercase();
+    lower.starts_with("rango de") || lower.starts_with("not in range")
+}
+
+/// Separa el identificador final entre corchetes: `Desde SB [SB_01]` → (`Desde SB`, `SB_01`).
+fn split_range_id(text: &str) -> (&str, Option<&str>) {
+    if let Some(without_bracket) = text.strip_suffix(']') {
+        if let Some(open) = without_bracket.rfind('[') {
+            let id = without_bracket[open + 1..].trim();
+            if !id.is_empty() {
+                return (without_bracket[..open].trim_end(), Some(id));
+            }
+        }
+    }
+    (text, None)
+}
+
+/// Extrae el tamaño en bb de headers como "Rango de 4bet a 21bb".
+fn parse_size_bb(text: &str) -> Option<f64> {
+    let (_, size) = text.rsplit_once(" a ")?;
+    size.trim().strip_suffix("bb")?.parse().ok()
+}
+
+/// Crea la situación implícita de un archivo con frontmatter.
+fn implicit_situation(frontmatter: &Frontmatter, line: usize) -> Situation {
+    Situation {
+        id: frontmatter.situation_id.clone(),
+        title: frontmatter.title.clone().unwrap_or_default(),
+        category: frontmatter.category.clone(),
+        actions: Vec::new(),
+        line,
+    }
+}
+
+/// Parsea el frontmatter (`key: value`) y devuelve la línea donde empieza el cuerpo.
+fn parse_frontmatter(lines: &[&str]) -> Result<(Frontmatter, usize), RangeParseError> {
+    let end = lines
+        .iter()
+        .skip(1)
+        .position(|l| l.trim() == "---")
+        .map(|p| p + 1)
+        .ok_or_else(|| RangeParseError::new(1, 1, RangeErrorKind::UnterminatedFrontmatter))?;
+
+    let mut frontmatter = Frontmatter::default();
+
+    for (index, line) in lines.iter().enumerate().take(end).skip(1) {
+        let line_no = index + 1;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = line.split_once(':').ok_or_else(|| {
+            RangeParseError::new(
+                line_no,
+                1,
+                RangeErrorKind::InvalidFrontmatter(format!(
+                    "se esperaba 'clave: valor' en '{}'",
+                    trimmed
+                )),
+            )
+        })?;
+
+        let value_col = key.chars().count() + 2 + (value.len() - value.trim_start().len());
+        let value = value.trim();
+
+        match key.trim() {
+            "title" => frontmatter.title = Some(unquote(value).to_string()),
+            "category" => frontmatter.category = Some(unquote(value).to_string()),
+            "position" => frontmatter.position = Some(unquote(value).to_string()),
+            "situationId" => frontmatter.situation_id = Some(unquote(value).to_string()),
+            "benchmark_time" => {
+                let seconds = unquote(value).parse().map_err(|_| {
+                    RangeParseError::new(
+                        line_no,
+                        value_col,
+                        RangeErrorKind::InvalidFrontmatter(format!(
+                            "benchmark_time debe ser un entero, recibido '{}'",
+                            value
+                        )),
+                    )
+                })?;
+                frontmatter.benchmark_time = Some(seconds);
+            }
+            "tags" => {
+                let list = value
+                    .strip_prefix('[')
+                    .and_then(|v| v.strip_suffix(']'))
+                    .ok_or_else(|| {
+                        RangeParseError::new(
+                            line_no,
+                            value_col,
+                            RangeErrorKind::InvalidFrontmatter(
+                                "tags debe ser una lista [..]".to_string(),
+                            ),
+                        )
+                    })?;
+                frontmatter.tags = list
+                    .split(',')
+                    .map(|t| unquote(t.trim()).to_string())
+                    .filter(|t| !t.is_empty())
+                    .collect();
+            }
+            // Claves desconocidas se ignoran para permitir metadatos extra
+            _ => {}
+        }
+    }
+
+    Ok((frontmatter, end + 1))
+}
+
+/// Elimina comillas simples o dobles alrededor de un valor.
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+        .unwrap_or(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+# Rangos Preflop
+
+## Open raise
+
+### Desde SB [SB_Open_Raise_01]
+
+#### Rango de open raise a 2.6bb
+**`AA:1,AKs:1,AQo:0.28`**
+
+#### Rango de marginal call
+**`AQo:0.02`**
+
+---
+
+### Desde BTN hacen open raise a 2.4bb y estamos en BB
+
+#### Rango de call
+**`KK:0.5`**
+
+#### Rango de fold
+**`KK:0.5,72o:1`**
+";
+
+    fn hand(s: &str) -> HandClass {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_document_structure() {
+        let document = RangeParser::new().parse_str(SAMPLE).unwrap();
+
+        assert_eq!(document.title.as_deref(), Some("Rangos Preflop"));
+        assert_eq!(document.situations.len(), 2);
+        assert_eq!(document.range_count(), 4);
+
+        let sb = document.situation("SB_Open_Raise_01").unwrap();
+        assert_eq!(sb.title, "Desde SB");
+        assert_eq!(sb.category.as_deref(), Some("Open raise"));
+        assert_eq!(sb.line, 5);
+        assert_eq!(sb.actions[0].action, RangeAction::Raise);
+        assert_eq!(sb.actions[0].size_bb, Some(2.6));
+        assert_eq!(sb.actions[1].action, RangeAction::Marginal);
+
+        let bb = &document.situations[1];
+        assert_eq!(bb.id, None);
+        assert_eq!(bb.actions.len(), 2);
+    }
+
+    #[test]
+    fn test_mixed_strategy_and_implicit_fold() {
+        let document = RangeParser::new().parse_str(SAMPLE).unwrap();
+        let sb = document.situation("SB_Open_Raise_01").unwrap();
+
+        assert_eq!(
+            sb.strategy(hand("AQo")),
+            vec![(RangeAction::Raise, 0.28), (RangeAction::Marginal, 0.02)]
+        );
+        assert!((sb.fold_frequency(hand("AQo")) - 0.70).abs() < 1e-9);
+        assert_eq!(sb.fold_frequency(hand("AA")), 0.0);
+        assert_eq!(sb.fold_frequency(hand("72o")), 1.0);
+        assert_eq!(sb.range(RangeAction::Raise).len(), 3);
+    }
+
+    #[test]
+    fn test_marginal_sections_count_for_base_action() {
+        let document = RangeParser::new().parse_str(SAMPLE).unwrap();
+        let sb = document.situation("SB_Open_Raise_01").unwrap();
+
+        assert_eq!(sb.actions[1].base_action(), RangeAction::Call);
+        assert_eq!(sb.played_frequency(hand("AQo"), RangeAction::Call), 0.02);
+        assert_eq!(sb.played_frequency(hand("AQo"), RangeAction::Raise), 0.28);
+        assert!(sb.has_action(RangeAction::Call));
+        assert!(!sb.has_action(RangeAction::AllIn));
+    }
+
+    #[test]
+    fn test_parse_range_notation() {
+        let range = Range::parse("AA:1,KK:0.9,AKs:0.8,T9o:0").unwrap();
+        assert_eq!(range.frequency(hand("AA")), 1.0);
+        assert_eq!(range.frequency(hand("KK")), 0.9);
+        assert_eq!(range.frequency(hand("AKs")), 0.8);
+        assert_eq!(range.len(), 3);
+    }
+
+    #[test]
+    fn test_range_notation_errors_have_columns() {
+        let err = Range::parse("AA:1,AKx:0.5").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 6));
+        assert_eq!(err.kind, RangeErrorKind::InvalidHand("AKx".to_string()));
+
+        let err = Range::parse("AA:1,KK:1.5").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 9));
+        assert_eq!(err.kind, RangeErrorKind::FrequencyOutOfRange(1.5));
+
+        let err = Range::parse("AA:1, KK:1").unwrap_err();
+        assert_eq!(err.column, 6);
+        assert_eq!(err.kind, RangeErrorKind::UnexpectedWhitespace);
+
+        let err = Range::parse("AA:1,AA:0.5").unwrap_err();
+        assert_eq!(err.kind, RangeErrorKind::DuplicateHand("AA".to_string()));
+
+        let err = Range::parse("AA:1,").unwrap_err();
+        assert_eq!((err.column, err.kind), (6, RangeErrorKind::EmptyEntry));
+
+        let err = Range::parse("AA:x").unwrap_err();
+        assert_eq!(err.kind, RangeErrorKind::InvalidFrequency("x".to_string()));
+
+        let err = Range::parse("AA").unwrap_err();
+        assert_eq!(err.kind, RangeErrorKind::MissingFrequency("AA".to_string()));
+    }
+
+    #[test]
+    fn test_document_errors_have_line_and_column() {
+        let content = "### Desde SB [SB_01]\n\n#### Rango de call\n**`AA:1,KQs:2`**\n";
+        let err = RangeParser::new().parse_str(content).unwrap_err();
+        assert_eq!((err.line, err.column), (4, 13));
+
+        let content = "### Desde SB [SB_01]\n#### Rango de call\n**`AA:1`\n";
+        let err = RangeParser::new().parse_str(content).unwrap_err();
+        assert_eq!(err.kind, RangeErrorKind::UnterminatedRange);
+        assert_eq!(err.line, 3);
+
+        let content = "### Desde SB [SB_01]\n#### Rango de limp\n**`AA:1`**\n";
+        let err = RangeParser::new().parse_str(content).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+
+        let content = "## Open raise\n#### Rango de call\n**`AA:1`**\n";
+        let err = RangeParser::new().parse_str(content).unwrap_err();
+        assert_eq!(err.kind, RangeErrorKind::SectionOutsideSituation);
+
+        let content = "### A [ID_01]\n### B [ID_01]\n";
+        let err = RangeParser::new().parse_str(content).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 8));
+        assert_eq!(
+            err.kind,
+            RangeErrorKind::DuplicateRangeId("ID_01".to_string())
+        );
+    }
+
+    #[test]
+    fn test_frequency_sum_validation() {
+        let content = "\
+### Desde SB [SB_01]
+#### Rango de 3bet a 9bb
+**`AA:0.6,KK:0.5`**
+#### Rango de call
+**`KK:0.5,AA:0.6`**
+#### Rango de fold
+**`KK:1`**
+";
+        let document = RangeParser::new().parse_str(content).unwrap();
+        assert_eq!(document.situations[0].actions.len(), 3);
+
+        let err = RangeParser::new()
+            .with_sum_validation(true)
+            .parse_str(content)
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (5, 11));
+        assert!(matches!(
+            err.kind,
+            RangeErrorKind::FrequencySumExceeded { ref hand, .. } if hand == "AA"
+        ));
+    }
+
+    #[test]
+    fn test_frontmatter_implicit_situation() {
+        let content = "\
+---
+title: \"SB Open Raise 100bb\"
+category: \"RFI\"
+position: \"SB\"
+situationId: \"SB_Open_Raise\"
+benchmark_time: 45
+tags: [\"6-max\", \"GTO\"]
+---
+
+## Sección de Acción 1
+
+### Rango de open raise
+**`AA:1,KK:1,AKs:0.8`**
+
+### Rango de marginal call
+**`QQ:0.5`**
+";
+        let document = RangeParser::new().parse_str(content).unwrap();
+        let frontmatter = document.frontmatter.as_ref().unwrap();
+        assert_eq!(frontmatter.title.as_deref(), Some("SB Open Raise 100bb"));
+        assert_eq!(frontmatter.benchmark_time, Some(45));
+        assert_eq!(frontmatter.tags, vec!["6-max", "GTO"]);
+
+        let situation = document.situation("SB_Open_Raise").unwrap();
+        assert_eq!(situation.category.as_deref(), Some("RFI"));
+        assert_eq!(situation.actions.len(), 2);
+        assert_eq!(
+            situation.range(RangeAction::Raise).frequency(hand("AKs")),
+            0.8
+        );
+
+        let err = RangeParser::new()
+            .parse_str("---\nbenchmark_time: abc\n---\n")
+            .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 17));
+
+        let err = RangeParser::new().parse_str("---\ntitle: x\n").unwrap_err();
+        assert_eq!(err.kind, RangeErrorKind::UnterminatedFrontmatter);
+    }
+
+    #[test]
+    fn test_parse_preflop_ranges_file() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../docs/ranges/preflop-ranges.md"
+        );
+
+        let document = RangeParser::new().parse_file(path).unwrap();
+
+        // El archivo contiene una mano cuya suma supera 1.0 (AQs: 0.77 + 0.35)
+        let err = RangeParser::new()
+            .with_sum_validation(true)
+            .parse_file(path)
+            .unwrap_err();
+        assert!(matches!(
+            err.kind,
+            RangeErrorKind::FrequencySumExceeded { ref hand, .. } if hand == "AQs"
+        ));
+
+        assert_eq!(document.title.as_deref(), Some("Rangos Preflop"));
+        assert!(document.situations.len() > 300);
+
+        let sb = document.situation("SB_Open_Raise_01").unwrap();
+        assert_eq!(sb.category.as_deref(), Some("Open raise"));
+        assert_eq!(sb.range(RangeAction::Raise).frequency(hand("AA")), 1.0);
+        assert_eq!(sb.range(RangeAction::Marginal).frequency(hand("J3s")), 0.51);
+    }
+}
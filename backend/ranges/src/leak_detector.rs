@@ -0,0 +1,797 @@
+//! Detector de leaks preflop.
+//!
+//! Compara las decisiones preflop del héroe en manos parseadas con los rangos
+//! GTO de `docs/ranges/preflop-ranges.md` (ver `docs/specs/poker-logic.md`,
+//! sección 6).
+//!
+//! ## Flujo
+//!
+//! 1. Se reconstruye la secuencia de acciones preflop y se identifica el
+//!    escenario de cada decisión del héroe (open, vs open, vs 3bet, vs 4bet).
+//! 2. El escenario se traduce al `situationId` del chart
+//!    (ej: `MP_Open_Raise_01`, `BB_vs_CO_Open_06`).
+//! 3. Se busca la frecuencia de la acción tomada con la mano del héroe.
+//!    Si no supera el umbral (0.0 por defecto), la decisión es un leak.
+//!
+//! Los escenarios sin chart (botes limpeados, squeezes, multiway) se cuentan
+//! como decisiones sin situación y no se evalúan.
+//!
+//! ## Ejemplo
+//!
+//! ```rust,no_run
+//! use poker_ranges::LeakDetector;
+//!
+//! let detector = LeakDetector::from_file("docs/ranges/preflop-ranges.md").unwrap();
+//! # let hands: Vec<poker_parsers::ParsedHand> = Vec::new();
+//! let report = detector.analyze(&hands);
+//!
+//! for scenario in &report.scenarios {
+//!     for deviation in &scenario.deviations {
+//!         println!("{}: {}", scenario.range_id, deviation);
+//!     }
+//! }
+//! ```
+
+use crate::dsl_parser::{RangeDocument, RangeParseError, RangeParser, Situation};
+use crate::range::{rank_from_char, HandClass, HandClassKind, RangeAction};
+use poker_parsers::types::{ActionType, Card, ParsedHand, Position, Street};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+// ============================================================================
+// ESCENARIOS
+// ============================================================================
+
+/// Escenario preflop en el que el héroe toma una decisión.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreflopScenario {
+    /// Primer jugador en entrar al bote.
+    Open { hero: Position },
+    /// Ante un open raise sin callers.
+    VsOpen { hero: Position, opener: Position },
+    /// El héroe abrió y recibe un 3bet.
+    VsThreeBet {
+        hero: Position,
+        three_bettor: Position,
+    },
+    /// El héroe hizo 3bet y el opener responde con 4bet.
+    VsFourBet {
+        hero: Position,
+        opener: Position,
+        all_in: bool,
+    },
+}
+
+impl PreflopScenario {
+    /// Posición del héroe en el escenario.
+    pub fn hero_position(&self) -> Position {
+        match *self {
+            Self::Open { hero }
+            | Self::VsOpen { hero, .. }
+            | Self::VsThreeBet { hero, .. }
+            | Self::VsFourBet { hero, .. } => hero,
+        }
+    }
+
+    /// Identificador de la situación en `preflop-ranges.md`.
+    ///
+    /// Devuelve `None` si el escenario no puede existir en el chart
+    /// (ej: open raise desde la BB).
+    pub fn range_id(&self) -> Option<String> {
+        match *self {
+            Self::Open {
+                hero: Position::BigBlind,
+            } => None,
+            Self::Open { hero } => Some(format!("{}_Open_Raise_01", chart_label(hero))),
+            Self::VsOpen { hero, opener } => Some(format!(
+                "{}_vs_{}_Open_06",
+                chart_label(hero),
+                chart_label(opener)
+            )),
+            Self::VsThreeBet { hero, three_bettor } => Some(format!(
+                "{}_OR_vs_{}_3bet_02",
+                chart_label(hero),
+                chart_label(three_bettor)
+            )),
+            Self::VsFourBet {
+                hero,
+                opener,
+                all_in: true,
+            } => Some(format!(
+                "{}_vs_{}_4bet_100_07",
+                chart_label(hero),
+                chart_label(opener)
+            )),
+            Self::VsFourBet {
+                hero,
+                opener,
+                all_in: false,
+            } => Some(format!(
+                "{}_Call_4bet_{}_07",
+                chart_label(hero),
+                chart_label(opener)
+            )),
+        }
+    }
+}
+
+impl fmt::Display for PreflopScenario {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Open { hero } => write!(f, "open desde {}", chart_label(hero)),
+            Self::VsOpen { hero, opener } => write!(
+                f,
+                "{} vs open de {}",
+                chart_label(hero),
+                chart_label(opener)
+            ),
+            Self::VsThreeBet { hero, three_bettor } => write!(
+                f,
+                "open de {} vs 3bet de {}",
+                chart_label(hero),
+                chart_label(three_bettor)
+            ),
+            Self::VsFourBet {
+                hero,
+                opener,
+                all_in,
+            } => write!(
+                f,
+                "3bet de {} vs 4bet{} de {}",
+                chart_label(hero),
+                if all_in { " all-in" } else { "" },
+                chart_label(opener)
+            ),
+        }
+    }
+}
+
+/// Nombre de la posición en los charts (UTG se denomina EP).
+///
+/// Los charts son 6-max: en full ring las posiciones tempranas (UTG a LJ)
+/// usan el chart de EP y el HJ el de MP.
+fn chart_label(position: Position) -> &'static str {
+    match position {
+        Position::Button => "BTN",
+        Position::SmallBlind => "SB",
+        Position::BigBlind => "BB",
+        Position::UTG | Position::UTG1 | Position::UTG2 | Position::LJ => "EP",
+        Position::MP | Position::HJ => "MP",
+        Position::CO => "CO",
+    }
+}
+
+// ============================================================================
+// DECISIONES
+// ============================================================================
+
+/// Decisión preflop del héroe que se desvía del chart.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Deviation {
+    /// ID de la mano.
+    pub hand_id: String,
+    /// Clase de mano del héroe.
+    pub hand: HandClass,
+    /// Acción tomada por el héroe.
+    pub action: RangeAction,
+    /// Frecuencia de esa acción con esa mano en el chart.
+    pub chart_frequency: f64,
+    /// Si la mano está marcada como "Not in range" en la situación.
+    pub not_in_range: bool,
+}
+
+impl fmt::Display for Deviation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "mano {}: {} con {} (frecuencia en el chart {:.2})",
+            self.hand_id, self.action, self.hand, self.chart_frequency
+        )?;
+        if self.not_in_range {
+            write!(f, " [not in range]")?;
+        }
+        Ok(())
+    }
+}
+
+/// Resultados de un escenario (una situación del chart).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScenarioReport {
+    /// Identificador de la situación en el chart.
+    pub range_id: String,
+    /// Escenario preflop.
+    pub scenario: PreflopScenario,
+    /// Decisiones del héroe evaluadas en el escenario.
+    pub decisions: usize,
+    /// Decisiones fuera del chart, en orden de aparición.
+    pub deviations: Vec<Deviation>,
+}
+
+impl ScenarioReport {
+    /// Porcentaje de decisiones que son leaks (0.0 - 1.0).
+    pub fn deviation_rate(&self) -> f64 {
+        if self.decisions == 0 {
+            0.0
+        } else {
+            self.deviations.len() as f64 / self.decisions as f64
+        }
+    }
+}
+
+/// Informe de leaks de un conjunto de manos.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LeakReport {
+    /// Manos analizadas (con héroe y cartas conocidas).
+    pub hands_analyzed: usize,
+    /// Decisiones del héroe sin situación equivalente en el chart.
+    pub unmatched_decisions: usize,
+    /// Resultados por escenario, ordenados por `range_id`.
+    pub scenarios: Vec<ScenarioReport>,
+}
+
+impl LeakReport {
+    /// Busca el informe de una situación.
+    pub fn scenario(&self, range_id: &str) -> Option<&ScenarioReport> {
+        self.scenarios.iter().find(|s| s.range_id == range_id)
+    }
+
+    /// Número total de decisiones evaluadas.
+    pub fn total_decisions(&self) -> usize {
+        self.scenarios.iter().map(|s| s.decisions).sum()
+    }
+
+    /// Número total de leaks detectados.
+    pub fn total_deviations(&self) -> usize {
+        self.scenarios.iter().map(|s| s.deviations.len()).sum()
+    }
+}
+
+/// Decisión del héroe identificada en la secuencia preflop.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct HeroDecision {
+    scenario: Option<PreflopScenario>,
+    action: RangeAction,
+    all_in: bool,
+}
+
+// ============================================================================
+// DETECTOR
+// ============================================================================
+
+/// Detector de leaks preflop basado en los charts de `preflop-ranges.md`.
+#[derive(Debug, Clone)]
+pub struct LeakDetector {
+    /// Charts de rangos preflop.
+    document: RangeDocument,
+    /// Frecuencia máxima del chart para considerar una acción leak.
+    leak_threshold: f64,
+}
+
+impl LeakDetector {
+    /// Crea un detector a partir de un documento de rangos ya parseado.
+    pub fn new(document: RangeDocument) -> Self {
+        Self {
+            document,
+            leak_threshold: 0.0,
+        }
+    }
+
+    /// Carga los charts desde un archivo HandRangeDSL.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, RangeParseError> {
+        let document = RangeParser::new().parse_file(path)?;
+        Ok(Self::new(document))
+    }
+
+    /// Marca como leak cualquier acción con frecuencia en el chart menor o
+    /// igual al umbral (por defecto 0.0).
+    pub fn with_leak_threshold(mut self, threshold: f64) -> Self {
+        self.leak_threshold = threshold.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Documento de rangos usado por el detector.
+    pub fn document(&self) -> &RangeDocument {
+        &self.document
+    }
+
+    /// Analiza un conjunto de manos y agrupa las desviaciones por escenario.
+    pub fn analyze(&self, hands: &[ParsedHand]) -> LeakReport {
+        let mut report = LeakReport::default();
+        let mut scenarios: BTreeMap<String, ScenarioReport> = BTreeMap::new();
+
+        for parsed in hands {
+            let Some(hand) = hero_hand_class(parsed) else {
+                continue;
+            };
+            report.hands_analyzed += 1;
+
+            for decision in hero_decisions(parsed) {
+                let matched = decision.scenario.and_then(|scenario| {
+                    let range_id = scenario.range_id()?;
+                    let situation = self.document.situation(&range_id)?;
+                    Some((scenario, range_id, situation))
+                });
+
+                let Some((scenario, range_id, situation)) = matched else {
+                    report.unmatched_decisions += 1;
+                    continue;
+                };
+
+                let entry = scenarios
+                    .entry(range_id.clone())
+                    .or_insert_with(|| ScenarioReport {
+                        range_id,
+                        scenario,
+                        decisions: 0,
+                        deviations: Vec::new(),
+                    });
+                entry.decisions += 1;
+
+                let chart_frequency = chart_frequency(situation, hand, &decision);
+                if chart_frequency <= self.leak_threshold {
+                    entry.deviations.push(Deviation {
+                        hand_id: parsed.hand_id.clone(),
+                        hand,
+                        action: decision.action,
+                        chart_frequency,
+                        not_in_range: situation.is_not_in_range(hand),
+                    });
+                }
+            }
+        }
+
+        report.scenarios = scenarios.into_values().collect();
+        report
+    }
+}
+
+/// Frecuencia del chart para la acción tomada por el héroe.
+///
+/// Un all-in (shove o call de un all-in) se compara con la sección de all-in
+/// si la situación la tiene; si no, con la acción equivalente.
+fn chart_frequency(situation: &Situation, hand: HandClass, decision: &HeroDecision) -> f64 {
+    match decision.action {
+        RangeAction::Fold => situation.fold_frequency(hand),
+        _ if decision.all_in && situation.has_action(RangeAction::AllIn) => {
+            situation.played_frequency(hand, RangeAction::AllIn)
+        }
+        action => situation.played_frequency(hand, action),
+    }
+}
+
+/// Clase de mano del héroe a partir de sus cartas.
+///
+/// Los rangos son de Hold'em: las manos de Omaha no tienen clase.
+fn hero_hand_class(hand: &ParsedHand) -> Option<HandClass> {
+    let cards = hand.hero_cards.as_ref().or_else(|| {
+        hand.players
+            .iter()
+            .find(|p| p.is_hero)
+            .and_then(|p| p.hole_cards.as_ref())
+    })?;
+
+    match cards.as_slice() {
+        [card1, card2] => hand_class_from_cards(card1, card2),
+        _ => None,
+    }
+}
+
+fn hand_class_from_cards(card1: &Card, card2: &Card) -> Option<HandClass> {
+    let rank1 = rank_from_char(card1.rank)?;
+    let rank2 = rank_from_char(card2.rank)?;
+    let kind = if rank1 == rank2 {
+        HandClassKind::Pair
+    } else if card1.suit == card2.suit {
+        HandClassKind::Suited
+    } else {
+        HandClassKind::Offsuit
+    };
+
+    HandClass::new(rank1, rank2, kind)
+}
+
+/// Reconstruye la secuencia preflop e identifica cada decisión del héroe.
+///
+/// Solo las dos primeras decisiones del héroe pueden tener chart (open o
+/// respuesta a un open, y respuesta a 3bet/4bet); el resto no se evalúa.
+fn hero_decisions(hand: &ParsedHand) -> Vec<HeroDecision> {
+    let Some(hero) = hand.players.iter().find(|p| p.is_hero) else {
+        return Vec::new();
+    };
+    let position_of = |name: &str| {
+        hand.players
+            .iter()
+            .find(|p| &*p.name == name)
+            .and_then(|p| p.position)
+    };
+
+    let mut decisions = Vec::new();
+    // Jugadores que han subido, en orden, y si la subida fue all-in
+    let mut raisers: Vec<(&str, bool)> = Vec::new();
+    // Calls voluntarios de rivales (limps o cold calls)
+    let mut callers = 0usize;
+
+    for action in hand.actions.iter().filter(|a| a.street == Street::Preflop) {
+        let is_hero = action.player_name == hero.name;
+
+        let range_action = match action.action_type {
+            ActionType::Fold => RangeAction::Fold,
+            ActionType::Check => RangeAction::Check,
+            ActionType::Call => RangeAction::Call,
+            ActionType::Raise | ActionType::Bet | ActionType::AllIn => RangeAction::Raise,
+            // Ciegas, antes, shows...
+            _ => continue,
+        };
+
+        if is_hero {
+            let facing_all_in = raisers.last().is_some_and(|&(_, all_in)| all_in);
+            let all_in = action.is_all_in
+                || action.action_type == ActionType::AllIn
+                || (range_action == RangeAction::Call && facing_all_in);
+            let action = match range_action {
+                RangeAction::Raise if all_in => RangeAction::AllIn,
+                other => other,
+            };
+
+            decisions.push(HeroDecision {
+                scenario: identify_scenario(
+                    &hero.name,
+                    hero.position,
+                    &raisers,
+                    callers,
+                    &position_of,
+                ),
+                action,
+                all_in,
+            });
+        }
+
+        match range_action {
+            RangeAction::Raise => raisers.push((&action.player_name, action.is_all_in)),
+            RangeAction::Call if !is_hero => callers += 1,
+            _ => {}
+        }
+    }
+
+    decisions.truncate(2);
+    decisions
+}
+
+/// Escenario de una decisión del héroe según las subidas previas.
+fn identify_scenario(
+    hero: &str,
+    hero_position: Option<Position>,
+    raisers: &[(&str, bool)],
+    callers: usize,
+    position_of: &dyn Fn(&str) -> Option<Position>,
+) -> Option<PreflopScenario> {
+    let hero_position = hero_position?;
+    if callers > 0 {
+        return None;
+    }
+
+    match raisers {
+        [] => Some(PreflopScenario::Open {
+            hero: hero_position,
+        }),
+        [(opener, _)] if *opener != hero => Some(PreflopScenario::VsOpen {
+            hero: hero_position,
+            opener: position_of(opener)?,
+        }),
+        [(opener, _), (three_bettor, _)] if *opener == hero => Some(PreflopScenario::VsThreeBet {
+            hero: hero_position,
+            three_bettor: position_of(three_bettor)?,
+        }),
+        [(opener, _), (three_bettor, _), (four_bettor, all_in)]
+            if *three_bettor == hero && four_bettor == opener =>
+        {
+            Some(PreflopScenario::VsFourBet {
+                hero: hero_position,
+                opener: position_of(opener)?,
+                all_in: *all_in,
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poker_parsers::types::{Action, Player};
+
+    const CHART: &str = "\
+## Open raise
+
+### Desde MP [MP_Open_Raise_01]
+
+#### Rango de open raise a 2.1bb
+**`AA:1,AKs:1,KQo:0.5`**
+
+#### Rango de marginal open raise
+**`A9o:0.1`**
+
+### Desde CO [CO_Open_Raise_01]
+
+#### Rango de open raise a 2.2bb
+**`AA:1,87s:1`**
+
+## Open raise + 3bet
+
+### Desde MP open raise a 2.1bb + 3bet de BTN a 7bb [MP_OR_vs_BTN_3bet_02]
+
+#### Rango de all in
+**`AA:0.5`**
+
+#### Rango de 4bet a 17bb
+**`AA:0.5,AKs:0.4`**
+
+#### Rango de call
+**`AKs:0.6`**
+
+## 3bet / Call
+
+### Desde MP hacen open raise a 2.1bb y estamos en BB [BB_vs_MP_Open_06]
+
+#### Rango de 3bet a 10bb
+**`AA:1`**
+
+#### Rango de call
+**`87s:1`**
+
+#### Not in range (no deberías de haber llegado a esta situación con estas manos)
+**`72o:1`**
+";
+
+    fn detector() -> LeakDetector {
+        LeakDetector::new(RangeParser::new().parse_str(CHART).unwrap())
+    }
+
+    /// Mesa 6-max con el héroe en MP.
+    fn hand(id: &str, hero_cards: (&str, &str), actions: Vec<Action>) -> ParsedHand {
+        ParsedHand {
+            hand_id: id.to_string(),
+            players: vec![
+                Player::new("utg", 1, 10_000).with_position(Position::UTG),
+                Player::new("thesmoy", 2, 10_000)
+                    .with_position(Position::MP)
+                    .hero(),
+                Player::new("co", 3, 10_000).with_position(Position::CO),
+                Player::new("btn", 4, 10_000).with_position(Position::Button),
+                Player::new("sb", 5, 10_000).with_position(Position::SmallBlind),
+                Player::new("bb", 6, 10_000).with_position(Position::BigBlind),
+            ],
+            hero_cards: Some(vec![
+                Card::parse(hero_cards.0).unwrap(),
+                Card::parse(hero_cards.1).unwrap(),
+            ]),
+            actions,
+            ..Default::default()
+        }
+    }
+
+    fn blinds() -> Vec<Action> {
+        vec![
+            Action::new("sb", ActionType::PostSmallBlind, Some(25), Street::Preflop),
+            Action::new("bb", ActionType::PostBigBlind, Some(50), Street::Preflop),
+        ]
+    }
+
+    fn open_from_mp(id: &str, cards: (&str, &str)) -> ParsedHand {
+        let mut actions = blinds();
+        actions.extend([
+            Action::new("utg", ActionType::Fold, None, Street::Preflop),
+            Action::new("thesmoy", ActionType::Raise, Some(105), Street::Preflop),
+            Action::new("co", ActionType::Fold, None, Street::Preflop),
+            Action::new("btn", ActionType::Fold, None, Street::Preflop),
+            Action::new("sb", ActionType::Fold, None, Street::Preflop),
+            Action::new("bb", ActionType::Fold, None, Street::Preflop),
+        ]);
+        hand(id, cards, actions)
+    }
+
+    #[test]
+    fn test_range_ids() {
+        let open = PreflopScenario::Open {
+            hero: Position::UTG,
+        };
+        assert_eq!(open.range_id().as_deref(), Some("EP_Open_Raise_01"));
+
+        let vs_open = PreflopScenario::VsOpen {
+            hero: Position::BigBlind,
+            opener: Position::Button,
+        };
+        assert_eq!(vs_open.range_id().as_deref(), Some("BB_vs_BTN_Open_06"));
+
+        let vs_4bet = PreflopScenario::VsFourBet {
+            hero: Position::SmallBlind,
+            opener: Position::Button,
+            all_in: true,
+        };
+        assert_eq!(vs_4bet.range_id().as_deref(), Some("SB_vs_BTN_4bet_100_07"));
+
+        let bb_open = PreflopScenario::Open {
+            hero: Position::BigBlind,
+        };
+        assert_eq!(bb_open.range_id(), None);
+
+        // Full ring: las posiciones tempranas usan los charts 6-max
+        let utg1_open = PreflopScenario::Open {
+            hero: Position::UTG1,
+        };
+        assert_eq!(utg1_open.range_id().as_deref(), Some("EP_Open_Raise_01"));
+        let vs_hj = PreflopScenario::VsOpen {
+            hero: Position::Button,
+            opener: Position::HJ,
+        };
+        assert_eq!(vs_hj.range_id().as_deref(), Some("BTN_vs_MP_Open_06"));
+    }
+
+    #[test]
+    fn test_open_outside_range_is_leak() {
+        let hands = vec![
+            open_from_mp("1", ("8h", "7c")),
+            open_from_mp("2", ("Ah", "Kh")),
+            open_from_mp("3", ("Ah", "9c")),
+        ];
+
+        let report = detector().analyze(&hands);
+        assert_eq!(report.hands_analyzed, 3);
+        assert_eq!(report.unmatched_decisions, 0);
+
+        let mp = report.scenario("MP_Open_Raise_01").unwrap();
+        assert_eq!(mp.decisions, 3);
+        assert_eq!(mp.deviations.len(), 1);
+
+        let leak = &mp.deviations[0];
+        assert_eq!(leak.hand_id, "1");
+        assert_eq!(leak.hand.to_string(), "87o");
+        assert_eq!(leak.action, RangeAction::Raise);
+        assert_eq!(leak.chart_frequency, 0.0);
+        assert_eq!(
+            leak.to_string(),
+            "mano 1: RAISE con 87o (frecuencia en el chart 0.00)"
+        );
+    }
+
+    #[test]
+    fn test_fold_uses_implicit_fold_frequency() {
+        let mut actions = blinds();
+        actions.extend([
+            Action::new("utg", ActionType::Fold, None, Street::Preflop),
+            Action::new("thesmoy", ActionType::Fold, None, Street::Preflop),
+        ]);
+        let folded_aces = hand("1", ("As", "Ad"), actions.clone());
+        let folded_kqo = hand("2", ("Ks", "Qd"), actions);
+
+        let report = detector().analyze(&[folded_aces, folded_kqo]);
+        let mp = report.scenario("MP_Open_Raise_01").unwrap();
+
+        assert_eq!(mp.decisions, 2);
+        assert_eq!(mp.deviations.len(), 1);
+        assert_eq!(mp.deviations[0].hand.to_string(), "AA");
+        assert_eq!(mp.deviations[0].action, RangeAction::Fold);
+        assert!((mp.deviation_rate() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_vs_open_from_big_blind() {
+        let mut parsed = hand("1", ("7h", "2c"), Vec::new());
+        for p in &mut parsed.players {
+            p.is_hero = &*p.name == "bb";
+        }
+        parsed.actions = blinds();
+        parsed.actions.extend([
+            Action::new("utg", ActionType::Fold, None, Street::Preflop),
+            Action::new("thesmoy", ActionType::Raise, Some(105), Street::Preflop),
+            Action::new("co", ActionType::Fold, None, Street::Preflop),
+            Action::new("btn", ActionType::Fold, None, Street::Preflop),
+            Action::new("sb", ActionType::Fold, None, Street::Preflop),
+            Action::new("bb", ActionType::Call, Some(55), Street::Preflop),
+        ]);
+
+        let report = detector().analyze(&[parsed]);
+        let bb = report.scenario("BB_vs_MP_Open_06").unwrap();
+
+        assert_eq!(
+            bb.scenario,
+            PreflopScenario::VsOpen {
+                hero: Position::BigBlind,
+                opener: Position::MP
+            }
+        );
+        assert_eq!(bb.deviations.len(), 1);
+        assert!(bb.deviations[0].not_in_range);
+    }
+
+    #[test]
+    fn test_response_to_three_bet() {
+        let mut actions = blinds();
+        actions.extend([
+            Action::new("utg", ActionType::Fold, None, Street::Preflop),
+            Action::new("thesmoy", ActionType::Raise, Some(105), Street::Preflop),
+            Action::new("co", ActionType::Fold, None, Street::Preflop),
+            Action::new("btn", ActionType::Raise, Some(350), Street::Preflop),
+            Action::new("sb", ActionType::Fold, None, Street::Preflop),
+            Action::new("bb", ActionType::Fold, None, Street::Preflop),
+        ]);
+
+        let jam = Action::new("thesmoy", ActionType::Raise, Some(10_000), Street::Preflop).all_in();
+
+        let mut shove_aces = actions.clone();
+        shove_aces.push(jam.clone());
+        let mut shove_aks = actions.clone();
+        shove_aks.push(jam);
+        let mut call_aks = actions;
+        call_aks.push(Action::new(
+            "thesmoy",
+            ActionType::Call,
+            Some(245),
+            Street::Preflop,
+        ));
+
+        let report = detector().analyze(&[
+            hand("1", ("As", "Ad"), shove_aces),
+            hand("2", ("Ks", "As"), shove_aks),
+            hand("3", ("Ks", "As"), call_aks),
+        ]);
+
+        // Cada mano tiene el open y la respuesta al 3bet
+        assert_eq!(report.scenario("MP_Open_Raise_01").unwrap().decisions, 3);
+
+        let vs_3bet = report.scenario("MP_OR_vs_BTN_3bet_02").unwrap();
+        assert_eq!(vs_3bet.decisions, 3);
+        assert_eq!(vs_3bet.deviations.len(), 1);
+        assert_eq!(vs_3bet.deviations[0].hand_id, "2");
+        assert_eq!(vs_3bet.deviations[0].action, RangeAction::AllIn);
+    }
+
+    #[test]
+    fn test_unmatched_scenarios() {
+        // Limp de UTG: bote limpeado sin chart
+        let mut actions = blinds();
+        actions.extend([
+            Action::new("utg", ActionType::Call, Some(50), Street::Preflop),
+            Action::new("thesmoy", ActionType::Raise, Some(200), Street::Preflop),
+        ]);
+        let limped = hand("1", ("As", "Ad"), actions);
+
+        // Open desde UTG: la situación no está en el chart
+        let mut actions = blinds();
+        actions.push(Action::new("utg", ActionType::Fold, None, Street::Preflop));
+        let mut from_utg = hand("2", ("As", "Ad"), actions);
+        for p in &mut from_utg.players {
+            p.is_hero = &*p.name == "utg";
+        }
+
+        // Sin cartas del héroe: no se analiza
+        let mut unknown = open_from_mp("3", ("As", "Ad"));
+        unknown.hero_cards = None;
+
+        let report = detector().analyze(&[limped, from_utg, unknown]);
+        assert_eq!(report.hands_analyzed, 2);
+        assert_eq!(report.unmatched_decisions, 2);
+        assert_eq!(report.total_decisions(), 0);
+    }
+
+    #[test]
+    fn test_preflop_ranges_file() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../docs/ranges/preflop-ranges.md"
+        );
+        let detector = LeakDetector::from_file(path).unwrap();
+
+        let report = detector.analyze(&[
+            open_from_mp("1", ("8h", "7c")),
+            open_from_mp("2", ("Ah", "Kh")),
+        ]);
+
+        let mp = report.scenario("MP_Open_Raise_01").unwrap();
+        assert_eq!(mp.decisions, 2);
+        assert_eq!(mp.deviations.len(), 1);
+        assert_eq!(mp.deviations[0].hand.to_string(), "87o");
+        assert_eq!(report.total_deviations(), 1);
+    }
+}
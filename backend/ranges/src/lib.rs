@@ -5,24 +5,32 @@
 //! Soporta:
 //! - Archivos Markdown con frontmatter YAML
 //! - Notación compacta de rangos (AA:1, KK:0.9, AKs:0.8)
+//! - Expansión a combos concretos con card removal (board, cartas muertas)
 //! - Análisis de desviaciones entre acciones reales y rangos GTO
 //! - Integración con sistema de detección de leaks
 //!
 //! ## Formato HandRangeDSL
 //! Los rangos se definen en `docs/ranges/preflop-ranges.md` con sintaxis:
 //! ```markdown
-//! ---
-//! position: BTN
-//! action: Open
-//! ---
-//! AA:1,KK:0.9,AKs:0.8,...
+//! ### Desde SB [SB_Open_Raise_01]
+//!
+//! #### Rango de open raise a 2.6bb
+//! **`AA:1,KK:0.9,AKs:0.8,...`**
 //! ```
 
+pub mod combos;
+pub mod dsl_parser;
+pub mod leak_detector;
+pub mod range;
+
 // TODO: Implementar módulos (Fase 2.1 según roadmap)
-// pub mod dsl_parser;
 // pub mod range_analyzer;
-// pub mod leak_detector;
 
-// pub use dsl_parser::RangeParser;
+pub use combos::{Combo, WeightedCombo};
+pub use dsl_parser::{
+    ActionRange, Frontmatter, RangeDocument, RangeErrorKind, RangeParseError, RangeParser,
+    Situation,
+};
+pub use leak_detector::{Deviation, LeakDetector, LeakReport, PreflopScenario, ScenarioReport};
+pub use range::{HandClass, HandClassKind, Range, RangeAction, NUM_HAND_CLASSES};
 // pub use range_analyzer::RangeAnalyzer;
-// pub use leak_detector::LeakDetector;
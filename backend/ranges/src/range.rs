@@ -0,0 +1,521 @@
+//! Tipos base de rangos preflop: clases de mano, acciones y `Range`.
+//!
+//! Un rango preflop se representa como una matriz de 13x13 = 169 clases de
+//! mano (13 parejas, 78 suited y 78 offsuit), cada una con una frecuencia
+//! entre 0.0 y 1.0.
+//!
+//! ## Disposición de la matriz
+//!
+//! Se usa la matriz clásica de los visualizadores de rangos: filas y columnas
+//! ordenadas de As a Dos. La diagonal contiene las parejas, la mitad superior
+//! las manos suited y la mitad inferior las offsuit.
+//!
+//! ```text
+//!       A    K    Q   ...
+//!  A   AA   AKs  AQs
+//!  K   AKo  KK   KQs
+//!  Q   AQo  KQo  QQ
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Número total de clases de mano preflop (13 parejas + 78 suited + 78 offsuit).
+pub const NUM_HAND_CLASSES: usize = 169;
+
+/// Caracteres de rango ordenados de menor a mayor (índice 0 = Dos, 12 = As).
+///
+/// Coincide con el orden de `poker_math::Rank`.
+const RANK_CHARS: [char; 13] = [
+    '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
+];
+
+/// Convierte un carácter de rango (`A`, `K`, ..., `2`) a su índice (0-12).
+pub fn rank_from_char(c: char) -> Option<u8> {
+    RANK_CHARS
+        .iter()
+        .position(|&r| r == c.to_ascii_uppercase())
+        .map(|i| i as u8)
+}
+
+/// Convierte un índice de rango (0-12) a su carácter.
+pub fn rank_to_char(rank: u8) -> char {
+    RANK_CHARS[rank as usize]
+}
+
+// ============================================================================
+// HAND CLASS
+// ============================================================================
+
+/// Tipo de clase de mano preflop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HandClassKind {
+    /// Pareja (ej: `AA`, `22`). 6 combos.
+    Pair,
+    /// Mismo palo (ej: `AKs`). 4 combos.
+    Suited,
+    /// Distinto palo (ej: `AKo`). 12 combos.
+    Offsuit,
+}
+
+/// Una de las 169 clases de mano preflop.
+///
+/// Los rangos se almacenan como índices 0-12 (Dos-As), siempre con
+/// `high >= low`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HandClass {
+    high: u8,
+    low: u8,
+    kind: HandClassKind,
+}
+
+impl HandClass {
+    /// Crea una clase de mano validando la combinación.
+    ///
+    /// Los rangos se reordenan si es necesario. Devuelve `None` si los rangos
+    /// están fuera de 0-12 o si el tipo no es coherente (pareja con rangos
+    /// distintos, suited/offsuit con rangos iguales).
+    pub fn new(rank1: u8, rank2: u8, kind: HandClassKind) -> Option<Self> {
+        if rank1 > 12 || rank2 > 12 {
+            return None;
+        }
+
+        let (high, low) = if rank1 >= rank2 {
+            (rank1, rank2)
+        } else {
+            (rank2, rank1)
+        };
+
+        match kind {
+            HandClassKind::Pair if high != low => None,
+            HandClassKind::Suited | HandClassKind::Offsuit if high == low => None,
+            _ => Some(Self { high, low, kind }),
+        }
+    }
+
+    /// Rango de la carta alta (0 = Dos, 12 = As).
+    #[inline]
+    pub const fn high(&self) -> u8 {
+        self.high
+    }
+
+    /// Rango de la carta baja (0 = Dos, 12 = As).
+    #[inline]
+    pub const fn low(&self) -> u8 {
+        self.low
+    }
+
+    /// Tipo de la clase de mano.
+    #[inline]
+    pub const fn kind(&self) -> HandClassKind {
+        self.kind
+    }
+
+    /// Indica si la clase es una pareja.
+    #[inline]
+    pub fn is_pair(&self) -> bool {
+        self.kind == HandClassKind::Pair
+    }
+
+    /// Indica si la clase es suited.
+    #[inline]
+    pub fn is_suited(&self) -> bool {
+        self.kind == HandClassKind::Suited
+    }
+
+    /// Número de combinaciones concretas de la clase (6, 4 o 12).
+    #[inline]
+    pub const fn num_combos(&self) -> u32 {
+        match self.kind {
+            HandClassKind::Pair => 6,
+            HandClassKind::Suited => 4,
+            HandClassKind::Offsuit => 12,
+        }
+    }
+
+    /// Índice de la clase en la matriz 13x13 (0-168).
+    ///
+    /// `AA` es el índice 0 y `22` el 168.
+    #[inline]
+    pub fn index(&self) -> usize {
+        let high_row = (12 - self.high) as usize;
+        let low_row = (12 - self.low) as usize;
+
+        match self.kind {
+            HandClassKind::Pair | HandClassKind::Suited => high_row * 13 + low_row,
+            HandClassKind::Offsuit => low_row * 13 + high_row,
+        }
+    }
+
+    /// Reconstruye una clase de mano a partir de su índice en la matriz.
+    ///
+    /// # Panics
+    ///
+    /// Si `index >= 169`.
+    pub fn from_index(index: usize) -> Self {
+        assert!(
+            index < NUM_HAND_CLASSES,
+            "Índice de clase inválido: {}",
+            index
+        );
+
+        let row = index / 13;
+        let col = index % 13;
+        let row_rank = 12 - row as u8;
+        let col_rank = 12 - col as u8;
+
+        match row.cmp(&col) {
+            std::cmp::Ordering::Equal => Self {
+                high: row_rank,
+                low: row_rank,
+                kind: HandClassKind::Pair,
+            },
+            std::cmp::Ordering::Less => Self {
+                high: row_rank,
+                low: col_rank,
+                kind: HandClassKind::Suited,
+            },
+            std::cmp::Ordering::Greater => Self {
+                high: col_rank,
+                low: row_rank,
+                kind: HandClassKind::Offsuit,
+            },
+        }
+    }
+
+    /// Itera las 169 clases de mano en orden de matriz.
+    pub fn all() -> impl Iterator<Item = HandClass> {
+        (0..NUM_HAND_CLASSES).map(Self::from_index)
+    }
+}
+
+impl fmt::Display for HandClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let high = rank_to_char(self.high);
+        let low = rank_to_char(self.low);
+        match self.kind {
+            HandClassKind::Pair => write!(f, "{}{}", high, low),
+            HandClassKind::Suited => write!(f, "{}{}s", high, low),
+            HandClassKind::Offsuit => write!(f, "{}{}o", high, low),
+        }
+    }
+}
+
+impl FromStr for HandClass {
+    type Err = ();
+
+    /// Parsea una clase de mano en notación estándar (`AA`, `AKs`, `T9o`).
+    ///
+    /// La carta alta debe ir primero (`KAs` no es válido).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = s.chars().collect();
+
+        let (high, low, kind) = match chars.as_slice() {
+            [h, l] => (*h, *l, HandClassKind::Pair),
+            [h, l, 's'] => (*h, *l, HandClassKind::Suited),
+            [h, l, 'o'] => (*h, *l, HandClassKind::Offsuit),
+            _ => return Err(()),
+        };
+
+        // Los rangos deben escribirse en mayúscula
+        if !high.is_ascii_uppercase() && !high.is_ascii_digit()
+            || !low.is_ascii_uppercase() && !low.is_ascii_digit()
+        {
+            return Err(());
+        }
+
+        let high = rank_from_char(high).ok_or(())?;
+        let low = rank_from_char(low).ok_or(())?;
+        if high < low {
+            return Err(());
+        }
+
+        Self::new(high, low, kind).ok_or(())
+    }
+}
+
+// ============================================================================
+// RANGE ACTION
+// ============================================================================
+
+/// Acción estratégica asociada a una sección de rango.
+///
+/// Ver el mapeo de headers en `docs/specs/range-spec.md`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RangeAction {
+    /// Open raise, 3bet, 4bet, 5bet, squeeze, raise sobre limp.
+    Raise,
+    /// Call simple.
+    Call,
+    /// All-in preflop.
+    AllIn,
+    /// Acción marginal (GTO perfecto), independiente de la frecuencia.
+    Marginal,
+    /// Check (ciega grande ante limp).
+    Check,
+    /// Fold explícito.
+    Fold,
+    /// Manos que no deberían llegar a la situación.
+    NotInRange,
+}
+
+impl RangeAction {
+    /// Mapea el texto de un header de sección a su acción.
+    ///
+    /// El mapeo es case-insensitive y admite el prefijo "Rango de".
+    /// "Marginal" tiene prioridad sobre cualquier otra acción del header.
+    pub fn from_header(header: &str) -> Option<Self> {
+        let lower = header.trim().to_lowercase();
+        let text = lower.strip_prefix("rango de").unwrap_or(&lower).trim();
+
+        if text.starts_with("not in range") {
+            return Some(Self::NotInRange);
+        }
+        if text.starts_with("marginal") {
+            return Some(Self::Marginal);
+        }
+
+        let first_word = text
+            .split(|c: char| c.is_whitespace())
+            .next()
+            .unwrap_or_default();
+
+        match first_word {
+            "fold" => Some(Self::Fold),
+            "check" => Some(Self::Check),
+            "call" | "flat" | "pagar" => Some(Self::Call),
+            "shove" | "push" | "all-in" => Some(Self::AllIn),
+            "all" if text.starts_with("all in") => Some(Self::AllIn),
+            "open" | "rfi" | "raise" | "reraise" | "resubir" | "3bet" | "4bet" | "5bet"
+            | "squeeze" | "rol" => Some(Self::Raise),
+            _ => None,
+        }
+    }
+
+    /// Indica si la acción forma parte de la estrategia jugada.
+    ///
+    /// `Fold` y `NotInRange` no cuentan para la suma de frecuencias.
+    #[inline]
+    pub fn is_played(&self) -> bool {
+        !matches!(self, Self::Fold | Self::NotInRange)
+    }
+}
+
+impl fmt::Display for RangeAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Raise => "RAISE",
+            Self::Call => "CALL",
+            Self::AllIn => "ALL_IN",
+            Self::Marginal => "MARGINAL",
+            Self::Check => "CHECK",
+            Self::Fold => "FOLD",
+            Self::NotInRange => "NOT_IN_RANGE",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+// ============================================================================
+// RANGE
+// ============================================================================
+
+/// Rango preflop: frecuencia (0.0-1.0) para cada una de las 169 clases de mano.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Range {
+    frequencies: [f64; NUM_HAND_CLASSES],
+}
+
+impl Default for Range {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Range {
+    /// Crea un rango vacío (todas las frecuencias a 0).
+    pub fn new() -> Self {
+        Self {
+            frequencies: [0.0; NUM_HAND_CLASSES],
+        }
+    }
+
+    /// Crea un rango con todas las clases de mano a frecuencia 1.0.
+    pub fn full() -> Self {
+        Self {
+            frequencies: [1.0; NUM_HAND_CLASSES],
+        }
+    }
+
+    /// Parsea un rango en notación compacta (`AA:1,AKs:0.8,...`).
+    ///
+    /// Los errores se reportan en línea 1, columna relativa al string.
+    pub fn parse(input: &str) -> Result<Self, crate::dsl_parser::RangeParseError> {
+        crate::dsl_parser::parse_range_notation(input, 1, 1)
+    }
+
+    /// Frecuencia de una clase de mano.
+    #[inline]
+    pub fn frequency(&self, hand: HandClass) -> f64 {
+        self.frequencies[hand.index()]
+    }
+
+    /// Establece la frecuencia de una clase de mano (se recorta a 0.0-1.0).
+    #[inline]
+    pub fn set_frequency(&mut self, hand: HandClass, frequency: f64) {
+        self.frequencies[hand.index()] = frequency.clamp(0.0, 1.0);
+    }
+
+    /// Indica si la mano tiene frecuencia mayor que 0.
+    #[inline]
+    pub fn contains(&self, hand: HandClass) -> bool {
+        self.frequency(hand) > 0.0
+    }
+
+    /// Acceso directo a la matriz de frecuencias (orden de `HandClass::index`).
+    #[inline]
+    pub fn frequencies(&self) -> &[f64; NUM_HAND_CLASSES] {
+        &self.frequencies
+    }
+
+    /// Itera las clases de mano con frecuencia mayor que 0.
+    pub fn iter(&self) -> impl Iterator<Item = (HandClass, f64)> + '_ {
+        self.frequencies
+            .iter()
+            .enumerate()
+            .filter(|(_, &freq)| freq > 0.0)
+            .map(|(index, &freq)| (HandClass::from_index(index), freq))
+    }
+
+    /// Número de clases de mano con frecuencia mayor que 0.
+    pub fn len(&self) -> usize {
+        self.frequencies.iter().filter(|&&freq| freq > 0.0).count()
+    }
+
+    /// Indica si el rango está vacío.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Número de combos ponderado por frecuencia (máximo 1326).
+    pub fn combos(&self) -> f64 {
+        self.iter()
+            .map(|(hand, freq)| hand.num_combos() as f64 * freq)
+            .sum()
+    }
+
+    /// Porcentaje de manos (combos ponderados sobre 1326).
+    pub fn percentage(&self) -> f64 {
+        self.combos() / 1326.0 * 100.0
+    }
+}
+
+impl FromStr for Range {
+    type Err = crate::dsl_parser::RangeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for Range {
+    /// Serializa el rango en notación compacta, en orden de matriz.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for (hand, freq) in self.iter() {
+            if !first {
+                write!(f, ",")?;
+            }
+            write!(f, "{}:{}", hand, freq)?;
+            first = false;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hand_class_parse_and_display() {
+        for text in ["AA", "22", "AKs", "AKo", "T9s", "72o"] {
+            let hand: HandClass = text.parse().unwrap();
+            assert_eq!(hand.to_string(), text);
+        }
+
+        assert!("KAs".parse::<HandClass>().is_err());
+        assert!("AAs".parse::<HandClass>().is_err());
+        assert!("AK".parse::<HandClass>().is_err());
+        assert!("aks".parse::<HandClass>().is_err());
+        assert!("AKx".parse::<HandClass>().is_err());
+        assert!("1Ks".parse::<HandClass>().is_err());
+    }
+
+    #[test]
+    fn test_hand_class_index_roundtrip() {
+        let all: Vec<HandClass> = HandClass::all().collect();
+        assert_eq!(all.len(), NUM_HAND_CLASSES);
+
+        for (index, hand) in all.iter().enumerate() {
+            assert_eq!(hand.index(), index);
+        }
+
+        assert_eq!(HandClass::from_index(0).to_string(), "AA");
+        assert_eq!(HandClass::from_index(1).to_string(), "AKs");
+        assert_eq!(HandClass::from_index(13).to_string(), "AKo");
+        assert_eq!(HandClass::from_index(168).to_string(), "22");
+
+        let total_combos: u32 = all.iter().map(|h| h.num_combos()).sum();
+        assert_eq!(total_combos, 1326);
+    }
+
+    #[test]
+    fn test_range_action_from_header() {
+        let cases = [
+            ("Rango de open raise a 2.6bb", RangeAction::Raise),
+            ("Rango de 4bet a 21bb", RangeAction::Raise),
+            ("Rango de squeeze a 9bb", RangeAction::Raise),
+            ("Rango de rol a 3.4bb", RangeAction::Raise),
+            ("Rango de call", RangeAction::Call),
+            ("Rango de all in", RangeAction::AllIn),
+            ("Rango de all-in", RangeAction::AllIn),
+            ("Rango de marginal call", RangeAction::Marginal),
+            ("Rango de marginal all in", RangeAction::Marginal),
+            ("Rango de marginal open raise", RangeAction::Marginal),
+            ("Rango de fold", RangeAction::Fold),
+            ("Rango de check", RangeAction::Check),
+            (
+                "Not in range (no deberías de haber llegado a esta situación con estas manos)",
+                RangeAction::NotInRange,
+            ),
+        ];
+
+        for (header, expected) in cases {
+            assert_eq!(
+                RangeAction::from_header(header),
+                Some(expected),
+                "{}",
+                header
+            );
+        }
+
+        assert_eq!(RangeAction::from_header("Notas estratégicas"), None);
+    }
+
+    #[test]
+    fn test_range_combos() {
+        let mut range = Range::new();
+        assert!(range.is_empty());
+
+        range.set_frequency("AA".parse().unwrap(), 1.0);
+        range.set_frequency("AKs".parse().unwrap(), 0.5);
+        range.set_frequency("AKo".parse().unwrap(), 1.5);
+
+        assert_eq!(range.len(), 3);
+        assert!((range.combos() - (6.0 + 2.0 + 12.0)).abs() < 1e-9);
+        assert_eq!(range.to_string(), "AA:1,AKs:0.5,AKo:1");
+        assert!((Range::full().combos() - 1326.0).abs() < 1e-9);
+    }
+}
@@ -18,7 +18,7 @@ Uso:
     equity = calculate_equity("AhKd", "QsQh", "", 100000)
 """
 
-from typing import List
+from typing import List, Optional
 
 
 def _get_ffi_module():
@@ -44,12 +44,14 @@ def is_ffi_available() -> bool:
     return _ffi is not None
 
 
-def parse_files(files: List[str]) -> dict:
+def parse_files(files: List[str], heroes: Optional[List[str]] = None) -> dict:
     """
     Parsea archivos de historial Winamax usando el parser Rust.
     
     Args:
         files: Lista de rutas a archivos de historial
+        heroes: Nicknames del héroe ("nick" o "Sala:nick"); sin ellos el
+            héroe es el jugador de "Dealt to"
         
     Returns:
         dict con:
@@ -61,6 +63,7 @@ def parse_files(files: List[str]) -> dict:
     Raises:
         RuntimeError: Si el módulo FFI no está disponible
         IOError: Si no se puede acceder a algún archivo
+        ValueError: Si algún alias de héroe es inválido
     """
     if _ffi is None:
         raise RuntimeError(
@@ -68,7 +71,7 @@ def parse_files(files: List[str]) -> dict:
             "Ejecutar 'maturin develop' en backend/ffi para compilar."
         )
     
-    result = _ffi.parse_winamax_files(files)
+    result = _ffi.parse_winamax_files(files, heroes)
     return {
         "total_hands": result.total_hands,
         "successful_files": result.successful_files,
@@ -77,12 +80,15 @@ def parse_files(files: List[str]) -> dict:
     }
 
 
-def parse_files_with_details(files: List[str]) -> List[dict]:
+def parse_files_with_details(
+    files: List[str], heroes: Optional[List[str]] = None
+) -> List[dict]:
     """
     Parsea archivos y retorna detalles de cada mano.
     
     Args:
         files: Lista de rutas a archivos
+        heroes: Nicknames del héroe ("nick" o "Sala:nick")
         
     Returns:
         Lista de dicts con información de cada mano:
@@ -90,13 +96,13 @@ def parse_files_with_details(files: List[str]) -> List[dict]:
             - timestamp: Fecha/hora
             - table_name: Nombre de la mesa
             - player_count: Número de jugadores
-            - hero_played: Si thesmoy participó
+            - hero_played: Si alguno de los alias del héroe participó
             - total_pot_cents: Pot total en centavos
     """
     if _ffi is None:
         raise RuntimeError("Módulo FFI no disponible")
     
-    summaries = _ffi.parse_winamax_with_details(files)
+    summaries = _ffi.parse_winamax_with_details(files, heroes)
     return [
         {
             "hand_id": s.hand_id,
@@ -133,6 +139,7 @@ def calculate_equity(
             - simulations_run: Simulaciones ejecutadas
             - converged_early: Si convergió temprano
             - standard_error: Error estándar
+            - is_exact: Si el resultado es exacto (enumeración completa)
     """
     if _ffi is None:
         raise RuntimeError("Módulo FFI no disponible")
@@ -145,6 +152,7 @@ def calculate_equity(
         "simulations_run": result.simulations_run,
         "converged_early": result.converged_early,
         "standard_error": result.standard_error,
+        "is_exact": result.is_exact,
         "hero_percent": result.hero_equity * 100.0,
         "villain_percent": result.villain_equity * 100.0,
     }
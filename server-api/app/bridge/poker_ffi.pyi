@@ -7,7 +7,7 @@ estático como mypy, pyright, etc.
 El módulo real es compilado desde Rust con PyO3/maturin.
 """
 
-from typing import List, Callable
+from typing import List, Callable, Optional
 
 
 class PyParseResult:
@@ -49,6 +49,9 @@ class PyEquityResult:
     standard_error: float
     """Error estándar estimado de la equity."""
     
+    is_exact: bool
+    """Si el resultado es exacto (todos los runouts enumerados, sin muestreo)."""
+    
     def __repr__(self) -> str: ...
     
     def hero_percent(self) -> float:
@@ -76,7 +79,7 @@ class PyHandSummary:
     """Número de jugadores."""
     
     hero_played: bool
-    """Si el héroe (thesmoy) participó."""
+    """Si el héroe (nicknames configurados en `heroes`) participó."""
     
     total_pot_cents: int
     """Pot total en centavos."""
@@ -117,11 +120,15 @@ class PyWatcherConfig:
     retry_delay_ms: int
     """Delay inicial para retry en milisegundos."""
     
+    heroes: List[str]
+    """Nicknames del héroe (`nick` o `Sala:nick`)."""
+    
     def __init__(
         self,
         watch_path: str,
         max_retries: int = 3,
-        retry_delay_ms: int = 100
+        retry_delay_ms: int = 100,
+        heroes: Optional[List[str]] = None
     ) -> None: ...
     
     def __repr__(self) -> str: ...
@@ -142,9 +149,33 @@ class PyFileEvent:
     def __repr__(self) -> str: ...
 
 
+class PyQuarantinedFile:
+    """Archivo de historial en cuarentena tras fallar repetidamente."""
+    
+    original_path: str
+    """Ruta original del archivo."""
+    
+    quarantined_path: str
+    """Ruta dentro de la cuarentena."""
+    
+    failures: int
+    """Fallos acumulados al apartarlo."""
+    
+    reason: str
+    """Motivo del último fallo."""
+    
+    quarantined_at: str
+    """Momento en que se apartó (RFC 3339)."""
+    
+    def __repr__(self) -> str: ...
+
+
 # Funciones de parsing
 
-def parse_winamax_files(files: List[str]) -> PyParseResult:
+def parse_winamax_files(
+    files: List[str],
+    heroes: Optional[List[str]] = None
+) -> PyParseResult:
     """
     Parsea archivos de historial Winamax en paralelo.
     
@@ -153,6 +184,8 @@ def parse_winamax_files(files: List[str]) -> PyParseResult:
     
     Args:
         files: Lista de rutas a archivos de historial
+        heroes: Nicknames del héroe (`nick` o `Sala:nick`); sin ellos el
+            héroe es el jugador de `Dealt to`
         
     Returns:
         PyParseResult con estadísticas del procesamiento
@@ -160,19 +193,27 @@ def parse_winamax_files(files: List[str]) -> PyParseResult:
     Raises:
         IOError: Si no se puede acceder a algún archivo (código 101)
         RuntimeError: Si hay errores de parsing (código 102)
+        ValueError: Si algún alias de héroe es inválido (código 103)
     """
     ...
 
 
-def parse_winamax_with_details(files: List[str]) -> List[PyHandSummary]:
+def parse_winamax_with_details(
+    files: List[str],
+    heroes: Optional[List[str]] = None
+) -> List[PyHandSummary]:
     """
     Parsea archivos y retorna resúmenes de manos.
     
     Args:
         files: Lista de rutas a archivos de historial
+        heroes: Nicknames del héroe (`nick` o `Sala:nick`)
         
     Returns:
         Lista de PyHandSummary con información de cada mano
+        
+    Raises:
+        ValueError: Si algún alias de héroe es inválido (código 103)
     """
     ...
 
@@ -223,6 +264,56 @@ def calculate_equity_multiway(
     ...
 
 
+def calculate_equity_vs_range(
+    hero_cards: str,
+    villain_range: str,
+    board: str = "",
+    iterations: int = 100000
+) -> PyEquityResult:
+    """
+    Calcula la equity de una mano contra un rango ponderado del villano.
+    
+    Args:
+        hero_cards: Cartas del héroe (ej: "AhKd")
+        villain_range: Rango HandRangeDSL (ej: "QQ:1,AKs:0.5")
+        board: Cartas comunitarias (ej: "Qh7s2c")
+        iterations: Número de simulaciones
+        
+    Returns:
+        PyEquityResult ponderado por combos (con card removal)
+        
+    Raises:
+        ValueError: Si las cartas o el rango son inválidos (código 201)
+        RuntimeError: Si la simulación excede 500ms (código 202)
+    """
+    ...
+
+
+def calculate_range_vs_range(
+    hero_range: str,
+    villain_range: str,
+    board: str = "",
+    iterations: int = 100000
+) -> PyEquityResult:
+    """
+    Calcula la equity de un rango contra otro rango.
+    
+    Args:
+        hero_range: Rango HandRangeDSL del héroe
+        villain_range: Rango HandRangeDSL del villano
+        board: Cartas comunitarias (ej: "Qh7s2c")
+        iterations: Número de simulaciones
+        
+    Returns:
+        PyEquityResult desde la perspectiva del rango del héroe
+        
+    Raises:
+        ValueError: Si algún rango es inválido o no hay combos compatibles (código 201)
+        RuntimeError: Si la simulación excede 500ms (código 202)
+    """
+    ...
+
+
 # Funciones de utilidad
 
 def is_simd_available() -> bool:
@@ -310,3 +401,46 @@ def start_file_watcher_with_parsing(
     """
     ...
 
+
+# Funciones de cuarentena
+
+def list_quarantined_files(history_dir: str) -> List[PyQuarantinedFile]:
+    """
+    Lista los archivos en cuarentena de un directorio de historiales.
+    
+    Args:
+        history_dir: Directorio de historiales (la cuarentena es su
+            subdirectorio `quarantine/`)
+        
+    Returns:
+        Lista de PyQuarantinedFile
+        
+    Raises:
+        IOError: Si no se puede leer la cuarentena (código 101)
+    """
+    ...
+
+
+def reimport_quarantined_files(
+    history_dir: str,
+    heroes: Optional[List[str]] = None
+) -> PyParseResult:
+    """
+    Vuelve a parsear los archivos en cuarentena (p.ej. tras actualizar el
+    parser).
+    
+    Los que ya se parsean bien vuelven a su ruta original; el resto sigue en
+    cuarentena y cuenta en `failed_files`.
+    
+    Args:
+        history_dir: Directorio de historiales
+        heroes: Nicknames del héroe (`nick` o `Sala:nick`)
+        
+    Returns:
+        PyParseResult con las manos recuperadas
+        
+    Raises:
+        IOError: Si no se puede leer la cuarentena o mover un archivo (código 101)
+        ValueError: Si algún alias de héroe es inválido (código 103)
+    """
+    ...
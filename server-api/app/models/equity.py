@@ -96,6 +96,7 @@ class EquityCalculateResponse(BaseModel):
     simulations_run: int = Field(..., ge=0, description="Simulaciones ejecutadas")
     converged_early: bool = Field(..., description="Si convergió antes de completar")
     standard_error: float = Field(..., ge=0, description="Error estándar estimado")
+    is_exact: bool = Field(False, description="Si el resultado es exacto (enumeración completa)")
     elapsed_ms: Optional[int] = Field(None, ge=0, description="Tiempo de cálculo en ms")
     
     class Config:
@@ -109,6 +110,7 @@ class EquityCalculateResponse(BaseModel):
                 "simulations_run": 50000,
                 "converged_early": False,
                 "standard_error": 0.002,
+                "is_exact": False,
                 "elapsed_ms": 85
             }
         }
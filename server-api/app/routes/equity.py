@@ -96,6 +96,7 @@ async def calculate_hand_equity(request: EquityCalculateRequest) -> EquityCalcul
             simulations_run=result["simulations_run"],
             converged_early=result["converged_early"],
             standard_error=result["standard_error"],
+            is_exact=result["is_exact"],
             elapsed_ms=elapsed_ms,
         )
     
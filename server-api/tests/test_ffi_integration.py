@@ -42,6 +42,7 @@ def mock_ffi():
     mock_equity_result.simulations_run = 100000
     mock_equity_result.converged_early = False
     mock_equity_result.standard_error = 0.001
+    mock_equity_result.is_exact = False
     mock_module.calculate_equity.return_value = mock_equity_result
     
     # Mock calculate_equity_multiway